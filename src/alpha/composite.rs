@@ -0,0 +1,52 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+macro_rules! impl_composite {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::Alpha<::polywave::rgb::SRgb<$tys>> {
+				/// Composites the colour over a background.
+				///
+				/// This uses the Porter--Duff *source-over* operator on the gamma-encoded channels, i.e. in the same way that web browsers blend colours.
+				/// If the background is opaque, then so is the result.
+				#[must_use]
+				pub const fn composite_over(self, background: Self) -> Self {
+					let (colour, alpha) = self.detach();
+					let (background, background_alpha) = background.detach();
+
+					let (red, green, blue) = colour.get();
+					let (background_red, background_green, background_blue) = background.get();
+
+					let background_alpha = background_alpha * (1.0 - alpha);
+
+					let result_alpha = alpha + background_alpha;
+
+					if result_alpha == 0.0 {
+						let colour = ::polywave::rgb::SRgb::new(0.0, 0.0, 0.0);
+						return Self::attach(colour, 0.0);
+					}
+
+					let red   = (red   * alpha + background_red   * background_alpha) / result_alpha;
+					let green = (green * alpha + background_green * background_alpha) / result_alpha;
+					let blue  = (blue  * alpha + background_blue  * background_alpha) / result_alpha;
+
+					let colour = ::polywave::rgb::SRgb::new(red, green, blue);
+					Self::attach(colour, result_alpha)
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_composite!(f16);
+
+impl_composite!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_composite!(f128);
@@ -6,7 +6,9 @@
 // can obtain one at:
 // <https://mozilla.org/MPL/2.0/>.
 
-use crate::{BalancedColour, Colour, DefinedGamut};
+mod composite;
+
+use crate::{BalancedColour, Colour, DefinedGamut, NormalisedComponent};
 
 use core::mem::{ManuallyDrop, offset_of};
 
@@ -84,7 +86,36 @@ unsafe impl<T: BalancedColour> BalancedColour for Alpha<T> {
 	type Component = T::Component;
 }
 
-impl<T: BalancedColour + DefinedGamut> DefinedGamut for Alpha<T> { }
+impl<T> DefinedGamut for Alpha<T>
+where
+	T:            BalancedColour + DefinedGamut<Scalar = T::Component>,
+	T::Component: NormalisedComponent,
+{
+	type Scalar = T::Component;
+
+	#[inline]
+	fn is_in_gamut(&self, epsilon: Self::Scalar) -> bool {
+		self.colour.is_in_gamut(epsilon) && self.alpha.is_normalised(epsilon)
+	}
+
+	#[inline]
+	fn clip(self) -> Self {
+		let (colour, alpha) = self.detach();
+
+		let colour = colour.clip();
+		let alpha  = alpha.clamp_normalised();
+
+		Self::attach(colour, alpha)
+	}
+
+	#[inline]
+	fn gamut_distance(&self) -> Self::Scalar {
+		let colour = self.colour.gamut_distance();
+		let alpha  = self.alpha.normalised_distance();
+
+		if alpha > colour { alpha } else { colour }
+	}
+}
 
 // SAFETY: `BalancedColour` guarantees that there
 // will be no extra padding between `colour` and
@@ -0,0 +1,45 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+/// A language of generated colour constants.
+///
+/// See [`ColourConstants`](crate::codegen::ColourConstants) for the generated code.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CodeLanguage {
+	/// CSS custom properties on `:root`, e.g. `--brand-primary: #663399;`.
+	Css,
+
+	/// SCSS variables, e.g. `$brand-primary: #663399;`.
+	Scss,
+
+	/// Swift static properties on `UIColor`, e.g. `static let brandPrimary = UIColor(red: 0.4, ...)`.
+	UiKit,
+
+	/// Swift static properties on SwiftUI's `Color`, e.g. `static let brandPrimary = Color(.sRGB, red: 0.4, ...)`.
+	SwiftUi,
+
+	/// Kotlin (Jetpack Compose) values, e.g. `val BrandPrimary = Color(0xFF663399)`.
+	Kotlin,
+
+	/// C header constants in the order `0xRRGGBBAA`, e.g. `static const uint32_t BRAND_PRIMARY = 0x663399FF;`.
+	C,
+
+	/// Rust constants of [`Html`](crate::www::Html), e.g. `pub const BRAND_PRIMARY: Html = Html::new(0x66, 0x33, 0x99, 0xFF);`.
+	Rust,
+}
+
+impl CodeLanguage {
+	/// Checks whether the language supports wide-gamut colours.
+	///
+	/// Colours outside of sRGB are written in Display P3 for these languages and are otherwise gamut-mapped to sRGB.
+	#[inline]
+	#[must_use]
+	pub const fn is_wide_gamut(self) -> bool {
+		matches!(self, Self::Css | Self::Scss | Self::UiKit | Self::SwiftUi)
+	}
+}
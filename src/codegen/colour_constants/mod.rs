@@ -0,0 +1,343 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::DefinedGamut;
+use crate::codegen::CodeLanguage;
+use crate::lab::OkLab;
+use crate::rgb::{DisplayP3, SRgb};
+use crate::www::{CssColour, Html};
+
+use alloc::format;
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+
+/// The tolerance of colours lying within sRGB.
+///
+/// This is half a step of eight-bit channels, i.e. colours within the tolerance are quantised the same as if they had been clipped.
+const S_RGB_TOLERANCE: f64 = 0.5 / 255.0;
+
+/// The indentation of generated code.
+const INDENT: &str = "    ";
+
+/// A generator of colour constants.
+///
+/// This type generates a complete source file that declares a constant for each named colour, in the order they were given.
+/// Names are converted to the conventional case of the language (e.g. `brand primary` becomes `--brand-primary` in CSS and `BRAND_PRIMARY` in C), with words separated by non-alphanumeric characters or lowercase-to-uppercase transitions.
+/// Only ASCII letters and digits are kept, and identifiers that would start with a digit are prefixed with an underscore.
+///
+/// See the [module documentation](crate::codegen) for how colours are converted.
+///
+/// # Examples
+///
+/// ```
+/// use polywave::codegen::{CodeLanguage, ColourConstants};
+/// use polywave::www::Html;
+///
+/// let colours = [("accentColour", Html::from_u32(0x1E90FF80).into())];
+///
+/// let css   = ColourConstants::new(CodeLanguage::Css,   &colours).to_string();
+/// let swift = ColourConstants::new(CodeLanguage::UiKit, &colours).to_string();
+/// let c     = ColourConstants::new(CodeLanguage::C,     &colours).to_string();
+///
+/// assert_eq!(css, ":root {\n    --accent-colour: #1e90ff80;\n}\n");
+/// assert!(swift.contains("static let accentColour = UIColor(red: 0.1176, green: 0.5647, blue: 1, alpha: 0.502)\n"));
+/// assert!(c.ends_with("static const uint32_t ACCENT_COLOUR = 0x1E90FF80;\n"));
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct ColourConstants<'a> {
+	language: CodeLanguage,
+	colours:  &'a [(&'a str, CssColour)],
+}
+
+impl<'a> ColourConstants<'a> {
+	/// Constructs a new generator.
+	#[inline(always)]
+	pub const fn new(language: CodeLanguage, colours: &'a [(&'a str, CssColour)]) -> Self {
+		Self { language, colours }
+	}
+
+	/// Retrieves the language of the generated code.
+	#[inline(always)]
+	#[must_use]
+	pub const fn language(self) -> CodeLanguage {
+		self.language
+	}
+
+	/// Retrieves the named colours.
+	#[inline(always)]
+	#[must_use]
+	pub const fn colours(self) -> &'a [(&'a str, CssColour)] {
+		self.colours
+	}
+}
+
+impl Display for ColourConstants<'_> {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		let header = match self.language {
+			CodeLanguage::Css     => ":root {\n",
+			CodeLanguage::Scss    => "",
+			CodeLanguage::UiKit   => "import UIKit\n\nextension UIColor {\n",
+			CodeLanguage::SwiftUi => "import SwiftUI\n\nextension Color {\n",
+			CodeLanguage::Kotlin  => "import androidx.compose.ui.graphics.Color\n\n",
+			CodeLanguage::C       => "#pragma once\n\n#include <stdint.h>\n\n",
+			CodeLanguage::Rust    => "use polywave::www::Html;\n\n",
+		};
+
+		f.write_str(header)?;
+
+		for &(name, colour) in self.colours {
+			let colour = ResolvedColour::new(colour, self.language.is_wide_gamut());
+
+			write_constant(f, self.language, name, colour)?;
+		}
+
+		if matches!(self.language, CodeLanguage::Css | CodeLanguage::UiKit | CodeLanguage::SwiftUi) {
+			f.write_str("}\n")?;
+		}
+
+		Ok(())
+	}
+}
+
+/// A colour converted for code generation.
+#[derive(Clone, Copy, Debug)]
+enum ResolvedColour {
+	/// An eight-bit sRGB colour.
+	SRgb(Html),
+
+	/// A Display P3 colour outside of sRGB, with alpha.
+	DisplayP3(DisplayP3<f64>, f64),
+}
+
+impl ResolvedColour {
+	/// Converts a colour, gamut-mapping it to sRGB or (if `wide_gamut` is `true`) Display P3 if it lies outside of sRGB.
+	#[must_use]
+	fn new(colour: CssColour, wide_gamut: bool) -> Self {
+		let (colour, alpha) = colour.to_s_rgb().detach();
+
+		let alpha = alpha.clamp(0.0, 1.0);
+
+		let to_html = |colour: SRgb<f64>| {
+			let (red, green, blue) = colour.to_u8().get();
+
+			// NaN becomes zero.
+			Html::new(red, green, blue, (alpha * 255.0 + 0.5) as u8)
+		};
+
+		if colour.is_in_gamut(S_RGB_TOLERANCE) {
+			return Self::SRgb(to_html(colour));
+		}
+
+		let colour = OkLab::<f64>::from_s_rgb(colour);
+
+		if wide_gamut {
+			Self::DisplayP3(colour.map_to_gamut(), alpha)
+		} else {
+			Self::SRgb(to_html(colour.map_to_gamut()))
+		}
+	}
+
+	/// Retrieves the channels and alpha in the range `0` to `1`.
+	#[must_use]
+	fn channels(self) -> [f64; 0x4] {
+		match self {
+			Self::SRgb(colour) => {
+				let (red, green, blue, alpha) = colour.get();
+				[red, green, blue, alpha].map(|channel| f64::from(channel) / 255.0)
+			}
+
+			Self::DisplayP3(colour, alpha) => {
+				let (red, green, blue) = colour.get();
+				[red, green, blue, alpha]
+			}
+		}
+	}
+}
+
+/// Writes the declaration of a single constant.
+fn write_constant(f: &mut Formatter, language: CodeLanguage, name: &str, colour: ResolvedColour) -> fmt::Result {
+	let [red, green, blue, alpha] = colour.channels().map(Decimal);
+
+	match (language, colour) {
+		(CodeLanguage::Css | CodeLanguage::Scss, _) => {
+			let (prefix, case) = if language == CodeLanguage::Css {
+				("    --", Case::KebabCss)
+			} else {
+				("$", Case::Kebab)
+			};
+
+			write!(f, "{prefix}{}: ", Identifier { name, case })?;
+
+			match colour {
+				ResolvedColour::SRgb(colour) => write!(f, "{}", colour.display().lowercase(true).omit_opaque_alpha(true))?,
+
+				ResolvedColour::DisplayP3(..) if alpha.0 >= 1.0 => write!(f, "color(display-p3 {red} {green} {blue})")?,
+
+				ResolvedColour::DisplayP3(..) => write!(f, "color(display-p3 {red} {green} {blue} / {alpha})")?,
+			}
+
+			writeln!(f, ";")
+		}
+
+		(CodeLanguage::UiKit, _) => {
+			let name = Identifier { name, case: Case::LowerCamel };
+
+			let initialiser = if let ResolvedColour::SRgb(_) = colour { "red" } else { "displayP3Red" };
+
+			writeln!(f, "{INDENT}static let {name} = UIColor({initialiser}: {red}, green: {green}, blue: {blue}, alpha: {alpha})")
+		}
+
+		(CodeLanguage::SwiftUi, _) => {
+			let name = Identifier { name, case: Case::LowerCamel };
+
+			let space = if let ResolvedColour::SRgb(_) = colour { "sRGB" } else { "displayP3" };
+
+			writeln!(f, "{INDENT}static let {name} = Color(.{space}, red: {red}, green: {green}, blue: {blue}, opacity: {alpha})")
+		}
+
+		// Only wide-gamut languages are given Display P3 colours.
+		(_, ResolvedColour::DisplayP3(..)) => unreachable!(),
+
+		(CodeLanguage::Kotlin, ResolvedColour::SRgb(colour)) => {
+			let name = Identifier { name, case: Case::UpperCamel };
+
+			writeln!(f, "val {name} = Color(0x{:08X})", colour.to_u32().rotate_right(0x8))
+		}
+
+		(CodeLanguage::C, ResolvedColour::SRgb(colour)) => {
+			let name = Identifier { name, case: Case::ScreamingSnake };
+
+			writeln!(f, "static const uint32_t {name} = 0x{:08X};", colour.to_u32())
+		}
+
+		(CodeLanguage::Rust, ResolvedColour::SRgb(colour)) => {
+			let name = Identifier { name, case: Case::ScreamingSnake };
+
+			let (red, green, blue, alpha) = colour.get();
+
+			writeln!(f, "pub const {name}: Html = Html::new(0x{red:02X}, 0x{green:02X}, 0x{blue:02X}, 0x{alpha:02X});")
+		}
+	}
+}
+
+/// A number written with at most four decimals and without trailing zeroes.
+#[derive(Clone, Copy, Debug)]
+struct Decimal(f64);
+
+impl Display for Decimal {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		let s = format!("{:.4}", self.0);
+		let s = s.trim_end_matches('0').trim_end_matches('.');
+
+		match s {
+			"-0" => f.write_str("0"),
+
+			_ => f.write_str(s),
+		}
+	}
+}
+
+/// The case of an identifier.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Case {
+	/// `brand-primary`.
+	Kebab,
+
+	/// `brand-primary`, but allowed to start with a digit.
+	KebabCss,
+
+	/// `brandPrimary`.
+	LowerCamel,
+
+	/// `BrandPrimary`.
+	UpperCamel,
+
+	/// `BRAND_PRIMARY`.
+	ScreamingSnake,
+}
+
+/// A name converted to an identifier.
+#[derive(Clone, Copy, Debug)]
+struct Identifier<'a> {
+	name: &'a str,
+	case: Case,
+}
+
+impl Display for Identifier<'_> {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		let words = words(self.name);
+
+		let starts_with_digit = words
+			.first()
+			.is_none_or(|word| word.starts_with(|c: char| c.is_ascii_digit()));
+
+		if starts_with_digit && self.case != Case::KebabCss {
+			f.write_str("_")?;
+		}
+
+		for (index, word) in words.into_iter().enumerate() {
+			let separator = match self.case {
+				Case::Kebab | Case::KebabCss => "-",
+				Case::ScreamingSnake         => "_",
+
+				_ => "",
+			};
+
+			if index != 0x0 {
+				f.write_str(separator)?;
+			}
+
+			let capitalise = match self.case {
+				Case::LowerCamel => index != 0x0,
+				Case::UpperCamel => true,
+
+				_ => false,
+			};
+
+			for (position, c) in word.chars().enumerate() {
+				let c = if self.case == Case::ScreamingSnake || (capitalise && position == 0x0) {
+					c.to_ascii_uppercase()
+				} else {
+					c.to_ascii_lowercase()
+				};
+
+				write!(f, "{c}")?;
+			}
+		}
+
+		Ok(())
+	}
+}
+
+/// Splits a name into words.
+///
+/// Words are separated by any character that is not an ASCII letter or digit, and by transitions from lowercase letters or digits to uppercase letters.
+#[must_use]
+fn words(name: &str) -> Vec<&str> {
+	let mut words = Vec::new();
+
+	for part in name.split(|c: char| !c.is_ascii_alphanumeric()) {
+		let mut start = 0x0;
+
+		for (index, pair) in part.as_bytes().windows(0x2).enumerate() {
+			let [previous, c] = [pair[0x0], pair[0x1]];
+
+			if (previous.is_ascii_lowercase() || previous.is_ascii_digit()) && c.is_ascii_uppercase() {
+				words.push(&part[start..=index]);
+				start = index + 0x1;
+			}
+		}
+
+		if start < part.len() {
+			words.push(&part[start..]);
+		}
+	}
+
+	words
+}
@@ -0,0 +1,32 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+//! Code generation of colour constants.
+//!
+//! Named colours can be written as constant declarations in a number of [languages](CodeLanguage), which lets design tokens be shared between platforms without hand-formatting their values:
+//!
+//! ```
+//! use polywave::codegen::{CodeLanguage, ColourConstants};
+//! use polywave::www::Html;
+//!
+//! let colours = [("brand primary", Html::REBECCA_PURPLE.into())];
+//!
+//! let kotlin = ColourConstants::new(CodeLanguage::Kotlin, &colours).to_string();
+//!
+//! assert!(kotlin.ends_with("val BrandPrimary = Color(0xFF663399)\n"));
+//! ```
+//!
+//! Colours are converted to eight-bit sRGB, after [gamut mapping](crate::lch::OkLch::map_to_gamut) if needed.
+//! For languages that support wide-gamut colours (i.e. CSS, SCSS, and Swift), colours outside of sRGB are instead written in Display P3.
+
+mod code_language;
+mod colour_constants;
+mod test;
+
+pub use code_language::CodeLanguage;
+pub use colour_constants::ColourConstants;
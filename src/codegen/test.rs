@@ -0,0 +1,61 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+#![cfg(test)]
+
+use crate::Alpha;
+use crate::codegen::{CodeLanguage, ColourConstants};
+use crate::rgb::DisplayP3;
+use crate::www::{CssColour, Html};
+
+use alloc::string::ToString;
+
+#[test]
+fn test_colour_constants() {
+	let colours = [
+		("brand primary", CssColour::from(Html::REBECCA_PURPLE)),
+		("HTMLRed",       CssColour::from(Html::new(0xFF, 0x00, 0x00, 0x80))),
+		("2xl-surface",   CssColour::from(Html::BLACK)),
+		("vividGreen",    CssColour::DisplayP3(Alpha::attach(DisplayP3::new(0.0, 1.0, 0.0), 1.0))),
+	];
+
+	let generate = |language: CodeLanguage| ColourConstants::new(language, &colours).to_string();
+
+	assert_eq!(
+		generate(CodeLanguage::Css),
+		":root {\n    --brand-primary: #663399;\n    --htmlred: #ff000080;\n    --2xl-surface: #000000;\n    --vivid-green: color(display-p3 0 1 0);\n}\n",
+	);
+
+	assert_eq!(
+		generate(CodeLanguage::Scss),
+		"$brand-primary: #663399;\n$htmlred: #ff000080;\n$_2xl-surface: #000000;\n$vivid-green: color(display-p3 0 1 0);\n",
+	);
+
+	assert_eq!(
+		generate(CodeLanguage::UiKit),
+		"import UIKit\n\nextension UIColor {\n    static let brandPrimary = UIColor(red: 0.4, green: 0.2, blue: 0.6, alpha: 1)\n    static let htmlred = UIColor(red: 1, green: 0, blue: 0, alpha: 0.502)\n    static let _2xlSurface = UIColor(red: 0, green: 0, blue: 0, alpha: 1)\n    static let vividGreen = UIColor(displayP3Red: 0, green: 1, blue: 0, alpha: 1)\n}\n",
+	);
+
+	assert!(generate(CodeLanguage::SwiftUi).contains("    static let vividGreen = Color(.displayP3, red: 0, green: 1, blue: 0, opacity: 1)\n"));
+
+	let kotlin = generate(CodeLanguage::Kotlin);
+
+	assert!(kotlin.contains("val BrandPrimary = Color(0xFF663399)\n"));
+	assert!(kotlin.contains("val Htmlred = Color(0x80FF0000)\n"));
+	assert!(kotlin.contains("val _2xlSurface = Color(0xFF000000)\n"));
+
+	// Out-of-gamut colours are mapped into sRGB.
+	assert!(kotlin.contains("val VividGreen = Color(0xFF00FB29)\n"));
+
+	assert_eq!(
+		generate(CodeLanguage::C),
+		"#pragma once\n\n#include <stdint.h>\n\nstatic const uint32_t BRAND_PRIMARY = 0x663399FF;\nstatic const uint32_t HTMLRED = 0xFF000080;\nstatic const uint32_t _2XL_SURFACE = 0x000000FF;\nstatic const uint32_t VIVID_GREEN = 0x00FB29FF;\n",
+	);
+
+	assert!(generate(CodeLanguage::Rust).contains("pub const BRAND_PRIMARY: Html = Html::new(0x66, 0x33, 0x99, 0xFF);\n"));
+}
@@ -0,0 +1,26 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::Colour;
+
+/// Denotes a colour with a measurable (perceptual) difference.
+///
+/// Each implementor selects the metric that is most suitable for its colour space.
+/// For [`CieLab`](crate::lab::CieLab), this is CIEDE2000 with unit weights, and for [`OkLab`](crate::lab::OkLab), this is the Euclidean distance ΔE<sub>OK</sub>.
+///
+/// Other metrics may be available as inherent methods on the respective types.
+pub trait ColourDifference: Colour {
+	/// The type used to express differences.
+	type Difference;
+
+	/// Computes the difference between two colours.
+	///
+	/// A difference of zero denotes that the colours are (perceptually) identical.
+	#[must_use]
+	fn difference(self, other: Self) -> Self::Difference;
+}
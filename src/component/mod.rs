@@ -6,6 +6,8 @@
 // can obtain one at:
 // <https://mozilla.org/MPL/2.0/>.
 
+use core::mem::zeroed;
+
 /// Denotes a type suitable for use as a colour component.
 ///
 /// Note that not all components types are necessarily transformable; most colour spaces and formats require some form of floating- or fixed-point arithmetic for transformations to be at least be somewhat accurate.
@@ -21,6 +23,15 @@
 /// Additionally, the component must also have a bit state for denoting `0` (zero), and this state must only contain null bits.
 pub unsafe trait Component: Copy + Sized { }
 
+/// Constructs a component denoting zero.
+#[inline(always)]
+#[must_use]
+pub(crate) const fn zero<T: Component>() -> T {
+	// SAFETY: `Component` guarantees that the all-
+	// zero bit pattern denotes zero.
+	unsafe { zeroed() }
+}
+
 macro_rules! impl_component {
 	{
 		$(
@@ -6,7 +6,7 @@
 // can obtain one at:
 // <https://mozilla.org/MPL/2.0/>.
 
-use crate::Colour;
+use crate::{Colour, Component};
 
 /// Denotes a colour with a well-defined gamut.
 ///
@@ -15,4 +15,34 @@ use crate::Colour;
 ///
 /// Types such as [`Rgb`](crate::rgb::Rgb) do not define this trait as their gamuts are unspecified.
 /// Note that colours that do define this trait may still be further transformed before being displayed.
-pub trait DefinedGamut: Colour { }
+///
+/// # Gamut operations
+///
+/// This trait additionally provides methods for checking and enforcing the gamut in the colour's own coordinates.
+/// Colours such as [`SRgb`](crate::rgb::SRgb) or [`OkHsv`](crate::hsv::OkHsv) are only valid inside of a bounded volume, and their methods check against this volume.
+///
+/// Colour spaces such as [`CieXyz`](crate::xyz::CieXyz) or [`OkLab`](crate::lab::OkLab) are, on the other hand, not themselves bounded by any display gamut, and every value of these is considered to lie within the gamut.
+pub trait DefinedGamut: Colour + Sized {
+	/// The type used for measuring distances to the gamut.
+	type Scalar: Component;
+
+	/// Checks whether the colour lies within its gamut.
+	///
+	/// The gamut is extended by `epsilon` in each channel, which allows for small rounding errors from prior conversions.
+	#[must_use]
+	fn is_in_gamut(&self, epsilon: Self::Scalar) -> bool;
+
+	/// Clips the colour to its gamut.
+	///
+	/// This is done by clamping each channel separately, and colours that are already in gamut are returned unchanged.
+	/// Note that this does not preserve hue nor luminance -- see [`OkLch::map_to_gamut`](crate::lch::OkLch::map_to_gamut) for a perceptual alternative.
+	#[must_use]
+	fn clip(self) -> Self;
+
+	/// Computes the distance from the colour to its gamut.
+	///
+	/// This is the largest distance by which any single channel lies outside of its range (i.e. the Chebyshev distance to the [clipped](Self::clip) colour).
+	/// If the colour lies within its gamut, then this is zero.
+	#[must_use]
+	fn gamut_distance(&self) -> Self::Scalar;
+}
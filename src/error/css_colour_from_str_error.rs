@@ -0,0 +1,58 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::error::HtmlFromStrError;
+
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// A [CSS colour](crate::www::CssColour) could not be parsed.
+#[derive(Debug, Eq, PartialEq)]
+pub enum CssColourFromStrError {
+	/// A hexadecimal or named colour was invalid.
+	InvalidHtml(HtmlFromStrError),
+
+	/// A colour function was otherwise malformed.
+	InvalidSyntax,
+
+	/// A `color()` function named an unknown colour space.
+	UnknownColourSpace,
+
+	/// A colour function was unknown.
+	UnknownFunction,
+}
+
+impl Error for CssColourFromStrError {
+	#[inline]
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match *self {
+			Self::InvalidHtml(ref e) => Some(e),
+
+			_ => None,
+		}
+	}
+}
+
+impl Display for CssColourFromStrError {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::InvalidHtml(ref e)
+			=> write!(f, "css colour is invalid: {e}"),
+
+			Self::InvalidSyntax
+			=> write!(f, "css colour function is malformed"),
+
+			Self::UnknownColourSpace
+			=> write!(f, "css `color()` function names unknown colour space"),
+
+			Self::UnknownFunction
+			=> write!(f, "css colour function is unknown"),
+		}
+	}
+}
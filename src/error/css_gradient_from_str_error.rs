@@ -0,0 +1,64 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::error::CssColourFromStrError;
+
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// A [CSS gradient](crate::www::CssGradient) could not be parsed.
+#[derive(Debug, Eq, PartialEq)]
+pub enum CssGradientFromStrError {
+	/// A colour stop had an invalid colour.
+	InvalidColour(CssColourFromStrError),
+
+	/// A CSS gradient was otherwise malformed.
+	InvalidSyntax,
+
+	/// A CSS gradient had fewer than two colour stops.
+	TooFewStops,
+
+	/// A colour-interpolation method named an unknown colour space.
+	UnknownColourSpace,
+
+	/// A CSS gradient used an unknown gradient function.
+	UnknownFunction,
+}
+
+impl Error for CssGradientFromStrError {
+	#[inline]
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match *self {
+			Self::InvalidColour(ref e) => Some(e),
+
+			_ => None,
+		}
+	}
+}
+
+impl Display for CssGradientFromStrError {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::InvalidColour(ref e)
+			=> write!(f, "css gradient has invalid colour stop: {e}"),
+
+			Self::InvalidSyntax
+			=> write!(f, "css gradient is malformed"),
+
+			Self::TooFewStops
+			=> write!(f, "css gradient has fewer than two colour stops"),
+
+			Self::UnknownColourSpace
+			=> write!(f, "css colour-interpolation method names unknown colour space"),
+
+			Self::UnknownFunction
+			=> write!(f, "css gradient function is unknown"),
+		}
+	}
+}
@@ -0,0 +1,67 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use alloc::string::String;
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// A [design token](crate::www::DesignTokens) could not be read, written, or resolved.
+///
+/// All paths are given as the dot-separated names of the groups and the token, e.g. `colour.brand.primary`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DesignTokenError {
+	/// A colour token had a malformed value.
+	InvalidColour {
+		/// The path of the token.
+		path: String,
+	},
+
+	/// A group or token was malformed or had an invalid name.
+	///
+	/// This is also returned when writing tokens whose paths conflict, i.e. where one token is the group of another.
+	InvalidGroup {
+		/// The path of the group or token.
+		path: String,
+	},
+
+	/// An alias referenced a token that does not exist or is not a colour token.
+	UnknownReference {
+		/// The path of the alias.
+		path: String,
+
+		/// The path of the referenced token.
+		reference: String,
+	},
+
+	/// An alias referenced itself, either directly or through other aliases.
+	CircularReference {
+		/// The path of the alias.
+		path: String,
+	},
+}
+
+impl Error for DesignTokenError { }
+
+impl Display for DesignTokenError {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::InvalidColour { ref path }
+			=> write!(f, "design token `{path}` has malformed colour value"),
+
+			Self::InvalidGroup { ref path }
+			=> write!(f, "design token or group `{path}` is malformed"),
+
+			Self::UnknownReference { ref path, ref reference }
+			=> write!(f, "design token `{path}` references unknown colour token `{reference}`"),
+
+			Self::CircularReference { ref path }
+			=> write!(f, "design token `{path}` has circular reference"),
+		}
+	}
+}
@@ -1,19 +1,81 @@
 // Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
 
 use core::error::Error;
 use core::fmt::{self, Display, Formatter};
+use core::ops::Range;
 
 /// An [HTML colour](crate::www::Html) could not be parsed.
-#[derive(Debug, Eq, PartialEq)]
+///
+/// All offsets and spans are given in bytes relative to the start of the parsed string, including any leading whitespace.
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum HtmlFromStrError {
+	/// The string was empty or consisted only of whitespace.
+	Empty,
+
 	/// An HTML hexadecimal colour was missing a hash `#`.
-	MissingHash,
+	MissingHash {
+		/// The offset at which the hash was expected.
+		offset: usize,
+	},
+
+	/// An HTML colour contained an invalid character.
+	///
+	/// For hexadecimal colours, this is any character that is not a hexadecimal digit.
+	/// For named colours, this is any character that is not a letter.
+	InvalidCharacter {
+		/// The offset of the character.
+		offset: usize,
+
+		/// The invalid character.
+		character: char,
+	},
 
-	/// An HTML colour had an otherwise unknown format.
-	UnknownFormat,
+	/// An HTML hexadecimal colour had an invalid number of digits.
+	///
+	/// Valid colours have exactly three, four, six, or eight digits (see [`EXPECTED_DIGIT_COUNTS`](Self::EXPECTED_DIGIT_COUNTS)).
+	InvalidLength {
+		/// The span of the digits.
+		span: Range<usize>,
+
+		/// The number of digits.
+		count: usize,
+	},
 
 	/// An HTML named colour was unknown.
-	UnknownName,
+	UnknownName {
+		/// The span of the name.
+		span: Range<usize>,
+
+		/// The most similar known name, if any is sufficiently similar.
+		suggestion: Option<&'static str>,
+	},
+}
+
+impl HtmlFromStrError {
+	/// The valid numbers of digits in HTML hexadecimal colours.
+	pub const EXPECTED_DIGIT_COUNTS: [usize; 0x4] = [0x3, 0x4, 0x6, 0x8];
+
+	/// Retrieves the span of the problem.
+	///
+	/// For errors that concern a single position, the span is empty or covers a single character.
+	#[must_use]
+	pub fn span(&self) -> Range<usize> {
+		match *self {
+			Self::Empty => 0x0..0x0,
+
+			Self::MissingHash { offset } => offset..offset,
+
+			Self::InvalidCharacter { offset, character } => offset..offset + character.len_utf8(),
+
+			Self::InvalidLength { ref span, .. } | Self::UnknownName { ref span, .. } => span.clone(),
+		}
+	}
 }
 
 impl Error for HtmlFromStrError { }
@@ -22,14 +84,23 @@ impl Display for HtmlFromStrError {
 	#[inline]
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
 		match *self {
-			Self::MissingHash
-			=> write!(f, "html hexadecimal colour is missing prefixed hash `#`"),
+			Self::Empty
+			=> write!(f, "html colour is empty"),
+
+			Self::MissingHash { offset }
+			=> write!(f, "html hexadecimal colour is missing prefixed hash `#` at offset {offset}"),
+
+			Self::InvalidCharacter { offset, character }
+			=> write!(f, "html colour contains invalid character {character:?} at offset {offset}"),
+
+			Self::InvalidLength { ref span, count }
+			=> write!(f, "html hexadecimal colour at offset {} has {count} digit(s), expected 3, 4, 6, or 8", span.start),
 
-			Self::UnknownFormat
-			=> write!(f, "html colour is of an otherwise unknown format"),
+			Self::UnknownName { ref span, suggestion: Some(suggestion) }
+			=> write!(f, "html named colour at offset {} is unknown; did you mean `{suggestion}`?", span.start),
 
-			Self::UnknownName
-			=> write!(f, "html named colour is unknown"),
+			Self::UnknownName { ref span, suggestion: None }
+			=> write!(f, "html named colour at offset {} is unknown", span.start),
 		}
 	}
 }
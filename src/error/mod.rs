@@ -8,6 +8,26 @@
 
 //! Error types.
 
+mod css_colour_from_str_error;
+#[cfg(feature = "alloc")]
+mod css_gradient_from_str_error;
+#[cfg(all(feature = "alloc", feature = "serde"))]
+mod design_token_error;
 mod html_from_str_error;
+#[cfg(feature = "alloc")]
+mod palette_error;
+#[cfg(feature = "alloc")]
+mod terminal_scheme_error;
+mod x11_colour_from_str_error;
 
+pub use css_colour_from_str_error::CssColourFromStrError;
+#[cfg(feature = "alloc")]
+pub use css_gradient_from_str_error::CssGradientFromStrError;
+#[cfg(all(feature = "alloc", feature = "serde"))]
+pub use design_token_error::DesignTokenError;
 pub use html_from_str_error::HtmlFromStrError;
+#[cfg(feature = "alloc")]
+pub use palette_error::PaletteError;
+#[cfg(feature = "alloc")]
+pub use terminal_scheme_error::TerminalSchemeError;
+pub use x11_colour_from_str_error::X11ColourFromStrError;
@@ -0,0 +1,73 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// A [palette](crate::palette::Palette) could not be read or written.
+///
+/// All lines are one-based, and all offsets are given in bytes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PaletteError {
+	/// A colour of a textual palette was malformed.
+	InvalidColour {
+		/// The line of the colour.
+		line: usize,
+	},
+
+	/// A binary palette was malformed or truncated.
+	InvalidData {
+		/// The offset of the problem.
+		offset: usize,
+	},
+
+	/// A textual palette was otherwise malformed.
+	InvalidSyntax {
+		/// The line of the problem.
+		line: usize,
+	},
+
+	/// A palette had more entries than its format allows.
+	TooManyEntries {
+		/// The number of entries.
+		count: usize,
+
+		/// The maximum number of entries of the format.
+		maximum: usize,
+	},
+
+	/// An entry used a colour model that is not supported by the format.
+	UnsupportedColour {
+		/// The index of the entry.
+		index: usize,
+	},
+}
+
+impl Error for PaletteError { }
+
+impl Display for PaletteError {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::InvalidColour { line }
+			=> write!(f, "palette has malformed colour on line {line}"),
+
+			Self::InvalidData { offset }
+			=> write!(f, "palette is malformed or truncated at offset {offset}"),
+
+			Self::InvalidSyntax { line }
+			=> write!(f, "palette is malformed on line {line}"),
+
+			Self::TooManyEntries { count, maximum }
+			=> write!(f, "palette has {count} entries, but format allows at most {maximum}"),
+
+			Self::UnsupportedColour { index }
+			=> write!(f, "palette entry {index} uses colour model unsupported by format"),
+		}
+	}
+}
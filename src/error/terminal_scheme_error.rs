@@ -0,0 +1,61 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// A [terminal scheme](crate::terminal::TerminalScheme) or [Base16 scheme](crate::terminal::BaseScheme) could not be read.
+///
+/// All lines are one-based.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TerminalSchemeError {
+	/// A colour was malformed.
+	InvalidColour {
+		/// The line of the colour.
+		line: usize,
+	},
+
+	/// The scheme was otherwise malformed.
+	InvalidSyntax {
+		/// The line of the problem.
+		line: usize,
+	},
+
+	/// A required colour was missing.
+	MissingColour {
+		/// The name of the colour in the format of the scheme.
+		name: &'static str,
+	},
+
+	/// A colour was given in an unsupported colour space.
+	UnsupportedColourSpace {
+		/// The line of the colour.
+		line: usize,
+	},
+}
+
+impl Error for TerminalSchemeError { }
+
+impl Display for TerminalSchemeError {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::InvalidColour { line }
+			=> write!(f, "terminal scheme has malformed colour on line {line}"),
+
+			Self::InvalidSyntax { line }
+			=> write!(f, "terminal scheme is malformed on line {line}"),
+
+			Self::MissingColour { name }
+			=> write!(f, "terminal scheme is missing colour `{name}`"),
+
+			Self::UnsupportedColourSpace { line }
+			=> write!(f, "terminal scheme has colour in unsupported colour space on line {line}"),
+		}
+	}
+}
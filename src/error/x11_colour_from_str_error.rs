@@ -0,0 +1,55 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// An [X11 colour](crate::x11::X11Colour) could not be parsed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum X11ColourFromStrError {
+	/// A channel was malformed or out of range.
+	InvalidChannel {
+		/// The index of the channel.
+		index: usize,
+	},
+
+	/// A colour specification did not have exactly three channels.
+	InvalidChannelCount {
+		/// The number of channels.
+		count: usize,
+	},
+
+	/// A legacy hexadecimal colour had invalid digits or an invalid number of digits.
+	///
+	/// Valid colours have exactly three, six, nine, or twelve digits.
+	InvalidHexadecimal,
+
+	/// A colour specification was neither a known syntax nor (if enabled) a known name.
+	UnknownFormat,
+}
+
+impl Error for X11ColourFromStrError { }
+
+impl Display for X11ColourFromStrError {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::InvalidChannel { index }
+			=> write!(f, "x11 colour channel {index} is malformed or out of range"),
+
+			Self::InvalidChannelCount { count }
+			=> write!(f, "x11 colour has {count} channel(s), expected 3"),
+
+			Self::InvalidHexadecimal
+			=> write!(f, "x11 hexadecimal colour must have 3, 6, 9, or 12 digits"),
+
+			Self::UnknownFormat
+			=> write!(f, "x11 colour has unknown format"),
+		}
+	}
+}
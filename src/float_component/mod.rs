@@ -0,0 +1,54 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::{Interpolate, NormalisedComponent};
+
+/// Denotes a floating-point component type.
+///
+/// This trait allows generic facilities -- such as [`Gradient`](crate::gradient::Gradient) -- to perform their calculations in [`f64`] regardless of the component type of the colours involved.
+pub trait FloatComponent: NormalisedComponent + Interpolate<Scalar = Self> {
+	/// Converts a [`f64`] value to the component type.
+	///
+	/// The value is rounded to the nearest representable value.
+	#[must_use]
+	fn from_f64(value: f64) -> Self;
+
+	/// Converts the component to a [`f64`] value.
+	///
+	/// The value is rounded to the nearest representable value.
+	#[must_use]
+	fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_float_component {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::FloatComponent for $tys {
+				#[allow(clippy::cast_possible_truncation)]
+				#[inline(always)]
+				fn from_f64(value: f64) -> Self {
+					value as Self
+				}
+
+				#[allow(clippy::cast_lossless)]
+				#[inline(always)]
+				fn to_f64(self) -> f64 {
+					self as f64
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_float_component!(f16);
+
+impl_float_component!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_float_component!(f128);
@@ -0,0 +1,111 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+/// An easing function.
+///
+/// Easing functions remap the progress through a gradient segment, and they are defined as by the [CSS](https://www.w3.org/TR/css-easing-1/) functions of the same names.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Easing {
+	/// The identity function.
+	#[default]
+	Linear,
+
+	/// Equivalent to `CubicBezier(0.25, 0.1, 0.25, 1.0)`.
+	Ease,
+
+	/// Equivalent to `CubicBezier(0.42, 0.0, 1.0, 1.0)`.
+	EaseIn,
+
+	/// Equivalent to `CubicBezier(0.0, 0.0, 0.58, 1.0)`.
+	EaseOut,
+
+	/// Equivalent to `CubicBezier(0.42, 0.0, 0.58, 1.0)`.
+	EaseInOut,
+
+	/// A cubic Bézier curve with the control points `(x1, y1)` and `(x2, y2)`.
+	///
+	/// The end points are always `(0, 0)` and `(1, 1)`, and the `x` coordinates are clamped to the range `0` to `1`.
+	CubicBezier(f64, f64, f64, f64),
+}
+
+impl Easing {
+	/// Applies the easing function.
+	///
+	/// The progress is clamped to the range `0` to `1`.
+	#[must_use]
+	pub fn ease(self, progress: f64) -> f64 {
+		let (x1, y1, x2, y2) = match self {
+			Self::Linear => return progress.clamp(0.0, 1.0),
+
+			Self::Ease      => (0.25, 0.1, 0.25, 1.0),
+			Self::EaseIn    => (0.42, 0.0, 1.0,  1.0),
+			Self::EaseOut   => (0.0,  0.0, 0.58, 1.0),
+			Self::EaseInOut => (0.42, 0.0, 0.58, 1.0),
+
+			Self::CubicBezier(x1, y1, x2, y2) => (x1.clamp(0.0, 1.0), y1, x2.clamp(0.0, 1.0), y2),
+		};
+
+		let progress = progress.clamp(0.0, 1.0);
+
+		// Evaluates a single dimension of the curve.
+		let bezier = |p1: f64, p2: f64, s: f64| {
+			let t = 1.0 - s;
+			3.0 * t * t * s * p1 + 3.0 * t * s * s * p2 + s * s * s
+		};
+
+		// Evaluates the derivative of a dimension.
+		let derivative = |p1: f64, p2: f64, s: f64| {
+			let t = 1.0 - s;
+			3.0 * t * t * p1 + 6.0 * t * s * (p2 - p1) + 3.0 * s * s * (1.0 - p2)
+		};
+
+		// Solve `x(s) = progress` using Newton's method,
+		// falling back to bisection if it diverges.
+
+		let mut s = progress;
+
+		for _ in 0x0..0x8 {
+			let error = bezier(x1, x2, s) - progress;
+
+			if error.abs() < 1e-9 {
+				return bezier(y1, y2, s);
+			}
+
+			let slope = derivative(x1, x2, s);
+
+			if slope.abs() < 1e-6 {
+				break;
+			}
+
+			s -= error / slope;
+		}
+
+		let mut low  = 0.0;
+		let mut high = 1.0;
+
+		s = progress;
+
+		for _ in 0x0..0x40 {
+			let x = bezier(x1, x2, s);
+
+			if (x - progress).abs() < 1e-9 {
+				break;
+			}
+
+			if x < progress {
+				low = s;
+			} else {
+				high = s;
+			}
+
+			s = (low + high) / 2.0;
+		}
+
+		bezier(y1, y2, s)
+	}
+}
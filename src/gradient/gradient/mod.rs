@@ -0,0 +1,440 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::gradient::{Easing, GradientMode};
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "maths")]
+use crate::{DefinedGamut, FloatComponent, Interpolate, OkLabConvert};
+#[cfg(feature = "maths")]
+use crate::lab::OkLab;
+#[cfg(feature = "maths")]
+use crate::maths::Maths;
+#[cfg(feature = "maths")]
+use crate::rgb::SRgb;
+
+/// A colour stop of a gradient.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Stop<C> {
+	position: f64,
+	colour:   C,
+
+	/// The interpolation hint of the following
+	/// segment, relative to the segment.
+	hint: Option<f64>,
+
+	/// The easing of the following segment.
+	easing: Easing,
+}
+
+/// A multi-stop colour gradient.
+///
+/// Gradients consist of a sequence of colour stops, each positioned along the gradient line.
+/// Positions are usually -- but not necessarily -- in the range `0` to `1`.
+/// As in CSS, each stop is placed at least as far along the line as the previous, and positions before the first stop and after the last stop take the colours of these, respectively.
+///
+/// # Segments
+///
+/// The segment between two stops may be given an interpolation hint (see [`push_hint`](Self::push_hint)), which positions the midpoint of the transition, as well as an [easing function](Easing) (see [`set_easing`](Self::set_easing)).
+/// Two stops at the same position produce a hard transition.
+///
+/// # Modes
+///
+/// By default, adjacent stops are interpolated linearly in the colour space of the gradient itself (see [`Interpolate`]).
+/// Gradients in [`SRgb`] thus become darker and less saturated around their midpoints, whereas gradients in e.g. [`OkLab`] or [`OkLch`](crate::lch::OkLch) do not.
+/// Smooth curves through the stops are available using the [spline modes](GradientMode), which always interpolate in Oklab.
+/// Hard transitions split these curves, such that each run of stops between them gets its own.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "maths")]
+/// # {
+/// use polywave::gradient::Gradient;
+/// use polywave::lch::OkLch;
+/// use polywave::rgb::SRgb;
+///
+/// let mut gradient = Gradient::new();
+///
+/// gradient.push(0.0, OkLch::<f32>::new(0.5, 0.2, 30.0));
+/// gradient.push(1.0, OkLch::<f32>::new(0.5, 0.2, 260.0));
+///
+/// let mut ramp = [SRgb::new(0x00, 0x00, 0x00); 0x10];
+/// gradient.render_into(&mut ramp);
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gradient<C> {
+	stops: Vec<Stop<C>>,
+	mode:  GradientMode,
+}
+
+impl<C> Gradient<C> {
+	/// Constructs a new, empty gradient.
+	#[inline(always)]
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			stops: Vec::new(),
+			mode:  GradientMode::Linear,
+		}
+	}
+
+	/// Appends a colour stop to the gradient.
+	///
+	/// If the position is before that of the previous stop, then it is moved to the previous stop.
+	#[inline]
+	pub fn push(&mut self, position: f64, colour: C) {
+		let position = match self.stops.last() {
+			Some(last) if last.position > position => last.position,
+
+			_ => position,
+		};
+
+		let stop = Stop {
+			position,
+			colour,
+			hint:   None,
+			easing: Easing::Linear,
+		};
+
+		self.stops.push(stop);
+	}
+
+	/// Sets the interpolation hint of the current segment.
+	///
+	/// The hint positions the midpoint of the transition between the last stop and the next one to be pushed.
+	/// It is given on the gradient line, not relative to the segment, and is clamped to be between the two stops.
+	///
+	/// If no stop has been pushed yet, then the hint is ignored.
+	#[inline]
+	pub fn push_hint(&mut self, position: f64) {
+		if let Some(last) = self.stops.last_mut() {
+			// The relative position is resolved when the
+			// next stop is known.
+			last.hint = Some(position);
+		}
+	}
+
+	/// Sets the easing function of the current segment.
+	///
+	/// The easing applies between the last stop and the next one to be pushed.
+	/// If no stop has been pushed yet, then the easing is ignored.
+	#[inline]
+	pub fn set_easing(&mut self, easing: Easing) {
+		if let Some(last) = self.stops.last_mut() {
+			last.easing = easing;
+		}
+	}
+
+	/// Sets the interpolation mode of the gradient.
+	#[inline(always)]
+	pub fn set_mode(&mut self, mode: GradientMode) {
+		self.mode = mode;
+	}
+
+	/// Retrieves the interpolation mode of the gradient.
+	#[inline(always)]
+	#[must_use]
+	pub const fn mode(&self) -> GradientMode {
+		self.mode
+	}
+
+	/// Retrieves the amount of stops in the gradient.
+	#[inline(always)]
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.stops.len()
+	}
+
+	/// Checks whether the gradient has no stops.
+	#[inline(always)]
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.stops.is_empty()
+	}
+
+	/// Locates the segment containing a position.
+	///
+	/// The index of the segment's first stop is returned
+	/// together with the eased progress through it.
+	/// Positions outside of all segments yield either the
+	/// first or last stop with no progress.
+	#[cfg(feature = "maths")]
+	#[must_use]
+	fn locate(&self, position: f64) -> (usize, f64) {
+		let Some(last) = self.stops.len().checked_sub(0x1) else {
+			return (0x0, 0.0);
+		};
+
+		if position <= self.stops[0x0].position {
+			return (0x0, 0.0);
+		}
+
+		if position >= self.stops[last].position {
+			return (last, 0.0);
+		}
+
+		let Some(index) = self.stops.windows(0x2).position(|window| {
+			window[0x0].position <= position && position < window[0x1].position
+		}) else {
+			return (last, 0.0);
+		};
+
+		let start = &self.stops[index];
+		let end   = &self.stops[index + 0x1];
+
+		let length = end.position - start.position;
+
+		let mut progress = (position - start.position) / length;
+
+		if let Some(hint) = start.hint {
+			let hint = (hint - start.position) / length;
+
+			progress = if hint <= 0.0 {
+				1.0
+			} else if hint >= 1.0 {
+				0.0
+			} else {
+				Maths::powf(progress, Maths::ln(0.5_f64) / Maths::ln(hint))
+			};
+		}
+
+		(index, start.easing.ease(progress))
+	}
+
+	/// Locates the run of stops containing a segment.
+	///
+	/// Runs are delimited by hard transitions, i.e. by
+	/// stops at the same position, and the indices of
+	/// their first and last stops are returned.
+	#[cfg(feature = "maths")]
+	#[must_use]
+	fn run(&self, index: usize) -> (usize, usize) {
+		let mut first = index;
+		let mut last  = index + 0x1;
+
+		while first > 0x0 && self.stops[first - 0x1].position < self.stops[first].position {
+			first -= 0x1;
+		}
+
+		while last + 0x1 < self.stops.len() && self.stops[last + 0x1].position > self.stops[last].position {
+			last += 0x1;
+		}
+
+		(first, last)
+	}
+}
+
+impl<C> Default for Gradient<C> {
+	#[inline(always)]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[cfg(feature = "maths")]
+impl<C, T> Gradient<C>
+where
+	C: Copy + Interpolate<Scalar = T> + OkLabConvert<Scalar = T>,
+	T: FloatComponent,
+	SRgb<T>: OkLabConvert<Scalar = T>,
+{
+	/// Converts a stop to Oklab coordinates.
+	#[must_use]
+	fn point(&self, index: usize) -> [f64; 0x3] {
+		let (luminance, a_star, b_star) = self.stops[index].colour.to_ok_lab().get();
+		[luminance.to_f64(), a_star.to_f64(), b_star.to_f64()]
+	}
+
+	/// Samples the gradient at a given position.
+	///
+	/// # Panics
+	///
+	/// This method panics if the gradient is empty.
+	#[must_use]
+	pub fn sample(&self, position: f64) -> C {
+		assert!(!self.is_empty(), "cannot sample empty gradient");
+
+		let (index, progress) = self.locate(position);
+
+		let Some(next) = self.stops.get(index + 0x1) else {
+			return self.stops[index].colour;
+		};
+
+		let start = self.stops[index].colour;
+
+		match self.mode {
+			GradientMode::Linear => start.interpolate(next.colour, T::from_f64(progress)),
+
+			GradientMode::CatmullRom => {
+				let (first, last) = self.run(index);
+
+				let position = |index: usize| self.stops[index].position;
+
+				// Use finite differences for the tangents,
+				// which accounts for the spacing of the stops.
+				// The end points of the run use one-sided
+				// differences.
+				let tangent = |index: usize| -> [f64; 0x3] {
+					let before = if index > first { index - 0x1 } else { index };
+					let after  = if index < last  { index + 0x1 } else { index };
+
+					let (start, end) = (self.point(before), self.point(after));
+					let length = position(after) - position(before);
+
+					[0x0, 0x1, 0x2].map(|channel| (end[channel] - start[channel]) / length)
+				};
+
+				let length = position(index + 0x1) - position(index);
+
+				let (p0, p1) = (self.point(index), self.point(index + 0x1));
+				let (m0, m1) = (tangent(index), tangent(index + 0x1));
+
+				let t  = progress;
+				let t2 = t * t;
+				let t3 = t2 * t;
+
+				let weights = [
+					2.0 * t3 - 3.0 * t2 + 1.0,
+					(t3 - 2.0 * t2 + t) * length,
+					-2.0 * t3 + 3.0 * t2,
+					(t3 - t2) * length,
+				];
+
+				let [luminance, a_star, b_star] = [0x0, 0x1, 0x2].map(|channel| {
+					let value = weights[0x0] * p0[channel]
+						+ weights[0x1] * m0[channel]
+						+ weights[0x2] * p1[channel]
+						+ weights[0x3] * m1[channel];
+
+					T::from_f64(value)
+				});
+
+				C::from_ok_lab(OkLab::new(luminance, a_star, b_star))
+			}
+
+			GradientMode::BSpline => {
+				let (first, last) = self.run(index);
+
+				let start    = self.stops[index].position;
+				let position = start + progress * (self.stops[index + 0x1].position - start);
+
+				// The curve's position is monotonic in its
+				// parameter, so we can solve for the latter.
+				#[allow(clippy::cast_precision_loss)]
+				let (mut lower, mut upper) = (first as f64, last as f64);
+
+				for _ in 0x0..0x40 {
+					let middle = (lower + upper) / 2.0;
+
+					let [other] = b_spline(first, last, middle, |index| [self.stops[index].position]);
+
+					if other < position {
+						lower = middle;
+					} else {
+						upper = middle;
+					}
+				}
+
+				let parameter = (lower + upper) / 2.0;
+
+				let [luminance, a_star, b_star] = b_spline(first, last, parameter, |index| self.point(index))
+					.map(T::from_f64);
+
+				C::from_ok_lab(OkLab::new(luminance, a_star, b_star))
+			}
+		}
+	}
+
+	/// Renders the gradient into an sRGB buffer.
+	///
+	/// The gradient is sampled at evenly-spaced positions from the first to the last stop, inclusive, and each sample is [clipped](DefinedGamut::clip) to the sRGB gamut.
+	/// If the buffer contains a single element, then only the first stop is sampled.
+	///
+	/// # Panics
+	///
+	/// This method panics if the gradient is empty.
+	pub fn render_into(&self, buffer: &mut [SRgb<u8>]) {
+		assert!(!self.is_empty(), "cannot render empty gradient");
+
+		let start = self.stops[0x0].position;
+		let end   = self.stops[self.stops.len() - 0x1].position;
+
+		#[allow(clippy::cast_precision_loss)]
+		let step = (end - start) / buffer.len().saturating_sub(0x1).max(0x1) as f64;
+
+		for (index, pixel) in buffer.iter_mut().enumerate() {
+			#[allow(clippy::cast_precision_loss)]
+			let position = start + step * index as f64;
+
+			let colour = self.sample(position).to_s_rgb().clip();
+
+			#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+			let colour = colour.map(|channel| Maths::round(channel.to_f64() * 255.0) as u8);
+
+			*pixel = colour;
+		}
+	}
+
+	/// Renders the gradient into a new sRGB buffer.
+	///
+	/// See [`render_into`](Self::render_into) for more information.
+	///
+	/// # Panics
+	///
+	/// This method panics if the gradient is empty.
+	#[must_use]
+	pub fn render(&self, len: usize) -> Vec<SRgb<u8>> {
+		let mut buffer = alloc::vec![SRgb::new(0x00, 0x00, 0x00); len];
+		self.render_into(&mut buffer);
+
+		buffer
+	}
+}
+
+/// Evaluates a uniform cubic B-spline at a parameter.
+///
+/// The control points are those from `first` to `last`, inclusive, and the parameter is in the same range.
+/// The end points are reflected to get phantom points beyond them, such that the curve passes through the first and last control points.
+#[cfg(feature = "maths")]
+#[must_use]
+fn b_spline<const N: usize>(first: usize, last: usize, parameter: f64, control: impl Fn(usize) -> [f64; N]) -> [f64; N] {
+	#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+	let index = (parameter as usize).clamp(first, last - 0x1);
+
+	let reflect = |point: [f64; N], around: [f64; N]| -> [f64; N] {
+		core::array::from_fn(|channel| 2.0 * around[channel] - point[channel])
+	};
+
+	let p1 = control(index);
+	let p2 = control(index + 0x1);
+
+	let p0 = if index > first { control(index - 0x1) } else { reflect(p2, p1) };
+	let p3 = if index + 0x1 < last { control(index + 0x2) } else { reflect(p1, p2) };
+
+	#[allow(clippy::cast_precision_loss)]
+	let t  = parameter - index as f64;
+	let t2 = t * t;
+	let t3 = t2 * t;
+
+	let weights = [
+		(-t3 + 3.0 * t2 - 3.0 * t + 1.0) / 6.0,
+		(3.0 * t3 - 6.0 * t2 + 4.0) / 6.0,
+		(-3.0 * t3 + 3.0 * t2 + 3.0 * t + 1.0) / 6.0,
+		t3 / 6.0,
+	];
+
+	core::array::from_fn(|channel| {
+		weights[0x0] * p0[channel]
+			+ weights[0x1] * p1[channel]
+			+ weights[0x2] * p2[channel]
+			+ weights[0x3] * p3[channel]
+	})
+}
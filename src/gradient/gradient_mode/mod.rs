@@ -0,0 +1,32 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+/// A method of interpolating between gradient stops.
+///
+/// See [`Gradient`](crate::gradient::Gradient) for more information.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum GradientMode {
+	/// Linear interpolation between adjacent stops.
+	///
+	/// This is done in the colour space of the gradient itself.
+	#[default]
+	Linear,
+
+	/// A Catmull-Rom spline through all stops.
+	///
+	/// This is done in Oklab, and the curve passes through every stop.
+	/// The tangents at the stops take their spacing into account, so unevenly-spaced stops do not overshoot.
+	CatmullRom,
+
+	/// A uniform cubic B-spline over all stops.
+	///
+	/// This is done in Oklab.
+	/// The curve passes through the first and last stops, but is otherwise only pulled towards the intermediate stops, making it smoother than [`CatmullRom`](Self::CatmullRom).
+	/// The positions of the stops are treated as an additional coordinate of the control points, so the curve respects their spacing.
+	BSpline,
+}
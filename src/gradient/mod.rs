@@ -0,0 +1,19 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+//! Gradient facilities.
+
+mod easing;
+#[allow(clippy::module_inception)]
+mod gradient;
+mod gradient_mode;
+mod test;
+
+pub use easing::Easing;
+pub use gradient::Gradient;
+pub use gradient_mode::GradientMode;
@@ -0,0 +1,142 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+#![cfg(all(test, feature = "maths"))]
+
+use crate::OkLabConvert;
+use crate::gradient::{Easing, Gradient, GradientMode};
+use crate::lab::OkLab;
+use crate::rgb::SRgb;
+use crate::test::assert_approx_eq;
+
+#[test]
+fn test_easing() {
+	for easing in [Easing::Linear, Easing::Ease, Easing::EaseIn, Easing::EaseOut, Easing::EaseInOut] {
+		assert!(easing.ease(0.0).abs() < 1e-9);
+		assert!((easing.ease(1.0) - 1.0).abs() < 1e-9);
+	}
+
+	assert!((Easing::EaseInOut.ease(0.5) - 0.5).abs() < 1e-6);
+	assert!(Easing::EaseIn.ease(0.5) < 0.5);
+	assert!(Easing::EaseOut.ease(0.5) > 0.5);
+
+	// `ease` at 25% progress per the CSS reference.
+	assert!((Easing::Ease.ease(0.25) - 0.408_510_6).abs() < 1e-4);
+}
+
+#[test]
+fn test_gradient_linear() {
+	let mut gradient = Gradient::new();
+
+	gradient.push(0.0, SRgb::<f64>::new(0.0, 0.0, 0.0));
+	gradient.push(0.5, SRgb::<f64>::new(1.0, 0.0, 0.0));
+	gradient.push(1.0, SRgb::<f64>::new(1.0, 1.0, 1.0));
+
+	assert_eq!(gradient.len(), 0x3);
+
+	assert_approx_eq!(gradient.sample(-1.0).get(), (0.0, 0.0, 0.0), 1e-12);
+	assert_approx_eq!(gradient.sample(0.25).get(), (0.5, 0.0, 0.0), 1e-12);
+	assert_approx_eq!(gradient.sample(0.75).get(), (1.0, 0.5, 0.5), 1e-12);
+	assert_approx_eq!(gradient.sample(2.0).get(),  (1.0, 1.0, 1.0), 1e-12);
+
+	assert_eq!(
+		gradient.render(0x3),
+		[
+			SRgb::new(0x00, 0x00, 0x00),
+			SRgb::new(0xFF, 0x00, 0x00),
+			SRgb::new(0xFF, 0xFF, 0xFF),
+		],
+	);
+}
+
+#[test]
+fn test_gradient_hard_stop_and_hint() {
+	let mut gradient = Gradient::new();
+
+	gradient.push(0.0, SRgb::<f64>::new(0.0, 0.0, 0.0));
+	gradient.push_hint(0.25);
+	gradient.push(1.0, SRgb::<f64>::new(1.0, 1.0, 1.0));
+
+	assert_approx_eq!(gradient.sample(0.25).get(), (0.5, 0.5, 0.5), 1e-12);
+
+	let mut gradient = Gradient::new();
+
+	gradient.push(0.0, SRgb::<f64>::new(0.0, 0.0, 0.0));
+	gradient.push(0.5, SRgb::<f64>::new(0.0, 0.0, 0.0));
+	gradient.push(0.25, SRgb::<f64>::new(1.0, 1.0, 1.0));
+	gradient.push(1.0, SRgb::<f64>::new(1.0, 1.0, 1.0));
+
+	assert_approx_eq!(gradient.sample(0.499).get(), (0.0, 0.0, 0.0), 1e-12);
+	assert_approx_eq!(gradient.sample(0.5).get(),   (1.0, 1.0, 1.0), 1e-12);
+}
+
+#[test]
+fn test_gradient_spline() {
+	let stops = [
+		OkLab::<f64>::new(0.2, 0.1,  0.0),
+		OkLab::<f64>::new(0.6, 0.0,  0.1),
+		OkLab::<f64>::new(0.9, -0.1, 0.0),
+	];
+
+	for mode in [GradientMode::CatmullRom, GradientMode::BSpline] {
+		let mut gradient = Gradient::new();
+		gradient.set_mode(mode);
+
+		for (index, stop) in stops.into_iter().enumerate() {
+			gradient.push(index as f64 / 2.0, stop);
+		}
+
+		assert_approx_eq!(gradient.sample(0.0).get(), (0.2, 0.1, 0.0),  1e-9);
+		assert_approx_eq!(gradient.sample(1.0).get(), (0.9, -0.1, 0.0), 1e-9);
+	}
+
+	let mut gradient = Gradient::new();
+	gradient.set_mode(GradientMode::CatmullRom);
+
+	for (index, stop) in stops.into_iter().enumerate() {
+		gradient.push(index as f64 / 2.0, stop);
+	}
+
+	assert_approx_eq!(gradient.sample(0.5).get(), (0.6, 0.0, 0.1), 1e-9);
+}
+
+#[test]
+fn test_gradient_spline_spacing() {
+	// The stops lie on a line through Oklab, so the
+	// splines must reproduce linear interpolation
+	// despite the uneven spacing.
+	let colour = |position: f64| OkLab::<f64>::new(0.2 + 0.7 * position, 0.1 - 0.2 * position, 0.05);
+
+	for mode in [GradientMode::CatmullRom, GradientMode::BSpline] {
+		let mut gradient = Gradient::new();
+		gradient.set_mode(mode);
+
+		for position in [0.0, 0.1, 1.0] {
+			gradient.push(position, colour(position));
+		}
+
+		for position in [0.05, 0.1, 0.3, 0.55, 0.9] {
+			assert_approx_eq!(gradient.sample(position).get(), colour(position).get(), 1e-9);
+		}
+	}
+}
+
+#[test]
+fn test_gradient_render_s_rgb() {
+	// sRGB colours are rendered without a round trip
+	// through Oklab.
+	let colour = SRgb::<f32>::new(0.1, 0.2, 0.3);
+	assert_eq!(OkLabConvert::to_s_rgb(colour), colour);
+
+	let mut gradient = Gradient::new();
+
+	gradient.push(0.0, SRgb::<f32>::new(0.5 / 255.0, 0.0, 1.0));
+	gradient.push(1.0, SRgb::<f32>::new(1.0, 0.0, 0.0));
+
+	assert_eq!(gradient.render(0x1), [SRgb::new(0x01, 0x00, 0xFF)]);
+}
@@ -11,9 +11,6 @@ use crate::{BalancedColour, Colour, Component};
 #[cfg(feature = "bytemuck")]
 use bytemuck::{Pod, Zeroable};
 
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
-
 #[cfg(feature = "zerocopy")]
 use zerocopy::{FromZeros, Immutable, IntoBytes};
 
@@ -23,7 +20,6 @@ use zerocopy::{FromZeros, Immutable, IntoBytes};
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
-#[cfg_attr(feature = "serde",    derive(Deserialize, Serialize))]
 #[cfg_attr(feature = "zerocopy", derive(FromZeros, Immutable, IntoBytes))]
 pub struct Hsl<T>([T; 0x3]);
 
@@ -62,6 +58,87 @@ impl<T: Component> Hsl<T> {
 	}
 }
 
+macro_rules! impl_conversions {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::hsv::Hsl<$tys> {
+				/// Converts an sRGB colour to HSL.
+				///
+				/// The hue is given in degrees, and saturation and luminosity are in the range `0` to `1`.
+				/// Achromatic colours are given a hue of zero.
+				#[inline]
+				#[must_use]
+				pub const fn from_s_rgb(colour: ::polywave::rgb::SRgb<$tys>) -> Self {
+					use ::polywave::hsv::maths::Float;
+
+					let (red, green, blue) = colour.get();
+
+					let max = Float::<$tys>::max(Float::<$tys>::max(red, green), blue);
+					let min = Float::<$tys>::min(Float::<$tys>::min(red, green), blue);
+
+					let mut hue = Float::<$tys>::hue_from_s_rgb(colour);
+
+					let luminosity = (max + min) / 2.0;
+
+					let mut saturation = if luminosity == 0.0 || luminosity == 1.0 {
+						0.0
+					} else {
+						(max - luminosity) / Float::<$tys>::min(luminosity, 1.0 - luminosity)
+					};
+
+					// Colours outside of the sRGB gamut may yield
+					// negative saturations.
+					if saturation < 0.0 {
+						hue        = Float::<$tys>::normalise_hue(hue + 180.0);
+						saturation = -saturation;
+					}
+
+					Self::new(hue, saturation, luminosity)
+				}
+
+				/// Converts the HSL colour to sRGB.
+				#[inline]
+				#[must_use]
+				pub const fn to_s_rgb(self) -> ::polywave::rgb::SRgb<$tys> {
+					use ::polywave::hsv::maths::Float;
+
+					let (hue, saturation, luminosity) = self.get();
+
+					let hue = Float::<$tys>::normalise_hue(hue);
+
+					let a = saturation * Float::<$tys>::min(luminosity, 1.0 - luminosity);
+
+					let red   = Self::channel(0.0, hue, luminosity, a);
+					let green = Self::channel(8.0, hue, luminosity, a);
+					let blue  = Self::channel(4.0, hue, luminosity, a);
+
+					::polywave::rgb::SRgb::new(red, green, blue)
+				}
+
+				/// Computes a single sRGB channel of an HSL colour.
+				///
+				/// The hue must already be normalised.
+				#[inline(always)]
+				#[must_use]
+				const fn channel(n: $tys, hue: $tys, luminosity: $tys, a: $tys) -> $tys {
+					use ::polywave::hsv::maths::Float;
+
+					let k = (n + hue / 30.0) % 12.0;
+					luminosity - a * Float::<$tys>::clamp(Float::<$tys>::min(k - 3.0, 9.0 - k), -1.0, 1.0)
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_conversions!(f16);
+
+impl_conversions!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_conversions!(f128);
+
 unsafe impl<T: Component> BalancedColour for Hsl<T> {
 	type Component = T;
 }
@@ -12,9 +12,6 @@ use crate::hsv::Hwb;
 #[cfg(feature = "bytemuck")]
 use bytemuck::{Pod, Zeroable};
 
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
-
 #[cfg(feature = "zerocopy")]
 use zerocopy::{FromZeros, Immutable, IntoBytes};
 
@@ -24,7 +21,6 @@ use zerocopy::{FromZeros, Immutable, IntoBytes};
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
-#[cfg_attr(feature = "serde",    derive(Deserialize, Serialize))]
 #[cfg_attr(feature = "zerocopy", derive(FromZeros, Immutable, IntoBytes))]
 pub struct Hsv<T>([T; 0x3]);
 
@@ -80,7 +76,7 @@ macro_rules! impl_conversions {
 
 				/// Converts the HSV colour to HWB.
 				#[must_use]
-				pub fn to_hwb(self) -> ::polywave::hsv::Hwb<$tys> {
+				pub const fn to_hwb(self) -> ::polywave::hsv::Hwb<$tys> {
 					let (hue, saturation, value) = self.get();
 
 					let whiteness = (1.0 - saturation) * value;
@@ -89,6 +85,58 @@ macro_rules! impl_conversions {
 
 					Hwb::new(hue, whiteness, blackness)
 				}
+
+				/// Converts an sRGB colour to HSV.
+				///
+				/// The hue is given in degrees, and saturation and value are in the range `0` to `1`.
+				/// Achromatic colours are given a hue of zero.
+				#[inline]
+				#[must_use]
+				pub const fn from_s_rgb(colour: ::polywave::rgb::SRgb<$tys>) -> Self {
+					use ::polywave::hsv::maths::Float;
+
+					let (red, green, blue) = colour.get();
+
+					let max = Float::<$tys>::max(Float::<$tys>::max(red, green), blue);
+					let min = Float::<$tys>::min(Float::<$tys>::min(red, green), blue);
+
+					let hue = Float::<$tys>::hue_from_s_rgb(colour);
+
+					let saturation = if max == 0.0 {
+						0.0
+					} else {
+						(max - min) / max
+					};
+
+					Self::new(hue, saturation, max)
+				}
+
+				/// Converts the HSV colour to sRGB.
+				#[inline]
+				#[must_use]
+				pub const fn to_s_rgb(self) -> ::polywave::rgb::SRgb<$tys> {
+					let (hue, saturation, value) = self.get();
+
+					let hue = ::polywave::hsv::maths::Float::<$tys>::normalise_hue(hue);
+
+					let red   = Self::channel(5.0, hue, saturation, value);
+					let green = Self::channel(3.0, hue, saturation, value);
+					let blue  = Self::channel(1.0, hue, saturation, value);
+
+					::polywave::rgb::SRgb::new(red, green, blue)
+				}
+
+				/// Computes a single sRGB channel of an HSV colour.
+				///
+				/// The hue must already be normalised.
+				#[inline(always)]
+				#[must_use]
+				const fn channel(n: $tys, hue: $tys, saturation: $tys, value: $tys) -> $tys {
+					use ::polywave::hsv::maths::Float;
+
+					let k = (n + hue / 60.0) % 6.0;
+					value - value * saturation * Float::<$tys>::clamp(Float::<$tys>::min(k, 4.0 - k), 0.0, 1.0)
+				}
 			}
 		)*
 	};
@@ -11,9 +11,6 @@ use crate::{BalancedColour, Colour, Component};
 #[cfg(feature = "bytemuck")]
 use bytemuck::{Pod, Zeroable};
 
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
-
 #[cfg(feature = "zerocopy")]
 use zerocopy::{FromZeros, Immutable, IntoBytes};
 
@@ -23,7 +20,6 @@ use zerocopy::{FromZeros, Immutable, IntoBytes};
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
-#[cfg_attr(feature = "serde",    derive(Deserialize, Serialize))]
 #[cfg_attr(feature = "zerocopy", derive(FromZeros, Immutable, IntoBytes))]
 pub struct Hwb<T>([T; 0x3]);
 
@@ -62,6 +58,65 @@ impl<T: Component> Hwb<T> {
 	}
 }
 
+macro_rules! impl_conversions {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::hsv::Hwb<$tys> {
+				/// Converts an sRGB colour to HWB.
+				///
+				/// The hue is given in degrees, and whiteness and blackness are in the range `0` to `1`.
+				/// Achromatic colours are given a hue of zero.
+				#[inline]
+				#[must_use]
+				pub const fn from_s_rgb(colour: ::polywave::rgb::SRgb<$tys>) -> Self {
+					use ::polywave::hsv::maths::Float;
+
+					let (red, green, blue) = colour.get();
+
+					let hue = Float::<$tys>::hue_from_s_rgb(colour);
+
+					let whiteness = Float::<$tys>::min(Float::<$tys>::min(red, green), blue);
+					let blackness = 1.0 - Float::<$tys>::max(Float::<$tys>::max(red, green), blue);
+
+					Self::new(hue, whiteness, blackness)
+				}
+
+				/// Converts the HWB colour to sRGB.
+				///
+				/// If the sum of whiteness and blackness is at least one, then the result is a grey.
+				#[inline]
+				#[must_use]
+				pub const fn to_s_rgb(self) -> ::polywave::rgb::SRgb<$tys> {
+					let (hue, whiteness, blackness) = self.get();
+
+					if whiteness + blackness >= 1.0 {
+						let grey = whiteness / (whiteness + blackness);
+						return ::polywave::rgb::SRgb::new(grey, grey, grey);
+					}
+
+					let scale = 1.0 - whiteness - blackness;
+
+					let (red, green, blue) = ::polywave::hsv::Hsl::<$tys>::new(hue, 1.0, 0.5).to_s_rgb().get();
+
+					::polywave::rgb::SRgb::new(
+						red   * scale + whiteness,
+						green * scale + whiteness,
+						blue  * scale + whiteness,
+					)
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_conversions!(f16);
+
+impl_conversions!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_conversions!(f128);
+
 unsafe impl<T: Component> BalancedColour for Hwb<T> {
 	type Component = T;
 }
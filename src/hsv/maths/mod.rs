@@ -0,0 +1,108 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+//! Maths shared between the HSV-based colours.
+//!
+//! All functions are usable in constant expressions.
+
+use core::marker::PhantomData;
+
+/// Maths functions for a floating-point type.
+///
+/// This type is never constructed, and only serves to namespace the functions of each type.
+pub(crate) struct Float<T>(PhantomData<T>);
+
+macro_rules! impl_float {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::hsv::maths::Float<$tys> {
+				/// Computes the greater of two values, ignoring NaN.
+				///
+				/// This mirrors `max`, which is not usable in constant expressions on all supported toolchains.
+				#[allow(clippy::eq_op)]
+				#[inline(always)]
+				#[must_use]
+				pub(crate) const fn max(lhs: $tys, rhs: $tys) -> $tys {
+					if lhs > rhs || rhs != rhs { lhs } else { rhs }
+				}
+
+				/// Computes the lesser of two values, ignoring NaN.
+				///
+				/// This mirrors `min`, which is not usable in constant expressions on all supported toolchains.
+				#[allow(clippy::eq_op)]
+				#[inline(always)]
+				#[must_use]
+				pub(crate) const fn min(lhs: $tys, rhs: $tys) -> $tys {
+					if lhs < rhs || rhs != rhs { lhs } else { rhs }
+				}
+
+				/// Clamps a value to a range, propagating NaN.
+				#[inline(always)]
+				#[must_use]
+				pub(crate) const fn clamp(value: $tys, lower: $tys, upper: $tys) -> $tys {
+					if value < lower {
+						lower
+					} else if value > upper {
+						upper
+					} else {
+						value
+					}
+				}
+
+				/// Normalises a hue to the range `0` to `360` degrees.
+				#[inline]
+				#[must_use]
+				pub(crate) const fn normalise_hue(hue: $tys) -> $tys {
+					let hue = hue % 360.0;
+
+					if hue < 0.0 {
+						hue + 360.0
+					} else {
+						hue
+					}
+				}
+
+				/// Computes the hue of an sRGB colour in degrees.
+				///
+				/// This is shared between HSL, HSV, and HWB.
+				#[inline]
+				#[must_use]
+				pub(crate) const fn hue_from_s_rgb(colour: ::polywave::rgb::SRgb<$tys>) -> $tys {
+					let (red, green, blue) = colour.get();
+
+					let max = Self::max(Self::max(red, green), blue);
+					let min = Self::min(Self::min(red, green), blue);
+
+					let delta = max - min;
+
+					if delta == 0.0 {
+						return 0.0;
+					}
+
+					let hue = if max == red {
+						(green - blue) / delta + if green < blue { 6.0 } else { 0.0 }
+					} else if max == green {
+						(blue - red) / delta + 2.0
+					} else {
+						(red - green) / delta + 4.0
+					};
+
+					hue * 60.0
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_float!(f16);
+
+impl_float!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_float!(f128);
@@ -11,7 +11,13 @@
 mod hsl;
 mod hsv;
 mod hwb;
+mod maths;
+mod ok_hsl;
+mod ok_hsv;
+mod test;
 
 pub use hsl::Hsl;
 pub use hsv::Hsv;
 pub use hwb::Hwb;
+pub use ok_hsl::OkHsl;
+pub use ok_hsv::OkHsv;
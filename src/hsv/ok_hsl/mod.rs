@@ -0,0 +1,217 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::{BalancedColour, Colour, Component};
+
+#[cfg(feature = "maths")]
+use crate::maths::Maths;
+
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+
+#[cfg(feature = "zerocopy")]
+use zerocopy::{FromZeros, Immutable, IntoBytes};
+
+/// An Okhsl colour.
+///
+/// This type guarantees that its three channels -- hue, saturation, and luminosity (or *lightness*) -- are stored sequentially in memory (in this order).
+///
+/// Okhsl is a perceptual alternative to [`Hsl`](crate::hsv::Hsl) as designed by Björn Ottosson.
+/// It is based on [`OkLab`](crate::lab::OkLab), but is shaped to exactly fit the sRGB gamut.
+/// The luminosity is the toe-corrected L<sub>r</sub> of [`OkLrab`](crate::lab::OkLrab).
+/// The hue is measured in degrees, whilst saturation and luminosity both lie in the range `0` to `1`.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
+#[cfg_attr(feature = "zerocopy", derive(FromZeros, Immutable, IntoBytes))]
+pub struct OkHsl<T>([T; 0x3]);
+
+impl<T: Component> OkHsl<T> {
+	/// Constructs a new Okhsl colour.
+	#[inline(always)]
+	#[must_use]
+	pub const fn new(hue: T, saturation: T, luminosity: T) -> Self {
+		let data = [hue, saturation, luminosity];
+		Self(data)
+	}
+
+	/// Maps the Okhsl colour's channels.
+	#[inline]
+	#[must_use]
+	pub fn map<U, F>(self, mut op: F) -> OkHsl<U>
+	where
+		U: Component,
+		F: FnMut(T) -> U,
+	{
+		let (hue, saturation, luminosity) = self.get();
+
+		let hue        = op(hue);
+		let saturation = op(saturation);
+		let luminosity = op(luminosity);
+
+		OkHsl::new(hue, saturation, luminosity)
+	}
+
+	/// Deconstructs the Okhsl colour.
+	#[inline(always)]
+	#[must_use]
+	pub const fn get(self) -> (T, T, T) {
+		let [hue, saturation, luminosity] = self.0;
+		(hue, saturation, luminosity)
+	}
+}
+
+macro_rules! impl_conversions {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::hsv::OkHsl<$tys> {
+				/// The saturation at which the middle chroma is reached.
+				#[cfg(feature = "maths")]
+				const MID: $tys = 0.8;
+
+				/// Converts an sRGB colour to Okhsl.
+				///
+				/// Achromatic colours are given a hue of zero.
+				#[cfg(feature = "maths")]
+				#[must_use]
+				pub fn from_s_rgb(colour: ::polywave::rgb::SRgb<$tys>) -> Self {
+					use ::polywave::lab::{OkLab, OkLrab};
+
+					let (luminance, a_star, b_star) = OkLab::<$tys>::from_s_rgb(colour).get();
+
+					let luminosity = OkLrab::<$tys>::toe(luminance);
+
+					let chroma = Maths::hypot(a_star, b_star);
+
+					// Treat nearly achromatic colours as grey, as
+					// the chroma bounds degenerate towards the
+					// black and white points.
+					if chroma < 1e-6 || luminance <= 0.0 || luminance >= 1.0 {
+						return Self::new(0.0, 0.0, luminosity);
+					}
+
+					let hue = Maths::rem_euclid(Maths::atan2(b_star, a_star).to_degrees(), 360.0);
+
+					let a = a_star / chroma;
+					let b = b_star / chroma;
+
+					let (zero_chroma, mid_chroma, max_chroma) = OkLab::<$tys>::s_rgb_chroma_bounds(luminance, a, b);
+
+					let saturation = if chroma < mid_chroma {
+						let k1 = Self::MID * zero_chroma;
+						let k2 = 1.0 - k1 / mid_chroma;
+
+						let t = chroma / (k1 + k2 * chroma);
+
+						t * Self::MID
+					} else {
+						let k0 = mid_chroma;
+						let k1 = (1.0 - Self::MID) * mid_chroma * mid_chroma / (Self::MID * Self::MID * zero_chroma);
+						let k2 = 1.0 - k1 / (max_chroma - mid_chroma);
+
+						let t = (chroma - k0) / (k1 + k2 * (chroma - k0));
+
+						Self::MID + (1.0 - Self::MID) * t
+					};
+
+					Self::new(hue, saturation, luminosity)
+				}
+
+				/// Converts the Okhsl colour to sRGB.
+				#[cfg(feature = "maths")]
+				#[must_use]
+				pub fn to_s_rgb(self) -> ::polywave::rgb::SRgb<$tys> {
+					use ::polywave::lab::{OkLab, OkLrab};
+
+					let (hue, saturation, luminosity) = self.get();
+
+					if luminosity >= 1.0 {
+						return ::polywave::rgb::SRgb::new(1.0, 1.0, 1.0);
+					}
+
+					if luminosity <= 0.0 {
+						return ::polywave::rgb::SRgb::new(0.0, 0.0, 0.0);
+					}
+
+					let (b, a) = Maths::sin_cos(hue.to_radians());
+
+					let luminance = OkLrab::<$tys>::toe_inverse(luminosity);
+
+					let (zero_chroma, mid_chroma, max_chroma) = OkLab::<$tys>::s_rgb_chroma_bounds(luminance, a, b);
+
+					let chroma = if saturation < Self::MID {
+						let t = saturation / Self::MID;
+
+						let k1 = Self::MID * zero_chroma;
+						let k2 = 1.0 - k1 / mid_chroma;
+
+						t * k1 / (1.0 - k2 * t)
+					} else {
+						let t = (saturation - Self::MID) / (1.0 - Self::MID);
+
+						let k0 = mid_chroma;
+						let k1 = (1.0 - Self::MID) * mid_chroma * mid_chroma / (Self::MID * Self::MID * zero_chroma);
+						let k2 = 1.0 - k1 / (max_chroma - mid_chroma);
+
+						k0 + t * k1 / (1.0 - k2 * t)
+					};
+
+					OkLab::new(luminance, chroma * a, chroma * b).to_s_rgb()
+				}
+			}
+
+			impl ::polywave::DefinedGamut for ::polywave::hsv::OkHsl<$tys> {
+				type Scalar = $tys;
+
+				#[inline]
+				fn is_in_gamut(&self, epsilon: Self::Scalar) -> bool {
+					use ::polywave::NormalisedComponent;
+
+					let (_, saturation, luminosity) = self.get();
+
+					saturation.is_normalised(epsilon) && luminosity.is_normalised(epsilon)
+				}
+
+				#[inline]
+				fn clip(self) -> Self {
+					use ::polywave::NormalisedComponent;
+
+					let (hue, saturation, luminosity) = self.get();
+
+					Self::new(hue, saturation.clamp_normalised(), luminosity.clamp_normalised())
+				}
+
+				#[inline]
+				fn gamut_distance(&self) -> Self::Scalar {
+					use ::polywave::NormalisedComponent;
+
+					let (_, saturation, luminosity) = self.get();
+
+					let saturation = saturation.normalised_distance();
+					let luminosity = luminosity.normalised_distance();
+
+					if luminosity > saturation { luminosity } else { saturation }
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_conversions!(f16);
+
+impl_conversions!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_conversions!(f128);
+
+unsafe impl<T: Component> BalancedColour for OkHsl<T> {
+	type Component = T;
+}
+
+impl<T: Component> Colour for OkHsl<T> { }
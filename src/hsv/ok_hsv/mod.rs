@@ -0,0 +1,230 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::{BalancedColour, Colour, Component};
+
+#[cfg(feature = "maths")]
+use crate::maths::Maths;
+
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+
+#[cfg(feature = "zerocopy")]
+use zerocopy::{FromZeros, Immutable, IntoBytes};
+
+/// An Okhsv colour.
+///
+/// This type guarantees that its three channels -- hue, saturation, and value -- are stored sequentially in memory (in this order).
+///
+/// Okhsv is a perceptual alternative to [`Hsv`](crate::hsv::Hsv) as designed by Björn Ottosson.
+/// It is based on [`OkLab`](crate::lab::OkLab), but is shaped to exactly fit the sRGB gamut.
+/// The hue is measured in degrees, whilst saturation and value both lie in the range `0` to `1`.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
+#[cfg_attr(feature = "zerocopy", derive(FromZeros, Immutable, IntoBytes))]
+pub struct OkHsv<T>([T; 0x3]);
+
+impl<T: Component> OkHsv<T> {
+	/// Constructs a new Okhsv colour.
+	#[inline(always)]
+	#[must_use]
+	pub const fn new(hue: T, saturation: T, value: T) -> Self {
+		let data = [hue, saturation, value];
+		Self(data)
+	}
+
+	/// Maps the Okhsv colour's channels.
+	#[inline]
+	#[must_use]
+	pub fn map<U, F>(self, mut op: F) -> OkHsv<U>
+	where
+		U: Component,
+		F: FnMut(T) -> U,
+	{
+		let (hue, saturation, value) = self.get();
+
+		let hue        = op(hue);
+		let saturation = op(saturation);
+		let value      = op(value);
+
+		OkHsv::new(hue, saturation, value)
+	}
+
+	/// Deconstructs the Okhsv colour.
+	#[inline(always)]
+	#[must_use]
+	pub const fn get(self) -> (T, T, T) {
+		let [hue, saturation, value] = self.0;
+		(hue, saturation, value)
+	}
+}
+
+macro_rules! impl_conversions {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::hsv::OkHsv<$tys> {
+				/// Converts an sRGB colour to Okhsv.
+				///
+				/// Achromatic colours are given a hue of zero.
+				#[cfg(feature = "maths")]
+				#[must_use]
+				pub fn from_s_rgb(colour: ::polywave::rgb::SRgb<$tys>) -> Self {
+					use ::polywave::lab::{OkLab, OkLrab};
+
+					let (luminance, a_star, b_star) = OkLab::<$tys>::from_s_rgb(colour).get();
+
+					if luminance <= 0.0 {
+						return Self::new(0.0, 0.0, 0.0);
+					}
+
+					// Treat nearly achromatic colours as grey.
+					let chroma = Maths::hypot(a_star, b_star);
+
+					let (chroma, a, b, hue) = if chroma < 1e-6 {
+						(0.0, 1.0, 0.0, 0.0)
+					} else {
+						let hue = Maths::rem_euclid(Maths::atan2(b_star, a_star).to_degrees(), 360.0);
+						(chroma, a_star / chroma, b_star / chroma, hue)
+					};
+
+					let (cusp_l, cusp_c) = OkLab::<$tys>::s_rgb_cusp(a, b);
+
+					let max_s = cusp_c / cusp_l;
+					let max_t = cusp_c / (1.0 - cusp_l);
+
+					let s_0 = 0.5;
+					let k   = 1.0 - s_0 / max_s;
+
+					// Find the luminance and chroma on the
+					// `value = 1.0` line.
+					let t = max_t / (chroma + luminance * max_t);
+
+					let l_v = t * luminance;
+					let c_v = t * chroma;
+
+					let l_vt = OkLrab::<$tys>::toe_inverse(l_v);
+					let c_vt = c_v * l_vt / l_v;
+
+					// Invert the compensation for the toe and
+					// the curved top part of the triangle.
+					let (red, green, blue) = OkLab::new(l_vt, a * c_vt, b * c_vt).to_linear_s_rgb().get();
+
+					let scale = Maths::cbrt(1.0 / red.max(green).max(blue).max(0.0));
+
+					let luminance = OkLrab::<$tys>::toe(luminance / scale);
+
+					let value      = luminance / l_v;
+					let saturation = (s_0 + max_t) * c_v / (max_t * s_0 + max_t * k * c_v);
+
+					Self::new(hue, saturation, value)
+				}
+
+				/// Converts the Okhsv colour to sRGB.
+				#[cfg(feature = "maths")]
+				#[must_use]
+				pub fn to_s_rgb(self) -> ::polywave::rgb::SRgb<$tys> {
+					use ::polywave::lab::{OkLab, OkLrab};
+
+					let (hue, saturation, value) = self.get();
+
+					if value <= 0.0 {
+						return ::polywave::rgb::SRgb::new(0.0, 0.0, 0.0);
+					}
+
+					let (b, a) = Maths::sin_cos(hue.to_radians());
+
+					let (cusp_l, cusp_c) = OkLab::<$tys>::s_rgb_cusp(a, b);
+
+					let max_s = cusp_c / cusp_l;
+					let max_t = cusp_c / (1.0 - cusp_l);
+
+					let s_0 = 0.5;
+					let k   = 1.0 - s_0 / max_s;
+
+					// Compute the luminance and chroma as if the
+					// gamut were a perfect triangle.
+					let divisor = s_0 + max_t - max_t * k * saturation;
+
+					let l_v = 1.0 - saturation * s_0 / divisor;
+					let c_v = saturation * max_t * s_0 / divisor;
+
+					let luminance = value * l_v;
+					let chroma    = value * c_v;
+
+					// Compensate for both the toe and the curved
+					// top part of the triangle.
+					let l_vt = OkLrab::<$tys>::toe_inverse(l_v);
+					let c_vt = c_v * l_vt / l_v;
+
+					let new_luminance = OkLrab::<$tys>::toe_inverse(luminance);
+
+					let chroma    = chroma * new_luminance / luminance;
+					let luminance = new_luminance;
+
+					let (red, green, blue) = OkLab::new(l_vt, a * c_vt, b * c_vt).to_linear_s_rgb().get();
+
+					let scale = Maths::cbrt(1.0 / red.max(green).max(blue).max(0.0));
+
+					let luminance = luminance * scale;
+					let chroma    = chroma * scale;
+
+					OkLab::new(luminance, chroma * a, chroma * b).to_s_rgb()
+				}
+			}
+
+			impl ::polywave::DefinedGamut for ::polywave::hsv::OkHsv<$tys> {
+				type Scalar = $tys;
+
+				#[inline]
+				fn is_in_gamut(&self, epsilon: Self::Scalar) -> bool {
+					use ::polywave::NormalisedComponent;
+
+					let (_, saturation, value) = self.get();
+
+					saturation.is_normalised(epsilon) && value.is_normalised(epsilon)
+				}
+
+				#[inline]
+				fn clip(self) -> Self {
+					use ::polywave::NormalisedComponent;
+
+					let (hue, saturation, value) = self.get();
+
+					Self::new(hue, saturation.clamp_normalised(), value.clamp_normalised())
+				}
+
+				#[inline]
+				fn gamut_distance(&self) -> Self::Scalar {
+					use ::polywave::NormalisedComponent;
+
+					let (_, saturation, value) = self.get();
+
+					let saturation = saturation.normalised_distance();
+					let value      = value.normalised_distance();
+
+					if value > saturation { value } else { saturation }
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_conversions!(f16);
+
+impl_conversions!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_conversions!(f128);
+
+unsafe impl<T: Component> BalancedColour for OkHsv<T> {
+	type Component = T;
+}
+
+impl<T: Component> Colour for OkHsv<T> { }
@@ -0,0 +1,91 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+#![cfg(test)]
+
+use crate::DefinedGamut;
+use crate::hsv::{Hsl, Hsv, Hwb, OkHsv};
+#[cfg(feature = "maths")]
+use crate::hsv::OkHsl;
+use crate::rgb::SRgb;
+use crate::test::assert_approx_eq;
+
+#[cfg(feature = "maths")]
+#[test]
+fn test_ok_hsv() {
+	let red = OkHsv::<f64>::from_s_rgb(SRgb::new(1.0, 0.0, 0.0));
+	assert_approx_eq!(red.get(), (29.233_885, 1.0, 1.0), 1e-3);
+
+	let grey = OkHsv::<f64>::from_s_rgb(SRgb::new(0.5, 0.5, 0.5));
+	assert_approx_eq!((grey.get().0, grey.get().1, 0.0), (0.0, 0.0, 0.0), 1e-9);
+
+	for colour in [SRgb::new(0.4, 0.2, 0.6), SRgb::new(0.9, 0.8, 0.1), SRgb::new(0.1, 0.5, 0.45)] {
+		assert_approx_eq!(OkHsv::<f64>::from_s_rgb(colour).to_s_rgb().get(), colour.get(), 1e-6);
+	}
+}
+
+#[test]
+fn test_ok_hsv_gamut() {
+	let inside = OkHsv::<f64>::new(120.0, 0.5, 1.0);
+
+	assert!(inside.is_in_gamut(0.0));
+	assert_eq!(inside.clip(), inside);
+	assert_eq!(inside.gamut_distance(), 0.0);
+
+	// The hue is unbounded.
+	let outside = OkHsv::<f64>::new(400.0, 1.25, -0.125);
+
+	assert!(!outside.is_in_gamut(0.0));
+	assert!(outside.is_in_gamut(0.25));
+	assert_eq!(outside.clip(), OkHsv::new(400.0, 1.0, 0.0));
+	assert_eq!(outside.gamut_distance(), 0.25);
+}
+
+#[cfg(feature = "maths")]
+#[test]
+fn test_ok_hsl() {
+	let red = OkHsl::<f64>::from_s_rgb(SRgb::new(1.0, 0.0, 0.0));
+	assert_approx_eq!(red.get(), (29.233_885, 1.0, 0.568_085), 1e-3);
+
+	let white = OkHsl::<f64>::from_s_rgb(SRgb::new(1.0, 1.0, 1.0));
+	assert_approx_eq!(white.get(), (0.0, 0.0, 1.0), 1e-6);
+
+	for colour in [SRgb::new(0.4, 0.2, 0.6), SRgb::new(0.9, 0.8, 0.1), SRgb::new(0.1, 0.5, 0.45)] {
+		assert_approx_eq!(OkHsl::<f64>::from_s_rgb(colour).to_s_rgb().get(), colour.get(), 1e-6);
+	}
+}
+
+#[test]
+fn test_hsl_hsv_hwb_from_s_rgb() {
+	let colour = SRgb::new(0.4, 0.2, 0.6);
+
+	assert_approx_eq!(Hsl::<f64>::from_s_rgb(colour).get(), (270.0, 0.5, 0.4), 1e-12);
+	assert_approx_eq!(Hsv::<f64>::from_s_rgb(colour).get(), (270.0, 2.0 / 3.0, 0.6), 1e-12);
+	assert_approx_eq!(Hwb::<f64>::from_s_rgb(colour).get(), (270.0, 0.2, 0.4), 1e-12);
+
+	assert_approx_eq!(Hsl::<f64>::from_s_rgb(colour).to_s_rgb().get(), colour.get(), 1e-12);
+	assert_approx_eq!(Hsv::<f64>::from_s_rgb(colour).to_s_rgb().get(), colour.get(), 1e-12);
+	assert_approx_eq!(Hwb::<f64>::from_s_rgb(colour).to_s_rgb().get(), colour.get(), 1e-12);
+}
+
+#[test]
+fn test_hsl_hsv_hwb_const() {
+	const COLOUR: SRgb<f64> = SRgb::new(0.4, 0.2, 0.6);
+
+	const HSL: Hsl<f64> = Hsl::<f64>::from_s_rgb(COLOUR);
+	const HSV: Hsv<f64> = Hsv::<f64>::from_s_rgb(COLOUR);
+	const HWB: Hwb<f64> = Hsv::<f64>::from_s_rgb(COLOUR).to_hwb();
+
+	assert_eq!(HSL, Hsl::<f64>::from_s_rgb(COLOUR));
+	assert_eq!(HSV, Hsv::<f64>::from_s_rgb(COLOUR));
+	assert_approx_eq!(HWB.get(), Hwb::<f64>::from_s_rgb(COLOUR).get(), 1e-12);
+
+	const ROUND_TRIP: SRgb<f64> = Hwb::<f64>::new(270.0, 0.2, 0.4).to_s_rgb();
+
+	assert_approx_eq!(ROUND_TRIP.get(), COLOUR.get(), 1e-12);
+}
@@ -0,0 +1,43 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::{HueInterpolation, Interpolate};
+
+/// Denotes a colour with an interpolable hue channel.
+///
+/// Hues are measured in degrees and are thus periodic, meaning that there are always two arcs between any two hues.
+/// The arc that is traversed is selected by a [`HueInterpolation`] method.
+///
+/// If the hue of one colour is *powerless* -- e.g. if its chroma or saturation is zero or negligible -- then the hue of the other colour is used for both.
+pub trait HueInterpolate: Interpolate {
+	/// Interpolates between two colours using the given hue-interpolation method.
+	///
+	/// All other channels are interpolated as by [`interpolate`](Interpolate::interpolate), which itself uses [`Shorter`](HueInterpolation::Shorter).
+	/// The resulting hue is always in the range `0` to `360` (exclusive).
+	#[must_use]
+	fn interpolate_hue(self, other: Self, factor: Self::Scalar, method: HueInterpolation) -> Self;
+}
+
+// The thresholds below which hues are powerless.
+//
+// Chroma and saturation values are considered zero
+// if they are negligible relative to their typical
+// ranges, as conversions of achromatic colours
+// rarely yield exact zeroes.
+
+/// The powerless threshold for saturations in the range `0` to `1`.
+pub(crate) const POWERLESS_SATURATION: f64 = 0.000_001;
+
+/// The powerless threshold for saturations in the range `0` to `100`.
+pub(crate) const POWERLESS_LUV_SATURATION: f64 = 0.000_1;
+
+/// The powerless threshold for CIE chromas.
+pub(crate) const POWERLESS_CIE_CHROMA: f64 = 0.015;
+
+/// The powerless threshold for Oklab chromas.
+pub(crate) const POWERLESS_OK_CHROMA: f64 = 0.000_04;
@@ -0,0 +1,27 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+/// A hue-interpolation method.
+///
+/// These correspond to the [CSS](https://www.w3.org/TR/css-color-4/#hue-interpolation) methods of the same names and select which of the two arcs between two hues is traversed.
+/// See [`HueInterpolate`](crate::HueInterpolate) for more information.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum HueInterpolation {
+	/// The shorter arc, i.e. the one spanning at most 180 degrees.
+	#[default]
+	Shorter,
+
+	/// The longer arc, i.e. the one spanning at least 180 degrees.
+	Longer,
+
+	/// The arc along which the hue increases.
+	Increasing,
+
+	/// The arc along which the hue decreases.
+	Decreasing,
+}
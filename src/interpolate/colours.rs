@@ -0,0 +1,392 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::{
+	Alpha,
+	BalancedColour,
+	HueInterpolate,
+	HueInterpolation,
+	Interpolate,
+};
+use crate::hsv::{Hsl, Hsv, Hwb, OkHsl, OkHsv};
+use crate::hue_interpolate::{
+	POWERLESS_CIE_CHROMA,
+	POWERLESS_LUV_SATURATION,
+	POWERLESS_OK_CHROMA,
+	POWERLESS_SATURATION,
+};
+use crate::lab::{CieLab, CieLuv, OkLab, OkLrab};
+use crate::lch::{CieLch, CieLchUv, HpLuv, HsLuv, OkLch, OkLrch};
+use crate::rgb::{DisplayP3, OpRgb, ProPhotoRgb, Rec2020, Rgb, SRgb};
+use crate::www::Html;
+use crate::xyz::CieXyz;
+
+/// Interpolation of angles in degrees.
+trait InterpolateAngle: Sized {
+	#[must_use]
+	fn normalise_angle(self) -> Self;
+
+	#[must_use]
+	fn interpolate_angle(self, other: Self, factor: Self, method: HueInterpolation) -> Self;
+}
+
+macro_rules! impl_interpolate_angle {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl InterpolateAngle for $tys {
+				#[inline]
+				fn normalise_angle(self) -> Self {
+					let angle = self % 360.0;
+
+					if angle < 0.0 {
+						angle + 360.0
+					} else {
+						angle
+					}
+				}
+
+				#[inline]
+				fn interpolate_angle(self, other: Self, factor: Self, method: HueInterpolation) -> Self {
+					let mut this  = self.normalise_angle();
+					let mut other = other.normalise_angle();
+
+					let delta = other - this;
+
+					match method {
+						HueInterpolation::Shorter => {
+							if delta > 180.0 {
+								this += 360.0;
+							} else if delta < -180.0 {
+								other += 360.0;
+							}
+						}
+
+						HueInterpolation::Longer => {
+							if delta > 0.0 && delta < 180.0 {
+								this += 360.0;
+							} else if delta > -180.0 && delta <= 0.0 {
+								other += 360.0;
+							}
+						}
+
+						HueInterpolation::Increasing => {
+							if other < this {
+								other += 360.0;
+							}
+						}
+
+						HueInterpolation::Decreasing => {
+							if this < other {
+								this += 360.0;
+							}
+						}
+					}
+
+					this.interpolate(other, factor).normalise_angle()
+				}
+			}
+		)*
+	};
+}
+
+/// Implements [`Interpolate`] for colours without
+/// hue channels.
+macro_rules! impl_interpolate_rectangular {
+	($tys:ty: $($colours:ident),+$(,)?) => {
+		$(
+			impl Interpolate for $colours<$tys> {
+				type Scalar = $tys;
+
+				#[inline]
+				fn interpolate(self, other: Self, factor: Self::Scalar) -> Self {
+					let (this_0,  this_1,  this_2)  = self.get();
+					let (other_0, other_1, other_2) = other.get();
+
+					Self::new(
+						this_0.interpolate(other_0, factor),
+						this_1.interpolate(other_1, factor),
+						this_2.interpolate(other_2, factor),
+					)
+				}
+
+				#[inline]
+				fn premultiply(self, alpha: Self::Scalar) -> Self {
+					self.map(|channel| channel.premultiply(alpha))
+				}
+
+				#[inline]
+				fn unpremultiply(self, alpha: Self::Scalar) -> Self {
+					self.map(|channel| channel.unpremultiply(alpha))
+				}
+			}
+		)*
+	};
+}
+
+/// Implements [`Interpolate`] and [`HueInterpolate`]
+/// for colours with a hue channel.
+///
+/// The hue channel is given by its index, and the
+/// powerless condition is evaluated on an array of
+/// the three channels.
+macro_rules! impl_interpolate_polar {
+	{
+		$tys:ty:
+		$(
+			$colours:ident {
+				hue: $hue:literal,
+				powerless($channels:ident) => $powerless:expr$(,)?
+			}
+		),+$(,)?
+	} => {
+		$(
+			impl Interpolate for $colours<$tys> {
+				type Scalar = $tys;
+
+				#[inline(always)]
+				fn interpolate(self, other: Self, factor: Self::Scalar) -> Self {
+					self.interpolate_hue(other, factor, HueInterpolation::Shorter)
+				}
+
+				#[inline]
+				fn premultiply(self, alpha: Self::Scalar) -> Self {
+					let (channel_0, channel_1, channel_2) = self.get();
+
+					let mut channels = [channel_0, channel_1, channel_2];
+
+					for (index, channel) in channels.iter_mut().enumerate() {
+						if index != $hue {
+							*channel = channel.premultiply(alpha);
+						}
+					}
+
+					let [channel_0, channel_1, channel_2] = channels;
+					Self::new(channel_0, channel_1, channel_2)
+				}
+
+				#[inline]
+				fn unpremultiply(self, alpha: Self::Scalar) -> Self {
+					let (channel_0, channel_1, channel_2) = self.get();
+
+					let mut channels = [channel_0, channel_1, channel_2];
+
+					for (index, channel) in channels.iter_mut().enumerate() {
+						if index != $hue {
+							*channel = channel.unpremultiply(alpha);
+						}
+					}
+
+					let [channel_0, channel_1, channel_2] = channels;
+					Self::new(channel_0, channel_1, channel_2)
+				}
+			}
+
+			impl HueInterpolate for $colours<$tys> {
+				#[inline]
+				fn interpolate_hue(self, other: Self, factor: Self::Scalar, method: HueInterpolation) -> Self {
+					let is_powerless = |$channels: [$tys; 0x3]| -> bool {
+						$powerless
+					};
+
+					let (this_0,  this_1,  this_2)  = self.get();
+					let (other_0, other_1, other_2) = other.get();
+
+					let mut this  = [this_0,  this_1,  this_2];
+					let mut other = [other_0, other_1, other_2];
+
+					if is_powerless(this) {
+						this[$hue] = other[$hue];
+					} else if is_powerless(other) {
+						other[$hue] = this[$hue];
+					}
+
+					let mut channels = [0.0; 0x3];
+
+					for (index, channel) in channels.iter_mut().enumerate() {
+						*channel = if index == $hue {
+							this[index].interpolate_angle(other[index], factor, method)
+						} else {
+							this[index].interpolate(other[index], factor)
+						};
+					}
+
+					let [channel_0, channel_1, channel_2] = channels;
+					Self::new(channel_0, channel_1, channel_2)
+				}
+			}
+		)*
+	};
+}
+
+macro_rules! impl_interpolate_colours {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl_interpolate_angle!($tys);
+
+			impl_interpolate_rectangular! {
+				$tys:
+				CieLab,
+				CieLuv,
+				CieXyz,
+				DisplayP3,
+				OkLab,
+				OkLrab,
+				OpRgb,
+				ProPhotoRgb,
+				Rec2020,
+				Rgb,
+				SRgb,
+			}
+
+			impl_interpolate_polar! {
+				$tys:
+
+				Hsl      { hue: 0x0, powerless(channels) => channels[0x1].abs() < POWERLESS_SATURATION as $tys },
+				Hsv      { hue: 0x0, powerless(channels) => channels[0x1].abs() < POWERLESS_SATURATION as $tys },
+				Hwb      { hue: 0x0, powerless(channels) => channels[0x1] + channels[0x2] >= 1.0 - POWERLESS_SATURATION as $tys },
+				OkHsl    { hue: 0x0, powerless(channels) => channels[0x1].abs() < POWERLESS_SATURATION as $tys },
+				OkHsv    { hue: 0x0, powerless(channels) => channels[0x1].abs() < POWERLESS_SATURATION as $tys },
+				HpLuv    { hue: 0x0, powerless(channels) => channels[0x1].abs() < POWERLESS_LUV_SATURATION as $tys },
+				HsLuv    { hue: 0x0, powerless(channels) => channels[0x1].abs() < POWERLESS_LUV_SATURATION as $tys },
+				CieLch   { hue: 0x2, powerless(channels) => channels[0x1].abs() < POWERLESS_CIE_CHROMA as $tys },
+				CieLchUv { hue: 0x2, powerless(channels) => channels[0x1].abs() < POWERLESS_CIE_CHROMA as $tys },
+				OkLch    { hue: 0x2, powerless(channels) => channels[0x1].abs() < POWERLESS_OK_CHROMA as $tys },
+				OkLrch   { hue: 0x2, powerless(channels) => channels[0x1].abs() < POWERLESS_OK_CHROMA as $tys },
+			}
+		)*
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_interpolate_colours!(f16);
+
+impl_interpolate_colours!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_interpolate_colours!(f128);
+
+impl<T> Interpolate for Alpha<T>
+where
+	T:            BalancedColour + Interpolate<Scalar = T::Component>,
+	T::Component: Interpolate<Scalar = T::Component>,
+{
+	type Scalar = T::Component;
+
+	#[inline]
+	fn interpolate(self, other: Self, factor: Self::Scalar) -> Self {
+		let (this_colour,  this_alpha)  = self.detach();
+		let (other_colour, other_alpha) = other.detach();
+
+		let alpha = this_alpha.interpolate(other_alpha, factor);
+
+		let colour = this_colour
+			.premultiply(this_alpha)
+			.interpolate(other_colour.premultiply(other_alpha), factor)
+			.unpremultiply(alpha);
+
+		Self::attach(colour, alpha)
+	}
+
+	/// Premultiplies the colour by an alpha value.
+	///
+	/// Both the colour channels and the alpha channel itself are multiplied.
+	#[inline]
+	fn premultiply(self, alpha: Self::Scalar) -> Self {
+		let (colour, this_alpha) = self.detach();
+		Self::attach(colour.premultiply(alpha), this_alpha.premultiply(alpha))
+	}
+
+	/// Reverts a premultiplication by an alpha value.
+	///
+	/// Both the colour channels and the alpha channel itself are divided.
+	#[inline]
+	fn unpremultiply(self, alpha: Self::Scalar) -> Self {
+		let (colour, this_alpha) = self.detach();
+		Self::attach(colour.unpremultiply(alpha), this_alpha.unpremultiply(alpha))
+	}
+}
+
+impl<T> HueInterpolate for Alpha<T>
+where
+	T:            BalancedColour + HueInterpolate<Scalar = T::Component>,
+	T::Component: Interpolate<Scalar = T::Component>,
+{
+	#[inline]
+	fn interpolate_hue(self, other: Self, factor: Self::Scalar, method: HueInterpolation) -> Self {
+		let (this_colour,  this_alpha)  = self.detach();
+		let (other_colour, other_alpha) = other.detach();
+
+		let alpha = this_alpha.interpolate(other_alpha, factor);
+
+		let colour = this_colour
+			.premultiply(this_alpha)
+			.interpolate_hue(other_colour.premultiply(other_alpha), factor, method)
+			.unpremultiply(alpha);
+
+		Self::attach(colour, alpha)
+	}
+}
+
+impl Interpolate for Html {
+	type Scalar = f32;
+
+	/// Interpolates between two HTML colours.
+	///
+	/// This is done in sRGB using premultiplied alpha, with the result being rounded to the nearest representable colour.
+	#[inline]
+	fn interpolate(self, other: Self, factor: Self::Scalar) -> Self {
+		let this  = html_to_float(self);
+		let other = html_to_float(other);
+
+		html_from_float(this.interpolate(other, factor))
+	}
+
+	/// Premultiplies the colour by an alpha value.
+	///
+	/// Both the colour channels and the alpha channel are multiplied, and the result is rounded to the nearest representable colour.
+	/// Note that this quantisation makes premultiplication lossy, especially for small alpha values.
+	/// Use [`Alpha<SRgb<f32>>`](Alpha) for premultiplied colours that can be reverted exactly.
+	#[inline]
+	fn premultiply(self, alpha: Self::Scalar) -> Self {
+		html_from_float(html_to_float(self).premultiply(alpha))
+	}
+
+	/// Reverts a premultiplication by an alpha value.
+	///
+	/// The result is rounded to the nearest representable colour.
+	/// Because [`premultiply`](Self::premultiply) quantises its result, this does not in general restore the original colour.
+	#[inline]
+	fn unpremultiply(self, alpha: Self::Scalar) -> Self {
+		html_from_float(html_to_float(self).unpremultiply(alpha))
+	}
+}
+
+#[inline]
+#[must_use]
+fn html_to_float(colour: Html) -> Alpha<SRgb<f32>> {
+	let (colour, alpha) = colour.to_s_rgba().detach();
+
+	let colour = colour.map(|channel| f32::from(channel) / 255.0);
+	let alpha  = f32::from(alpha) / 255.0;
+
+	Alpha::attach(colour, alpha)
+}
+
+#[inline]
+#[must_use]
+fn html_from_float(colour: Alpha<SRgb<f32>>) -> Html {
+	// Note that float-to-integer casts saturate.
+	let to_u8 = |channel: f32| (channel * 255.0 + 0.5) as u8;
+
+	let (colour, alpha) = colour.detach();
+
+	let colour = colour.map(to_u8);
+	let alpha  = to_u8(alpha);
+
+	Html::from_s_rgba(Alpha::attach(colour, alpha))
+}
@@ -0,0 +1,81 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+mod colours;
+mod test;
+
+use crate::Component;
+
+/// Denotes a type that can be linearly interpolated.
+///
+/// This is implemented for all colours with floating-point components and for [`Html`](crate::www::Html), as well as for the floating-point types themselves.
+/// Colours are interpolated channel-wise in their own colour space.
+/// Colours with a hue channel do so along the shorter arc (see [`HueInterpolate`](crate::HueInterpolate) for other methods), and powerless hues -- e.g. that of a colour without chroma -- are replaced by that of the other colour.
+///
+/// For [`Alpha`](crate::Alpha), interpolation is done using [premultiplied](Self::premultiply) channels.
+/// Mixing opaque red and fully transparent blue thus yields semi-transparent red, as is the case with CSS.
+pub trait Interpolate: Sized {
+	/// The type of interpolation factors.
+	type Scalar: Component;
+
+	/// Interpolates between two values.
+	///
+	/// A factor of zero yields `self` and a factor of one yields `other`.
+	/// Factors outside of this range extrapolate.
+	#[must_use]
+	fn interpolate(self, other: Self, factor: Self::Scalar) -> Self;
+
+	/// Premultiplies the value by an alpha value.
+	///
+	/// Hue channels are left untouched.
+	#[must_use]
+	fn premultiply(self, alpha: Self::Scalar) -> Self;
+
+	/// Reverts a premultiplication by an alpha value.
+	///
+	/// If `alpha` is zero, then the value is returned unchanged.
+	#[must_use]
+	fn unpremultiply(self, alpha: Self::Scalar) -> Self;
+}
+
+macro_rules! impl_interpolate_float {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::Interpolate for $tys {
+				type Scalar = Self;
+
+				#[inline(always)]
+				fn interpolate(self, other: Self, factor: Self::Scalar) -> Self {
+					self + (other - self) * factor
+				}
+
+				#[inline(always)]
+				fn premultiply(self, alpha: Self::Scalar) -> Self {
+					self * alpha
+				}
+
+				#[inline]
+				fn unpremultiply(self, alpha: Self::Scalar) -> Self {
+					if alpha == 0.0 {
+						self
+					} else {
+						self / alpha
+					}
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_interpolate_float!(f16);
+
+impl_interpolate_float!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_interpolate_float!(f128);
@@ -0,0 +1,110 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+#![cfg(test)]
+
+use crate::{Alpha, HueInterpolate, HueInterpolation, Interpolate};
+use crate::hsv::{Hsl, Hwb};
+use crate::lch::OkLch;
+use crate::rgb::SRgb;
+use crate::www::Html;
+
+#[test]
+fn test_interpolate_rectangular() {
+	let black = SRgb::<f32>::new(0.0, 0.0, 0.0);
+	let white = SRgb::<f32>::new(1.0, 1.0, 1.0);
+
+	assert_eq!(black.interpolate(white, 0.0),  black);
+	assert_eq!(black.interpolate(white, 1.0),  white);
+	assert_eq!(black.interpolate(white, 0.25), SRgb::new(0.25, 0.25, 0.25));
+}
+
+#[test]
+fn test_interpolate_hue() {
+	let this  = OkLch::<f64>::new(0.5, 0.1, 350.0);
+	let other = OkLch::<f64>::new(0.5, 0.1, 30.0);
+
+	let hue = |method| this.interpolate_hue(other, 0.5, method).get().2;
+
+	assert_eq!(hue(HueInterpolation::Shorter),    10.0);
+	assert_eq!(hue(HueInterpolation::Longer),     190.0);
+	assert_eq!(hue(HueInterpolation::Increasing), 10.0);
+	assert_eq!(hue(HueInterpolation::Decreasing), 190.0);
+
+	assert_eq!(this.interpolate(other, 0.5).get().2, 10.0);
+
+	let this  = Hsl::<f64>::new(30.0, 1.0, 0.5);
+	let other = Hsl::<f64>::new(90.0, 1.0, 0.5);
+
+	let hue = |method| this.interpolate_hue(other, 0.5, method).get().0;
+
+	assert_eq!(hue(HueInterpolation::Shorter),    60.0);
+	assert_eq!(hue(HueInterpolation::Longer),     240.0);
+	assert_eq!(hue(HueInterpolation::Increasing), 60.0);
+	assert_eq!(hue(HueInterpolation::Decreasing), 240.0);
+}
+
+#[test]
+fn test_interpolate_powerless_hue() {
+	let grey = OkLch::<f64>::new(0.5, 0.0, 0.0);
+	let blue = OkLch::<f64>::new(0.5, 0.2, 260.0);
+
+	assert_eq!(grey.interpolate(blue, 0.5), OkLch::new(0.5, 0.1, 260.0));
+	assert_eq!(blue.interpolate(grey, 0.5), OkLch::new(0.5, 0.1, 260.0));
+
+	let grey = Hwb::<f64>::new(0.0, 0.5, 0.5);
+	let red  = Hwb::<f64>::new(120.0, 0.0, 0.0);
+
+	assert_eq!(grey.interpolate(red, 0.5), Hwb::new(120.0, 0.25, 0.25));
+}
+
+#[cfg(feature = "maths")]
+#[test]
+fn test_interpolate_near_powerless_hue() {
+	use crate::lab::OkLab;
+
+	// Converted achromatic colours retain a tiny
+	// chroma with an arbitrary hue.
+	let white = OkLch::<f64>::from_ok_lab(OkLab::<f64>::from_s_rgb(SRgb::new(1.0, 1.0, 1.0)));
+	let blue  = OkLch::<f64>::from_ok_lab(OkLab::<f64>::from_s_rgb(SRgb::new(0.0, 0.0, 1.0)));
+
+	assert!(white.get().1 > 0.0);
+
+	let (_, _, hue) = white.interpolate(blue, 0.5).get();
+
+	assert!((hue - blue.get().2).abs() < 1e-9);
+	assert!((hue - 264.052).abs() < 1e-3);
+}
+
+#[test]
+fn test_interpolate_premultiplied() {
+	let red  = Alpha::attach(SRgb::<f32>::new(1.0, 0.0, 0.0), 1.0);
+	let blue = Alpha::attach(SRgb::<f32>::new(0.0, 0.0, 1.0), 0.0);
+
+	assert_eq!(red.interpolate(blue, 0.5), Alpha::attach(SRgb::new(1.0, 0.0, 0.0), 0.5));
+
+	let red  = Alpha::attach(SRgb::<f32>::new(1.0, 0.0, 0.0), 1.0);
+	let blue = Alpha::attach(SRgb::<f32>::new(0.0, 0.0, 1.0), 0.5);
+
+	let (colour, alpha) = red.interpolate(blue, 0.5).detach();
+	let (red, green, blue) = colour.get();
+
+	assert_eq!(alpha, 0.75);
+	assert!((red - 2.0 / 3.0).abs() < 1e-6);
+	assert_eq!(green, 0.0);
+	assert!((blue - 1.0 / 3.0).abs() < 1e-6);
+
+	let transparent = Html::new(0x00, 0x00, 0x00, 0x00);
+
+	assert_eq!(Html::REBECCA_PURPLE.interpolate(transparent, 0.5), Html::new(0x66, 0x33, 0x99, 0x80));
+
+	let colour = Alpha::attach(SRgb::<f32>::new(1.0, 0.5, 0.25), 0.5);
+
+	assert_eq!(colour.premultiply(0.5), Alpha::attach(SRgb::new(0.5, 0.25, 0.125), 0.25));
+	assert_eq!(colour.premultiply(0.5).unpremultiply(0.5), colour);
+}
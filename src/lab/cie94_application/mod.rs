@@ -0,0 +1,20 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+/// An application domain of the CIE94 colour difference.
+///
+/// CIE94 defines different weighting factors depending on the application domain (see [`CieLab::delta_e_94`](crate::lab::CieLab::delta_e_94)).
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Cie94Application {
+	/// Graphic arts, i.e. `k_L = 1`, `K_1 = 0.045`, and `K_2 = 0.015`.
+	#[default]
+	GraphicArts,
+
+	/// Textiles, i.e. `k_L = 2`, `K_1 = 0.048`, and `K_2 = 0.014`.
+	Textiles,
+}
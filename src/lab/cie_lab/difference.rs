@@ -0,0 +1,241 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+#[cfg(feature = "maths")]
+use crate::maths::Maths;
+
+macro_rules! impl_difference {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::lab::CieLab<$tys> {
+				/// Computes the CIE76 colour difference (ΔE*<sub>ab</sub>).
+				///
+				/// This is the Euclidean distance between the two colours.
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn delta_e_76(self, other: Self) -> $tys {
+					let (l0, a0, b0) = self.get();
+					let (l1, a1, b1) = other.get();
+
+					let delta_l = l0 - l1;
+					let delta_a = a0 - a1;
+					let delta_b = b0 - b1;
+
+					Maths::sqrt(delta_l * delta_l + delta_a * delta_a + delta_b * delta_b)
+				}
+
+				/// Computes the CIE94 colour difference (ΔE*<sub>94</sub>).
+				///
+				/// Note that CIE94 is not symmetric: `self` is taken to be the reference colour, and `other` the sample.
+				#[cfg(feature = "maths")]
+				#[must_use]
+				pub fn delta_e_94(self, other: Self, application: ::polywave::lab::Cie94Application) -> $tys {
+					use ::polywave::lab::Cie94Application;
+
+					let (k_l, k_1, k_2) = match application {
+						Cie94Application::GraphicArts => (1.0, 0.045, 0.015),
+						Cie94Application::Textiles    => (2.0, 0.048, 0.014),
+					};
+
+					let (l0, a0, b0) = self.get();
+					let (l1, a1, b1) = other.get();
+
+					let c0 = Maths::hypot(a0, b0);
+					let c1 = Maths::hypot(a1, b1);
+
+					let delta_l = l0 - l1;
+					let delta_c = c0 - c1;
+					let delta_a = a0 - a1;
+					let delta_b = b0 - b1;
+
+					let delta_h_squared = (delta_a * delta_a + delta_b * delta_b - delta_c * delta_c).max(0.0);
+
+					let s_c = 1.0 + k_1 * c0;
+					let s_h = 1.0 + k_2 * c0;
+
+					let l = delta_l / k_l;
+					let c = delta_c / s_c;
+
+					Maths::sqrt(l * l + c * c + delta_h_squared / (s_h * s_h))
+				}
+
+				/// Computes the CIEDE2000 colour difference (ΔE<sub>00</sub>).
+				///
+				/// The parametric weighting factors `k_l`, `k_c`, and `k_h` are usually all `1.0`.
+				///
+				/// This implementation follows the formulation of [Sharma *et al.*](https://hajim.rochester.edu/ece/sites/gsharma/ciede2000/).
+				#[cfg(feature = "maths")]
+				#[must_use]
+				pub fn delta_e_2000(self, other: Self, k_l: $tys, k_c: $tys, k_h: $tys) -> $tys {
+					// Computes `x^7 / (x^7 + 25^7)` without
+					// overflowing for small component types.
+					let ratio = |chroma: $tys| -> $tys {
+						1.0 / (1.0 + Maths::powi(25.0 / chroma, 0x7))
+					};
+
+					let (l0, a0, b0) = self.get();
+					let (l1, a1, b1) = other.get();
+
+					let mean_c = (Maths::hypot(a0, b0) + Maths::hypot(a1, b1)) / 2.0;
+
+					let g = 0.5 * (1.0 - Maths::sqrt(ratio(mean_c)));
+
+					let a0 = (1.0 + g) * a0;
+					let a1 = (1.0 + g) * a1;
+
+					let c0 = Maths::hypot(a0, b0);
+					let c1 = Maths::hypot(a1, b1);
+
+					let hue = |a: $tys, b: $tys| -> $tys {
+						if a == 0.0 && b == 0.0 {
+							0.0
+						} else {
+							Maths::rem_euclid(Maths::atan2(b, a).to_degrees(), 360.0)
+						}
+					};
+
+					let h0 = hue(a0, b0);
+					let h1 = hue(a1, b1);
+
+					let delta_l = l1 - l0;
+					let delta_c = c1 - c0;
+
+					let is_achromatic = c0 * c1 == 0.0;
+
+					let delta_h = if is_achromatic {
+						0.0
+					} else {
+						let delta = h1 - h0;
+
+						if Maths::abs(delta) <= 180.0 {
+							delta
+						} else if delta > 180.0 {
+							delta - 360.0
+						} else {
+							delta + 360.0
+						}
+					};
+
+					let delta_h = 2.0 * Maths::sqrt(c0 * c1) * Maths::sin((delta_h / 2.0).to_radians());
+
+					let mean_l = (l0 + l1) / 2.0;
+					let mean_c = (c0 + c1) / 2.0;
+
+					let mean_h = if is_achromatic {
+						h0 + h1
+					} else if Maths::abs(h0 - h1) <= 180.0 {
+						(h0 + h1) / 2.0
+					} else if h0 + h1 < 360.0 {
+						(h0 + h1 + 360.0) / 2.0
+					} else {
+						(h0 + h1 - 360.0) / 2.0
+					};
+
+					let t = 1.0
+						- 0.17 * Maths::cos((mean_h - 30.0).to_radians())
+						+ 0.24 * Maths::cos((2.0 * mean_h).to_radians())
+						+ 0.32 * Maths::cos((3.0 * mean_h + 6.0).to_radians())
+						- 0.20 * Maths::cos((4.0 * mean_h - 63.0).to_radians());
+
+					let delta_theta = {
+						let value = (mean_h - 275.0) / 25.0;
+						30.0 * Maths::exp(-(value * value))
+					};
+
+					let r_c = 2.0 * Maths::sqrt(ratio(mean_c));
+
+					let s_l = {
+						let value = (mean_l - 50.0) * (mean_l - 50.0);
+						1.0 + 0.015 * value / Maths::sqrt(20.0 + value)
+					};
+
+					let s_c = 1.0 + 0.045 * mean_c;
+					let s_h = 1.0 + 0.015 * mean_c * t;
+
+					let r_t = -Maths::sin((2.0 * delta_theta).to_radians()) * r_c;
+
+					let l = delta_l / (k_l * s_l);
+					let c = delta_c / (k_c * s_c);
+					let h = delta_h / (k_h * s_h);
+
+					Maths::sqrt(l * l + c * c + h * h + r_t * c * h)
+				}
+
+				/// Computes the CMC l:c colour difference (ΔE<sub>CMC</sub>).
+				///
+				/// The lightness and chroma weights `l` and `c` are commonly `2.0` and `1.0` for acceptability, or `1.0` and `1.0` for perceptibility.
+				///
+				/// Note that CMC l:c is not symmetric: `self` is taken to be the reference colour, and `other` the sample.
+				#[cfg(feature = "maths")]
+				#[must_use]
+				pub fn delta_e_cmc(self, other: Self, l: $tys, c: $tys) -> $tys {
+					let (l0, a0, b0) = self.get();
+					let (l1, a1, b1) = other.get();
+
+					let c0 = Maths::hypot(a0, b0);
+					let c1 = Maths::hypot(a1, b1);
+
+					let delta_l = l0 - l1;
+					let delta_c = c0 - c1;
+					let delta_a = a0 - a1;
+					let delta_b = b0 - b1;
+
+					let delta_h_squared = (delta_a * delta_a + delta_b * delta_b - delta_c * delta_c).max(0.0);
+
+					let h0 = Maths::rem_euclid(Maths::atan2(b0, a0).to_degrees(), 360.0);
+
+					// Computes `sqrt(c^4 / (c^4 + 1900))` without
+					// overflowing for small component types.
+					let f = Maths::sqrt(1.0 / (1.0 + 1900.0 / Maths::powi(c0, 0x4)));
+
+					let t = if (164.0..=345.0).contains(&h0) {
+						0.56 + Maths::abs(0.2 * Maths::cos((h0 + 168.0).to_radians()))
+					} else {
+						0.36 + Maths::abs(0.4 * Maths::cos((h0 + 35.0).to_radians()))
+					};
+
+					let s_l = if l0 < 16.0 {
+						0.511
+					} else {
+						0.040_975 * l0 / (1.0 + 0.017_65 * l0)
+					};
+
+					let s_c = 0.063_8 * c0 / (1.0 + 0.013_1 * c0) + 0.638;
+					let s_h = s_c * (f * t + 1.0 - f);
+
+					let l = delta_l / (l * s_l);
+					let c = delta_c / (c * s_c);
+
+					Maths::sqrt(l * l + c * c + delta_h_squared / (s_h * s_h))
+				}
+			}
+
+			#[cfg(feature = "maths")]
+			impl ::polywave::ColourDifference for ::polywave::lab::CieLab<$tys> {
+				type Difference = $tys;
+
+				/// Computes the CIEDE2000 colour difference with unit weights.
+				///
+				/// See [`delta_e_2000`](Self::delta_e_2000) for more information.
+				#[inline]
+				fn difference(self, other: Self) -> Self::Difference {
+					self.delta_e_2000(other, 1.0, 1.0, 1.0)
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_difference!(f16);
+
+impl_difference!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_difference!(f128);
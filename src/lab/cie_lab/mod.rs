@@ -6,14 +6,16 @@
 // can obtain one at:
 // <https://mozilla.org/MPL/2.0/>.
 
+mod difference;
+
 use crate::{BalancedColour, Colour, Component, DefinedGamut};
+use crate::component::zero;
+#[cfg(feature = "maths")]
+use crate::maths::Maths;
 
 #[cfg(feature = "bytemuck")]
 use bytemuck::{Pod, Zeroable};
 
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
-
 #[cfg(feature = "zerocopy")]
 use zerocopy::{FromZeros, Immutable, IntoBytes};
 
@@ -23,7 +25,6 @@ use zerocopy::{FromZeros, Immutable, IntoBytes};
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
-#[cfg_attr(feature = "serde",    derive(Deserialize, Serialize))]
 #[cfg_attr(feature = "zerocopy", derive(FromZeros, Immutable, IntoBytes))]
 pub struct CieLab<T>([T; 0x3]);
 
@@ -62,10 +63,113 @@ impl<T: Component> CieLab<T> {
 	}
 }
 
+macro_rules! impl_conversions {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::lab::CieLab<$tys> {
+				/// Converts a CIEXYZ colour to CIELAB.
+				///
+				/// The reference white must be provided as `white`.
+				/// CSS uses [`CieXyz::D50`](crate::xyz::CieXyz::D50) for this purpose.
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn from_xyz(colour: ::polywave::xyz::CieXyz<$tys>, white: ::polywave::xyz::CieXyz<$tys>) -> Self {
+					const EPSILON: $tys = ::polywave::lab::CieLuv::<$tys>::EPSILON;
+					const KAPPA:   $tys = ::polywave::lab::CieLuv::<$tys>::KAPPA;
+
+					let (x,       y,       z)       = colour.get();
+					let (white_x, white_y, white_z) = white.get();
+
+					let f = |value: $tys| {
+						if value > EPSILON {
+							Maths::cbrt(value)
+						} else {
+							(KAPPA * value + 16.0) / 116.0
+						}
+					};
+
+					let f_x = f(x / white_x);
+					let f_y = f(y / white_y);
+					let f_z = f(z / white_z);
+
+					let luminance = 116.0 * f_y - 16.0;
+					let a_star    = 500.0 * (f_x - f_y);
+					let b_star    = 200.0 * (f_y - f_z);
+
+					Self::new(luminance, a_star, b_star)
+				}
+
+				/// Converts the CIELAB colour to CIEXYZ.
+				///
+				/// The reference white must be provided as `white`.
+				#[inline]
+				#[must_use]
+				pub const fn to_xyz(self, white: ::polywave::xyz::CieXyz<$tys>) -> ::polywave::xyz::CieXyz<$tys> {
+					const EPSILON: $tys = ::polywave::lab::CieLuv::<$tys>::EPSILON;
+					const KAPPA:   $tys = ::polywave::lab::CieLuv::<$tys>::KAPPA;
+
+					let (luminance, a_star,  b_star)  = self.get();
+					let (white_x,   white_y, white_z) = white.get();
+
+					let f_y = (luminance + 16.0) / 116.0;
+					let f_x = f_y + a_star / 500.0;
+					let f_z = f_y - b_star / 200.0;
+
+					let x = if f_x * f_x * f_x > EPSILON {
+						f_x * f_x * f_x
+					} else {
+						(116.0 * f_x - 16.0) / KAPPA
+					};
+
+					let y = if luminance > KAPPA * EPSILON {
+						f_y * f_y * f_y
+					} else {
+						luminance / KAPPA
+					};
+
+					let z = if f_z * f_z * f_z > EPSILON {
+						f_z * f_z * f_z
+					} else {
+						(116.0 * f_z - 16.0) / KAPPA
+					};
+
+					::polywave::xyz::CieXyz::new(x * white_x, y * white_y, z * white_z)
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_conversions!(f16);
+
+impl_conversions!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_conversions!(f128);
+
 unsafe impl<T: Component> BalancedColour for CieLab<T> {
 	type Component = T;
 }
 
 impl<T: Component> Colour for CieLab<T> { }
 
-impl<T: Component> DefinedGamut for CieLab<T> { }
+impl<T: Component> DefinedGamut for CieLab<T> {
+	type Scalar = T;
+
+	#[inline(always)]
+	fn is_in_gamut(&self, _epsilon: Self::Scalar) -> bool {
+		true
+	}
+
+	#[inline(always)]
+	fn clip(self) -> Self {
+		self
+	}
+
+	#[inline(always)]
+	fn gamut_distance(&self) -> Self::Scalar {
+		zero()
+	}
+}
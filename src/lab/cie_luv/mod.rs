@@ -0,0 +1,190 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::{BalancedColour, Colour, Component, DefinedGamut};
+use crate::component::zero;
+#[cfg(feature = "maths")]
+use crate::maths::Maths;
+
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+
+#[cfg(feature = "zerocopy")]
+use zerocopy::{FromZeros, Immutable, IntoBytes};
+
+/// A CIELUV colour.
+///
+/// This type guarantees that its three channels -- luminance, u*, and v* -- are stored sequentially in memory (in this order).
+///
+/// Like [`CieLab`](crate::lab::CieLab), CIELUV is defined relative to some reference white, which must be provided explicitly when converting from or to [`CieXyz`](crate::xyz::CieXyz).
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
+#[cfg_attr(feature = "zerocopy", derive(FromZeros, Immutable, IntoBytes))]
+pub struct CieLuv<T>([T; 0x3]);
+
+impl<T: Component> CieLuv<T> {
+	/// Constructs a new CIELUV colour.
+	#[inline(always)]
+	#[must_use]
+	pub const fn new(luminance: T, u_star: T, v_star: T) -> Self {
+		let data = [luminance, u_star, v_star];
+		Self(data)
+	}
+
+	/// Maps the CIELUV colour's channels.
+	#[inline]
+	#[must_use]
+	pub fn map<U, F>(self, mut op: F) -> CieLuv<U>
+	where
+		U: Component,
+		F: FnMut(T) -> U,
+	{
+		let (luminance, u_star, v_star) = self.get();
+
+		let luminance = op(luminance);
+		let u_star    = op(u_star);
+		let v_star    = op(v_star);
+
+		CieLuv::new(luminance, u_star, v_star)
+	}
+
+	/// Deconstructs the CIELUV colour.
+	#[inline(always)]
+	#[must_use]
+	pub const fn get(self) -> (T, T, T) {
+		let [luminance, u_star, v_star] = self.0;
+		(luminance, u_star, v_star)
+	}
+}
+
+macro_rules! impl_conversions {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::lab::CieLuv<$tys> {
+				/// The CIE standard `epsilon` constant, i.e. `(6/29)^3`.
+				pub(crate) const EPSILON: $tys = const { 216.0 / 24_389.0 };
+
+				/// The CIE standard `kappa` constant, i.e. `(29/3)^3`.
+				pub(crate) const KAPPA: $tys = const { 24_389.0 / 27.0 };
+
+				/// Computes the chromaticity coordinates u' and v' of a CIEXYZ colour.
+				#[inline]
+				#[must_use]
+				const fn chromaticity(colour: ::polywave::xyz::CieXyz<$tys>) -> ($tys, $tys) {
+					let (x, y, z) = colour.get();
+
+					let divisor = x + 15.0 * y + 3.0 * z;
+
+					if divisor == 0.0 {
+						return (0.0, 0.0);
+					}
+
+					let u_prime = 4.0 * x / divisor;
+					let v_prime = 9.0 * y / divisor;
+
+					(u_prime, v_prime)
+				}
+
+				/// Converts a CIEXYZ colour to CIELUV.
+				///
+				/// The reference white -- usually [`CieXyz::D65`](crate::xyz::CieXyz::D65) -- must be provided as `white`.
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn from_xyz(colour: ::polywave::xyz::CieXyz<$tys>, white: ::polywave::xyz::CieXyz<$tys>) -> Self {
+					let (_, y, _) = colour.get();
+					let (_, white_y, _) = white.get();
+
+					let y = y / white_y;
+
+					let luminance = if y > Self::EPSILON {
+						116.0 * Maths::cbrt(y) - 16.0
+					} else {
+						Self::KAPPA * y
+					};
+
+					if luminance == 0.0 {
+						return Self::new(0.0, 0.0, 0.0);
+					}
+
+					let (u_prime,       v_prime)       = Self::chromaticity(colour);
+					let (white_u_prime, white_v_prime) = Self::chromaticity(white);
+
+					let u_star = 13.0 * luminance * (u_prime - white_u_prime);
+					let v_star = 13.0 * luminance * (v_prime - white_v_prime);
+
+					Self::new(luminance, u_star, v_star)
+				}
+
+				/// Converts the CIELUV colour to CIEXYZ.
+				///
+				/// The reference white must be the same as was used when the CIELUV colour was constructed (see [`from_xyz`](Self::from_xyz)).
+				#[must_use]
+				pub const fn to_xyz(self, white: ::polywave::xyz::CieXyz<$tys>) -> ::polywave::xyz::CieXyz<$tys> {
+					let (luminance, u_star, v_star) = self.get();
+
+					if luminance == 0.0 {
+						return ::polywave::xyz::CieXyz::new(0.0, 0.0, 0.0);
+					}
+
+					let (_, white_y, _) = white.get();
+					let (white_u_prime, white_v_prime) = Self::chromaticity(white);
+
+					let u_prime = u_star / (13.0 * luminance) + white_u_prime;
+					let v_prime = v_star / (13.0 * luminance) + white_v_prime;
+
+					let y = if luminance > Self::KAPPA * Self::EPSILON {
+						let value = (luminance + 16.0) / 116.0;
+						white_y * value * value * value
+					} else {
+						white_y * luminance / Self::KAPPA
+					};
+
+					let x = y * 9.0 * u_prime / (4.0 * v_prime);
+					let z = y * (12.0 - 3.0 * u_prime - 20.0 * v_prime) / (4.0 * v_prime);
+
+					::polywave::xyz::CieXyz::new(x, y, z)
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_conversions!(f16);
+
+impl_conversions!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_conversions!(f128);
+
+unsafe impl<T: Component> BalancedColour for CieLuv<T> {
+	type Component = T;
+}
+
+impl<T: Component> Colour for CieLuv<T> { }
+
+impl<T: Component> DefinedGamut for CieLuv<T> {
+	type Scalar = T;
+
+	#[inline(always)]
+	fn is_in_gamut(&self, _epsilon: Self::Scalar) -> bool {
+		true
+	}
+
+	#[inline(always)]
+	fn clip(self) -> Self {
+		self
+	}
+
+	#[inline(always)]
+	fn gamut_distance(&self) -> Self::Scalar {
+		zero()
+	}
+}
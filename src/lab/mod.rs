@@ -8,8 +8,15 @@
 
 //! LAB-based colour facilities.
 
+mod cie94_application;
 mod cie_lab;
+mod cie_luv;
 mod ok_lab;
+mod ok_lrab;
+mod test;
 
+pub use cie94_application::Cie94Application;
 pub use cie_lab::CieLab;
+pub use cie_luv::CieLuv;
 pub use ok_lab::OkLab;
+pub use ok_lrab::OkLrab;
@@ -0,0 +1,250 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+//! Facilities for approximating the sRGB gamut in Oklab.
+//!
+//! These are adapted from Björn Ottosson's [reference implementation](https://bottosson.github.io/posts/colorpicker/) of Okhsv and Okhsl.
+//! In all of the following functions, `a` and `b` denote a normalised hue direction, i.e. `a^2 + b^2 = 1`.
+
+#[cfg(feature = "maths")]
+use crate::maths::Maths;
+
+macro_rules! impl_cusp {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::lab::OkLab<$tys> {
+				/// Computes the coefficients used when differentiating LMS with respect to chroma.
+				#[allow(clippy::excessive_precision)]
+				#[must_use]
+				const fn lms_derivative(a: $tys, b: $tys) -> ($tys, $tys, $tys) {
+					let k_l =  0.396_337_777_4 * a + 0.215_803_757_3 * b;
+					let k_m = -0.105_561_345_8 * a - 0.063_854_172_8 * b;
+					let k_s = -0.089_484_177_5 * a - 1.291_485_548_0 * b;
+
+					(k_l, k_m, k_s)
+				}
+
+				/// Computes the maximum saturation (`S = C / L`) possible for a given hue that fits in sRGB.
+				#[allow(clippy::excessive_precision)]
+				#[must_use]
+				pub(crate) const fn max_s_rgb_saturation(a: $tys, b: $tys) -> $tys {
+					// The maximum saturation is reached when one
+					// of the RGB channels goes below zero. Select
+					// coefficients depending on which channel
+					// does so first.
+					let (k0, k1, k2, k3, k4, wl, wm, ws) = if -1.881_703_28 * a - 0.809_364_93 * b > 1.0 {
+						(1.190_862_77, 1.765_767_28, 0.596_626_41, 0.755_151_97, 0.567_712_45, 4.076_741_662_1, -3.307_711_591_3, 0.230_969_929_2)
+					} else if 1.814_441_04 * a - 1.194_452_76 * b > 1.0 {
+						(0.739_565_15, -0.459_544_04, 0.082_854_27, 0.125_410_70, 0.145_032_04, -1.268_438_004_6, 2.609_757_401_1, -0.341_319_396_5)
+					} else {
+						(1.357_336_52, -0.009_157_99, -1.151_302_10, -0.505_596_06, 0.006_921_67, -0.004_196_086_3, -0.703_418_614_7, 1.707_614_701_0)
+					};
+
+					// Approximate using a polynomial.
+					let saturation = k0 + k1 * a + k2 * b + k3 * a * a + k4 * a * b;
+
+					// Do one step of Halley's method to get closer.
+					let (k_l, k_m, k_s) = Self::lms_derivative(a, b);
+
+					let l = 1.0 + saturation * k_l;
+					let m = 1.0 + saturation * k_m;
+					let s = 1.0 + saturation * k_s;
+
+					let l_ds = 3.0 * k_l * l * l;
+					let m_ds = 3.0 * k_m * m * m;
+					let s_ds = 3.0 * k_s * s * s;
+
+					let l_ds2 = 6.0 * k_l * k_l * l;
+					let m_ds2 = 6.0 * k_m * k_m * m;
+					let s_ds2 = 6.0 * k_s * k_s * s;
+
+					let f0 = wl * l * l * l + wm * m * m * m + ws * s * s * s;
+					let f1 = wl * l_ds + wm * m_ds + ws * s_ds;
+					let f2 = wl * l_ds2 + wm * m_ds2 + ws * s_ds2;
+
+					saturation - f0 * f1 / (f1 * f1 - 0.5 * f0 * f2)
+				}
+
+				/// Finds the cusp of the sRGB gamut for a given hue.
+				///
+				/// The cusp is returned as a luminance--chroma pair.
+				#[cfg(feature = "maths")]
+				#[must_use]
+				pub(crate) fn s_rgb_cusp(a: $tys, b: $tys) -> ($tys, $tys) {
+					let saturation = Self::max_s_rgb_saturation(a, b);
+
+					let (red, green, blue) = Self::new(1.0, saturation * a, saturation * b).to_linear_s_rgb().get();
+
+					let luminance = Maths::cbrt(1.0 / red.max(green).max(blue));
+					let chroma    = luminance * saturation;
+
+					(luminance, chroma)
+				}
+
+				/// Finds the intersection of a line with the sRGB gamut.
+				///
+				/// The line is defined from `(l0, 0.0)` to `(l1, c1)`, and `t` is returned such that `(lerp(l0, l1, t), t * c1)` lies on the gamut boundary.
+				#[allow(clippy::excessive_precision)]
+				#[must_use]
+				pub(crate) const fn s_rgb_gamut_intersection(
+					a:    $tys,
+					b:    $tys,
+					l1:   $tys,
+					c1:   $tys,
+					l0:   $tys,
+					cusp: ($tys, $tys),
+				) -> $tys {
+					let (cusp_l, cusp_c) = cusp;
+
+					if (l1 - l0) * cusp_c - (cusp_l - l0) * c1 <= 0.0 {
+						// Lower half.
+						return cusp_c * l0 / (c1 * cusp_l + cusp_c * (l0 - l1));
+					}
+
+					// Upper half: first intersect with the
+					// triangle, and then do one step of Hal-
+					// ley's method.
+					let t = cusp_c * (l0 - 1.0) / (c1 * (cusp_l - 1.0) + cusp_c * (l0 - l1));
+
+					let dl = l1 - l0;
+					let dc = c1;
+
+					let (k_l, k_m, k_s) = Self::lms_derivative(a, b);
+
+					let l_dt = dl + dc * k_l;
+					let m_dt = dl + dc * k_m;
+					let s_dt = dl + dc * k_s;
+
+					let luminance = l0 * (1.0 - t) + t * l1;
+					let chroma    = t * c1;
+
+					let l = luminance + chroma * k_l;
+					let m = luminance + chroma * k_m;
+					let s = luminance + chroma * k_s;
+
+					let l_cubed = l * l * l;
+					let m_cubed = m * m * m;
+					let s_cubed = s * s * s;
+
+					let l_dt1 = 3.0 * l_dt * l * l;
+					let m_dt1 = 3.0 * m_dt * m * m;
+					let s_dt1 = 3.0 * s_dt * s * s;
+
+					let l_dt2 = 6.0 * l_dt * l_dt * l;
+					let m_dt2 = 6.0 * m_dt * m_dt * m;
+					let s_dt2 = 6.0 * s_dt * s_dt * s;
+
+					let weights = [
+						( 4.076_741_662_1, -3.307_711_591_3,  0.230_969_929_2),
+						(-1.268_438_004_6,  2.609_757_401_1, -0.341_319_396_5),
+						(-0.004_196_086_3, -0.703_418_614_7,  1.707_614_701_0),
+					];
+
+					let mut step = <$tys>::MAX;
+
+					let mut index = 0x0;
+					while index < weights.len() {
+						let (wl, wm, ws) = weights[index];
+
+						let f0 = wl * l_cubed + wm * m_cubed + ws * s_cubed - 1.0;
+						let f1 = wl * l_dt1 + wm * m_dt1 + ws * s_dt1;
+						let f2 = wl * l_dt2 + wm * m_dt2 + ws * s_dt2;
+
+						let u = f1 / (f1 * f1 - 0.5 * f0 * f2);
+
+						if u >= 0.0 {
+							let candidate = -f0 * u;
+
+							if candidate < step {
+								step = candidate;
+							}
+						}
+
+						index += 0x1;
+					}
+
+					t + step
+				}
+
+				/// Approximates the saturation and toe-corrected slope (`S` and `T`) at the middle of the gamut for a given hue.
+				#[allow(clippy::excessive_precision)]
+				#[must_use]
+				pub(crate) const fn s_rgb_mid_slopes(a: $tys, b: $tys) -> ($tys, $tys) {
+					let s = 0.115_169_93 + 1.0 / (
+						7.447_789_70 + 4.159_012_40 * b
+							+ a * (-2.195_573_47 + 1.751_984_01 * b
+							+ a * (-2.137_049_48 - 10.023_010_43 * b
+							+ a * (-4.248_945_61 + 5.387_708_19 * b + 4.698_910_13 * a)))
+					);
+
+					let t = 0.112_396_42 + 1.0 / (
+						1.613_203_20 - 0.681_243_79 * b
+							+ a * (0.403_706_12 + 0.901_481_23 * b
+							+ a * (-0.270_879_43 + 0.612_239_90 * b
+							+ a * (0.002_992_15 - 0.453_995_68 * b - 0.146_618_72 * a)))
+					);
+
+					(s, t)
+				}
+
+				/// Computes the three chroma values used by Okhsl for a given luminance and hue.
+				///
+				/// These are, in order: the chroma at zero saturation, the chroma at the middle of the saturation scale, and the maximum chroma within the sRGB gamut.
+				#[cfg(feature = "maths")]
+				#[must_use]
+				pub(crate) fn s_rgb_chroma_bounds(luminance: $tys, a: $tys, b: $tys) -> ($tys, $tys, $tys) {
+					let cusp = Self::s_rgb_cusp(a, b);
+
+					let max_chroma = Self::s_rgb_gamut_intersection(a, b, luminance, 1.0, luminance, cusp);
+
+					let (cusp_l, cusp_c) = cusp;
+
+					let max_s = cusp_c / cusp_l;
+					let max_t = cusp_c / (1.0 - cusp_l);
+
+					// Scale factor compensating for the curved
+					// part of the gamut.
+					let k = max_chroma / (luminance * max_s).min((1.0 - luminance) * max_t);
+
+					let mid_chroma = {
+						let (mid_s, mid_t) = Self::s_rgb_mid_slopes(a, b);
+
+						let c_a = luminance * mid_s;
+						let c_b = (1.0 - luminance) * mid_t;
+
+						// Use a soft minimum function instead of a
+						// sharp triangle shape.
+						let c_a = c_a * c_a * c_a * c_a;
+						let c_b = c_b * c_b * c_b * c_b;
+
+						0.9 * k * Maths::sqrt(Maths::sqrt(1.0 / (1.0 / c_a + 1.0 / c_b)))
+					};
+
+					let zero_chroma = {
+						// The shape is independent of hue here, so
+						// use roughly average values of `S` and `T`.
+						let c_a = luminance * 0.4;
+						let c_b = (1.0 - luminance) * 0.8;
+
+						Maths::sqrt(1.0 / (1.0 / (c_a * c_a) + 1.0 / (c_b * c_b)))
+					};
+
+					(zero_chroma, mid_chroma, max_chroma)
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_cusp!(f16);
+
+impl_cusp!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_cusp!(f128);
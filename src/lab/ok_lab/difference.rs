@@ -0,0 +1,57 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+#[cfg(feature = "maths")]
+use crate::maths::Maths;
+
+macro_rules! impl_difference {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::lab::OkLab<$tys> {
+				/// Computes the Oklab colour difference (ΔE<sub>OK</sub>).
+				///
+				/// This is the Euclidean distance between the two colours.
+				/// Note that the magnitude of this difference is roughly a hundredth of that of [CIE76](crate::lab::CieLab::delta_e_76), as Oklab luminance lies in the range `0` to `1`.
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn delta_e_ok(self, other: Self) -> $tys {
+					let (l0, a0, b0) = self.get();
+					let (l1, a1, b1) = other.get();
+
+					let delta_l = l0 - l1;
+					let delta_a = a0 - a1;
+					let delta_b = b0 - b1;
+
+					Maths::sqrt(delta_l * delta_l + delta_a * delta_a + delta_b * delta_b)
+				}
+			}
+
+			#[cfg(feature = "maths")]
+			impl ::polywave::ColourDifference for ::polywave::lab::OkLab<$tys> {
+				type Difference = $tys;
+
+				/// Computes the ΔE<sub>OK</sub> colour difference.
+				///
+				/// See [`delta_e_ok`](Self::delta_e_ok) for more information.
+				#[inline]
+				fn difference(self, other: Self) -> Self::Difference {
+					self.delta_e_ok(other)
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_difference!(f16);
+
+impl_difference!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_difference!(f128);
@@ -6,14 +6,16 @@
 // can obtain one at:
 // <https://mozilla.org/MPL/2.0/>.
 
+#[cfg(feature = "maths")]
+mod cusp;
+mod difference;
+
 use crate::{BalancedColour, Colour, Component, DefinedGamut};
+use crate::component::zero;
 
 #[cfg(feature = "bytemuck")]
 use bytemuck::{Pod, Zeroable};
 
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
-
 #[cfg(feature = "zerocopy")]
 use zerocopy::{FromZeros, Immutable, IntoBytes};
 
@@ -23,7 +25,6 @@ use zerocopy::{FromZeros, Immutable, IntoBytes};
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
-#[cfg_attr(feature = "serde",    derive(Deserialize, Serialize))]
 #[cfg_attr(feature = "zerocopy", derive(FromZeros, Immutable, IntoBytes))]
 pub struct OkLab<T>([T; 0x3]);
 
@@ -62,10 +63,146 @@ impl<T: Component> OkLab<T> {
 	}
 }
 
+macro_rules! impl_conversions {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::lab::OkLab<$tys> {
+				/// Converts a linear sRGB colour to the LMS cone responses.
+				#[allow(clippy::excessive_precision)]
+				#[must_use]
+				const fn lms_from_linear_s_rgb(colour: ::polywave::rgb::Rgb<$tys>) -> ($tys, $tys, $tys) {
+					let (red, green, blue) = colour.get();
+
+					let l = 0.412_221_470_8 * red + 0.536_332_536_3 * green + 0.051_445_992_9 * blue;
+					let m = 0.211_903_498_2 * red + 0.680_699_545_1 * green + 0.107_396_956_6 * blue;
+					let s = 0.088_302_461_9 * red + 0.281_718_837_6 * green + 0.629_978_700_5 * blue;
+
+					(l, m, s)
+				}
+
+				/// Converts the cube roots of LMS cone responses to Oklab.
+				#[allow(clippy::excessive_precision)]
+				#[must_use]
+				const fn from_lms_roots(l: $tys, m: $tys, s: $tys) -> Self {
+					let luminance = 0.210_454_255_3 * l + 0.793_617_785_0 * m - 0.004_072_046_8 * s;
+					let a_star    = 1.977_998_495_1 * l - 2.428_592_205_0 * m + 0.450_593_709_9 * s;
+					let b_star    = 0.025_904_037_1 * l + 0.782_771_766_2 * m - 0.808_675_766_0 * s;
+
+					Self::new(luminance, a_star, b_star)
+				}
+
+				/// Converts a linear sRGB colour to Oklab.
+				#[allow(clippy::cast_lossless, clippy::cast_possible_truncation)]
+				#[must_use]
+				pub(crate) const fn from_linear_s_rgb(colour: ::polywave::rgb::Rgb<$tys>) -> Self {
+					use ::polywave::maths::cbrt;
+
+					let (l, m, s) = Self::lms_from_linear_s_rgb(colour);
+
+					Self::from_lms_roots(
+						cbrt(l as f64) as $tys,
+						cbrt(m as f64) as $tys,
+						cbrt(s as f64) as $tys,
+					)
+				}
+
+				/// Converts the Oklab colour to linear sRGB.
+				#[allow(clippy::excessive_precision)]
+				#[must_use]
+				pub(crate) const fn to_linear_s_rgb(self) -> ::polywave::rgb::Rgb<$tys> {
+					let (luminance, a_star, b_star) = self.get();
+
+					let l = luminance + 0.396_337_777_4 * a_star + 0.215_803_757_3 * b_star;
+					let m = luminance - 0.105_561_345_8 * a_star - 0.063_854_172_8 * b_star;
+					let s = luminance - 0.089_484_177_5 * a_star - 1.291_485_548_0 * b_star;
+
+					let l = l * l * l;
+					let m = m * m * m;
+					let s = s * s * s;
+
+					let red   =  4.076_741_662_1 * l - 3.307_711_591_3 * m + 0.230_969_929_2 * s;
+					let green = -1.268_438_004_6 * l + 2.609_757_401_1 * m - 0.341_319_396_5 * s;
+					let blue  = -0.004_196_086_3 * l - 0.703_418_614_7 * m + 1.707_614_701_0 * s;
+
+					::polywave::rgb::Rgb::new(red, green, blue)
+				}
+
+				/// Converts an sRGB colour to Oklab.
+				///
+				/// This is computed in double precision using the crate's own maths functions, and it is therefore usable in constant expressions.
+				#[inline]
+				#[must_use]
+				pub const fn from_s_rgb(colour: ::polywave::rgb::SRgb<$tys>) -> Self {
+					Self::from_linear_s_rgb(colour.untransfer())
+				}
+
+				/// Converts the Oklab colour to sRGB.
+				///
+				/// This is computed in double precision using the crate's own maths functions, and it is therefore usable in constant expressions.
+				///
+				/// Note that the returned colour is not clamped to the sRGB gamut.
+				#[inline]
+				#[must_use]
+				pub const fn to_s_rgb(self) -> ::polywave::rgb::SRgb<$tys> {
+					::polywave::rgb::SRgb::<$tys>::transfer(self.to_linear_s_rgb())
+				}
+
+				/// Converts a CIEXYZ colour to Oklab.
+				///
+				/// The colour is expected to be relative to the [D65](crate::xyz::CieXyz::D65) white point.
+				/// This is computed in double precision using the crate's own maths functions, and it is therefore usable in constant expressions.
+				#[inline]
+				#[must_use]
+				pub const fn from_xyz(colour: ::polywave::xyz::CieXyz<$tys>) -> Self {
+					let (red, green, blue) = colour.transform(&::polywave::xyz::CieXyz::<$tys>::TO_S_RGB).get();
+
+					Self::from_linear_s_rgb(::polywave::rgb::Rgb::new(red, green, blue))
+				}
+
+				/// Converts the Oklab colour to CIEXYZ.
+				///
+				/// The returned colour is relative to the [D65](crate::xyz::CieXyz::D65) white point.
+				#[inline]
+				#[must_use]
+				pub const fn to_xyz(self) -> ::polywave::xyz::CieXyz<$tys> {
+					let (red, green, blue) = self.to_linear_s_rgb().get();
+
+					::polywave::xyz::CieXyz::new(red, green, blue).transform(&::polywave::xyz::CieXyz::<$tys>::FROM_S_RGB)
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_conversions!(f16);
+
+impl_conversions!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_conversions!(f128);
+
 unsafe impl<T: Component> BalancedColour for OkLab<T> {
 	type Component = T;
 }
 
 impl<T: Component> Colour for OkLab<T> { }
 
-impl<T: Component> DefinedGamut for OkLab<T> { }
+impl<T: Component> DefinedGamut for OkLab<T> {
+	type Scalar = T;
+
+	#[inline(always)]
+	fn is_in_gamut(&self, _epsilon: Self::Scalar) -> bool {
+		true
+	}
+
+	#[inline(always)]
+	fn clip(self) -> Self {
+		self
+	}
+
+	#[inline(always)]
+	fn gamut_distance(&self) -> Self::Scalar {
+		zero()
+	}
+}
@@ -0,0 +1,148 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::{BalancedColour, Colour, Component, DefinedGamut};
+use crate::component::zero;
+#[cfg(feature = "maths")]
+use crate::maths::Maths;
+
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+
+#[cfg(feature = "zerocopy")]
+use zerocopy::{FromZeros, Immutable, IntoBytes};
+
+/// An Oklrab colour.
+///
+/// This type guarantees that its three channels -- luminance, a*, and b* -- are stored sequentially in memory (in this order).
+///
+/// Oklrab is a variant of [`OkLab`](crate::lab::OkLab) that uses an improved luminance estimate, L<sub>r</sub>, which more closely matches the luminance of CIELAB.
+/// The a* and b* channels are left unchanged.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
+#[cfg_attr(feature = "zerocopy", derive(FromZeros, Immutable, IntoBytes))]
+pub struct OkLrab<T>([T; 0x3]);
+
+impl<T: Component> OkLrab<T> {
+	/// Constructs a new Oklrab colour.
+	#[inline(always)]
+	#[must_use]
+	pub const fn new(luminance: T, a_star: T, b_star: T) -> Self {
+		let data = [luminance, a_star, b_star];
+		Self(data)
+	}
+
+	/// Maps the Oklrab colour's channels.
+	#[inline]
+	#[must_use]
+	pub fn map<U, F>(self, mut op: F) -> OkLrab<U>
+	where
+		U: Component,
+		F: FnMut(T) -> U,
+	{
+		let (luminance, a_star, b_star) = self.get();
+
+		let luminance = op(luminance);
+		let a_star    = op(a_star);
+		let b_star    = op(b_star);
+
+		OkLrab::new(luminance, a_star, b_star)
+	}
+
+	/// Deconstructs the Oklrab colour.
+	#[inline(always)]
+	#[must_use]
+	pub const fn get(self) -> (T, T, T) {
+		let [luminance, a_star, b_star] = self.0;
+		(luminance, a_star, b_star)
+	}
+}
+
+macro_rules! impl_conversions {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::lab::OkLrab<$tys> {
+				const K1: $tys = 0.206;
+				const K2: $tys = 0.03;
+				const K3: $tys = const { (1.0 + Self::K1) / (1.0 + Self::K2) };
+
+				/// Applies the "toe" function, mapping Oklab luminance to L<sub>r</sub>.
+				#[cfg(feature = "maths")]
+				#[must_use]
+				pub(crate) fn toe(luminance: $tys) -> $tys {
+					let value = Self::K3 * luminance - Self::K1;
+
+					0.5 * (value + Maths::sqrt(value * value + 4.0 * Self::K2 * Self::K3 * luminance))
+				}
+
+				/// Applies the inverse "toe" function, mapping L<sub>r</sub> to Oklab luminance.
+				#[must_use]
+				pub(crate) const fn toe_inverse(luminance: $tys) -> $tys {
+					(luminance * luminance + Self::K1 * luminance) / (Self::K3 * (luminance + Self::K2))
+				}
+
+				/// Converts an Oklab colour to Oklrab.
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn from_ok_lab(colour: ::polywave::lab::OkLab<$tys>) -> Self {
+					let (luminance, a_star, b_star) = colour.get();
+
+					let luminance = Self::toe(luminance);
+
+					Self::new(luminance, a_star, b_star)
+				}
+
+				/// Converts the Oklrab colour to Oklab.
+				#[inline]
+				#[must_use]
+				pub const fn to_ok_lab(self) -> ::polywave::lab::OkLab<$tys> {
+					let (luminance, a_star, b_star) = self.get();
+
+					let luminance = Self::toe_inverse(luminance);
+
+					::polywave::lab::OkLab::new(luminance, a_star, b_star)
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_conversions!(f16);
+
+impl_conversions!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_conversions!(f128);
+
+unsafe impl<T: Component> BalancedColour for OkLrab<T> {
+	type Component = T;
+}
+
+impl<T: Component> Colour for OkLrab<T> { }
+
+impl<T: Component> DefinedGamut for OkLrab<T> {
+	type Scalar = T;
+
+	#[inline(always)]
+	fn is_in_gamut(&self, _epsilon: Self::Scalar) -> bool {
+		true
+	}
+
+	#[inline(always)]
+	fn clip(self) -> Self {
+		self
+	}
+
+	#[inline(always)]
+	fn gamut_distance(&self) -> Self::Scalar {
+		zero()
+	}
+}
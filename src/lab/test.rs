@@ -0,0 +1,174 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+#![cfg(all(test, feature = "maths"))]
+
+use crate::ColourDifference;
+use crate::lab::{Cie94Application, CieLab, OkLab, OkLrab};
+use crate::rgb::SRgb;
+use crate::test::assert_approx_eq;
+use crate::xyz::CieXyz;
+
+/// The CIEDE2000 test data of Sharma *et al.*
+const SHARMA_DATA: [(CieLab<f64>, CieLab<f64>, f64); 0x22] = [
+	(CieLab::new(50.000_0,   2.677_2, -79.775_1), CieLab::new(50.000_0,   0.000_0, -82.748_5),  2.042_5),
+	(CieLab::new(50.000_0,   3.157_1, -77.280_3), CieLab::new(50.000_0,   0.000_0, -82.748_5),  2.861_5),
+	(CieLab::new(50.000_0,   2.836_1, -74.020_0), CieLab::new(50.000_0,   0.000_0, -82.748_5),  3.441_2),
+	(CieLab::new(50.000_0,  -1.380_2, -84.281_4), CieLab::new(50.000_0,   0.000_0, -82.748_5),  1.000_0),
+	(CieLab::new(50.000_0,  -1.184_8, -84.800_6), CieLab::new(50.000_0,   0.000_0, -82.748_5),  1.000_0),
+	(CieLab::new(50.000_0,  -0.900_9, -85.521_1), CieLab::new(50.000_0,   0.000_0, -82.748_5),  1.000_0),
+	(CieLab::new(50.000_0,   0.000_0,   0.000_0), CieLab::new(50.000_0,  -1.000_0,   2.000_0),  2.366_9),
+	(CieLab::new(50.000_0,  -1.000_0,   2.000_0), CieLab::new(50.000_0,   0.000_0,   0.000_0),  2.366_9),
+	(CieLab::new(50.000_0,   2.490_0,  -0.001_0), CieLab::new(50.000_0,  -2.490_0,   0.000_9),  7.179_2),
+	(CieLab::new(50.000_0,   2.490_0,  -0.001_0), CieLab::new(50.000_0,  -2.490_0,   0.001_0),  7.179_2),
+	(CieLab::new(50.000_0,   2.490_0,  -0.001_0), CieLab::new(50.000_0,  -2.490_0,   0.001_1),  7.219_5),
+	(CieLab::new(50.000_0,   2.490_0,  -0.001_0), CieLab::new(50.000_0,  -2.490_0,   0.001_2),  7.219_5),
+	(CieLab::new(50.000_0,  -0.001_0,   2.490_0), CieLab::new(50.000_0,   0.000_9,  -2.490_0),  4.804_5),
+	(CieLab::new(50.000_0,  -0.001_0,   2.490_0), CieLab::new(50.000_0,   0.001_0,  -2.490_0),  4.804_5),
+	(CieLab::new(50.000_0,  -0.001_0,   2.490_0), CieLab::new(50.000_0,   0.001_1,  -2.490_0),  4.746_1),
+	(CieLab::new(50.000_0,   2.500_0,   0.000_0), CieLab::new(50.000_0,   0.000_0,  -2.500_0),  4.306_5),
+	(CieLab::new(50.000_0,   2.500_0,   0.000_0), CieLab::new(73.000_0,  25.000_0, -18.000_0), 27.149_2),
+	(CieLab::new(50.000_0,   2.500_0,   0.000_0), CieLab::new(61.000_0,  -5.000_0,  29.000_0), 22.897_7),
+	(CieLab::new(50.000_0,   2.500_0,   0.000_0), CieLab::new(56.000_0, -27.000_0,  -3.000_0), 31.903_0),
+	(CieLab::new(50.000_0,   2.500_0,   0.000_0), CieLab::new(58.000_0,  24.000_0,  15.000_0), 19.453_5),
+	(CieLab::new(50.000_0,   2.500_0,   0.000_0), CieLab::new(50.000_0,   3.173_6,   0.585_4),  1.000_0),
+	(CieLab::new(50.000_0,   2.500_0,   0.000_0), CieLab::new(50.000_0,   3.297_2,   0.000_0),  1.000_0),
+	(CieLab::new(50.000_0,   2.500_0,   0.000_0), CieLab::new(50.000_0,   1.863_4,   0.575_7),  1.000_0),
+	(CieLab::new(50.000_0,   2.500_0,   0.000_0), CieLab::new(50.000_0,   3.259_2,   0.335_0),  1.000_0),
+	(CieLab::new(60.257_4, -34.009_9,  36.267_7), CieLab::new(60.462_6, -34.175_1,  39.438_7),  1.264_4),
+	(CieLab::new(63.010_9, -31.096_1,  -5.866_3), CieLab::new(62.818_7, -29.794_6,  -4.086_4),  1.263_0),
+	(CieLab::new(61.290_1,   3.719_6,  -5.390_1), CieLab::new(61.429_2,   2.248_0,  -4.962_0),  1.873_1),
+	(CieLab::new(35.083_1, -44.116_4,   3.793_3), CieLab::new(35.023_2, -40.071_6,   1.590_1),  1.864_5),
+	(CieLab::new(22.723_3,  20.090_4, -46.694_0), CieLab::new(23.033_1,  14.973_0, -42.561_9),  2.037_3),
+	(CieLab::new(36.461_2,  47.858_0,  18.385_2), CieLab::new(36.271_5,  50.506_5,  21.223_1),  1.414_6),
+	(CieLab::new(90.802_7,  -2.083_1,   1.441_0), CieLab::new(91.152_8,  -1.643_5,   0.044_7),  1.444_1),
+	(CieLab::new(90.925_7,  -0.540_6,  -0.920_8), CieLab::new(88.638_1,  -0.898_5,  -0.723_9),  1.538_1),
+	(CieLab::new( 6.774_7,  -0.290_8,  -2.424_7), CieLab::new( 5.871_4,  -0.098_5,  -2.228_6),  0.637_7),
+	(CieLab::new( 2.077_6,   0.079_5,  -1.135_0), CieLab::new( 0.903_3,  -0.063_6,  -0.551_4),  0.908_2),
+];
+
+#[test]
+fn test_cie_lab_delta_e_2000() {
+	for (left, right, expected) in SHARMA_DATA {
+		let difference = left.delta_e_2000(right, 1.0, 1.0, 1.0);
+		assert!((difference - expected).abs() < 5e-5, "{left:?} and {right:?} differ by {difference}, expected {expected}");
+
+		// CIEDE2000 is symmetric.
+		assert!((right.difference(left) - difference).abs() < 1e-12);
+	}
+}
+
+#[test]
+fn test_cie_lab_delta_e_76() {
+	let left  = CieLab::<f64>::new(50.0, 2.0, -3.0);
+	let right = CieLab::<f64>::new(53.0, 6.0, -3.0);
+
+	assert!((left.delta_e_76(right) - 5.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_cie_lab_delta_e_94() {
+	let reference = CieLab::<f64>::new(50.0, 0.0, 0.0);
+	let sample    = CieLab::<f64>::new(52.0, 0.0, 0.0);
+
+	// With an achromatic reference colour, only the
+	// lightness weight has an effect.
+	assert!((reference.delta_e_94(sample, Cie94Application::GraphicArts) - 2.0).abs() < 1e-12);
+	assert!((reference.delta_e_94(sample, Cie94Application::Textiles)    - 1.0).abs() < 1e-12);
+
+	let reference = CieLab::<f64>::new(50.0, 30.0, 40.0);
+	let sample    = CieLab::<f64>::new(50.0, 33.0, 44.0);
+
+	// The chroma difference is 5.0, and the
+	// chroma weight is `1 + 0.045 * 50.0`.
+	assert!((reference.delta_e_94(sample, Cie94Application::GraphicArts) - 5.0 / 3.25).abs() < 1e-12);
+}
+
+#[test]
+fn test_cie_lab_delta_e_cmc() {
+	let reference = CieLab::<f64>::new(10.0, 0.0, 0.0);
+	let sample    = CieLab::<f64>::new(12.0, 0.0, 0.0);
+
+	assert!((reference.delta_e_cmc(sample, 1.0, 1.0) - 2.0 / 0.511).abs() < 1e-12);
+	assert!((reference.delta_e_cmc(sample, 2.0, 1.0) - 1.0 / 0.511).abs() < 1e-12);
+
+	let reference = CieLab::<f64>::new(50.0, 30.0, 40.0);
+	assert!(reference.delta_e_cmc(reference, 2.0, 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_ok_lab_delta_e_ok() {
+	let left  = OkLab::<f64>::new(0.5, 0.1, -0.1);
+	let right = OkLab::<f64>::new(0.5, 0.13, -0.06);
+
+	assert!((left.delta_e_ok(right) - 0.05).abs() < 1e-12);
+	assert!((left.difference(right) - 0.05).abs() < 1e-12);
+}
+
+#[test]
+fn test_ok_lab_const() {
+	const RED: OkLab<f64> = OkLab::<f64>::from_s_rgb(SRgb::new(1.0, 0.0, 0.0));
+
+	let (luminance, a_star, b_star) = RED.get();
+
+	assert!((luminance - 0.627_955_4).abs() < 1e-6);
+	assert!((a_star - 0.224_863_0).abs() < 1e-6);
+	assert!((b_star - 0.125_846_3).abs() < 1e-6);
+
+	const RED_XYZ: CieXyz<f64> = CieXyz::<f64>::from_s_rgb(SRgb::new(1.0, 0.0, 0.0));
+
+	assert!(OkLab::<f64>::from_xyz(RED_XYZ).delta_e_ok(RED) < 1e-12);
+
+	for colour in [SRgb::new(0.4, 0.2, 0.6), SRgb::new(0.9, 0.8, 0.1), SRgb::new(-0.1, 0.5, 1.2)] {
+		let ok_lab = OkLab::<f64>::from_s_rgb(colour);
+		let xyz    = CieXyz::<f64>::from_s_rgb(colour);
+
+		assert!(OkLab::<f64>::from_xyz(xyz).delta_e_ok(ok_lab) < 1e-12);
+
+		let (red, green, blue) = xyz.to_s_rgb().get();
+		let (expected_red, expected_green, expected_blue) = colour.get();
+
+		assert!((red - expected_red).abs() < 1e-12);
+		assert!((green - expected_green).abs() < 1e-12);
+		assert!((blue - expected_blue).abs() < 1e-12);
+
+		// The Oklab matrices are only approximate inverses.
+
+		let (x, y, z) = ok_lab.to_xyz().get();
+		let (expected_x, expected_y, expected_z) = xyz.get();
+
+		assert!((x - expected_x).abs() < 1e-6);
+		assert!((y - expected_y).abs() < 1e-6);
+		assert!((z - expected_z).abs() < 1e-6);
+
+		let (red, green, blue) = ok_lab.to_s_rgb().get();
+
+		assert!((red - expected_red).abs() < 1e-6);
+		assert!((green - expected_green).abs() < 1e-6);
+		assert!((blue - expected_blue).abs() < 1e-6);
+	}
+}
+
+#[test]
+fn test_ok_lab_from_s_rgb() {
+	let red = OkLab::<f64>::from_s_rgb(SRgb::new(1.0, 0.0, 0.0));
+	assert_approx_eq!(red.get(), (0.627_955_4, 0.224_863_0, 0.125_846_3), 1e-6);
+
+	let white = OkLab::<f64>::from_s_rgb(SRgb::new(1.0, 1.0, 1.0));
+	assert_approx_eq!(white.get(), (1.0, 0.0, 0.0), 1e-6);
+}
+
+#[test]
+fn test_ok_lrab_round_trip() {
+	let colour = OkLab::<f64>::new(0.627_955_4, 0.224_863_0, 0.125_846_3);
+
+	let lrab = OkLrab::<f64>::from_ok_lab(colour);
+	assert_approx_eq!(lrab.get(), (0.568_084_7, 0.224_863_0, 0.125_846_3), 1e-6);
+
+	assert_approx_eq!(lrab.to_ok_lab().get(), colour.get(), 1e-12);
+}
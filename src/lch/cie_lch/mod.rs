@@ -7,13 +7,13 @@
 // <https://mozilla.org/MPL/2.0/>.
 
 use crate::{BalancedColour, Colour, Component, DefinedGamut};
+use crate::component::zero;
+#[cfg(feature = "maths")]
+use crate::maths::Maths;
 
 #[cfg(feature = "bytemuck")]
 use bytemuck::{Pod, Zeroable};
 
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
-
 #[cfg(feature = "zerocopy")]
 use zerocopy::{FromZeros, Immutable, IntoBytes};
 
@@ -23,7 +23,6 @@ use zerocopy::{FromZeros, Immutable, IntoBytes};
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
-#[cfg_attr(feature = "serde",    derive(Deserialize, Serialize))]
 #[cfg_attr(feature = "zerocopy", derive(FromZeros, Immutable, IntoBytes))]
 pub struct CieLch<T>([T; 0x3]);
 
@@ -62,10 +61,78 @@ impl<T: Component> CieLch<T> {
 	}
 }
 
+macro_rules! impl_conversions {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::lch::CieLch<$tys> {
+				/// Converts a CIELAB colour to CIELCh.
+				///
+				/// Achromatic colours are given a hue of zero.
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn from_lab(colour: ::polywave::lab::CieLab<$tys>) -> Self {
+					let (luminance, a_star, b_star) = colour.get();
+
+					let chroma = Maths::hypot(a_star, b_star);
+
+					let hue = if chroma == 0.0 {
+						0.0
+					} else {
+						Maths::rem_euclid(Maths::atan2(b_star, a_star).to_degrees(), 360.0)
+					};
+
+					Self::new(luminance, chroma, hue)
+				}
+
+				/// Converts the CIELCh colour to CIELAB.
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn to_lab(self) -> ::polywave::lab::CieLab<$tys> {
+					let (luminance, chroma, hue) = self.get();
+
+					let (sin, cos) = Maths::sin_cos(hue.to_radians());
+
+					let a_star = chroma * cos;
+					let b_star = chroma * sin;
+
+					::polywave::lab::CieLab::new(luminance, a_star, b_star)
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_conversions!(f16);
+
+impl_conversions!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_conversions!(f128);
+
 unsafe impl<T: Component> BalancedColour for CieLch<T> {
 	type Component = T;
 }
 
 impl<T: Component> Colour for CieLch<T> { }
 
-impl<T: Component> DefinedGamut for CieLch<T> { }
+impl<T: Component> DefinedGamut for CieLch<T> {
+	type Scalar = T;
+
+	#[inline(always)]
+	fn is_in_gamut(&self, _epsilon: Self::Scalar) -> bool {
+		true
+	}
+
+	#[inline(always)]
+	fn clip(self) -> Self {
+		self
+	}
+
+	#[inline(always)]
+	fn gamut_distance(&self) -> Self::Scalar {
+		zero()
+	}
+}
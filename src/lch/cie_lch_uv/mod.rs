@@ -0,0 +1,205 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::{BalancedColour, Colour, Component, DefinedGamut};
+use crate::component::zero;
+#[cfg(feature = "maths")]
+use crate::maths::Maths;
+
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+
+#[cfg(feature = "zerocopy")]
+use zerocopy::{FromZeros, Immutable, IntoBytes};
+
+/// A CIELCh(uv) colour.
+///
+/// This type guarantees that its three channels -- luminance, chroma, and hue -- are stored sequentially in memory (in this order).
+///
+/// This is the cylindrical representation of [`CieLuv`](crate::lab::CieLuv).
+/// The hue is measured in degrees.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
+#[cfg_attr(feature = "zerocopy", derive(FromZeros, Immutable, IntoBytes))]
+pub struct CieLchUv<T>([T; 0x3]);
+
+impl<T: Component> CieLchUv<T> {
+	/// Constructs a new CIELCh(uv) colour.
+	#[inline(always)]
+	#[must_use]
+	pub const fn new(luminance: T, chroma: T, hue: T) -> Self {
+		let data = [luminance, chroma, hue];
+		Self(data)
+	}
+
+	/// Maps the CIELCh(uv) colour's channels.
+	#[inline]
+	#[must_use]
+	pub fn map<U, F>(self, mut op: F) -> CieLchUv<U>
+	where
+		U: Component,
+		F: FnMut(T) -> U,
+	{
+		let (luminance, chroma, hue) = self.get();
+
+		let luminance = op(luminance);
+		let chroma    = op(chroma);
+		let hue       = op(hue);
+
+		CieLchUv::new(luminance, chroma, hue)
+	}
+
+	/// Deconstructs the CIELCh(uv) colour.
+	#[inline(always)]
+	#[must_use]
+	pub const fn get(self) -> (T, T, T) {
+		let [luminance, chroma, hue] = self.0;
+		(luminance, chroma, hue)
+	}
+}
+
+macro_rules! impl_conversions {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::lch::CieLchUv<$tys> {
+				/// Converts a CIELUV colour to CIELCh(uv).
+				///
+				/// Achromatic colours are given a hue of zero.
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn from_luv(colour: ::polywave::lab::CieLuv<$tys>) -> Self {
+					let (luminance, u_star, v_star) = colour.get();
+
+					let chroma = Maths::hypot(u_star, v_star);
+
+					let hue = if chroma < 1e-8 {
+						0.0
+					} else {
+						Maths::rem_euclid(Maths::atan2(v_star, u_star).to_degrees(), 360.0)
+					};
+
+					Self::new(luminance, chroma, hue)
+				}
+
+				/// Converts the CIELCh(uv) colour to CIELUV.
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn to_luv(self) -> ::polywave::lab::CieLuv<$tys> {
+					let (luminance, chroma, hue) = self.get();
+
+					let (sin, cos) = Maths::sin_cos(hue.to_radians());
+
+					let u_star = chroma * cos;
+					let v_star = chroma * sin;
+
+					::polywave::lab::CieLuv::new(luminance, u_star, v_star)
+				}
+
+				/// Computes the lines bounding the sRGB gamut at the given luminance.
+				///
+				/// Each line is given as a slope--intercept pair in the u*v* plane.
+				#[allow(clippy::excessive_precision)]
+				#[cfg(feature = "maths")]
+				#[must_use]
+				pub(crate) const fn s_rgb_bounds(luminance: $tys) -> [($tys, $tys); 0x6] {
+					let scaled = (luminance + 16.0) / 116.0;
+					let scaled = scaled * scaled * scaled;
+
+					let scaled = if scaled > ::polywave::lab::CieLuv::<$tys>::EPSILON {
+						scaled
+					} else {
+						luminance / ::polywave::lab::CieLuv::<$tys>::KAPPA
+					};
+
+					let matrix = ::polywave::xyz::CieXyz::<$tys>::TO_S_RGB;
+
+					let mut bounds = [(0.0, 0.0); 0x6];
+
+					let mut index = 0x0;
+					while index < bounds.len() {
+						let [m0, m1, m2] = matrix[index / 0x2];
+
+						let t = (index % 0x2) as $tys;
+
+						let top0 = (2.25 * m0 - 0.75 * m2) * scaled;
+
+						let top1 = (6.630_357_764_210_926 * m2 + 6.088_159_934_204_283 * m1 + 5.786_527_694_302_976 * m0) * luminance * scaled
+							- 6.088_159_934_204_283 * t * luminance;
+
+						let bottom = (5.0 * m2 - m1) * scaled + t;
+
+						bounds[index] = (top0 / bottom, top1 / bottom);
+
+						index += 0x1;
+					}
+
+					bounds
+				}
+
+				/// Computes the maximum chroma within the sRGB gamut for the given luminance and hue.
+				#[cfg(feature = "maths")]
+				#[must_use]
+				pub(crate) fn max_s_rgb_chroma(luminance: $tys, hue: $tys) -> $tys {
+					let (sin, cos) = Maths::sin_cos(hue.to_radians());
+
+					Self::s_rgb_bounds(luminance)
+						.into_iter()
+						.map(|(slope, intercept)| intercept / (sin - slope * cos))
+						.filter(|&length| length >= 0.0)
+						.fold(<$tys>::INFINITY, <$tys>::min)
+				}
+
+				/// Computes the maximum chroma within the sRGB gamut for the given luminance, regardless of hue.
+				#[cfg(feature = "maths")]
+				#[must_use]
+				pub(crate) fn max_safe_s_rgb_chroma(luminance: $tys) -> $tys {
+					Self::s_rgb_bounds(luminance)
+						.into_iter()
+						.map(|(slope, intercept)| Maths::abs(intercept) / Maths::hypot(slope, 1.0))
+						.fold(<$tys>::INFINITY, <$tys>::min)
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_conversions!(f16);
+
+impl_conversions!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_conversions!(f128);
+
+unsafe impl<T: Component> BalancedColour for CieLchUv<T> {
+	type Component = T;
+}
+
+impl<T: Component> Colour for CieLchUv<T> { }
+
+impl<T: Component> DefinedGamut for CieLchUv<T> {
+	type Scalar = T;
+
+	#[inline(always)]
+	fn is_in_gamut(&self, _epsilon: Self::Scalar) -> bool {
+		true
+	}
+
+	#[inline(always)]
+	fn clip(self) -> Self {
+		self
+	}
+
+	#[inline(always)]
+	fn gamut_distance(&self) -> Self::Scalar {
+		zero()
+	}
+}
@@ -0,0 +1,199 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::{BalancedColour, Colour, Component};
+
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+
+#[cfg(feature = "zerocopy")]
+use zerocopy::{FromZeros, Immutable, IntoBytes};
+
+/// An HPLuv colour.
+///
+/// This type guarantees that its three channels -- hue, saturation, and luminance -- are stored sequentially in memory (in this order).
+///
+/// HPLuv is a pastel variant of [`HsLuv`](crate::lch::HsLuv) in which the saturation is scaled to the largest chroma that is within the sRGB gamut for *all* hues at the given luminance.
+/// This preserves the perceived saturation across hues, at the cost of only covering the pastel part of the sRGB gamut.
+/// The hue is measured in degrees, whilst saturation and luminance both nominally lie in the range `0` to `100` (as per the [reference implementation](https://www.hsluv.org/)).
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
+#[cfg_attr(feature = "zerocopy", derive(FromZeros, Immutable, IntoBytes))]
+pub struct HpLuv<T>([T; 0x3]);
+
+impl<T: Component> HpLuv<T> {
+	/// Constructs a new HPLuv colour.
+	#[inline(always)]
+	#[must_use]
+	pub const fn new(hue: T, saturation: T, luminance: T) -> Self {
+		let data = [hue, saturation, luminance];
+		Self(data)
+	}
+
+	/// Maps the HPLuv colour's channels.
+	#[inline]
+	#[must_use]
+	pub fn map<U, F>(self, mut op: F) -> HpLuv<U>
+	where
+		U: Component,
+		F: FnMut(T) -> U,
+	{
+		let (hue, saturation, luminance) = self.get();
+
+		let hue        = op(hue);
+		let saturation = op(saturation);
+		let luminance  = op(luminance);
+
+		HpLuv::new(hue, saturation, luminance)
+	}
+
+	/// Deconstructs the HPLuv colour.
+	#[inline(always)]
+	#[must_use]
+	pub const fn get(self) -> (T, T, T) {
+		let [hue, saturation, luminance] = self.0;
+		(hue, saturation, luminance)
+	}
+}
+
+macro_rules! impl_conversions {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::lch::HpLuv<$tys> {
+				/// Converts a CIELCh(uv) colour to HPLuv.
+				///
+				/// The colour is expected to be relative to the [D65](crate::xyz::CieXyz::D65) white point.
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn from_lch_uv(colour: ::polywave::lch::CieLchUv<$tys>) -> Self {
+					let (luminance, chroma, hue) = colour.get();
+
+					if luminance > 99.999_999_9 {
+						return Self::new(hue, 0.0, 100.0);
+					}
+
+					if luminance < 1e-8 {
+						return Self::new(hue, 0.0, 0.0);
+					}
+
+					let max_chroma = ::polywave::lch::CieLchUv::<$tys>::max_safe_s_rgb_chroma(luminance);
+
+					let saturation = chroma / max_chroma * 100.0;
+
+					Self::new(hue, saturation, luminance)
+				}
+
+				/// Converts the HPLuv colour to CIELCh(uv).
+				///
+				/// The returned colour is relative to the [D65](crate::xyz::CieXyz::D65) white point.
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn to_lch_uv(self) -> ::polywave::lch::CieLchUv<$tys> {
+					let (hue, saturation, luminance) = self.get();
+
+					if luminance > 99.999_999_9 {
+						return ::polywave::lch::CieLchUv::new(100.0, 0.0, hue);
+					}
+
+					if luminance < 1e-8 {
+						return ::polywave::lch::CieLchUv::new(0.0, 0.0, hue);
+					}
+
+					let max_chroma = ::polywave::lch::CieLchUv::<$tys>::max_safe_s_rgb_chroma(luminance);
+
+					let chroma = max_chroma / 100.0 * saturation;
+
+					::polywave::lch::CieLchUv::new(luminance, chroma, hue)
+				}
+
+				/// Converts an sRGB colour to HPLuv.
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn from_s_rgb(colour: ::polywave::rgb::SRgb<$tys>) -> Self {
+					let white = ::polywave::xyz::CieXyz::<$tys>::D65;
+
+					let colour = ::polywave::xyz::CieXyz::<$tys>::from_s_rgb(colour);
+					let colour = ::polywave::lab::CieLuv::<$tys>::from_xyz(colour, white);
+					let colour = ::polywave::lch::CieLchUv::<$tys>::from_luv(colour);
+
+					Self::from_lch_uv(colour)
+				}
+
+				/// Converts the HPLuv colour to sRGB.
+				///
+				/// Note that the returned colour is not clamped to the sRGB gamut.
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn to_s_rgb(self) -> ::polywave::rgb::SRgb<$tys> {
+					let white = ::polywave::xyz::CieXyz::<$tys>::D65;
+
+					self.to_lch_uv().to_luv().to_xyz(white).to_s_rgb()
+				}
+			}
+
+			impl ::polywave::DefinedGamut for ::polywave::lch::HpLuv<$tys> {
+				type Scalar = $tys;
+
+				#[inline]
+				fn is_in_gamut(&self, epsilon: Self::Scalar) -> bool {
+					let (_, saturation, luminance) = self.get();
+
+					let range = -epsilon..=100.0 + epsilon;
+
+					range.contains(&saturation) && range.contains(&luminance)
+				}
+
+				#[inline]
+				fn clip(self) -> Self {
+					let (hue, saturation, luminance) = self.get();
+
+					Self::new(hue, saturation.clamp(0.0, 100.0), luminance.clamp(0.0, 100.0))
+				}
+
+				#[inline]
+				fn gamut_distance(&self) -> Self::Scalar {
+					let distance = |channel: $tys| {
+						if channel < 0.0 {
+							-channel
+						} else if channel > 100.0 {
+							channel - 100.0
+						} else {
+							0.0
+						}
+					};
+
+					let (_, saturation, luminance) = self.get();
+
+					let saturation = distance(saturation);
+					let luminance  = distance(luminance);
+
+					if luminance > saturation { luminance } else { saturation }
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_conversions!(f16);
+
+impl_conversions!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_conversions!(f128);
+
+unsafe impl<T: Component> BalancedColour for HpLuv<T> {
+	type Component = T;
+}
+
+impl<T: Component> Colour for HpLuv<T> { }
@@ -9,7 +9,16 @@
 //! LCh-based colour facilities.
 
 mod cie_lch;
+mod cie_lch_uv;
+mod hp_luv;
+mod hs_luv;
 mod ok_lch;
+mod ok_lrch;
+mod test;
 
 pub use cie_lch::CieLch;
+pub use cie_lch_uv::CieLchUv;
+pub use hp_luv::HpLuv;
+pub use hs_luv::HsLuv;
 pub use ok_lch::OkLch;
+pub use ok_lrch::OkLrch;
@@ -0,0 +1,127 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+macro_rules! impl_gamut_map {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::lch::OkLch<$tys> {
+				/// The just-noticeable difference used when gamut mapping.
+				///
+				/// This is measured in ΔE<sub>OK</sub> (see [`OkLab::delta_e_ok`](::polywave::lab::OkLab::delta_e_ok)).
+				pub const GAMUT_MAP_JND: $tys = 0.02;
+
+				/// The chroma tolerance of the gamut mapping search.
+				const GAMUT_MAP_EPSILON: $tys = 0.000_1;
+
+				/// Maps the Oklch colour into an RGB gamut.
+				///
+				/// This is done using the [CSS Color 4](https://www.w3.org/TR/css-color-4/#css-gamut-mapping) gamut-mapping algorithm, which is also what browsers use for rendering out-of-gamut colours.
+				/// Lightness and hue are preserved whilst chroma is reduced -- by binary search -- until the [clipped](::polywave::DefinedGamut::clip) colour lies within a [just-noticeable difference](Self::GAMUT_MAP_JND) of the reduced colour.
+				/// The clipped colour is then returned.
+				///
+				/// Colours with a lightness of at least one or at most zero are mapped to white and black, respectively.
+				#[must_use]
+				pub fn map_to_gamut<G>(self) -> G
+				where
+					G: ::polywave::RgbGamut + ::polywave::DefinedGamut<Scalar = $tys>,
+				{
+					let (luminance, chroma, hue) = self.get();
+
+					if luminance >= 1.0 {
+						let white = ::polywave::lab::OkLab::new(1.0, 0.0, 0.0);
+						return G::from_ok_lab(white).clip();
+					}
+
+					if luminance <= 0.0 {
+						let black = ::polywave::lab::OkLab::new(0.0, 0.0, 0.0);
+						return G::from_ok_lab(black).clip();
+					}
+
+					let origin = G::from_ok_lab(self.to_ok_lab());
+
+					if origin.is_in_gamut(0.0) {
+						return origin;
+					}
+
+					// Returns the clipped colour together with
+					// its distance to the unclipped colour.
+					let clip = |chroma: $tys| -> (G, $tys) {
+						let current = Self::new(luminance, chroma, hue).to_ok_lab();
+
+						let clipped = G::from_ok_lab(current).clip();
+						let delta   = clipped.to_ok_lab().delta_e_ok(current);
+
+						(clipped, delta)
+					};
+
+					let (mut clipped, delta) = clip(chroma);
+
+					if delta < Self::GAMUT_MAP_JND {
+						return clipped;
+					}
+
+					let mut min: $tys = 0.0;
+					let mut max = chroma;
+
+					let mut min_in_gamut = true;
+
+					while max - min > Self::GAMUT_MAP_EPSILON {
+						let chroma = (min + max) / 2.0;
+
+						if min_in_gamut {
+							let current = Self::new(luminance, chroma, hue).to_ok_lab();
+
+							if G::from_ok_lab(current).is_in_gamut(0.0) {
+								min = chroma;
+								continue;
+							}
+						}
+
+						let delta;
+						(clipped, delta) = clip(chroma);
+
+						if delta < Self::GAMUT_MAP_JND {
+							if Self::GAMUT_MAP_JND - delta < Self::GAMUT_MAP_EPSILON {
+								return clipped;
+							}
+
+							min_in_gamut = false;
+							min = chroma;
+						} else {
+							max = chroma;
+						}
+					}
+
+					clipped
+				}
+			}
+
+			impl ::polywave::lab::OkLab<$tys> {
+				/// Maps the Oklab colour into an RGB gamut.
+				///
+				/// See [`OkLch::map_to_gamut`](::polywave::lch::OkLch::map_to_gamut) for more information.
+				#[inline]
+				#[must_use]
+				pub fn map_to_gamut<G>(self) -> G
+				where
+					G: ::polywave::RgbGamut + ::polywave::DefinedGamut<Scalar = $tys>,
+				{
+					::polywave::lch::OkLch::<$tys>::from_ok_lab(self).map_to_gamut()
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_gamut_map!(f16);
+
+impl_gamut_map!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_gamut_map!(f128);
@@ -6,24 +6,27 @@
 // can obtain one at:
 // <https://mozilla.org/MPL/2.0/>.
 
+#[cfg(feature = "maths")]
+mod gamut_map;
+
 use crate::{BalancedColour, Colour, Component, DefinedGamut};
+use crate::component::zero;
 
 #[cfg(feature = "bytemuck")]
 use bytemuck::{Pod, Zeroable};
 
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
-
 #[cfg(feature = "zerocopy")]
 use zerocopy::{FromZeros, Immutable, IntoBytes};
 
 /// An Oklch colour.
 ///
 /// This type guarantees that its three channels -- luminance, chroma, and hue -- are stored sequentially in memory (in this order).
+///
+/// This is the cylindrical representation of [`OkLab`](crate::lab::OkLab).
+/// The hue is measured in degrees.
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
-#[cfg_attr(feature = "serde",    derive(Deserialize, Serialize))]
 #[cfg_attr(feature = "zerocopy", derive(FromZeros, Immutable, IntoBytes))]
 pub struct OkLch<T>([T; 0x3]);
 
@@ -62,10 +65,106 @@ impl<T: Component> OkLch<T> {
 	}
 }
 
+macro_rules! impl_conversions {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::lch::OkLch<$tys> {
+				/// Converts an Oklab colour to Oklch.
+				///
+				/// Achromatic colours are given a hue of zero.
+				/// This is computed in double precision using the crate's own maths functions, and it is therefore usable in constant expressions.
+				///
+				/// # Examples
+				///
+				/// ```
+				/// use polywave::lab::OkLab;
+				/// use polywave::lch::OkLch;
+				/// use polywave::rgb::SRgb;
+				/// use polywave::www::Html;
+				///
+				/// const ACCENT: SRgb<f32> = {
+				///     let (colour, _) = Html::REBECCA_PURPLE.to_s_rgba().detach();
+				///     SRgb::<f32>::from_u8(colour)
+				/// };
+				///
+				/// const ACCENT_OKLCH: OkLch<f32> = OkLch::<f32>::from_ok_lab(OkLab::<f32>::from_s_rgb(ACCENT));
+				///
+				/// let (luminance, chroma, hue) = ACCENT_OKLCH.get();
+				///
+				/// assert!((luminance - 0.4403).abs() < 1e-4);
+				/// assert!((chroma    - 0.1603).abs() < 1e-4);
+				/// assert!((hue       - 303.37).abs() < 1e-2);
+				/// ```
+				#[allow(clippy::cast_lossless, clippy::cast_possible_truncation)]
+				#[inline]
+				#[must_use]
+				pub const fn from_ok_lab(colour: ::polywave::lab::OkLab<$tys>) -> Self {
+					use ::polywave::maths::{atan2, hypot, rem_euclid};
+
+					let (luminance, a_star, b_star) = colour.get();
+
+					let chroma = hypot(a_star as f64, b_star as f64);
+
+					let hue = if chroma == 0.0 {
+						0.0
+					} else {
+						let hue = atan2(b_star as f64, a_star as f64) * (180.0 / ::core::f64::consts::PI);
+						rem_euclid(hue, 360.0)
+					};
+
+					Self::new(luminance, chroma as $tys, hue as $tys)
+				}
+
+				/// Converts the Oklch colour to Oklab.
+				///
+				/// This is computed in double precision using the crate's own maths functions, and it is therefore usable in constant expressions.
+				#[allow(clippy::cast_lossless, clippy::cast_possible_truncation)]
+				#[inline]
+				#[must_use]
+				pub const fn to_ok_lab(self) -> ::polywave::lab::OkLab<$tys> {
+					let (luminance, chroma, hue) = self.get();
+
+					let (sin, cos) = ::polywave::maths::sin_cos(hue as f64 * (::core::f64::consts::PI / 180.0));
+
+					let a_star = chroma as f64 * cos;
+					let b_star = chroma as f64 * sin;
+
+					::polywave::lab::OkLab::new(luminance, a_star as $tys, b_star as $tys)
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_conversions!(f16);
+
+impl_conversions!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_conversions!(f128);
+
 unsafe impl<T: Component> BalancedColour for OkLch<T> {
 	type Component = T;
 }
 
 impl<T: Component> Colour for OkLch<T> { }
 
-impl<T: Component> DefinedGamut for OkLch<T> { }
+impl<T: Component> DefinedGamut for OkLch<T> {
+	type Scalar = T;
+
+	#[inline(always)]
+	fn is_in_gamut(&self, _epsilon: Self::Scalar) -> bool {
+		true
+	}
+
+	#[inline(always)]
+	fn clip(self) -> Self {
+		self
+	}
+
+	#[inline(always)]
+	fn gamut_distance(&self) -> Self::Scalar {
+		zero()
+	}
+}
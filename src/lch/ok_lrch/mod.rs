@@ -0,0 +1,157 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::{BalancedColour, Colour, Component, DefinedGamut};
+use crate::component::zero;
+
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+
+#[cfg(feature = "zerocopy")]
+use zerocopy::{FromZeros, Immutable, IntoBytes};
+
+/// An Oklrch colour.
+///
+/// This type guarantees that its three channels -- luminance, chroma, and hue -- are stored sequentially in memory (in this order).
+///
+/// This is the cylindrical representation of [`OkLrab`](crate::lab::OkLrab).
+/// The hue is measured in degrees.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
+#[cfg_attr(feature = "zerocopy", derive(FromZeros, Immutable, IntoBytes))]
+pub struct OkLrch<T>([T; 0x3]);
+
+impl<T: Component> OkLrch<T> {
+	/// Constructs a new Oklrch colour.
+	#[inline(always)]
+	#[must_use]
+	pub const fn new(luminance: T, chroma: T, hue: T) -> Self {
+		let data = [luminance, chroma, hue];
+		Self(data)
+	}
+
+	/// Maps the Oklrch colour's channels.
+	#[inline]
+	#[must_use]
+	pub fn map<U, F>(self, mut op: F) -> OkLrch<U>
+	where
+		U: Component,
+		F: FnMut(T) -> U,
+	{
+		let (luminance, chroma, hue) = self.get();
+
+		let luminance = op(luminance);
+		let chroma    = op(chroma);
+		let hue       = op(hue);
+
+		OkLrch::new(luminance, chroma, hue)
+	}
+
+	/// Deconstructs the Oklrch colour.
+	#[inline(always)]
+	#[must_use]
+	pub const fn get(self) -> (T, T, T) {
+		let [luminance, chroma, hue] = self.0;
+		(luminance, chroma, hue)
+	}
+}
+
+macro_rules! impl_conversions {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::lch::OkLrch<$tys> {
+				/// Converts an Oklrab colour to Oklrch.
+				///
+				/// Achromatic colours are given a hue of zero.
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn from_ok_lrab(colour: ::polywave::lab::OkLrab<$tys>) -> Self {
+					let (luminance, a_star, b_star) = colour.get();
+
+					let colour = ::polywave::lab::OkLab::new(luminance, a_star, b_star);
+
+					let (luminance, chroma, hue) = ::polywave::lch::OkLch::<$tys>::from_ok_lab(colour).get();
+
+					Self::new(luminance, chroma, hue)
+				}
+
+				/// Converts the Oklrch colour to Oklrab.
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn to_ok_lrab(self) -> ::polywave::lab::OkLrab<$tys> {
+					let (luminance, chroma, hue) = self.get();
+
+					let colour = ::polywave::lch::OkLch::new(luminance, chroma, hue);
+
+					let (luminance, a_star, b_star) = colour.to_ok_lab().get();
+
+					::polywave::lab::OkLrab::new(luminance, a_star, b_star)
+				}
+
+				/// Converts an Oklch colour to Oklrch.
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn from_ok_lch(colour: ::polywave::lch::OkLch<$tys>) -> Self {
+					let (luminance, chroma, hue) = colour.get();
+
+					let luminance = ::polywave::lab::OkLrab::<$tys>::toe(luminance);
+
+					Self::new(luminance, chroma, hue)
+				}
+
+				/// Converts the Oklrch colour to Oklch.
+				#[inline]
+				#[must_use]
+				pub const fn to_ok_lch(self) -> ::polywave::lch::OkLch<$tys> {
+					let (luminance, chroma, hue) = self.get();
+
+					let luminance = ::polywave::lab::OkLrab::<$tys>::toe_inverse(luminance);
+
+					::polywave::lch::OkLch::new(luminance, chroma, hue)
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_conversions!(f16);
+
+impl_conversions!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_conversions!(f128);
+
+unsafe impl<T: Component> BalancedColour for OkLrch<T> {
+	type Component = T;
+}
+
+impl<T: Component> Colour for OkLrch<T> { }
+
+impl<T: Component> DefinedGamut for OkLrch<T> {
+	type Scalar = T;
+
+	#[inline(always)]
+	fn is_in_gamut(&self, _epsilon: Self::Scalar) -> bool {
+		true
+	}
+
+	#[inline(always)]
+	fn clip(self) -> Self {
+		self
+	}
+
+	#[inline(always)]
+	fn gamut_distance(&self) -> Self::Scalar {
+		zero()
+	}
+}
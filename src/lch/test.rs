@@ -0,0 +1,133 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+#![cfg(all(test, feature = "maths"))]
+
+use crate::DefinedGamut;
+use crate::lab::OkLab;
+use crate::lch::{HpLuv, HsLuv, OkLch};
+use crate::rgb::SRgb;
+use crate::test::assert_approx_eq;
+use crate::www::Html;
+
+#[test]
+fn test_hs_luv_from_s_rgb() {
+	let red = HsLuv::<f64>::from_s_rgb(SRgb::new(1.0, 0.0, 0.0));
+	assert_approx_eq!(red.get(), (12.177_050_630_061_776, 100.0, 53.237_115_595_429_33), 1e-6);
+
+	let blue = HsLuv::<f64>::from_s_rgb(SRgb::new(0.0, 0.0, 1.0));
+	assert_approx_eq!(blue.get(), (265.874_320_218_177_9, 100.0, 32.300_872_903_980_02), 1e-6);
+
+	let white = HsLuv::<f64>::from_s_rgb(SRgb::new(1.0, 1.0, 1.0));
+	assert_approx_eq!((0.0, white.get().1, white.get().2), (0.0, 0.0, 100.0), 1e-3);
+}
+
+#[test]
+fn test_hs_luv_to_s_rgb() {
+	let colour = HsLuv::<f64>::new(12.177_050_630_061_776, 100.0, 53.237_115_595_429_33).to_s_rgb();
+	assert_approx_eq!(colour.get(), (1.0, 0.0, 0.0), 1e-4);
+
+	let colour = SRgb::new(0.4, 0.2, 0.6);
+	assert_approx_eq!(HsLuv::<f64>::from_s_rgb(colour).to_s_rgb().get(), colour.get(), 1e-9);
+}
+
+#[test]
+fn test_hp_luv_round_trip() {
+	let colour = SRgb::new(0.75, 0.7, 0.8);
+	assert_approx_eq!(HpLuv::<f64>::from_s_rgb(colour).to_s_rgb().get(), colour.get(), 1e-9);
+
+	// Every HPLuv colour with a saturation of at most
+	// `100` must lie within the sRGB gamut.
+	for hue in (0..360).step_by(15) {
+		let (red, green, blue) = HpLuv::<f64>::new(f64::from(hue), 100.0, 60.0).to_s_rgb().get();
+
+		assert!([red, green, blue].iter().all(|&channel| (-1e-9..=1.0 + 1e-9).contains(&channel)));
+	}
+}
+
+#[test]
+fn test_hs_luv_hp_luv_gamut() {
+	let colour = HsLuv::<f64>::new(12.0, 100.0, 53.0);
+
+	assert!(colour.is_in_gamut(0.0));
+	assert_eq!(colour.clip(), colour);
+
+	// Saturated colours lie outside of the pastel
+	// HPLuv gamut.
+	let red = HpLuv::<f64>::from_s_rgb(SRgb::new(1.0, 0.0, 0.0));
+
+	assert!(!red.is_in_gamut(1e-9));
+	assert!(red.gamut_distance() > 0.0);
+	assert_eq!(red.clip().get().1, 100.0);
+	assert!(red.clip().is_in_gamut(0.0));
+}
+
+#[test]
+fn test_ok_lch_map_to_gamut() {
+	// In-gamut colours are left unchanged.
+
+	let colour = SRgb::<f64>::new(0.4, 0.2, 0.6);
+	let mapped: SRgb<f64> = OkLab::<f64>::from_s_rgb(colour).map_to_gamut();
+
+	assert_approx_eq!(mapped.get(), colour.get(), 1e-6);
+
+	// Lightness is clamped to white and black.
+
+	let white: SRgb<f64> = OkLch::<f64>::new(1.5, 0.2, 120.0).map_to_gamut();
+	let black: SRgb<f64> = OkLch::<f64>::new(-0.5, 0.2, 120.0).map_to_gamut();
+
+	assert_approx_eq!(white.get(), (1.0, 1.0, 1.0), 1e-6);
+	assert_approx_eq!(black.get(), (0.0, 0.0, 0.0), 1e-6);
+
+	// Out-of-gamut colours have their chroma reduced
+	// whilst lightness and hue are kept.
+
+	for (luminance, chroma, hue) in [
+		(0.7, 0.4,  40.0),
+		(0.9, 0.4, 140.0),
+		(0.5, 0.3, 270.0),
+		(0.4, 0.5, 200.0),
+	] {
+		let colour = OkLch::<f64>::new(luminance, chroma, hue);
+		let mapped: SRgb<f64> = colour.map_to_gamut();
+
+		assert!(mapped.is_in_gamut(0.0));
+
+		let result = OkLab::<f64>::from_s_rgb(mapped);
+		let (result_luminance, result_chroma, result_hue) = OkLch::<f64>::from_ok_lab(result).get();
+
+		assert!(result_chroma < chroma);
+		assert!(result.delta_e_ok(OkLch::<f64>::new(luminance, result_chroma, hue).to_ok_lab()) < OkLch::<f64>::GAMUT_MAP_JND);
+		assert!((result_luminance - luminance).abs() < OkLch::<f64>::GAMUT_MAP_JND);
+		assert!((result_hue - hue).abs() < 5.0);
+	}
+}
+
+#[test]
+fn test_ok_lch_const() {
+	for colour in [SRgb::new(0.4, 0.2, 0.6), SRgb::new(0.9, 0.8, 0.1), SRgb::new(0.5, 0.5, 0.5)] {
+		let colour = OkLab::<f64>::from_s_rgb(colour);
+
+		assert_approx_eq!(OkLch::<f64>::from_ok_lab(colour).to_ok_lab().get(), colour.get(), 1e-12);
+	}
+
+	const ACHROMATIC: OkLch<f32> = OkLch::<f32>::from_ok_lab(OkLab::new(0.5, 0.0, 0.0));
+
+	assert_eq!(ACHROMATIC, OkLch::new(0.5, 0.0, 0.0));
+
+	const ACCENT: OkLch<f32> = {
+		let (colour, _) = Html::REBECCA_PURPLE.to_s_rgba().detach();
+		OkLch::<f32>::from_ok_lab(OkLab::<f32>::from_s_rgb(SRgb::<f32>::from_u8(colour)))
+	};
+
+	let (luminance, chroma, hue) = ACCENT.get();
+
+	assert!((luminance - 0.440_3).abs() < 1e-4);
+	assert!((chroma - 0.160_3).abs() < 1e-4);
+	assert!((hue - 303.37).abs() < 1e-2);
+}
@@ -20,25 +20,53 @@ extern crate self as polywave;
 #[cfg(feature = "std")]
 extern crate std;
 
-#[cfg(test)]
+#[cfg(any(feature = "alloc", test))]
 extern crate alloc;
 
+#[cfg(all(feature = "alloc", feature = "maths"))]
+pub mod codegen;
 pub mod error;
+#[cfg(feature = "alloc")]
+pub mod gradient;
 pub mod hsv;
 pub mod lab;
 pub mod lch;
+#[cfg(feature = "alloc")]
+pub mod palette;
 pub mod rgb;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod terminal;
 pub mod www;
+pub mod x11;
 pub mod xyz;
 
 mod alpha;
 mod balanced_colour;
 mod colour;
+mod colour_difference;
 mod component;
 mod defined_gamut;
+mod float_component;
+mod hue_interpolate;
+mod hue_interpolation;
+mod interpolate;
+mod maths;
+mod normalised_component;
+mod ok_lab_convert;
+mod rgb_gamut;
+mod test;
 
 pub use alpha::Alpha;
 pub use balanced_colour::BalancedColour;
 pub use colour::Colour;
+pub use colour_difference::ColourDifference;
 pub use component::Component;
 pub use defined_gamut::DefinedGamut;
+pub use float_component::FloatComponent;
+pub use hue_interpolate::HueInterpolate;
+pub use hue_interpolation::HueInterpolation;
+pub use interpolate::Interpolate;
+pub use normalised_component::NormalisedComponent;
+pub use ok_lab_convert::OkLabConvert;
+pub use rgb_gamut::RgbGamut;
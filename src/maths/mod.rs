@@ -0,0 +1,964 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+//! Portable implementations of floating-point functions.
+//!
+//! These back the `const` conversions between sRGB, CIEXYZ, Oklab, and Oklch, and they are used in place of the platform's implementations when the `maths` feature is enabled without `std`.
+//! All functions are implemented in terms of basic IEEE 754 arithmetic (which is correctly rounded), and they therefore yield the same results on every platform and during constant evaluation.
+//!
+//! The functions are computed in double precision and are accurate to within a few units in the last place.
+//! This also applies to `f128`, which is thus only as precise as `f64` in these conversions.
+//! Other runtime computations (such as colour differences) yield slightly different results with and without `std`, as the former uses the platform's functions (such as [`f64::powf`] and [`f64::cbrt`]) whilst the latter uses these.
+//! The difference is usually confined to the last few places, but it may be visible after rounding, e.g. when converting to [`SRgb<u8>`](crate::rgb::SRgb).
+//! Trigonometric functions reduce their arguments using a three-part representation of π/2, which is exact for arguments below about one million in magnitude.
+//!
+//! Only operations that are usable in `const` contexts on the minimum supported Rust version are used.
+//! Notably, this excludes [`f64::to_bits`] and [`f64::from_bits`], so exponents are extracted by repeated scaling instead.
+
+#![allow(clippy::eq_op)]
+
+mod test;
+
+use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, LOG2_E, PI, SQRT_2};
+
+/// The high part of ln(2), with the lower bits cleared so that multiples of it are exact.
+#[allow(clippy::excessive_precision)]
+const LN_2_HI: f64 = 6.93147180369123816490e-1;
+
+/// The remainder of ln(2) after [`LN_2_HI`].
+#[allow(clippy::excessive_precision)]
+const LN_2_LO: f64 = 1.90821492927058770002e-10;
+
+/// The remainder of π after [`PI`].
+#[allow(clippy::excessive_precision)]
+const PI_LO: f64 = 1.22464679914735317723e-16;
+
+/// The remainder of π/2 after [`FRAC_PI_2`].
+#[allow(clippy::excessive_precision)]
+const FRAC_PI_2_LO: f64 = 6.12323399573676588614e-17;
+
+/// The first part of π/2, with 33 significant bits.
+#[allow(clippy::excessive_precision)]
+const FRAC_PI_2_1: f64 = 1.57079632673412561417e0;
+
+/// The second part of π/2, with 33 significant bits.
+#[allow(clippy::excessive_precision)]
+const FRAC_PI_2_2: f64 = 6.07710050630396597660e-11;
+
+/// The remainder of π/2 after [`FRAC_PI_2_1`] and [`FRAC_PI_2_2`].
+#[allow(clippy::excessive_precision)]
+const FRAC_PI_2_3: f64 = 2.02226624879595063154e-21;
+
+/// The exponents by which [`normalise`] scales its argument, from largest to smallest.
+const SCALING_STEPS: [i32; 0xA] = [0x200, 0x100, 0x80, 0x40, 0x20, 0x10, 0x8, 0x4, 0x2, 0x1];
+
+/// Checks whether `x` is NaN.
+#[inline(always)]
+#[must_use]
+pub(crate) const fn is_nan(x: f64) -> bool {
+	x != x
+}
+
+/// Checks whether `x` is neither infinite nor NaN.
+#[inline(always)]
+#[must_use]
+pub(crate) const fn is_finite(x: f64) -> bool {
+	x - x == 0.0
+}
+
+/// Checks whether `x` is infinite.
+#[inline(always)]
+#[must_use]
+pub(crate) const fn is_infinite(x: f64) -> bool {
+	!is_nan(x) && !is_finite(x)
+}
+
+/// Checks whether `x` is negative, including negative zero.
+///
+/// NaN is never considered negative.
+#[inline(always)]
+#[must_use]
+pub(crate) const fn is_sign_negative(x: f64) -> bool {
+	x < 0.0 || x == 0.0 && 1.0 / x < 0.0
+}
+
+/// Computes `2^exponent` exactly.
+#[must_use]
+const fn exp2i(exponent: i32) -> f64 {
+	if exponent < 0x0 {
+		powi(0.5, -exponent)
+	} else {
+		powi(2.0, exponent)
+	}
+}
+
+/// Multiplies a value by `2^exponent`.
+#[must_use]
+const fn scalbn(mut x: f64, mut exponent: i32) -> f64 {
+	if exponent > 0x3FF {
+		x *= exp2i(0x3FF);
+		exponent -= 0x3FF;
+
+		if exponent > 0x3FF {
+			x *= exp2i(0x3FF);
+			exponent -= 0x3FF;
+
+			if exponent > 0x3FF {
+				exponent = 0x3FF;
+			}
+		}
+	} else if exponent < -0x3FE {
+		// Scale in two steps to avoid double rounding of subnormal results.
+		x *= exp2i(-0x3FE + 0x35);
+		exponent += 0x3FE - 0x35;
+
+		if exponent < -0x3FE {
+			x *= exp2i(-0x3FE + 0x35);
+			exponent += 0x3FE - 0x35;
+
+			if exponent < -0x3FE {
+				exponent = -0x3FE;
+			}
+		}
+	}
+
+	x * exp2i(exponent)
+}
+
+/// Decomposes a positive, finite value into `m 2^e` with `1 <= m < 2`.
+#[must_use]
+const fn normalise(mut x: f64) -> (f64, i32) {
+	let mut exponent = 0x0;
+
+	if x < f64::MIN_POSITIVE {
+		x *= exp2i(0x36);
+		exponent -= 0x36;
+	}
+
+	let mut index = 0x0;
+
+	while index < SCALING_STEPS.len() {
+		let step = SCALING_STEPS[index];
+
+		if x >= exp2i(step) {
+			x *= exp2i(-step);
+			exponent += step;
+		} else if x < exp2i(0x1 - step) {
+			x *= exp2i(step);
+			exponent -= step;
+		}
+
+		index += 0x1;
+	}
+
+	(x, exponent)
+}
+
+/// Computes the absolute value of `x`.
+#[inline(always)]
+#[must_use]
+pub(crate) const fn abs(x: f64) -> f64 {
+	if is_sign_negative(x) { -x } else { x }
+}
+
+/// Computes a value with the magnitude of `x` and the sign of `sign`.
+#[inline(always)]
+#[must_use]
+pub(crate) const fn copysign(x: f64, sign: f64) -> f64 {
+	if is_sign_negative(x) == is_sign_negative(sign) { x } else { -x }
+}
+
+/// Rounds `x` towards zero.
+#[must_use]
+pub(crate) const fn trunc(x: f64) -> f64 {
+	// Values of this magnitude (and non-finite values) are already integral.
+	if is_nan(x) || abs(x) >= 4503599627370496.0 {
+		return x;
+	}
+
+	#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+	let truncated = x as i64 as f64;
+
+	copysign(truncated, x)
+}
+
+/// Rounds `x` to the nearest integer, with halfway cases rounded away from zero.
+#[must_use]
+pub(crate) const fn round(x: f64) -> f64 {
+	let truncated = trunc(x);
+
+	// The difference is exact for values that are not already integral.
+	if abs(x - truncated) >= 0.5 {
+		truncated + copysign(1.0, x)
+	} else {
+		truncated
+	}
+}
+
+/// Computes the least non-negative remainder of `x` divided by `rhs`.
+#[inline]
+#[must_use]
+pub(crate) const fn rem_euclid(x: f64, rhs: f64) -> f64 {
+	let remainder = x % rhs;
+
+	if remainder < 0.0 {
+		remainder + abs(rhs)
+	} else {
+		remainder
+	}
+}
+
+/// Computes the square root of `x`.
+#[must_use]
+pub(crate) const fn sqrt(x: f64) -> f64 {
+	if x < 0.0 {
+		return f64::NAN;
+	}
+
+	if x == 0.0 || !is_finite(x) {
+		return x;
+	}
+
+	// Decompose the value into `m 2^e` with `1 <= m < 4` and an even `e`.
+	let (mut m, mut exponent) = normalise(x);
+
+	if exponent & 0x1 != 0x0 {
+		m *= 2.0;
+		exponent -= 0x1;
+	}
+
+	let mut y = 0.5 * (m + 1.0);
+
+	let mut iteration = 0x0;
+
+	while iteration < 0x6 {
+		y = 0.5 * (y + m / y);
+		iteration += 0x1;
+	}
+
+	y * exp2i(exponent / 0x2)
+}
+
+/// Computes the cube root of `x`.
+#[must_use]
+pub(crate) const fn cbrt(x: f64) -> f64 {
+	if x == 0.0 || !is_finite(x) {
+		return x;
+	}
+
+	// Decompose the value into `m 2^e` with `1 <= m < 8` and `e` divisible by three.
+	let (mut m, mut exponent) = normalise(abs(x));
+
+	let remainder = exponent.rem_euclid(0x3);
+
+	m *= exp2i(remainder);
+	exponent -= remainder;
+
+	let mut y = 1.0 + (m - 1.0) / 7.0;
+
+	let mut iteration = 0x0;
+
+	while iteration < 0x7 {
+		y = (2.0 * y + m / (y * y)) / 3.0;
+		iteration += 0x1;
+	}
+
+	copysign(y * exp2i(exponent / 0x3), x)
+}
+
+/// Computes the Euclidean distance `sqrt(x^2 + y^2)` without undue overflow or underflow.
+#[must_use]
+pub(crate) const fn hypot(x: f64, y: f64) -> f64 {
+	if is_infinite(x) || is_infinite(y) {
+		return f64::INFINITY;
+	}
+
+	if is_nan(x) || is_nan(y) {
+		return f64::NAN;
+	}
+
+	let (x, y) = (abs(x), abs(y));
+	let (max, min) = if x > y { (x, y) } else { (y, x) };
+
+	if max == 0.0 {
+		return 0.0;
+	}
+
+	let ratio = min / max;
+	max * sqrt(1.0 + ratio * ratio)
+}
+
+/// Computes `e^x`.
+#[allow(clippy::excessive_precision)]
+#[must_use]
+pub(crate) const fn exp(x: f64) -> f64 {
+	if is_nan(x) {
+		return x;
+	}
+
+	if x > 709.782712893383973096 {
+		return f64::INFINITY;
+	}
+
+	if x < -745.13321910194110842 {
+		return 0.0;
+	}
+
+	// Reduce the argument to `x = k ln(2) + r` with `|r| <= ln(2) / 2`.
+	#[allow(clippy::cast_possible_truncation)]
+	let k = (x * LOG2_E + copysign(0.5, x)) as i32;
+
+	#[allow(clippy::cast_lossless)]
+	let k_float = k as f64;
+
+	let r = (x - k_float * LN_2_HI) - k_float * LN_2_LO;
+
+	// The Taylor series converges to double precision within fourteen terms.
+	let mut series = 1.0;
+
+	let mut n = 0xD;
+
+	while n > 0x0 {
+		#[allow(clippy::cast_lossless)]
+		let n_float = n as f64;
+
+		series = 1.0 + series * r / n_float;
+		n -= 0x1;
+	}
+
+	scalbn(series, k)
+}
+
+/// Computes the natural logarithm of `x`.
+#[cfg_attr(not(feature = "maths"), allow(dead_code))]
+#[must_use]
+pub(crate) const fn ln(x: f64) -> f64 {
+	if is_nan(x) || x < 0.0 {
+		return f64::NAN;
+	}
+
+	if x == 0.0 {
+		return f64::NEG_INFINITY;
+	}
+
+	if is_infinite(x) {
+		return x;
+	}
+
+	let (hi, lo) = ln_parts(x);
+	hi + lo
+}
+
+/// Computes the natural logarithm of a positive, finite `x` as an unevaluated sum.
+///
+/// The first part is an exact multiple of [`LN_2_HI`], and the second part is the (comparatively small) remainder.
+#[must_use]
+const fn ln_parts(x: f64) -> (f64, f64) {
+	// Decompose the value into `m 2^e` with `sqrt(1/2) < m <= sqrt(2)`.
+	let (mut m, mut exponent) = normalise(x);
+
+	if m > SQRT_2 {
+		m *= 0.5;
+		exponent += 0x1;
+	}
+
+	// Use `ln(m) = 2 artanh(s)` with `s = (m - 1) / (m + 1)`, for which `|s| < 0.172`.
+	let s = (m - 1.0) / (m + 1.0);
+	let z = s * s;
+
+	let mut series = 0.0;
+
+	let mut n = 0xA;
+
+	while n >= 0x0 {
+		#[allow(clippy::cast_lossless)]
+		let denominator = (0x2 * n + 0x1) as f64;
+
+		series = 1.0 / denominator + z * series;
+		n -= 0x1;
+	}
+
+	#[allow(clippy::cast_lossless)]
+	let exponent = exponent as f64;
+
+	(exponent * LN_2_HI, 2.0 * s * series + exponent * LN_2_LO)
+}
+
+/// Splits a value into two halves, each of which having at most 26 significant bits.
+#[inline(always)]
+#[must_use]
+const fn split(x: f64) -> (f64, f64) {
+	let scaled = 134217729.0 * x;
+	let hi     = scaled - (scaled - x);
+
+	(hi, x - hi)
+}
+
+/// Computes the product of `x` and `y` together with its rounding error.
+///
+/// This uses Dekker's algorithm, which does not rely on fused multiply-add instructions.
+#[must_use]
+const fn two_product(x: f64, y: f64) -> (f64, f64) {
+	let product = x * y;
+
+	let (x_hi, x_lo) = split(x);
+	let (y_hi, y_lo) = split(y);
+
+	let error = ((x_hi * y_hi - product) + x_hi * y_lo + x_lo * y_hi) + x_lo * y_lo;
+
+	(product, error)
+}
+
+/// Computes `e^(y ln(x))` for a positive, finite `x`.
+#[must_use]
+const fn exp_ln(x: f64, y: f64) -> f64 {
+	let (hi, lo) = ln_parts(x);
+
+	let (product, error) = two_product(y, hi);
+
+	// The product is certain to overflow or underflow, and splitting it could itself overflow.
+	if abs(product) >= 1e3 {
+		return exp(product + y * lo);
+	}
+
+	let t = product + (error + y * lo);
+
+	exp(t) * (1.0 + ((product - t) + (error + y * lo)))
+}
+
+/// Checks whether `x` is an integer.
+#[inline(always)]
+#[must_use]
+const fn is_integer(x: f64) -> bool {
+	trunc(x) == x
+}
+
+/// Checks whether `x` is an odd integer.
+#[must_use]
+const fn is_odd_integer(x: f64) -> bool {
+	// Integers of at least this magnitude are all even.
+	if !is_integer(x) || abs(x) >= 9007199254740992.0 {
+		return false;
+	}
+
+	#[allow(clippy::cast_possible_truncation)]
+	let x = x as i64;
+
+	x & 0x1 == 0x1
+}
+
+/// Computes `x` raised to the power of `y`.
+#[must_use]
+pub(crate) const fn powf(x: f64, y: f64) -> f64 {
+	if y == 0.0 || x == 1.0 {
+		return 1.0;
+	}
+
+	if is_nan(x) || is_nan(y) {
+		return f64::NAN;
+	}
+
+	if x == 0.0 {
+		return match (y > 0.0, is_odd_integer(y)) {
+			(true,  true)  => x,
+			(true,  false) => 0.0,
+			(false, true)  => copysign(f64::INFINITY, x),
+			(false, false) => f64::INFINITY,
+		};
+	}
+
+	if is_infinite(y) {
+		let a = abs(x);
+
+		return if a == 1.0 {
+			1.0
+		} else if (a > 1.0) == (y > 0.0) {
+			f64::INFINITY
+		} else {
+			0.0
+		};
+	}
+
+	if is_infinite(x) {
+		let magnitude = if y > 0.0 { f64::INFINITY } else { 0.0 };
+
+		return if x < 0.0 && is_odd_integer(y) {
+			-magnitude
+		} else {
+			magnitude
+		};
+	}
+
+	if x < 0.0 {
+		if !is_integer(y) {
+			return f64::NAN;
+		}
+
+		let magnitude = exp_ln(-x, y);
+
+		return if is_odd_integer(y) { -magnitude } else { magnitude };
+	}
+
+	exp_ln(x, y)
+}
+
+/// Computes `x` raised to the integer power of `n`.
+#[must_use]
+pub(crate) const fn powi(x: f64, n: i32) -> f64 {
+	let mut base     = x;
+	let mut exponent = n.unsigned_abs();
+	let mut result   = 1.0;
+
+	while exponent > 0x0 {
+		if exponent & 0x1 == 0x1 {
+			result *= base;
+		}
+
+		base *= base;
+		exponent >>= 0x1;
+	}
+
+	if n < 0x0 { 1.0 / result } else { result }
+}
+
+/// Computes the arctangent of `x`.
+#[must_use]
+pub(crate) const fn atan(x: f64) -> f64 {
+	if is_nan(x) {
+		return x;
+	}
+
+	// Use `atan(t) = π/2 - atan(1/t)` for `t > 1`.
+	let a = abs(x);
+
+	let (base, mut t) = if a > 1.0 {
+		(FRAC_PI_2, -1.0 / a)
+	} else {
+		(0.0, a)
+	};
+
+	// Halve the angle twice using `atan(t) = 2 atan(t / (1 + sqrt(1 + t^2)))`, giving `|t| < 0.2`.
+	t /= 1.0 + sqrt(1.0 + t * t);
+	t /= 1.0 + sqrt(1.0 + t * t);
+
+	let z = t * t;
+
+	let mut series = 0.0;
+
+	let mut n = 0xC;
+
+	while n >= 0x0 {
+		#[allow(clippy::cast_lossless)]
+		let term = 1.0 / (0x2 * n + 0x1) as f64;
+
+		series = if n % 0x2 == 0x0 { term } else { -term } + z * series;
+		n -= 0x1;
+	}
+
+	copysign(base + 4.0 * t * series, x)
+}
+
+/// Computes the four-quadrant arctangent of `y` and `x`.
+#[must_use]
+pub(crate) const fn atan2(y: f64, x: f64) -> f64 {
+	if is_nan(x) || is_nan(y) {
+		return f64::NAN;
+	}
+
+	let x_is_positive = !is_sign_negative(x);
+
+	if y == 0.0 {
+		return if x_is_positive { y } else { copysign(PI, y) };
+	}
+
+	if x == 0.0 || is_infinite(y) && is_finite(x) {
+		return copysign(FRAC_PI_2, y);
+	}
+
+	if is_infinite(x) {
+		let angle = match (is_infinite(y), x_is_positive) {
+			(true,  true)  => FRAC_PI_4,
+			(true,  false) => 3.0 * FRAC_PI_4,
+			(false, true)  => 0.0,
+			(false, false) => PI,
+		};
+
+		return copysign(angle, y);
+	}
+
+	// Measure the angle from the nearest axis, and add the
+	// low part of the axis' angle before its high part so
+	// that the result is only rounded once.
+	if abs(x) >= abs(y) {
+		let angle = atan(y / x);
+
+		if x_is_positive {
+			angle
+		} else {
+			copysign(PI, y) + (angle + copysign(PI_LO, y))
+		}
+	} else {
+		let angle = -atan(x / y);
+		copysign(FRAC_PI_2, y) + (angle + copysign(FRAC_PI_2_LO, y))
+	}
+}
+
+/// Computes the sine and cosine of an angle within `[-π/4, π/4]`.
+#[must_use]
+const fn sin_cos_kernel(r: f64) -> (f64, f64) {
+	let z = r * r;
+
+	// The Taylor series converge to double precision within ten terms.
+	let mut sin = 1.0;
+	let mut cos = 1.0;
+
+	let mut n = 0x12;
+
+	while n > 0x0 {
+		#[allow(clippy::cast_lossless)]
+		let n_float = n as f64;
+
+		sin = 1.0 - z * sin / (n_float * (n_float + 1.0));
+		cos = 1.0 - z * cos / (n_float * (n_float - 1.0));
+		n -= 0x2;
+	}
+
+	(r * sin, cos)
+}
+
+/// Computes the sine and cosine of `x`.
+#[must_use]
+pub(crate) const fn sin_cos(x: f64) -> (f64, f64) {
+	if !is_finite(x) {
+		return (f64::NAN, f64::NAN);
+	}
+
+	// Reduce the argument to `x = k π/2 + r` with `|r| <= π/4`.
+	let k = round(x * (2.0 / PI));
+	let r = ((x - k * FRAC_PI_2_1) - k * FRAC_PI_2_2) - k * FRAC_PI_2_3;
+
+	let (sin, cos) = sin_cos_kernel(r);
+
+	#[allow(clippy::cast_possible_truncation)]
+	let quadrant = k as i64 & 0x3;
+
+	match quadrant {
+		0x0 => (sin,  cos),
+		0x1 => (cos,  -sin),
+		0x2 => (-sin, -cos),
+		_   => (-cos, sin),
+	}
+}
+
+/// Computes the sine of `x`.
+#[inline]
+#[cfg_attr(not(feature = "maths"), allow(dead_code))]
+#[must_use]
+pub(crate) const fn sin(x: f64) -> f64 {
+	sin_cos(x).0
+}
+
+/// Computes the cosine of `x`.
+#[inline]
+#[cfg_attr(not(feature = "maths"), allow(dead_code))]
+#[must_use]
+pub(crate) const fn cos(x: f64) -> f64 {
+	sin_cos(x).1
+}
+
+/// Floating-point functions that are otherwise provided by `std`.
+///
+/// With `std`, the methods forward to the inherent methods of the same names; without it, they use the portable functions of this module.
+/// The methods must be called as associated functions (e.g. `Maths::powf(x, n)`), as the inherent methods would otherwise take precedence whenever `std` is linked, which is always the case in test builds.
+///
+/// Note that the portable functions are computed in double precision.
+/// Without `std`, `f128` is therefore only as precise as `f64`.
+#[cfg(feature = "maths")]
+#[allow(dead_code)]
+pub(crate) trait Maths: Sized {
+	/// See [`f64::abs`].
+	#[must_use]
+	fn abs(self) -> Self;
+
+	/// See [`f64::copysign`].
+	#[must_use]
+	fn copysign(self, sign: Self) -> Self;
+
+	/// See [`f64::round`].
+	#[must_use]
+	fn round(self) -> Self;
+
+	/// See [`f64::rem_euclid`].
+	#[must_use]
+	fn rem_euclid(self, rhs: Self) -> Self;
+
+	/// See [`f64::sqrt`].
+	#[must_use]
+	fn sqrt(self) -> Self;
+
+	/// See [`f64::cbrt`].
+	#[must_use]
+	fn cbrt(self) -> Self;
+
+	/// See [`f64::hypot`].
+	#[must_use]
+	fn hypot(self, other: Self) -> Self;
+
+	/// See [`f64::exp`].
+	#[must_use]
+	fn exp(self) -> Self;
+
+	/// See [`f64::ln`].
+	#[must_use]
+	fn ln(self) -> Self;
+
+	/// See [`f64::powf`].
+	#[must_use]
+	fn powf(self, n: Self) -> Self;
+
+	/// See [`f64::powi`].
+	#[must_use]
+	fn powi(self, n: i32) -> Self;
+
+	/// See [`f64::atan`].
+	#[must_use]
+	fn atan(self) -> Self;
+
+	/// See [`f64::atan2`].
+	#[must_use]
+	fn atan2(self, other: Self) -> Self;
+
+	/// See [`f64::sin`].
+	#[must_use]
+	fn sin(self) -> Self;
+
+	/// See [`f64::cos`].
+	#[must_use]
+	fn cos(self) -> Self;
+
+	/// See [`f64::sin_cos`].
+	#[must_use]
+	fn sin_cos(self) -> (Self, Self);
+}
+
+#[cfg(all(feature = "std", test))]
+std::thread_local! {
+	/// Whether the portable functions are forced on the current thread.
+	static PORTABLE: core::cell::Cell<bool> = const { core::cell::Cell::new(false) };
+}
+
+/// Checks whether [`Maths`] should use the portable functions even though `std` is available.
+///
+/// This is only ever the case in tests (see [`with_portable`]).
+#[cfg(feature = "std")]
+#[inline(always)]
+#[must_use]
+fn is_portable() -> bool {
+	#[cfg(test)]
+	{
+		PORTABLE.with(core::cell::Cell::get)
+	}
+
+	#[cfg(not(test))]
+	{
+		false
+	}
+}
+
+/// Calls a closure with [`Maths`] forced to use the portable functions.
+///
+/// This lets tests exercise the code paths of builds without `std`.
+#[cfg(all(feature = "std", test))]
+pub(crate) fn with_portable<R>(op: impl FnOnce() -> R) -> R {
+	let previous = PORTABLE.with(|portable| portable.replace(true));
+	let result   = op();
+
+	PORTABLE.with(|portable| portable.set(previous));
+
+	result
+}
+
+#[cfg(feature = "maths")]
+macro_rules! impl_maths {
+	($($tys:ty),+$(,)?) => {
+		$(
+			#[allow(clippy::cast_lossless, clippy::cast_possible_truncation)]
+			impl ::polywave::maths::Maths for $tys {
+				#[inline(always)]
+				fn abs(self) -> Self {
+					#[cfg(feature = "std")]
+					if !::polywave::maths::is_portable() {
+						return <$tys>::abs(self);
+					}
+
+					::polywave::maths::abs(self as f64) as Self
+				}
+
+				#[inline(always)]
+				fn copysign(self, sign: Self) -> Self {
+					#[cfg(feature = "std")]
+					if !::polywave::maths::is_portable() {
+						return <$tys>::copysign(self, sign);
+					}
+
+					::polywave::maths::copysign(self as f64, sign as f64) as Self
+				}
+
+				#[inline(always)]
+				fn round(self) -> Self {
+					#[cfg(feature = "std")]
+					if !::polywave::maths::is_portable() {
+						return <$tys>::round(self);
+					}
+
+					::polywave::maths::round(self as f64) as Self
+				}
+
+				#[inline(always)]
+				fn rem_euclid(self, rhs: Self) -> Self {
+					#[cfg(feature = "std")]
+					if !::polywave::maths::is_portable() {
+						return <$tys>::rem_euclid(self, rhs);
+					}
+
+					::polywave::maths::rem_euclid(self as f64, rhs as f64) as Self
+				}
+
+				#[inline(always)]
+				fn sqrt(self) -> Self {
+					#[cfg(feature = "std")]
+					if !::polywave::maths::is_portable() {
+						return <$tys>::sqrt(self);
+					}
+
+					::polywave::maths::sqrt(self as f64) as Self
+				}
+
+				#[inline(always)]
+				fn cbrt(self) -> Self {
+					#[cfg(feature = "std")]
+					if !::polywave::maths::is_portable() {
+						return <$tys>::cbrt(self);
+					}
+
+					::polywave::maths::cbrt(self as f64) as Self
+				}
+
+				#[inline(always)]
+				fn hypot(self, other: Self) -> Self {
+					#[cfg(feature = "std")]
+					if !::polywave::maths::is_portable() {
+						return <$tys>::hypot(self, other);
+					}
+
+					::polywave::maths::hypot(self as f64, other as f64) as Self
+				}
+
+				#[inline(always)]
+				fn exp(self) -> Self {
+					#[cfg(feature = "std")]
+					if !::polywave::maths::is_portable() {
+						return <$tys>::exp(self);
+					}
+
+					::polywave::maths::exp(self as f64) as Self
+				}
+
+				#[inline(always)]
+				fn ln(self) -> Self {
+					#[cfg(feature = "std")]
+					if !::polywave::maths::is_portable() {
+						return <$tys>::ln(self);
+					}
+
+					::polywave::maths::ln(self as f64) as Self
+				}
+
+				#[inline(always)]
+				fn powf(self, n: Self) -> Self {
+					#[cfg(feature = "std")]
+					if !::polywave::maths::is_portable() {
+						return <$tys>::powf(self, n);
+					}
+
+					::polywave::maths::powf(self as f64, n as f64) as Self
+				}
+
+				#[inline(always)]
+				fn powi(self, n: i32) -> Self {
+					#[cfg(feature = "std")]
+					if !::polywave::maths::is_portable() {
+						return <$tys>::powi(self, n);
+					}
+
+					::polywave::maths::powi(self as f64, n) as Self
+				}
+
+				#[inline(always)]
+				fn atan(self) -> Self {
+					#[cfg(feature = "std")]
+					if !::polywave::maths::is_portable() {
+						return <$tys>::atan(self);
+					}
+
+					::polywave::maths::atan(self as f64) as Self
+				}
+
+				#[inline(always)]
+				fn atan2(self, other: Self) -> Self {
+					#[cfg(feature = "std")]
+					if !::polywave::maths::is_portable() {
+						return <$tys>::atan2(self, other);
+					}
+
+					::polywave::maths::atan2(self as f64, other as f64) as Self
+				}
+
+				#[inline(always)]
+				fn sin(self) -> Self {
+					#[cfg(feature = "std")]
+					if !::polywave::maths::is_portable() {
+						return <$tys>::sin(self);
+					}
+
+					::polywave::maths::sin(self as f64) as Self
+				}
+
+				#[inline(always)]
+				fn cos(self) -> Self {
+					#[cfg(feature = "std")]
+					if !::polywave::maths::is_portable() {
+						return <$tys>::cos(self);
+					}
+
+					::polywave::maths::cos(self as f64) as Self
+				}
+
+				#[inline(always)]
+				fn sin_cos(self) -> (Self, Self) {
+					#[cfg(feature = "std")]
+					if !::polywave::maths::is_portable() {
+						return <$tys>::sin_cos(self);
+					}
+
+					let (sin, cos) = ::polywave::maths::sin_cos(self as f64);
+					(sin as Self, cos as Self)
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(all(feature = "f16", feature = "maths"))]
+impl_maths!(f16);
+
+#[cfg(feature = "maths")]
+impl_maths!(f32, f64);
+
+#[cfg(all(feature = "f128", feature = "maths"))]
+impl_maths!(f128);
@@ -0,0 +1,207 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+#![cfg(test)]
+
+use crate::maths;
+#[cfg(feature = "std")]
+use crate::hsv::OkHsv;
+#[cfg(feature = "std")]
+use crate::lab::CieLab;
+#[cfg(feature = "std")]
+use crate::lch::CieLch;
+#[cfg(feature = "std")]
+use crate::rgb::{OpRgb, SRgb};
+#[cfg(feature = "std")]
+use crate::test::assert_approx_eq;
+#[cfg(feature = "std")]
+use crate::xyz::CieXyz;
+
+use core::f64::consts::PI;
+
+/// Asserts that the portable result is within a few units in the last place of the platform's.
+#[track_caller]
+fn assert_close(actual: f64, expected: f64) {
+	if expected.is_nan() {
+		assert!(actual.is_nan(), "expected NaN, got {actual}");
+		return;
+	}
+
+	if expected.is_infinite() || expected == 0.0 {
+		assert_eq!(actual, expected);
+		return;
+	}
+
+	let error = (actual - expected).abs() / expected.abs();
+
+	assert!(error < 1e-14, "expected {expected}, got {actual}");
+}
+
+/// Generates arguments spanning many magnitudes, including some subnormal ones.
+fn arguments() -> impl Iterator<Item = f64> {
+	let linear = (-0x200..=0x200).map(|index| f64::from(index) * 0.0371);
+
+	let logarithmic = (-0x42A..=0x3FF)
+		.step_by(0x7)
+		.map(|exponent| 1.3 * 2.0_f64.powi(exponent));
+
+	linear.chain(logarithmic)
+}
+
+#[test]
+fn test_maths_roots() {
+	for x in arguments() {
+		assert_close(maths::sqrt(x),        x.sqrt());
+		assert_close(maths::cbrt(x),        x.cbrt());
+		assert_close(maths::hypot(x, 0.75), x.hypot(0.75));
+		assert_close(maths::hypot(x, x),    x.hypot(x));
+	}
+
+	assert_eq!(maths::sqrt(4.0),   2.0);
+	assert_eq!(maths::cbrt(-27.0), -3.0);
+	assert_eq!(maths::hypot(3.0, -4.0), 5.0);
+
+	assert!(maths::sqrt(-1.0).is_nan());
+	assert_eq!(maths::hypot(f64::INFINITY, f64::NAN), f64::INFINITY);
+}
+
+#[test]
+fn test_maths_exponentials() {
+	for x in arguments() {
+		if x.abs() < 700.0 {
+			assert_close(maths::exp(x), x.exp());
+		}
+
+		assert_close(maths::ln(x), x.ln());
+	}
+
+	assert_eq!(maths::exp(0.0), 1.0);
+	assert_eq!(maths::exp(1e3), f64::INFINITY);
+	assert_eq!(maths::exp(-1e3), 0.0);
+	assert_eq!(maths::ln(1.0), 0.0);
+	assert_eq!(maths::ln(0.0), f64::NEG_INFINITY);
+}
+
+#[test]
+fn test_maths_powers() {
+	for x in arguments().filter(|x| *x >= 0.0) {
+		for y in [-2.4, -1.0, 0.5, 1.0 / 3.0, 2.2, 2.4, 7.0] {
+			let expected = x.powf(y);
+
+			if expected.is_finite() && expected > f64::MIN_POSITIVE {
+				assert_close(maths::powf(x, y), expected);
+			}
+		}
+
+		if x > 1e-30 && x < 1e30 {
+			assert_close(maths::powi(x, 0x7),  x.powi(0x7));
+			assert_close(maths::powi(x, -0x2), x.powi(-0x2));
+		}
+	}
+
+	assert_eq!(maths::powf(-2.0, 3.0), -8.0);
+	assert_eq!(maths::powf(0.0, -1.0), f64::INFINITY);
+	assert_eq!(maths::powf(-0.0, -1.0), f64::NEG_INFINITY);
+	assert_eq!(maths::powf(f64::NAN, 0.0), 1.0);
+	assert_eq!(maths::powf(0.5, f64::INFINITY), 0.0);
+	assert!(maths::powf(-2.0, 0.5).is_nan());
+}
+
+#[test]
+fn test_maths_trigonometry() {
+	for x in arguments().filter(|x| x.abs() < 1e5) {
+		let (sin, cos) = maths::sin_cos(x);
+
+		assert!((sin - x.sin()).abs() < 1e-15 * x.abs().max(1.0));
+		assert!((cos - x.cos()).abs() < 1e-15 * x.abs().max(1.0));
+
+		assert_close(maths::atan(x),       x.atan());
+		assert_close(maths::atan2(x, 0.3),  x.atan2(0.3));
+		assert_close(maths::atan2(x, -0.3), x.atan2(-0.3));
+		assert_close(maths::atan2(-0.3, x), (-0.3_f64).atan2(x));
+	}
+
+	assert_eq!(maths::atan2(0.0, -1.0),  PI);
+	assert_eq!(maths::atan2(-0.0, -1.0), -PI);
+	assert_eq!(maths::atan2(1.0, 0.0),   PI / 2.0);
+	assert_eq!(maths::cos(0.0), 1.0);
+	assert!(maths::sin(f64::INFINITY).is_nan());
+}
+
+#[test]
+fn test_maths_rounding() {
+	for x in arguments() {
+		assert_eq!(maths::round(x).to_bits(), x.round().to_bits());
+		assert_eq!(maths::trunc(x).to_bits(), x.trunc().to_bits());
+		assert_eq!(maths::rem_euclid(x, 360.0), x.rem_euclid(360.0));
+	}
+
+	assert_eq!(maths::round(2.5),  3.0);
+	assert_eq!(maths::round(-2.5), -3.0);
+	assert_eq!(maths::round(0.49999999999999994), 0.0);
+	assert_eq!(maths::copysign(2.0, -0.0), -2.0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_maths_portable_conversion() {
+	let colours = [
+		SRgb::<f64>::new(0.4, 0.2, 0.6),
+		SRgb::new(0.9, 0.8, 0.1),
+		SRgb::new(-0.1, 0.5, 1.2),
+	];
+
+	for colour in colours {
+		// sRGB always uses the portable functions.
+		let expected = colour.as_rgb().map(|channel| {
+			let magnitude = channel.abs();
+
+			let magnitude = if magnitude > 0.040_450 {
+				((magnitude + 0.055) / 1.055).powf(2.4)
+			} else {
+				magnitude / 12.920
+			};
+
+			magnitude.copysign(channel)
+		});
+
+		assert_approx_eq!(colour.untransfer().get(), expected.get(), 1e-14);
+
+		let xyz = CieXyz::<f64>::from_s_rgb(colour);
+
+		let expected = (
+			CieLab::<f64>::from_xyz(xyz, CieXyz::<f64>::D65),
+			CieLch::<f64>::from_lab(CieLab::<f64>::from_xyz(xyz, CieXyz::<f64>::D65)),
+			OkHsv::<f64>::from_s_rgb(colour),
+			OpRgb::<f64>::from_xyz(xyz),
+			colour.apca_contrast(SRgb::new(1.0, 1.0, 1.0)),
+		);
+
+		let portable = maths::with_portable(|| (
+			CieLab::<f64>::from_xyz(xyz, CieXyz::<f64>::D65),
+			CieLch::<f64>::from_lab(CieLab::<f64>::from_xyz(xyz, CieXyz::<f64>::D65)),
+			OkHsv::<f64>::from_s_rgb(colour),
+			OpRgb::<f64>::from_xyz(xyz),
+			colour.apca_contrast(SRgb::new(1.0, 1.0, 1.0)),
+		));
+
+		assert_approx_eq!(portable.0.get(), expected.0.get(), 1e-12);
+		assert_approx_eq!(portable.1.get(), expected.1.get(), 1e-12);
+		assert_approx_eq!(portable.2.get(), expected.2.get(), 1e-12);
+		assert_approx_eq!(portable.3.get(), expected.3.get(), 1e-12);
+
+		assert!((portable.4 - expected.4).abs() < 1e-12);
+	}
+
+	for (left, right) in [(CieLab::<f64>::new(50.0, -0.001, 2.49), CieLab::new(50.0, 0.001, -2.49)), (CieLab::<f64>::new(60.0, 20.0, -10.0), CieLab::new(55.0, 25.0, -5.0))] {
+		let expected = left.delta_e_2000(right, 1.0, 1.0, 1.0);
+		let portable = maths::with_portable(|| left.delta_e_2000(right, 1.0, 1.0, 1.0));
+
+		assert!((portable - expected).abs() < 1e-12);
+	}
+}
@@ -0,0 +1,136 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::Component;
+
+/// Denotes a component type with a normalised range.
+///
+/// Normalised channels -- such as those of [`SRgb`](crate::rgb::SRgb) or the alpha channel of [`Alpha`](crate::Alpha) -- map the lowest intensity to [`MINIMUM`](Self::MINIMUM) and the highest to [`MAXIMUM`](Self::MAXIMUM).
+/// For floating-point types, this is the interval `0` to `1`, whilst for integers it is the range from zero to the maximum of the type.
+/// Negative values of signed integers thus lie outside of the range.
+///
+/// This trait is mainly useful for implementing [`DefinedGamut`](crate::DefinedGamut).
+pub trait NormalisedComponent: Component + PartialOrd {
+	/// The value denoting the lowest intensity.
+	const MINIMUM: Self;
+
+	/// The value denoting the highest intensity.
+	const MAXIMUM: Self;
+
+	/// Checks whether the component lies within the normalised range.
+	///
+	/// The range is extended by `epsilon` in both directions.
+	#[must_use]
+	fn is_normalised(self, epsilon: Self) -> bool;
+
+	/// Clamps the component to the normalised range.
+	#[must_use]
+	fn clamp_normalised(self) -> Self;
+
+	/// Computes the distance from the component to the normalised range.
+	///
+	/// If the component lies within the range, then this is zero.
+	#[must_use]
+	fn normalised_distance(self) -> Self;
+}
+
+macro_rules! impl_normalised_component_unsigned {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::NormalisedComponent for $tys {
+				const MINIMUM: Self = <$tys>::MIN;
+				const MAXIMUM: Self = <$tys>::MAX;
+
+				#[inline(always)]
+				fn is_normalised(self, _epsilon: Self) -> bool {
+					true
+				}
+
+				#[inline(always)]
+				fn clamp_normalised(self) -> Self {
+					self
+				}
+
+				#[inline(always)]
+				fn normalised_distance(self) -> Self {
+					0x0
+				}
+			}
+		)*
+	};
+}
+
+macro_rules! impl_normalised_component_signed {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::NormalisedComponent for $tys {
+				const MINIMUM: Self = 0x0;
+				const MAXIMUM: Self = <$tys>::MAX;
+
+				#[inline]
+				fn is_normalised(self, epsilon: Self) -> bool {
+					self >= epsilon.saturating_neg()
+				}
+
+				#[inline]
+				fn clamp_normalised(self) -> Self {
+					self.max(0x0)
+				}
+
+				#[inline]
+				fn normalised_distance(self) -> Self {
+					self.min(0x0).saturating_neg()
+				}
+			}
+		)*
+	};
+}
+
+macro_rules! impl_normalised_component_float {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::NormalisedComponent for $tys {
+				const MINIMUM: Self = 0.0;
+				const MAXIMUM: Self = 1.0;
+
+				#[inline]
+				fn is_normalised(self, epsilon: Self) -> bool {
+					self >= -epsilon && self <= 1.0 + epsilon
+				}
+
+				#[inline]
+				fn clamp_normalised(self) -> Self {
+					self.clamp(0.0, 1.0)
+				}
+
+				#[inline]
+				fn normalised_distance(self) -> Self {
+					if self < 0.0 {
+						-self
+					} else if self > 1.0 {
+						self - 1.0
+					} else {
+						0.0
+					}
+				}
+			}
+		)*
+	};
+}
+
+impl_normalised_component_unsigned!(u8, u16, u32, u64, u128);
+
+impl_normalised_component_signed!(i8, i16, i32, i64, i128);
+
+#[cfg(feature = "f16")]
+impl_normalised_component_float!(f16);
+
+impl_normalised_component_float!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_normalised_component_float!(f128);
@@ -0,0 +1,252 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::OkLabConvert;
+use crate::hsv::{OkHsl, OkHsv};
+use crate::lab::{CieLuv, OkLab, OkLrab};
+use crate::lch::{CieLchUv, HpLuv, HsLuv, OkLch, OkLrch};
+use crate::rgb::{DisplayP3, OpRgb, ProPhotoRgb, Rec2020, Rgb, SRgb};
+use crate::xyz::CieXyz;
+
+/// Implements [`OkLabConvert`] using conversions to
+/// and from sRGB.
+macro_rules! impl_ok_lab_convert_s_rgb {
+	($tys:ty: $($colours:ident),+$(,)?) => {
+		$(
+			impl OkLabConvert for $colours<$tys> {
+				type Scalar = $tys;
+
+				#[inline]
+				fn from_ok_lab(colour: OkLab<Self::Scalar>) -> Self {
+					Self::from_s_rgb(colour.to_s_rgb())
+				}
+
+				#[inline]
+				fn to_ok_lab(self) -> OkLab<Self::Scalar> {
+					OkLab::<$tys>::from_s_rgb(self.to_s_rgb())
+				}
+
+				#[inline(always)]
+				fn to_s_rgb(self) -> SRgb<Self::Scalar> {
+					$colours::<$tys>::to_s_rgb(self)
+				}
+			}
+		)*
+	};
+}
+
+/// Implements [`OkLabConvert`] using conversions to
+/// and from CIEXYZ relative to the D65 white point.
+macro_rules! impl_ok_lab_convert_xyz {
+	($tys:ty: $($colours:ident),+$(,)?) => {
+		$(
+			impl OkLabConvert for $colours<$tys> {
+				type Scalar = $tys;
+
+				#[inline]
+				fn from_ok_lab(colour: OkLab<Self::Scalar>) -> Self {
+					Self::from_xyz(CieXyz::<$tys>::from_ok_lab(colour))
+				}
+
+				#[inline]
+				fn to_ok_lab(self) -> OkLab<Self::Scalar> {
+					self.to_xyz().to_ok_lab()
+				}
+
+				#[inline]
+				fn to_s_rgb(self) -> SRgb<Self::Scalar> {
+					self.to_xyz().to_s_rgb()
+				}
+			}
+		)*
+	};
+}
+
+macro_rules! impl_ok_lab_convert {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl_ok_lab_convert_s_rgb!($tys: HpLuv, HsLuv, OkHsl, OkHsv);
+
+			impl_ok_lab_convert_xyz!($tys: DisplayP3, OpRgb, Rec2020);
+
+			impl OkLabConvert for OkLab<$tys> {
+				type Scalar = $tys;
+
+				#[inline(always)]
+				fn from_ok_lab(colour: OkLab<Self::Scalar>) -> Self {
+					colour
+				}
+
+				#[inline(always)]
+				fn to_ok_lab(self) -> OkLab<Self::Scalar> {
+					self
+				}
+
+				#[inline(always)]
+				fn to_s_rgb(self) -> SRgb<Self::Scalar> {
+					OkLab::<$tys>::to_s_rgb(self)
+				}
+			}
+
+			impl OkLabConvert for OkLch<$tys> {
+				type Scalar = $tys;
+
+				#[inline(always)]
+				fn from_ok_lab(colour: OkLab<Self::Scalar>) -> Self {
+					Self::from_ok_lab(colour)
+				}
+
+				#[inline(always)]
+				fn to_ok_lab(self) -> OkLab<Self::Scalar> {
+					self.to_ok_lab()
+				}
+			}
+
+			impl OkLabConvert for OkLrab<$tys> {
+				type Scalar = $tys;
+
+				#[inline(always)]
+				fn from_ok_lab(colour: OkLab<Self::Scalar>) -> Self {
+					Self::from_ok_lab(colour)
+				}
+
+				#[inline(always)]
+				fn to_ok_lab(self) -> OkLab<Self::Scalar> {
+					self.to_ok_lab()
+				}
+			}
+
+			impl OkLabConvert for OkLrch<$tys> {
+				type Scalar = $tys;
+
+				#[inline]
+				fn from_ok_lab(colour: OkLab<Self::Scalar>) -> Self {
+					Self::from_ok_lch(OkLch::<$tys>::from_ok_lab(colour))
+				}
+
+				#[inline]
+				fn to_ok_lab(self) -> OkLab<Self::Scalar> {
+					self.to_ok_lch().to_ok_lab()
+				}
+			}
+
+			impl OkLabConvert for SRgb<$tys> {
+				type Scalar = $tys;
+
+				#[inline(always)]
+				fn from_ok_lab(colour: OkLab<Self::Scalar>) -> Self {
+					colour.to_s_rgb()
+				}
+
+				#[inline(always)]
+				fn to_ok_lab(self) -> OkLab<Self::Scalar> {
+					OkLab::<$tys>::from_s_rgb(self)
+				}
+
+				#[inline(always)]
+				fn to_s_rgb(self) -> SRgb<Self::Scalar> {
+					self
+				}
+			}
+
+			impl OkLabConvert for CieXyz<$tys> {
+				type Scalar = $tys;
+
+				/// Converts an Oklab colour to CIEXYZ.
+				///
+				/// The returned colour is relative to the [D65](CieXyz::D65) white point.
+				#[inline]
+				fn from_ok_lab(colour: OkLab<Self::Scalar>) -> Self {
+					let (red, green, blue) = colour.to_linear_s_rgb().get();
+
+					let [x, y, z] = Self::FROM_S_RGB.map(|[m0, m1, m2]| m0 * red + m1 * green + m2 * blue);
+
+					Self::new(x, y, z)
+				}
+
+				/// Converts the CIEXYZ colour to Oklab.
+				///
+				/// The colour is expected to be relative to the [D65](CieXyz::D65) white point.
+				#[inline]
+				fn to_ok_lab(self) -> OkLab<Self::Scalar> {
+					let (x, y, z) = self.get();
+
+					let [red, green, blue] = Self::TO_S_RGB.map(|[m0, m1, m2]| m0 * x + m1 * y + m2 * z);
+
+					OkLab::<$tys>::from_linear_s_rgb(Rgb::new(red, green, blue))
+				}
+
+				/// Converts the CIEXYZ colour to sRGB.
+				///
+				/// The colour is expected to be relative to the [D65](CieXyz::D65) white point.
+				#[inline(always)]
+				fn to_s_rgb(self) -> SRgb<Self::Scalar> {
+					CieXyz::<$tys>::to_s_rgb(self)
+				}
+			}
+
+			impl OkLabConvert for ProPhotoRgb<$tys> {
+				type Scalar = $tys;
+
+				#[inline]
+				fn from_ok_lab(colour: OkLab<Self::Scalar>) -> Self {
+					let colour = CieXyz::<$tys>::from_ok_lab(colour).adapt(CieXyz::<$tys>::D65, CieXyz::<$tys>::D50);
+					Self::from_xyz(colour)
+				}
+
+				#[inline]
+				fn to_ok_lab(self) -> OkLab<Self::Scalar> {
+					self.to_xyz().adapt(CieXyz::<$tys>::D50, CieXyz::<$tys>::D65).to_ok_lab()
+				}
+			}
+
+			impl OkLabConvert for CieLuv<$tys> {
+				type Scalar = $tys;
+
+				/// Converts an Oklab colour to CIELUV.
+				///
+				/// The returned colour is relative to the [D65](CieXyz::D65) white point.
+				#[inline]
+				fn from_ok_lab(colour: OkLab<Self::Scalar>) -> Self {
+					let colour = CieXyz::<$tys>::from_ok_lab(colour);
+					Self::from_xyz(colour, CieXyz::<$tys>::D65)
+				}
+
+				/// Converts the CIELUV colour to Oklab.
+				///
+				/// The colour is expected to be relative to the [D65](CieXyz::D65) white point.
+				#[inline]
+				fn to_ok_lab(self) -> OkLab<Self::Scalar> {
+					self.to_xyz(CieXyz::<$tys>::D65).to_ok_lab()
+				}
+			}
+
+			impl OkLabConvert for CieLchUv<$tys> {
+				type Scalar = $tys;
+
+				#[inline]
+				fn from_ok_lab(colour: OkLab<Self::Scalar>) -> Self {
+					Self::from_luv(CieLuv::<$tys>::from_ok_lab(colour))
+				}
+
+				#[inline]
+				fn to_ok_lab(self) -> OkLab<Self::Scalar> {
+					self.to_luv().to_ok_lab()
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_ok_lab_convert!(f16);
+
+impl_ok_lab_convert!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_ok_lab_convert!(f128);
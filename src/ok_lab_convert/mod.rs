@@ -0,0 +1,46 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+#[cfg(feature = "maths")]
+mod colours;
+
+use crate::Component;
+use crate::lab::OkLab;
+use crate::rgb::SRgb;
+
+/// Denotes a colour that can be converted to and from Oklab.
+///
+/// Oklab serves as a common connection space between colour types, which allows generic facilities -- such as [`Gradient`](crate::gradient::Gradient) -- to convert between arbitrary colours.
+/// Colours converted from Oklab are not necessarily in their gamut.
+///
+/// All colours with floating-point components and defined conversions to sRGB implement this trait when the `maths` feature is enabled.
+pub trait OkLabConvert: Sized {
+	/// The type of the Oklab components.
+	type Scalar: Component;
+
+	/// Converts an Oklab colour.
+	#[must_use]
+	fn from_ok_lab(colour: OkLab<Self::Scalar>) -> Self;
+
+	/// Converts the colour to Oklab.
+	#[must_use]
+	fn to_ok_lab(self) -> OkLab<Self::Scalar>;
+
+	/// Converts the colour to sRGB.
+	///
+	/// By default, this is done through Oklab.
+	/// Colours with a more direct conversion -- including sRGB itself -- use that instead.
+	#[inline]
+	#[must_use]
+	fn to_s_rgb(self) -> SRgb<Self::Scalar>
+	where
+		SRgb<Self::Scalar>: OkLabConvert<Scalar = Self::Scalar>,
+	{
+		SRgb::from_ok_lab(self.to_ok_lab())
+	}
+}
@@ -0,0 +1,33 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+//! Palette files.
+//!
+//! [Palettes](Palette) are named lists of colours as exchanged between graphics applications.
+//! The following formats can be read and written:
+//!
+//! | Format                            | Extension | Reader                          | Writer                      |
+//! | :-------------------------------- | :-------- | :------------------------------ | :-------------------------- |
+//! | GIMP palette                      | `.gpl`    | [`from_gpl`](Palette::from_gpl) | [`to_gpl`](Palette::to_gpl) |
+//! | Adobe Swatch Exchange             | `.ase`    | [`from_ase`](Palette::from_ase) | [`to_ase`](Palette::to_ase) |
+//! | Photoshop colour swatches         | `.aco`    | [`from_aco`](Palette::from_aco) | [`to_aco`](Palette::to_aco) |
+//! | Photoshop colour table            | `.act`    | [`from_act`](Palette::from_act) | [`to_act`](Palette::to_act) |
+//! | JASC (PaintShop Pro) palette      | `.pal`    | [`from_pal`](Palette::from_pal) | [`to_pal`](Palette::to_pal) |
+//! | Paint.NET palette                 | `.txt`    | [`from_paint_net`](Palette::from_paint_net) | [`to_paint_net`](Palette::to_paint_net) |
+//!
+//! Each entry keeps the [colour](PaletteColour) in the model specified by the file, e.g. [`CieLab`](crate::lab::CieLab) for Lab swatches.
+
+#[allow(clippy::module_inception)]
+mod palette;
+mod palette_colour;
+mod palette_entry;
+mod test;
+
+pub use palette::Palette;
+pub use palette_colour::PaletteColour;
+pub use palette_entry::PaletteEntry;
@@ -0,0 +1,243 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::error::PaletteError;
+use crate::hsv::Hsv;
+use crate::lab::CieLab;
+use crate::palette::{Palette, PaletteColour, PaletteEntry};
+use crate::palette::palette::{push_utf16, Reader};
+use crate::rgb::SRgb;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// The colour space identifier of RGB colours.
+const RGB_SPACE: u16 = 0x0;
+
+/// The colour space identifier of HSB colours.
+const HSB_SPACE: u16 = 0x1;
+
+/// The colour space identifier of CMYK colours.
+const CMYK_SPACE: u16 = 0x2;
+
+/// The colour space identifier of CIELAB colours.
+const LAB_SPACE: u16 = 0x7;
+
+/// The colour space identifier of grey colours.
+const GREY_SPACE: u16 = 0x8;
+
+impl Palette {
+	/// Reads an Adobe colour swatch file (`.aco`).
+	///
+	/// Both version 1 and version 2 files are supported, and the names of version 2 are used if present.
+	/// RGB, HSB, CMYK, LAB, and grey colours are supported.
+	/// As colour swatch files are unnamed, the name of the palette is empty.
+	///
+	/// # Errors
+	///
+	/// If the file is malformed or uses an unsupported colour space, an error is returned.
+	pub fn from_aco(data: &[u8]) -> Result<Self, PaletteError> {
+		let mut reader = Reader::new(data);
+
+		// Files may also consist of only a version 2 section.
+		let mut version = read_version(&mut reader)?;
+		let mut palette = read_section(&mut reader, version)?;
+
+		if version == 0x1 && !reader.is_empty() {
+			version = read_version(&mut reader)?;
+
+			if version != 0x2 {
+				return Err(PaletteError::InvalidData { offset: reader.offset - 0x2 });
+			}
+
+			palette = read_section(&mut reader, version)?;
+		}
+
+		if !reader.is_empty() {
+			return Err(reader.error());
+		}
+
+		Ok(palette)
+	}
+
+	/// Writes the palette as an Adobe colour swatch file.
+	///
+	/// Both a version 1 and a version 2 section are written, so that older readers can read the colours and newer readers can read the names as well.
+	/// The name of the palette is not written.
+	///
+	/// # Errors
+	///
+	/// If any entry is a translucent [`Html`](PaletteColour::Html) colour, or if there are more than 65535 entries, an error is returned.
+	pub fn to_aco(&self) -> Result<Vec<u8>, PaletteError> {
+		self.check_count(usize::from(u16::MAX))?;
+
+		let colours = self.entries
+			.iter()
+			.enumerate()
+			.map(|(index, entry)| encode_colour(entry.colour()).ok_or(PaletteError::UnsupportedColour { index }))
+			.collect::<Result<Vec<_>, _>>()?;
+
+		let mut data = Vec::new();
+
+		for version in [0x1, 0x2] {
+			data.extend_from_slice(&u16::to_be_bytes(version));
+
+			// The count has already been checked.
+			data.extend_from_slice(&(colours.len() as u16).to_be_bytes());
+
+			for (entry, &(space, channels)) in self.entries.iter().zip(&colours) {
+				data.extend_from_slice(&space.to_be_bytes());
+
+				for channel in channels {
+					data.extend_from_slice(&channel.to_be_bytes());
+				}
+
+				if version == 0x2 {
+					let units: Vec<u16> = entry.name().encode_utf16().collect();
+
+					// Names cannot realistically exceed `u32::MAX` code units.
+					data.extend_from_slice(&(units.len() as u32 + 0x1).to_be_bytes());
+					push_utf16(&mut data, &units);
+				}
+			}
+		}
+
+		Ok(data)
+	}
+}
+
+/// Reads the version of a section, which must be either `1` or `2`.
+fn read_version(reader: &mut Reader) -> Result<u16, PaletteError> {
+	let version = reader.u16()?;
+
+	if !matches!(version, 0x1 | 0x2) {
+		return Err(PaletteError::InvalidData { offset: reader.offset - 0x2 });
+	}
+
+	Ok(version)
+}
+
+/// Reads the entries of a section of the given version, following its version.
+fn read_section(reader: &mut Reader, version: u16) -> Result<Palette, PaletteError> {
+	let count = reader.u16()?;
+
+	let mut palette = Palette::default();
+
+	for _ in 0x0..count {
+		let offset = reader.offset;
+
+		let space    = reader.u16()?;
+		let channels = [reader.u16()?, reader.u16()?, reader.u16()?, reader.u16()?];
+
+		let colour = decode_colour(space, channels).ok_or(PaletteError::InvalidData { offset })?;
+
+		let name = if version == 0x2 {
+			let count = reader.u32()? as usize;
+			reader.utf16(count)?
+		} else {
+			String::new()
+		};
+
+		palette.push(PaletteEntry::new(name, colour));
+	}
+
+	Ok(palette)
+}
+
+/// Decodes the channels of a colour in the given colour space.
+#[must_use]
+fn decode_colour(space: u16, [w, x, y, z]: [u16; 0x4]) -> Option<PaletteColour> {
+	let unit = |value: u16| f32::from(value) / 65535.0;
+
+	// The components of CIELAB are stored in hundredths, with `a*` and `b*` being signed.
+	let hundredths        = |value: u16| f32::from(value) / 100.0;
+	let signed_hundredths = |value: u16| f32::from(value as i16) / 100.0;
+
+	let colour = match space {
+		RGB_SPACE => PaletteColour::SRgb(SRgb::new(unit(w), unit(x), unit(y))),
+
+		HSB_SPACE => PaletteColour::Hsv(Hsv::new(f32::from(w) * 360.0 / 65536.0, unit(x), unit(y))),
+
+		// CMYK channels denote the absence of ink.
+		CMYK_SPACE => PaletteColour::Cmyk([1.0 - unit(w), 1.0 - unit(x), 1.0 - unit(y), 1.0 - unit(z)]),
+
+		LAB_SPACE => PaletteColour::CieLab(CieLab::new(hundredths(w), signed_hundredths(x), signed_hundredths(y))),
+
+		// Grey channels denote the presence of ink.
+		GREY_SPACE => PaletteColour::Grey(1.0 - f32::from(w) / 10000.0),
+
+		_ => return None,
+	};
+
+	Some(colour)
+}
+
+/// Encodes a colour as a colour space and its channels.
+///
+/// [`None`] is returned for translucent [`Html`](PaletteColour::Html) colours.
+#[must_use]
+fn encode_colour(colour: PaletteColour) -> Option<(u16, [u16; 0x4])> {
+	let unit = |value: f32| (value.clamp(0.0, 1.0) * 65535.0 + 0.5) as u16;
+
+	let signed_hundredths = |value: f32| {
+		let value = value * 100.0;
+
+		// `as` saturates out-of-range values.
+		(value + if value < 0.0 { -0.5 } else { 0.5 }) as i16 as u16
+	};
+
+	let encoded = match colour {
+		PaletteColour::Html(colour) => {
+			let (red, green, blue, alpha) = colour.get();
+
+			if alpha != 0xFF {
+				return None;
+			}
+
+			let wide = |value: u8| u16::from(value) * 0x101;
+
+			(RGB_SPACE, [wide(red), wide(green), wide(blue), 0x0])
+		}
+
+		PaletteColour::SRgb(colour) => {
+			let (red, green, blue) = colour.get();
+			(RGB_SPACE, [unit(red), unit(green), unit(blue), 0x0])
+		}
+
+		PaletteColour::Hsv(colour) => {
+			let (hue, saturation, value) = colour.get();
+
+			let mut hue = hue % 360.0;
+
+			if hue < 0.0 {
+				hue += 360.0;
+			}
+
+			(HSB_SPACE, [(hue * 65536.0 / 360.0 + 0.5) as u16, unit(saturation), unit(value), 0x0])
+		}
+
+		PaletteColour::Cmyk([cyan, magenta, yellow, black]) => {
+			(CMYK_SPACE, [unit(1.0 - cyan), unit(1.0 - magenta), unit(1.0 - yellow), unit(1.0 - black)])
+		}
+
+		PaletteColour::CieLab(colour) => {
+			let (luminance, a_star, b_star) = colour.get();
+
+			let luminance = (luminance.clamp(0.0, 100.0) * 100.0 + 0.5) as u16;
+
+			(LAB_SPACE, [luminance, signed_hundredths(a_star), signed_hundredths(b_star), 0x0])
+		}
+
+		PaletteColour::Grey(level) => {
+			let ink = ((1.0 - level).clamp(0.0, 1.0) * 10000.0 + 0.5) as u16;
+			(GREY_SPACE, [ink, 0x0, 0x0, 0x0])
+		}
+	};
+
+	Some(encoded)
+}
@@ -0,0 +1,114 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::error::PaletteError;
+use crate::palette::{Palette, PaletteColour, PaletteEntry};
+use crate::www::Html;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// The maximum number of entries in colour tables.
+const MAXIMUM_ENTRY_COUNT: usize = 0x100;
+
+/// The size of the colour table, excluding the optional trailer.
+const TABLE_SIZE: usize = MAXIMUM_ENTRY_COUNT * 0x3;
+
+/// The size of the colour table, including the optional trailer.
+const TRAILED_TABLE_SIZE: usize = TABLE_SIZE + 0x4;
+
+/// The transparent index denoting that no entry is transparent.
+const NO_TRANSPARENT_INDEX: u16 = 0xFFFF;
+
+impl Palette {
+	/// Reads an Adobe colour table (`.act`).
+	///
+	/// Colour tables always store 256 entries, but they may be followed by a trailer specifying the number of used entries and the index of the transparent entry.
+	/// The transparent entry, if any, is given an alpha of zero.
+	/// As colour tables are unnamed, the names of the palette and its entries are empty.
+	///
+	/// # Errors
+	///
+	/// If the table is neither 768 nor 772 bytes long, or if the trailer is invalid, an error is returned.
+	pub fn from_act(data: &[u8]) -> Result<Self, PaletteError> {
+		let (count, transparent_index) = match data.len() {
+			TABLE_SIZE => (MAXIMUM_ENTRY_COUNT, None),
+
+			TRAILED_TABLE_SIZE => {
+				let count             = usize::from(u16::from_be_bytes([data[TABLE_SIZE], data[TABLE_SIZE + 0x1]]));
+				let transparent_index = u16::from_be_bytes([data[TABLE_SIZE + 0x2], data[TABLE_SIZE + 0x3]]);
+
+				if count > MAXIMUM_ENTRY_COUNT {
+					return Err(PaletteError::InvalidData { offset: TABLE_SIZE });
+				}
+
+				let transparent_index = (transparent_index != NO_TRANSPARENT_INDEX).then_some(usize::from(transparent_index));
+
+				(count, transparent_index)
+			}
+
+			size => return Err(PaletteError::InvalidData { offset: size.min(TABLE_SIZE) }),
+		};
+
+		let mut palette = Self::default();
+
+		for (index, channels) in data[..count * 0x3].chunks_exact(0x3).enumerate() {
+			let alpha = if transparent_index == Some(index) { 0x00 } else { 0xFF };
+
+			let colour = Html::new(channels[0x0], channels[0x1], channels[0x2], alpha);
+
+			palette.push(PaletteEntry::new(String::new(), PaletteColour::Html(colour)));
+		}
+
+		Ok(palette)
+	}
+
+	/// Writes the palette as an Adobe colour table.
+	///
+	/// The trailer is always written, and unused entries are filled with black.
+	/// An entry with an alpha of zero is written as the transparent entry.
+	/// The names of the palette and its entries are not written.
+	///
+	/// # Errors
+	///
+	/// If any entry is not an RGB, HSB, or grey colour, if any entry is translucent without being fully transparent, if multiple entries are transparent, or if there are more than 256 entries, an error is returned.
+	pub fn to_act(&self) -> Result<Vec<u8>, PaletteError> {
+		self.check_count(MAXIMUM_ENTRY_COUNT)?;
+
+		let entries = self.html_entries(true)?;
+
+		let mut data = Vec::with_capacity(TRAILED_TABLE_SIZE);
+
+		let mut transparent_index = None;
+
+		for (index, (_, colour)) in entries.iter().enumerate() {
+			let (red, green, blue, alpha) = colour.get();
+
+			match alpha {
+				0xFF => { }
+
+				0x00 if transparent_index.is_none() => transparent_index = Some(index),
+
+				_ => return Err(PaletteError::UnsupportedColour { index }),
+			}
+
+			data.extend_from_slice(&[red, green, blue]);
+		}
+
+		data.resize(TABLE_SIZE, 0x00);
+
+		// Both values fit in `u16` as there are at most 256 entries.
+		let count             = entries.len() as u16;
+		let transparent_index = transparent_index.map_or(NO_TRANSPARENT_INDEX, |index| index as u16);
+
+		data.extend_from_slice(&count.to_be_bytes());
+		data.extend_from_slice(&transparent_index.to_be_bytes());
+
+		Ok(data)
+	}
+}
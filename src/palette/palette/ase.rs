@@ -0,0 +1,230 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::error::PaletteError;
+use crate::lab::CieLab;
+use crate::palette::{Palette, PaletteColour, PaletteEntry};
+use crate::palette::palette::{push_utf16, Reader};
+use crate::rgb::SRgb;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// The signature of swatch exchange files.
+const SIGNATURE: [u8; 0x4] = *b"ASEF";
+
+/// The supported version of swatch exchange files.
+const VERSION: [u16; 0x2] = [0x1, 0x0];
+
+/// The type of colour blocks.
+const COLOUR_BLOCK: u16 = 0x0001;
+
+/// The type of blocks starting groups.
+const GROUP_START_BLOCK: u16 = 0xC001;
+
+/// The type of blocks ending groups.
+const GROUP_END_BLOCK: u16 = 0xC002;
+
+/// The colour type of normal (i.e. neither global nor spot) colours.
+const NORMAL_COLOUR_TYPE: u16 = 0x2;
+
+impl Palette {
+	/// Reads an Adobe swatch exchange file (`.ase`).
+	///
+	/// RGB, CMYK, LAB, and grey colours are supported.
+	/// Groups are flattened, with the name of the first group becoming the name of the palette.
+	///
+	/// # Errors
+	///
+	/// If the file is malformed or uses an unknown colour model, an error is returned.
+	pub fn from_ase(data: &[u8]) -> Result<Self, PaletteError> {
+		let mut reader = Reader::new(data);
+
+		if reader.array()? != SIGNATURE {
+			return Err(PaletteError::InvalidData { offset: 0x0 });
+		}
+
+		let version_offset = reader.offset;
+
+		if [reader.u16()?, reader.u16()?] != VERSION {
+			return Err(PaletteError::InvalidData { offset: version_offset });
+		}
+
+		let block_count = reader.u32()?;
+
+		let mut palette = Self::default();
+
+		let mut has_group = false;
+
+		for _ in 0x0..block_count {
+			let kind   = reader.u16()?;
+			let length = reader.u32()? as usize;
+
+			let start = reader.offset;
+
+			match kind {
+				COLOUR_BLOCK => {
+					let name = read_name(&mut reader)?;
+
+					let model_offset = reader.offset;
+
+					let colour = match &reader.array()? {
+						b"RGB " => PaletteColour::SRgb(SRgb::new(reader.f32()?, reader.f32()?, reader.f32()?)),
+
+						b"CMYK" => PaletteColour::Cmyk([reader.f32()?, reader.f32()?, reader.f32()?, reader.f32()?]),
+
+						b"LAB " => PaletteColour::CieLab(CieLab::new(reader.f32()? * 100.0, reader.f32()?, reader.f32()?)),
+
+						b"Gray" => PaletteColour::Grey(reader.f32()?),
+
+						_ => return Err(PaletteError::InvalidData { offset: model_offset }),
+					};
+
+					let _colour_type = reader.u16()?;
+
+					palette.push(PaletteEntry::new(name, colour));
+				}
+
+				GROUP_START_BLOCK => {
+					let name = read_name(&mut reader)?;
+
+					if !has_group {
+						palette.name = name;
+						has_group    = true;
+					}
+				}
+
+				GROUP_END_BLOCK => { }
+
+				_ => return Err(PaletteError::InvalidData { offset: start - 0x6 }),
+			}
+
+			// Skip any remaining data of the block.
+			let remaining = start
+				.checked_add(length)
+				.and_then(|end| end.checked_sub(reader.offset))
+				.ok_or_else(|| reader.error())?;
+			let _ = reader.bytes(remaining)?;
+		}
+
+		if !reader.is_empty() {
+			return Err(reader.error());
+		}
+
+		Ok(palette)
+	}
+
+	/// Writes the palette as an Adobe swatch exchange file.
+	///
+	/// HSB colours are written as RGB, as the format does not support them.
+	/// If the palette is named, its entries are wrapped in a group of the same name.
+	/// Names longer than 65534 UTF-16 code units are truncated.
+	///
+	/// # Errors
+	///
+	/// If any entry is a translucent [`Html`](PaletteColour::Html) colour, an error is returned.
+	pub fn to_ase(&self) -> Result<Vec<u8>, PaletteError> {
+		let mut blocks = Vec::new();
+
+		let mut block_count = 0x0u32;
+
+		let mut push_block = |kind: u16, block: &[u8]| {
+			// Block lengths are limited by the entries' names.
+			blocks.extend_from_slice(&kind.to_be_bytes());
+			blocks.extend_from_slice(&(block.len() as u32).to_be_bytes());
+			blocks.extend_from_slice(block);
+
+			block_count += 0x1;
+		};
+
+		if !self.name.is_empty() {
+			push_block(GROUP_START_BLOCK, &name_block(&self.name));
+		}
+
+		for (index, entry) in self.entries.iter().enumerate() {
+			let (model, channels, channel_count) = match entry.colour() {
+				PaletteColour::Html(colour) => {
+					let (colour, alpha) = colour.to_s_rgba().detach();
+
+					if alpha != 0xFF {
+						return Err(PaletteError::UnsupportedColour { index });
+					}
+
+					let (red, green, blue) = SRgb::<f32>::from_u8(colour).get();
+					(b"RGB ", [red, green, blue, 0.0], 0x3)
+				}
+
+				PaletteColour::SRgb(colour) => {
+					let (red, green, blue) = colour.get();
+					(b"RGB ", [red, green, blue, 0.0], 0x3)
+				}
+
+				PaletteColour::Hsv(colour) => {
+					let (red, green, blue) = colour.to_s_rgb().get();
+					(b"RGB ", [red, green, blue, 0.0], 0x3)
+				}
+
+				PaletteColour::Cmyk([cyan, magenta, yellow, black]) => (b"CMYK", [cyan, magenta, yellow, black], 0x4),
+
+				PaletteColour::CieLab(colour) => {
+					let (luminance, a_star, b_star) = colour.get();
+					(b"LAB ", [luminance / 100.0, a_star, b_star, 0.0], 0x3)
+				}
+
+				PaletteColour::Grey(level) => (b"Gray", [level, 0.0, 0.0, 0.0], 0x1),
+			};
+
+			let mut block = name_block(entry.name());
+
+			block.extend_from_slice(model);
+
+			for channel in &channels[..channel_count] {
+				block.extend_from_slice(&channel.to_be_bytes());
+			}
+
+			block.extend_from_slice(&NORMAL_COLOUR_TYPE.to_be_bytes());
+
+			push_block(COLOUR_BLOCK, &block);
+		}
+
+		if !self.name.is_empty() {
+			push_block(GROUP_END_BLOCK, &[]);
+		}
+
+		let mut data = Vec::with_capacity(0xC + blocks.len());
+
+		data.extend_from_slice(&SIGNATURE);
+		data.extend_from_slice(&VERSION[0x0].to_be_bytes());
+		data.extend_from_slice(&VERSION[0x1].to_be_bytes());
+		data.extend_from_slice(&block_count.to_be_bytes());
+		data.extend_from_slice(&blocks);
+
+		Ok(data)
+	}
+}
+
+/// Reads a name prefixed by its length in UTF-16 code units (including the terminating null character).
+fn read_name(reader: &mut Reader) -> Result<String, PaletteError> {
+	let count = usize::from(reader.u16()?);
+	reader.utf16(count)
+}
+
+/// Constructs the name field of a block.
+#[must_use]
+fn name_block(name: &str) -> Vec<u8> {
+	// Leave room for the terminating null character.
+	let units: Vec<u16> = name.encode_utf16().take(usize::from(u16::MAX) - 0x1).collect();
+
+	let mut block = Vec::with_capacity(0x4 + units.len() * 0x2);
+
+	// This cannot overflow as the name has been truncated.
+	block.extend_from_slice(&(units.len() as u16 + 0x1).to_be_bytes());
+	push_utf16(&mut block, &units);
+
+	block
+}
@@ -0,0 +1,118 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::error::PaletteError;
+use crate::palette::{Palette, PaletteColour, PaletteEntry};
+use crate::palette::palette::parse_channel;
+use crate::www::Html;
+
+use alloc::string::String;
+use core::fmt::Write;
+
+impl Palette {
+	/// Reads a GIMP palette (`.gpl`).
+	///
+	/// Each entry is given by its decimal channels followed by an optional name.
+	/// The `Channels: RGBA` header of GIMP 3 is supported, in which case each entry also has an alpha channel.
+	///
+	/// # Errors
+	///
+	/// If the palette is malformed, an error is returned.
+	pub fn from_gpl(s: &str) -> Result<Self, PaletteError> {
+		let mut lines = s.lines().enumerate().map(|(index, line)| (index + 0x1, line.trim()));
+
+		if lines.next().is_none_or(|(_, line)| line != "GIMP Palette") {
+			return Err(PaletteError::InvalidSyntax { line: 0x1 });
+		}
+
+		let mut palette = Self::default();
+
+		let mut channel_count = 0x3;
+
+		for (line, s) in lines {
+			if s.is_empty() || s.starts_with('#') {
+				continue;
+			}
+
+			if let Some(name) = s.strip_prefix("Name:") {
+				palette.name = String::from(name.trim());
+				continue;
+			}
+
+			if s.starts_with("Columns:") {
+				continue;
+			}
+
+			if let Some(channels) = s.strip_prefix("Channels:") {
+				channel_count = match channels.trim() {
+					"RGB"  => 0x3,
+					"RGBA" => 0x4,
+
+					_ => return Err(PaletteError::InvalidSyntax { line }),
+				};
+
+				continue;
+			}
+
+			let mut channels = [0xFF; 0x4];
+			let mut rest     = s;
+
+			for channel in channels.iter_mut().take(channel_count) {
+				let end = rest.find(|c: char| c.is_ascii_whitespace()).unwrap_or(rest.len());
+
+				*channel = parse_channel(&rest[..end]).ok_or(PaletteError::InvalidColour { line })?;
+
+				rest = rest[end..].trim_start();
+			}
+
+			let [red, green, blue, alpha] = channels;
+
+			palette.push(PaletteEntry::new(String::from(rest), PaletteColour::Html(Html::new(red, green, blue, alpha))));
+		}
+
+		Ok(palette)
+	}
+
+	/// Writes the palette as a GIMP palette.
+	///
+	/// If any entry is translucent, the `Channels: RGBA` header of GIMP 3 is written; note that this is not supported by earlier versions of GIMP.
+	///
+	/// # Errors
+	///
+	/// If any entry is not an RGB, HSB, or grey colour, an error is returned.
+	pub fn to_gpl(&self) -> Result<String, PaletteError> {
+		let entries = self.html_entries(true)?;
+
+		let has_alpha = entries.iter().any(|&(_, colour)| colour.get().3 != 0xFF);
+
+		let mut s = String::from("GIMP Palette\n");
+
+		// Writing to strings cannot fail.
+		let _ = writeln!(s, "Name: {}", self.name);
+
+		if has_alpha {
+			s.push_str("Channels: RGBA\n");
+		}
+
+		s.push_str("#\n");
+
+		for (name, colour) in entries {
+			let (red, green, blue, alpha) = colour.get();
+
+			let _ = write!(s, "{red:3} {green:3} {blue:3}");
+
+			if has_alpha {
+				let _ = write!(s, " {alpha:3}");
+			}
+
+			let _ = writeln!(s, "\t{name}");
+		}
+
+		Ok(s)
+	}
+}
@@ -0,0 +1,223 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+mod aco;
+mod act;
+mod ase;
+mod gpl;
+mod paint_net;
+mod pal;
+
+use crate::error::PaletteError;
+use crate::palette::PaletteEntry;
+use crate::www::Html;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// A palette of named colours.
+///
+/// Palettes can be read from and written to a number of palette file formats (see the [module documentation](crate::palette)).
+/// Readers keep the colours in the model specified by the file, whereas writers require each entry to be representable by the format; otherwise, [`UnsupportedColour`](PaletteError::UnsupportedColour) is returned.
+///
+/// # Examples
+///
+/// ```
+/// use polywave::palette::{Palette, PaletteColour, PaletteEntry};
+/// use polywave::www::Html;
+///
+/// let mut palette = Palette::new("Primaries".into());
+///
+/// palette.push(PaletteEntry::new("Red".into(),   PaletteColour::Html(Html::RED)));
+/// palette.push(PaletteEntry::new("Green".into(), PaletteColour::Html(Html::LIME)));
+/// palette.push(PaletteEntry::new("Blue".into(),  PaletteColour::Html(Html::BLUE)));
+///
+/// let gpl = palette.to_gpl().unwrap();
+///
+/// assert_eq!(gpl, "GIMP Palette\nName: Primaries\n#\n255   0   0\tRed\n  0 255   0\tGreen\n  0   0 255\tBlue\n");
+/// assert_eq!(Palette::from_gpl(&gpl), Ok(palette));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Palette {
+	name:    String,
+	entries: Vec<PaletteEntry>,
+}
+
+impl Palette {
+	/// Constructs a new, empty palette.
+	#[inline(always)]
+	#[must_use]
+	pub const fn new(name: String) -> Self {
+		Self { name, entries: Vec::new() }
+	}
+
+	/// Retrieves the name of the palette.
+	///
+	/// This is empty if the palette was read from a format without names.
+	#[inline(always)]
+	#[must_use]
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// Retrieves the entries of the palette.
+	#[inline(always)]
+	#[must_use]
+	pub fn entries(&self) -> &[PaletteEntry] {
+		&self.entries
+	}
+
+	/// Mutably borrows the entries of the palette.
+	#[inline(always)]
+	#[must_use]
+	pub fn entries_mut(&mut self) -> &mut [PaletteEntry] {
+		&mut self.entries
+	}
+
+	/// Sets the name of the palette.
+	#[inline(always)]
+	pub fn set_name(&mut self, name: String) {
+		self.name = name;
+	}
+
+	/// Appends an entry to the palette.
+	#[inline(always)]
+	pub fn push(&mut self, entry: PaletteEntry) {
+		self.entries.push(entry);
+	}
+
+	/// Converts the entries to eight-bit sRGB colours.
+	///
+	/// Translucent colours are only allowed if `allow_alpha` is `true`.
+	fn html_entries(&self, allow_alpha: bool) -> Result<Vec<(&str, Html)>, PaletteError> {
+		self.entries
+			.iter()
+			.enumerate()
+			.map(|(index, entry)| {
+				entry
+					.colour()
+					.as_html()
+					.filter(|colour| allow_alpha || colour.get().3 == 0xFF)
+					.map(|colour| (entry.name(), colour))
+					.ok_or(PaletteError::UnsupportedColour { index })
+			})
+			.collect()
+	}
+
+	/// Checks that the palette has at most the given number of entries.
+	fn check_count(&self, maximum: usize) -> Result<(), PaletteError> {
+		let count = self.entries.len();
+
+		if count > maximum {
+			return Err(PaletteError::TooManyEntries { count, maximum });
+		}
+
+		Ok(())
+	}
+}
+
+/// A reader of big-endian binary data.
+struct Reader<'a> {
+	data:   &'a [u8],
+	offset: usize,
+}
+
+impl<'a> Reader<'a> {
+	/// Constructs a new reader.
+	#[inline(always)]
+	#[must_use]
+	const fn new(data: &'a [u8]) -> Self {
+		Self { data, offset: 0x0 }
+	}
+
+	/// Constructs an error at the current offset.
+	#[inline(always)]
+	#[must_use]
+	const fn error(&self) -> PaletteError {
+		PaletteError::InvalidData { offset: self.offset }
+	}
+
+	/// Checks whether all data has been read.
+	#[inline(always)]
+	#[must_use]
+	const fn is_empty(&self) -> bool {
+		self.offset >= self.data.len()
+	}
+
+	/// Reads the given number of bytes.
+	fn bytes(&mut self, count: usize) -> Result<&'a [u8], PaletteError> {
+		let bytes = self.data
+			.get(self.offset..)
+			.and_then(|data| data.get(..count))
+			.ok_or_else(|| self.error())?;
+
+		self.offset += count;
+		Ok(bytes)
+	}
+
+	/// Reads a fixed number of bytes.
+	fn array<const N: usize>(&mut self) -> Result<[u8; N], PaletteError> {
+		let mut array = [0x0; N];
+		array.copy_from_slice(self.bytes(N)?);
+
+		Ok(array)
+	}
+
+	/// Reads a big-endian `u16`.
+	fn u16(&mut self) -> Result<u16, PaletteError> {
+		self.array().map(u16::from_be_bytes)
+	}
+
+	/// Reads a big-endian `u32`.
+	fn u32(&mut self) -> Result<u32, PaletteError> {
+		self.array().map(u32::from_be_bytes)
+	}
+
+	/// Reads a big-endian `f32`.
+	fn f32(&mut self) -> Result<f32, PaletteError> {
+		self.array().map(f32::from_be_bytes)
+	}
+
+	/// Reads a string of the given number of big-endian UTF-16 code units.
+	///
+	/// Any terminating null character is removed.
+	fn utf16(&mut self, count: usize) -> Result<String, PaletteError> {
+		let offset = self.offset;
+
+		let units = self.bytes(count.checked_mul(0x2).ok_or_else(|| self.error())?)?
+			.chunks_exact(0x2)
+			.map(|unit| u16::from_be_bytes([unit[0x0], unit[0x1]]));
+
+		let mut s: String = char::decode_utf16(units)
+			.collect::<Result<_, _>>()
+			.map_err(|_| PaletteError::InvalidData { offset })?;
+
+		if s.ends_with('\0') {
+			let _ = s.pop();
+		}
+
+		Ok(s)
+	}
+}
+
+/// Appends big-endian UTF-16 code units, followed by a null character.
+fn push_utf16(data: &mut Vec<u8>, units: &[u16]) {
+	for &unit in units.iter().chain(&[0x0]) {
+		data.extend_from_slice(&unit.to_be_bytes());
+	}
+}
+
+/// Parses an eight-bit decimal channel.
+#[must_use]
+fn parse_channel(s: &str) -> Option<u8> {
+	if !s.bytes().all(|c| c.is_ascii_digit()) {
+		return None;
+	}
+
+	s.parse().ok()
+}
@@ -0,0 +1,73 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::error::PaletteError;
+use crate::palette::{Palette, PaletteColour, PaletteEntry};
+use crate::www::Html;
+
+use alloc::string::String;
+use core::fmt::Write;
+
+/// The maximum number of entries in Paint.NET palettes.
+const MAXIMUM_ENTRY_COUNT: usize = 0x60;
+
+impl Palette {
+	/// Reads a Paint.NET palette (`.txt`).
+	///
+	/// Each entry is given as eight hexadecimal digits in the order `AARRGGBB`, and lines starting with a semicolon are comments.
+	/// As Paint.NET palettes are unnamed, the names of the palette and its entries are empty.
+	///
+	/// # Errors
+	///
+	/// If the palette is malformed, an error is returned.
+	pub fn from_paint_net(s: &str) -> Result<Self, PaletteError> {
+		let mut palette = Self::default();
+
+		for (index, s) in s.lines().enumerate() {
+			let s = s.trim();
+
+			if s.is_empty() || s.starts_with(';') {
+				continue;
+			}
+
+			if s.len() != 0x8 || !s.bytes().all(|c| c.is_ascii_hexdigit()) {
+				return Err(PaletteError::InvalidColour { line: index + 0x1 });
+			}
+
+			let value = u32::from_str_radix(s, 0x10).map_err(|_| PaletteError::InvalidColour { line: index + 0x1 })?;
+
+			let colour = Html::from_u32(value.rotate_left(0x8));
+
+			palette.push(PaletteEntry::new(String::new(), PaletteColour::Html(colour)));
+		}
+
+		Ok(palette)
+	}
+
+	/// Writes the palette as a Paint.NET palette.
+	///
+	/// The names of the palette and its entries are not written.
+	///
+	/// # Errors
+	///
+	/// If any entry is not an RGB, HSB, or grey colour, or if there are more than 96 entries, an error is returned.
+	pub fn to_paint_net(&self) -> Result<String, PaletteError> {
+		self.check_count(MAXIMUM_ENTRY_COUNT)?;
+
+		let entries = self.html_entries(true)?;
+
+		let mut s = String::from("; paint.net Palette File\n; Lines that start with a semicolon are comments\n; Colors are written as 8-digit hexadecimal numbers: aarrggbb\n");
+
+		for (_, colour) in entries {
+			// Writing to strings cannot fail.
+			let _ = writeln!(s, "{:08X}", colour.to_u32().rotate_right(0x8));
+		}
+
+		Ok(s)
+	}
+}
@@ -0,0 +1,86 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::error::PaletteError;
+use crate::palette::{Palette, PaletteColour, PaletteEntry};
+use crate::palette::palette::parse_channel;
+use crate::www::Html;
+
+use alloc::string::String;
+use core::fmt::Write;
+
+impl Palette {
+	/// Reads a JASC (PaintShop Pro) palette (`.pal`).
+	///
+	/// Note that this is not the RIFF palette format that shares the same extension.
+	/// As JASC palettes are unnamed, the names of the palette and its entries are empty.
+	///
+	/// # Errors
+	///
+	/// If the palette is malformed, or if it has fewer entries than declared, an error is returned.
+	pub fn from_pal(s: &str) -> Result<Self, PaletteError> {
+		let mut lines = s.lines().enumerate().map(|(index, line)| (index + 0x1, line.trim()));
+
+		let mut header = |expected: Option<&str>| {
+			let (line, s) = lines.next().ok_or(PaletteError::InvalidSyntax { line: 0x1 })?;
+
+			if expected.is_some_and(|expected| s != expected) {
+				return Err(PaletteError::InvalidSyntax { line });
+			}
+
+			Ok((line, s))
+		};
+
+		let _ = header(Some("JASC-PAL"))?;
+		let _ = header(Some("0100"))?;
+
+		let (line, count) = header(None)?;
+		let count = count.parse::<usize>().map_err(|_| PaletteError::InvalidSyntax { line })?;
+
+		let mut palette = Self::default();
+
+		for _ in 0x0..count {
+			let (line, s) = lines.next().ok_or(PaletteError::InvalidSyntax { line: line + palette.entries.len() + 0x1 })?;
+
+			let mut channels = s.split_ascii_whitespace().map(parse_channel);
+
+			let mut channel = || channels.next().flatten().ok_or(PaletteError::InvalidColour { line });
+
+			let colour = Html::new(channel()?, channel()?, channel()?, 0xFF);
+
+			palette.push(PaletteEntry::new(String::new(), PaletteColour::Html(colour)));
+		}
+
+		Ok(palette)
+	}
+
+	/// Writes the palette as a JASC palette.
+	///
+	/// The lines are terminated by CRLF, as is done by PaintShop Pro.
+	/// The names of the palette and its entries are not written.
+	///
+	/// # Errors
+	///
+	/// If any entry is not an opaque RGB, HSB, or grey colour, an error is returned.
+	pub fn to_pal(&self) -> Result<String, PaletteError> {
+		let entries = self.html_entries(false)?;
+
+		let mut s = String::from("JASC-PAL\r\n0100\r\n");
+
+		// Writing to strings cannot fail.
+		let _ = write!(s, "{}\r\n", entries.len());
+
+		for (_, colour) in entries {
+			let (red, green, blue, _) = colour.get();
+
+			let _ = write!(s, "{red} {green} {blue}\r\n");
+		}
+
+		Ok(s)
+	}
+}
@@ -0,0 +1,124 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::hsv::Hsv;
+use crate::lab::CieLab;
+use crate::rgb::SRgb;
+use crate::www::Html;
+#[cfg(feature = "maths")]
+use crate::xyz::CieXyz;
+
+/// The colour of a palette entry.
+///
+/// Each variant corresponds to a colour model used by palette files, and entries are kept in the model specified by the file.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PaletteColour {
+	/// An sRGB colour with eight-bit channels and alpha.
+	///
+	/// This is used by the formats that store eight-bit channels (GIMP, ACT, JASC, and Paint.NET).
+	Html(Html),
+
+	/// An sRGB colour with floating-point channels.
+	///
+	/// This is used by the formats that store RGB with more than eight bits (ASE and ACO).
+	SRgb(SRgb<f32>),
+
+	/// An HSB colour, with the hue in degrees.
+	Hsv(Hsv<f32>),
+
+	/// A CMYK colour, given as the ink coverages of cyan, magenta, yellow, and black in the range `0` to `1`.
+	Cmyk([f32; 0x4]),
+
+	/// A CIELAB colour, relative to the [D50](crate::xyz::CieXyz::D50) white point.
+	CieLab(CieLab<f32>),
+
+	/// A grey, given in the range `0` (black) to `1` (white).
+	Grey(f32),
+}
+
+impl PaletteColour {
+	/// Converts the colour to an eight-bit sRGB colour, if it is stored as RGB, HSB, or grey.
+	///
+	/// [`None`] is returned for CMYK and CIELAB colours, as these are not exactly representable.
+	/// See [`to_s_rgb`](Self::to_s_rgb) for converting colours in any model.
+	#[must_use]
+	pub fn as_html(self) -> Option<Html> {
+		match self {
+			Self::Html(colour) => Some(colour),
+
+			Self::SRgb(colour) => Some(Html::from(colour.to_u8())),
+
+			Self::Hsv(colour) => Some(Html::from(colour.to_s_rgb().to_u8())),
+
+			Self::Grey(level) => Some(Html::from(SRgb::new(level, level, level).to_u8())),
+
+			_ => None,
+		}
+	}
+
+	/// Converts the colour to sRGB.
+	///
+	/// CMYK colours are converted naively (i.e. without an ICC profile), and CIELAB colours are adapted from D50 to D65.
+	/// The alpha channel of [`Html`](Self::Html) colours is ignored, and the returned colour is not clamped to the sRGB gamut.
+	#[cfg(feature = "maths")]
+	#[must_use]
+	pub fn to_s_rgb(self) -> SRgb<f32> {
+		match self {
+			Self::Html(colour) => {
+				let (colour, _) = colour.to_s_rgba().detach();
+				SRgb::<f32>::from_u8(colour)
+			}
+
+			Self::SRgb(colour) => colour,
+
+			Self::Hsv(colour) => colour.to_s_rgb(),
+
+			Self::Cmyk([cyan, magenta, yellow, black]) => {
+				let white = 1.0 - black;
+				SRgb::new((1.0 - cyan) * white, (1.0 - magenta) * white, (1.0 - yellow) * white)
+			}
+
+			Self::CieLab(colour) => {
+				colour
+					.to_xyz(CieXyz::<f32>::D50)
+					.adapt(CieXyz::<f32>::D50, CieXyz::<f32>::D65)
+					.to_s_rgb()
+			}
+
+			Self::Grey(level) => SRgb::new(level, level, level),
+		}
+	}
+}
+
+impl From<Html> for PaletteColour {
+	#[inline(always)]
+	fn from(value: Html) -> Self {
+		Self::Html(value)
+	}
+}
+
+impl From<SRgb<f32>> for PaletteColour {
+	#[inline(always)]
+	fn from(value: SRgb<f32>) -> Self {
+		Self::SRgb(value)
+	}
+}
+
+impl From<Hsv<f32>> for PaletteColour {
+	#[inline(always)]
+	fn from(value: Hsv<f32>) -> Self {
+		Self::Hsv(value)
+	}
+}
+
+impl From<CieLab<f32>> for PaletteColour {
+	#[inline(always)]
+	fn from(value: CieLab<f32>) -> Self {
+		Self::CieLab(value)
+	}
+}
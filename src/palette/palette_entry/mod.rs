@@ -0,0 +1,56 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::palette::PaletteColour;
+
+use alloc::string::String;
+
+/// An entry of a palette.
+///
+/// Each entry is a colour with an optional name.
+/// Formats without names (e.g. ACT) leave the name empty.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PaletteEntry {
+	name:   String,
+	colour: PaletteColour,
+}
+
+impl PaletteEntry {
+	/// Constructs a new palette entry.
+	#[inline(always)]
+	#[must_use]
+	pub fn new(name: String, colour: PaletteColour) -> Self {
+		Self { name, colour }
+	}
+
+	/// Retrieves the name of the entry.
+	#[inline(always)]
+	#[must_use]
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// Retrieves the colour of the entry.
+	#[inline(always)]
+	#[must_use]
+	pub const fn colour(&self) -> PaletteColour {
+		self.colour
+	}
+
+	/// Sets the name of the entry.
+	#[inline(always)]
+	pub fn set_name(&mut self, name: String) {
+		self.name = name;
+	}
+
+	/// Sets the colour of the entry.
+	#[inline(always)]
+	pub fn set_colour(&mut self, colour: PaletteColour) {
+		self.colour = colour;
+	}
+}
@@ -0,0 +1,206 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+#![cfg(test)]
+
+use crate::error::PaletteError;
+use crate::hsv::Hsv;
+use crate::lab::CieLab;
+use crate::palette::{Palette, PaletteColour, PaletteEntry};
+use crate::rgb::SRgb;
+use crate::www::Html;
+
+use alloc::string::String;
+use alloc::vec;
+
+/// Constructs a palette with unnamed entries.
+#[must_use]
+fn unnamed(colours: &[Html]) -> Palette {
+	let mut palette = Palette::default();
+
+	for &colour in colours {
+		palette.push(PaletteEntry::new(String::new(), PaletteColour::Html(colour)));
+	}
+
+	palette
+}
+
+#[test]
+fn test_palette_gpl() {
+	let palette = Palette::from_gpl("GIMP Palette\nName: Test\nColumns: 4\n# Comment\n\n  0 128 255 Azure\n 12  34  56\n").unwrap();
+
+	assert_eq!(palette.name(), "Test");
+	assert_eq!(palette.entries().len(), 0x2);
+
+	assert_eq!(palette.entries()[0x0].name(), "Azure");
+	assert_eq!(palette.entries()[0x0].colour(), PaletteColour::Html(Html::new(0x00, 0x80, 0xFF, 0xFF)));
+	assert_eq!(palette.entries()[0x1].name(), "");
+
+	let mut palette = unnamed(&[Html::new(0x10, 0x20, 0x30, 0x40)]);
+	palette.set_name(String::from("Alpha"));
+
+	let gpl = palette.to_gpl().unwrap();
+
+	assert!(gpl.contains("Channels: RGBA\n"));
+	assert_eq!(Palette::from_gpl(&gpl), Ok(palette));
+
+	assert_eq!(Palette::from_gpl("GIMP Pallete\n"),                Err(PaletteError::InvalidSyntax { line: 0x1 }));
+	assert_eq!(Palette::from_gpl("GIMP Palette\n0 0 256\n"),       Err(PaletteError::InvalidColour { line: 0x2 }));
+	assert_eq!(Palette::from_gpl("GIMP Palette\nChannels: CMY\n"), Err(PaletteError::InvalidSyntax { line: 0x2 }));
+
+	let mut palette = Palette::default();
+	palette.push(PaletteEntry::new(String::new(), PaletteColour::Cmyk([0.0; 0x4])));
+
+	assert_eq!(palette.to_gpl(), Err(PaletteError::UnsupportedColour { index: 0x0 }));
+}
+
+#[test]
+fn test_palette_ase() {
+	let mut palette = Palette::new(String::from("Group"));
+
+	palette.push(PaletteEntry::new(String::from("Red"),  PaletteColour::SRgb(SRgb::new(1.0, 0.0, 0.0))));
+	palette.push(PaletteEntry::new(String::from("Ink"),  PaletteColour::Cmyk([0.25, 0.5, 0.75, 1.0])));
+	palette.push(PaletteEntry::new(String::from("Lab"),  PaletteColour::CieLab(CieLab::new(50.0, -20.0, 30.0))));
+	palette.push(PaletteEntry::new(String::from("Grå"),  PaletteColour::Grey(0.5)));
+
+	let data = palette.to_ase().unwrap();
+
+	assert_eq!(&data[..0xC], b"ASEF\x00\x01\x00\x00\x00\x00\x00\x06");
+	assert_eq!(Palette::from_ase(&data), Ok(palette));
+
+	let palette = unnamed(&[Html::BLUE]);
+
+	let data = palette.to_ase().unwrap();
+
+	assert_eq!(
+		Palette::from_ase(&data).unwrap().entries()[0x0].colour(),
+		PaletteColour::SRgb(SRgb::new(0.0, 0.0, 1.0)),
+	);
+
+	assert_eq!(Palette::from_ase(&data[..data.len() - 0x1]), Err(PaletteError::InvalidData { offset: data.len() - 0x2 }));
+	assert_eq!(Palette::from_ase(b"ASEF\x00\x02\x00\x00"),    Err(PaletteError::InvalidData { offset: 0x4 }));
+
+	assert_eq!(unnamed(&[Html::TRANSPARENT]).to_ase(), Err(PaletteError::UnsupportedColour { index: 0x0 }));
+}
+
+#[test]
+fn test_palette_aco() {
+	let mut palette = Palette::default();
+
+	palette.push(PaletteEntry::new(String::from("Red"),   PaletteColour::SRgb(SRgb::new(1.0, 0.0, 0.0))));
+	palette.push(PaletteEntry::new(String::from("Hue"),   PaletteColour::Hsv(Hsv::new(180.0, 1.0, 0.0))));
+	palette.push(PaletteEntry::new(String::from("Ink"),   PaletteColour::Cmyk([0.0, 0.0, 0.0, 1.0])));
+	palette.push(PaletteEntry::new(String::from("Lab"),   PaletteColour::CieLab(CieLab::new(50.0, -20.5, 30.25))));
+	palette.push(PaletteEntry::new(String::from("Black"), PaletteColour::Grey(0.0)));
+
+	let data = palette.to_aco().unwrap();
+
+	assert_eq!(&data[..0xE], [0x00, 0x01, 0x00, 0x05, 0x00, 0x00, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+	assert_eq!(Palette::from_aco(&data), Ok(palette));
+
+	// Version 1 only.
+	let data = [0x00, 0x01, 0x00, 0x01, 0x00, 0x08, 0x13, 0x88, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+
+	let palette = Palette::from_aco(&data).unwrap();
+
+	assert_eq!(palette.entries()[0x0].colour(), PaletteColour::Grey(0.5));
+
+	// Version 2 only.
+	let data = [0x00, 0x02, 0x00, 0x01, 0x00, 0x08, 0x13, 0x88, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x41, 0x00, 0x00];
+
+	let palette = Palette::from_aco(&data).unwrap();
+
+	assert_eq!(palette.entries()[0x0].name(),   "A");
+	assert_eq!(palette.entries()[0x0].colour(), PaletteColour::Grey(0.5));
+
+	let data = [0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00];
+
+	assert_eq!(Palette::from_aco(&data), Err(PaletteError::InvalidData { offset: 0x4 }));
+	assert_eq!(Palette::from_aco(&[0x00, 0x03, 0x00, 0x00]), Err(PaletteError::InvalidData { offset: 0x0 }));
+
+	let data = [0x00, 0x01, 0x00, 0x01, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+
+	assert_eq!(Palette::from_aco(&data),      Err(PaletteError::InvalidData { offset: 0x4 }));
+	assert_eq!(Palette::from_aco(&data[..0x3]), Err(PaletteError::InvalidData { offset: 0x2 }));
+}
+
+#[test]
+fn test_palette_act() {
+	let palette = unnamed(&[Html::RED, Html::TRANSPARENT, Html::new(0x12, 0x34, 0x56, 0xFF)]);
+
+	let data = palette.to_act().unwrap();
+
+	assert_eq!(data.len(), 0x304);
+	assert_eq!(&data[..0x9], [0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x12, 0x34, 0x56]);
+	assert_eq!(&data[0x300..], [0x00, 0x03, 0x00, 0x01]);
+	assert_eq!(Palette::from_act(&data), Ok(palette));
+
+	let palette = Palette::from_act(&[0x80; 0x300]).unwrap();
+
+	assert_eq!(palette.entries().len(), 0x100);
+	assert_eq!(palette.entries()[0xFF].colour(), PaletteColour::Html(Html::new(0x80, 0x80, 0x80, 0xFF)));
+
+	assert_eq!(Palette::from_act(&[0x0; 0x10]), Err(PaletteError::InvalidData { offset: 0x10 }));
+
+	assert_eq!(
+		unnamed(&[Html::new(0x00, 0x00, 0x00, 0x80)]).to_act(),
+		Err(PaletteError::UnsupportedColour { index: 0x0 }),
+	);
+
+	assert_eq!(
+		unnamed(&vec![Html::BLACK; 0x101]).to_act(),
+		Err(PaletteError::TooManyEntries { count: 0x101, maximum: 0x100 }),
+	);
+}
+
+#[test]
+fn test_palette_pal() {
+	let palette = unnamed(&[Html::RED, Html::new(0x01, 0x02, 0x03, 0xFF)]);
+
+	let pal = palette.to_pal().unwrap();
+
+	assert_eq!(pal, "JASC-PAL\r\n0100\r\n2\r\n255 0 0\r\n1 2 3\r\n");
+	assert_eq!(Palette::from_pal(&pal), Ok(palette));
+
+	assert_eq!(Palette::from_pal("JASC-PAL\n0100\n2\n0 0 0\n"), Err(PaletteError::InvalidSyntax { line: 0x5 }));
+	assert_eq!(Palette::from_pal("JASC-PAL\n0100\n1\n0 0\n"),   Err(PaletteError::InvalidColour { line: 0x4 }));
+	assert_eq!(Palette::from_pal("JASC-PAL\n0200\n"),           Err(PaletteError::InvalidSyntax { line: 0x2 }));
+}
+
+#[test]
+fn test_palette_paint_net() {
+	let palette = unnamed(&[Html::new(0x12, 0x34, 0x56, 0x78)]);
+
+	let txt = palette.to_paint_net().unwrap();
+
+	assert!(txt.ends_with("\n78123456\n"));
+	assert_eq!(Palette::from_paint_net(&txt), Ok(palette));
+
+	assert_eq!(Palette::from_paint_net("; Comment\nFF00000\n"), Err(PaletteError::InvalidColour { line: 0x2 }));
+
+	assert_eq!(
+		unnamed(&vec![Html::BLACK; 0x61]).to_paint_net(),
+		Err(PaletteError::TooManyEntries { count: 0x61, maximum: 0x60 }),
+	);
+}
+
+#[cfg(feature = "maths")]
+#[test]
+fn test_palette_colour() {
+	assert_eq!(PaletteColour::Grey(1.0).as_html(), Some(Html::WHITE));
+	assert_eq!(PaletteColour::Hsv(Hsv::new(120.0, 1.0, 1.0)).as_html(), Some(Html::LIME));
+	assert_eq!(PaletteColour::Cmyk([0.0; 0x4]).as_html(), None);
+
+	assert_eq!(PaletteColour::Cmyk([0.0, 1.0, 1.0, 0.0]).to_s_rgb(), SRgb::new(1.0, 0.0, 0.0));
+
+	let (red, green, blue) = PaletteColour::CieLab(CieLab::new(100.0, 0.0, 0.0)).to_s_rgb().get();
+
+	for channel in [red, green, blue] {
+		assert!((channel - 1.0).abs() < 0.001, "{channel}");
+	}
+}
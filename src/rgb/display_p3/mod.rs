@@ -0,0 +1,239 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::{BalancedColour, Colour, Component, DefinedGamut, NormalisedComponent};
+#[cfg(feature = "maths")]
+use crate::maths::Maths;
+use crate::rgb::Rgb;
+
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+
+#[cfg(feature = "zerocopy")]
+use zerocopy::{FromZeros, Immutable, IntoBytes};
+
+/// A Display P3 colour.
+///
+/// Display P3 uses the DCI-P3 primaries with the [D65](crate::xyz::CieXyz::D65) white point and the sRGB transfer function.
+/// Its gamut is roughly a quarter larger than that of [sRGB](crate::rgb::SRgb).
+///
+/// This type is guaranteed to always have the exact same layout as <code>[Rgb]&lt;T&gt;</code>, although with a different representation.
+/// Namely, channel order is preserved in memory.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
+#[cfg_attr(feature = "zerocopy", derive(FromZeros, Immutable, IntoBytes))]
+pub struct DisplayP3<T>(Rgb<T>);
+
+impl<T: Component> DisplayP3<T> {
+	/// Constructs a new Display P3 colour.
+	#[inline(always)]
+	#[must_use]
+	pub const fn new(red: T, green: T, blue: T) -> Self {
+		let colour = Rgb::new(red, green, blue);
+		Self(colour)
+	}
+
+	/// Reinterprets a raw RGB colour as Display P3.
+	///
+	/// The provided colour is *scaled* to fit the Display P3 gamut.
+	#[inline(always)]
+	#[must_use]
+	pub const fn from_rgb(colour: Rgb<T>) -> Self {
+		Self(colour)
+	}
+
+	/// Maps the Display P3 colour's channels.
+	#[inline]
+	#[must_use]
+	pub fn map<U, F>(self, mut op: F) -> DisplayP3<U>
+	where
+		U: Component,
+		F: FnMut(T) -> U,
+	{
+		let (red, green, blue) = self.get();
+
+		let red   = op(red);
+		let green = op(green);
+		let blue  = op(blue);
+
+		DisplayP3::new(red, green, blue)
+	}
+
+	/// Reinterprets the Display P3 colour as a raw RGB colour.
+	#[inline(always)]
+	#[must_use]
+	pub const fn as_rgb(self) -> Rgb<T> {
+		self.0
+	}
+
+	/// Deconstructs the Display P3 colour.
+	#[inline(always)]
+	#[must_use]
+	pub const fn get(self) -> (T, T, T) {
+		self.as_rgb().get()
+	}
+}
+
+macro_rules! impl_conversions {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::rgb::DisplayP3<$tys> {
+				/// The matrix transforming linear Display P3 to CIEXYZ.
+				#[allow(clippy::excessive_precision)]
+				#[cfg(feature = "maths")]
+				const TO_XYZ: [[$tys; 0x3]; 0x3] = [
+					[0.486_570_948_648_216_3, 0.265_667_693_169_092_9, 0.198_217_285_234_362_5],
+					[0.228_974_564_069_748_8, 0.691_738_521_836_506_2, 0.079_286_914_093_745_0],
+					[0.000_000_000_000_000_0, 0.045_113_381_858_902_6, 1.043_944_368_900_975_7],
+				];
+
+				/// The matrix transforming CIEXYZ to linear Display P3.
+				#[allow(clippy::excessive_precision)]
+				#[cfg(feature = "maths")]
+				const FROM_XYZ: [[$tys; 0x3]; 0x3] = [
+					[ 2.493_496_911_941_424_5, -0.931_383_617_919_123_6, -0.402_710_784_450_716_8],
+					[-0.829_488_969_561_575_0,  1.762_664_060_318_346_8,  0.023_624_685_841_943_6],
+					[ 0.035_845_830_243_784_3, -0.076_172_389_268_041_7,  0.956_884_524_007_687_3],
+				];
+
+				/// Transfers a raw RGB value to perceptual RGB, scaling to the Display P3 gamut.
+				///
+				/// The transfer is done as by the sRGB transfer function.
+				#[allow(clippy::excessive_precision)]
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn transfer(colour: Rgb<$tys>) -> Self {
+					let colour = colour.map(|mut colour| {
+						let sign = colour;
+
+						colour = Maths::abs(colour);
+
+						colour = if colour > 0.003_130_800 {
+							Maths::powf(colour, const { 1.0 / 2.4 }) * 1.055 - 0.055
+						} else {
+							colour * 12.920
+						};
+
+						colour = Maths::copysign(colour, sign);
+
+						colour
+					});
+
+					Self::from_rgb(colour)
+				}
+
+				/// "Untransfers" the gamma-encoded Display P3.
+				///
+				/// This method serves as the inverse of [`transfer`](Self::transfer).
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn untransfer(self) -> Rgb<$tys> {
+					self.as_rgb().map(|mut colour| {
+						let sign = colour;
+
+						colour = Maths::abs(colour);
+
+						colour = if colour > 0.040_450 {
+							Maths::powf((colour + 0.055) / 1.055, 2.4)
+						} else {
+							colour / 12.920
+						};
+
+						colour = Maths::copysign(colour, sign);
+
+						colour
+					})
+				}
+
+				/// Converts a CIEXYZ colour to Display P3.
+				///
+				/// The colour is expected to be relative to the [D65](crate::xyz::CieXyz::D65) white point.
+				/// Note that the returned colour is not clamped to the Display P3 gamut.
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn from_xyz(colour: ::polywave::xyz::CieXyz<$tys>) -> Self {
+					let (x, y, z) = colour.get();
+
+					let [red, green, blue] = Self::FROM_XYZ.map(|[m0, m1, m2]| m0 * x + m1 * y + m2 * z);
+
+					Self::transfer(Rgb::new(red, green, blue))
+				}
+
+				/// Converts the Display P3 colour to CIEXYZ.
+				///
+				/// The returned colour is relative to the [D65](crate::xyz::CieXyz::D65) white point.
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn to_xyz(self) -> ::polywave::xyz::CieXyz<$tys> {
+					let (red, green, blue) = self.untransfer().get();
+
+					let [x, y, z] = Self::TO_XYZ.map(|[m0, m1, m2]| m0 * red + m1 * green + m2 * blue);
+
+					::polywave::xyz::CieXyz::new(x, y, z)
+				}
+			}
+
+			#[cfg(feature = "maths")]
+			impl ::polywave::RgbGamut for ::polywave::rgb::DisplayP3<$tys> { }
+		)*
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_conversions!(f16);
+
+impl_conversions!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_conversions!(f128);
+
+unsafe impl<T: Component> BalancedColour for DisplayP3<T> {
+	type Component = T;
+}
+
+impl<T: Component> Colour for DisplayP3<T> { }
+
+impl<T: NormalisedComponent> DefinedGamut for DisplayP3<T> {
+	type Scalar = T;
+
+	#[inline]
+	fn is_in_gamut(&self, epsilon: Self::Scalar) -> bool {
+		let (red, green, blue) = self.get();
+
+		red.is_normalised(epsilon)
+			&& green.is_normalised(epsilon)
+			&& blue.is_normalised(epsilon)
+	}
+
+	#[inline]
+	fn clip(self) -> Self {
+		self.map(NormalisedComponent::clamp_normalised)
+	}
+
+	#[inline]
+	fn gamut_distance(&self) -> Self::Scalar {
+		let (red, green, blue) = self.map(NormalisedComponent::normalised_distance).get();
+
+		let mut distance = red;
+
+		if green > distance {
+			distance = green;
+		}
+
+		if blue > distance {
+			distance = blue;
+		}
+
+		distance
+	}
+}
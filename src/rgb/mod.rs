@@ -8,10 +8,17 @@
 
 //! RGB-based colour facilities.
 
+mod display_p3;
 mod op_rgb;
+mod pro_photo_rgb;
+mod rec2020;
 mod rgb;
 mod s_rgb;
+mod test;
 
+pub use display_p3::DisplayP3;
 pub use op_rgb::OpRgb;
+pub use pro_photo_rgb::ProPhotoRgb;
+pub use rec2020::Rec2020;
 pub use rgb::Rgb;
 pub use s_rgb::SRgb;
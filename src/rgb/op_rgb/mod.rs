@@ -6,15 +6,14 @@
 // can obtain one at:
 // <https://mozilla.org/MPL/2.0/>.
 
-use crate::{BalancedColour, Colour, Component, DefinedGamut};
+use crate::{BalancedColour, Colour, Component, DefinedGamut, NormalisedComponent};
+#[cfg(feature = "maths")]
+use crate::maths::Maths;
 use crate::rgb::Rgb;
 
 #[cfg(feature = "bytemuck")]
 use bytemuck::{Pod, Zeroable};
 
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
-
 #[cfg(feature = "zerocopy")]
 use zerocopy::{FromZeros, Immutable, IntoBytes};
 
@@ -25,7 +24,6 @@ use zerocopy::{FromZeros, Immutable, IntoBytes};
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
-#[cfg_attr(feature = "serde",    derive(Deserialize, Serialize))]
 #[cfg_attr(feature = "zerocopy", derive(FromZeros, Immutable, IntoBytes))]
 pub struct OpRgb<T>(Rgb<T>);
 
@@ -79,10 +77,151 @@ impl<T: Component> OpRgb<T> {
 	}
 }
 
+macro_rules! impl_conversions {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::rgb::OpRgb<$tys> {
+				/// The matrix transforming linear opRGB to CIEXYZ.
+				#[allow(clippy::excessive_precision)]
+				#[cfg(feature = "maths")]
+				const TO_XYZ: [[$tys; 0x3]; 0x3] = [
+					[0.576_669_042_910_130_8, 0.185_558_237_906_546_3, 0.188_228_646_234_994_7],
+					[0.297_344_975_250_536_2, 0.627_363_566_255_466_0, 0.075_291_458_493_997_9],
+					[0.027_031_361_386_412_4, 0.070_688_852_535_827_1, 0.991_337_536_837_638_9],
+				];
+
+				/// The matrix transforming CIEXYZ to linear opRGB.
+				#[allow(clippy::excessive_precision)]
+				#[cfg(feature = "maths")]
+				const FROM_XYZ: [[$tys; 0x3]; 0x3] = [
+					[ 2.041_587_903_810_746_1, -0.565_006_974_278_859_6, -0.344_731_350_778_329_5],
+					[-0.969_243_636_280_879_8,  1.875_967_501_507_720_6,  0.041_555_057_407_175_6],
+					[ 0.013_444_280_632_031_0, -0.118_362_392_231_018_2,  1.015_174_994_391_205_4],
+				];
+
+				/// Transfers a raw RGB value to perceptual RGB, scaling to the opRGB gamut.
+				///
+				/// The transfer is done as by the opRGB transfer function, i.e. a pure gamma of 563/256.
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn transfer(colour: Rgb<$tys>) -> Self {
+					let colour = colour.map(|mut colour| {
+						let sign = colour;
+
+						colour = Maths::abs(colour);
+
+						colour = Maths::powf(colour, const { 256.0 / 563.0 });
+
+						colour = Maths::copysign(colour, sign);
+
+						colour
+					});
+
+					Self::from_rgb(colour)
+				}
+
+				/// "Untransfers" the gamma-encoded opRGB.
+				///
+				/// This method serves as the inverse of [`transfer`](Self::transfer).
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn untransfer(self) -> Rgb<$tys> {
+					self.as_rgb().map(|mut colour| {
+						let sign = colour;
+
+						colour = Maths::abs(colour);
+
+						colour = Maths::powf(colour, const { 563.0 / 256.0 });
+
+						colour = Maths::copysign(colour, sign);
+
+						colour
+					})
+				}
+
+				/// Converts a CIEXYZ colour to opRGB.
+				///
+				/// The colour is expected to be relative to the [D65](crate::xyz::CieXyz::D65) white point.
+				/// Note that the returned colour is not clamped to the opRGB gamut.
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn from_xyz(colour: ::polywave::xyz::CieXyz<$tys>) -> Self {
+					let (x, y, z) = colour.get();
+
+					let [red, green, blue] = Self::FROM_XYZ.map(|[m0, m1, m2]| m0 * x + m1 * y + m2 * z);
+
+					Self::transfer(Rgb::new(red, green, blue))
+				}
+
+				/// Converts the opRGB colour to CIEXYZ.
+				///
+				/// The returned colour is relative to the [D65](crate::xyz::CieXyz::D65) white point.
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn to_xyz(self) -> ::polywave::xyz::CieXyz<$tys> {
+					let (red, green, blue) = self.untransfer().get();
+
+					let [x, y, z] = Self::TO_XYZ.map(|[m0, m1, m2]| m0 * red + m1 * green + m2 * blue);
+
+					::polywave::xyz::CieXyz::new(x, y, z)
+				}
+			}
+
+			#[cfg(feature = "maths")]
+			impl ::polywave::RgbGamut for ::polywave::rgb::OpRgb<$tys> { }
+		)*
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_conversions!(f16);
+
+impl_conversions!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_conversions!(f128);
+
 unsafe impl<T: Component> BalancedColour for OpRgb<T> {
 	type Component = T;
 }
 
 impl<T: Component> Colour for OpRgb<T> { }
 
-impl<T: Component> DefinedGamut for OpRgb<T> { }
+impl<T: NormalisedComponent> DefinedGamut for OpRgb<T> {
+	type Scalar = T;
+
+	#[inline]
+	fn is_in_gamut(&self, epsilon: Self::Scalar) -> bool {
+		let (red, green, blue) = self.get();
+
+		red.is_normalised(epsilon)
+			&& green.is_normalised(epsilon)
+			&& blue.is_normalised(epsilon)
+	}
+
+	#[inline]
+	fn clip(self) -> Self {
+		self.map(NormalisedComponent::clamp_normalised)
+	}
+
+	#[inline]
+	fn gamut_distance(&self) -> Self::Scalar {
+		let (red, green, blue) = self.map(NormalisedComponent::normalised_distance).get();
+
+		let mut distance = red;
+
+		if green > distance {
+			distance = green;
+		}
+
+		if blue > distance {
+			distance = blue;
+		}
+
+		distance
+	}
+}
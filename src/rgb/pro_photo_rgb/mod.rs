@@ -0,0 +1,237 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::{BalancedColour, Colour, Component, DefinedGamut, NormalisedComponent};
+#[cfg(feature = "maths")]
+use crate::maths::Maths;
+use crate::rgb::Rgb;
+
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+
+#[cfg(feature = "zerocopy")]
+use zerocopy::{FromZeros, Immutable, IntoBytes};
+
+/// A ProPhoto RGB (also known as ROMM RGB) colour.
+///
+/// Unlike most other RGB colour spaces, ProPhoto RGB is defined relative to the [D50](crate::xyz::CieXyz::D50) white point.
+///
+/// This type is guaranteed to always have the exact same layout as <code>[Rgb]&lt;T&gt;</code>, although with a different representation.
+/// Namely, channel order is preserved in memory.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
+#[cfg_attr(feature = "zerocopy", derive(FromZeros, Immutable, IntoBytes))]
+pub struct ProPhotoRgb<T>(Rgb<T>);
+
+impl<T: Component> ProPhotoRgb<T> {
+	/// Constructs a new ProPhoto RGB colour.
+	#[inline(always)]
+	#[must_use]
+	pub const fn new(red: T, green: T, blue: T) -> Self {
+		let colour = Rgb::new(red, green, blue);
+		Self(colour)
+	}
+
+	/// Reinterprets a raw RGB colour as ProPhoto RGB.
+	///
+	/// The provided colour is *scaled* to fit the ProPhoto RGB gamut.
+	#[inline(always)]
+	#[must_use]
+	pub const fn from_rgb(colour: Rgb<T>) -> Self {
+		Self(colour)
+	}
+
+	/// Maps the ProPhoto RGB colour's channels.
+	#[inline]
+	#[must_use]
+	pub fn map<U, F>(self, mut op: F) -> ProPhotoRgb<U>
+	where
+		U: Component,
+		F: FnMut(T) -> U,
+	{
+		let (red, green, blue) = self.get();
+
+		let red   = op(red);
+		let green = op(green);
+		let blue  = op(blue);
+
+		ProPhotoRgb::new(red, green, blue)
+	}
+
+	/// Reinterprets the ProPhoto RGB colour as a raw RGB colour.
+	#[inline(always)]
+	#[must_use]
+	pub const fn as_rgb(self) -> Rgb<T> {
+		self.0
+	}
+
+	/// Deconstructs the ProPhoto RGB colour.
+	#[inline(always)]
+	#[must_use]
+	pub const fn get(self) -> (T, T, T) {
+		self.as_rgb().get()
+	}
+}
+
+macro_rules! impl_conversions {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::rgb::ProPhotoRgb<$tys> {
+				/// The matrix transforming linear ProPhoto RGB to CIEXYZ.
+				#[allow(clippy::excessive_precision)]
+				#[cfg(feature = "maths")]
+				const TO_XYZ: [[$tys; 0x3]; 0x3] = [
+					[0.797_766_644_900_642_3, 0.135_181_297_400_533_1, 0.031_347_734_128_392_2],
+					[0.288_074_828_819_401_3, 0.711_835_234_241_873_0, 0.000_089_936_938_725_6],
+					[0.000_000_000_000_000_0, 0.000_000_000_000_000_0, 0.825_104_602_510_460_2],
+				];
+
+				/// The matrix transforming CIEXYZ to linear ProPhoto RGB.
+				#[allow(clippy::excessive_precision)]
+				#[cfg(feature = "maths")]
+				const FROM_XYZ: [[$tys; 0x3]; 0x3] = [
+					[ 1.345_786_881_647_158_3, -0.255_572_087_379_794_6, -0.051_101_864_975_545_3],
+					[-0.544_630_705_124_901_9,  1.508_247_742_845_146_8,  0.020_527_447_436_421_4],
+					[ 0.000_000_000_000_000_0,  0.000_000_000_000_000_0,  1.211_967_545_638_945_2],
+				];
+
+				/// Transfers a raw RGB value to perceptual RGB, scaling to the ProPhoto RGB gamut.
+				///
+				/// The transfer is done as by the transfer function of ROMM RGB, i.e. a gamma of 1.8 with a linear segment near black.
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn transfer(colour: Rgb<$tys>) -> Self {
+					let colour = colour.map(|mut colour| {
+						let sign = colour;
+
+						colour = Maths::abs(colour);
+
+						colour = if colour >= const { 1.0 / 512.0 } {
+							Maths::powf(colour, const { 1.0 / 1.8 })
+						} else {
+							colour * 16.0
+						};
+
+						colour = Maths::copysign(colour, sign);
+
+						colour
+					});
+
+					Self::from_rgb(colour)
+				}
+
+				/// "Untransfers" the gamma-encoded ProPhoto RGB.
+				///
+				/// This method serves as the inverse of [`transfer`](Self::transfer).
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn untransfer(self) -> Rgb<$tys> {
+					self.as_rgb().map(|mut colour| {
+						let sign = colour;
+
+						colour = Maths::abs(colour);
+
+						colour = if colour > const { 16.0 / 512.0 } {
+							Maths::powf(colour, 1.8)
+						} else {
+							colour / 16.0
+						};
+
+						colour = Maths::copysign(colour, sign);
+
+						colour
+					})
+				}
+
+				/// Converts a CIEXYZ colour to ProPhoto RGB.
+				///
+				/// The colour is expected to be relative to the [D50](crate::xyz::CieXyz::D50) white point (see [`CieXyz::adapt`](crate::xyz::CieXyz::adapt)).
+				/// Note that the returned colour is not clamped to the ProPhoto RGB gamut.
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn from_xyz(colour: ::polywave::xyz::CieXyz<$tys>) -> Self {
+					let (x, y, z) = colour.get();
+
+					let [red, green, blue] = Self::FROM_XYZ.map(|[m0, m1, m2]| m0 * x + m1 * y + m2 * z);
+
+					Self::transfer(Rgb::new(red, green, blue))
+				}
+
+				/// Converts the ProPhoto RGB colour to CIEXYZ.
+				///
+				/// The returned colour is relative to the [D50](crate::xyz::CieXyz::D50) white point.
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn to_xyz(self) -> ::polywave::xyz::CieXyz<$tys> {
+					let (red, green, blue) = self.untransfer().get();
+
+					let [x, y, z] = Self::TO_XYZ.map(|[m0, m1, m2]| m0 * red + m1 * green + m2 * blue);
+
+					::polywave::xyz::CieXyz::new(x, y, z)
+				}
+			}
+
+			#[cfg(feature = "maths")]
+			impl ::polywave::RgbGamut for ::polywave::rgb::ProPhotoRgb<$tys> { }
+		)*
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_conversions!(f16);
+
+impl_conversions!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_conversions!(f128);
+
+unsafe impl<T: Component> BalancedColour for ProPhotoRgb<T> {
+	type Component = T;
+}
+
+impl<T: Component> Colour for ProPhotoRgb<T> { }
+
+impl<T: NormalisedComponent> DefinedGamut for ProPhotoRgb<T> {
+	type Scalar = T;
+
+	#[inline]
+	fn is_in_gamut(&self, epsilon: Self::Scalar) -> bool {
+		let (red, green, blue) = self.get();
+
+		red.is_normalised(epsilon)
+			&& green.is_normalised(epsilon)
+			&& blue.is_normalised(epsilon)
+	}
+
+	#[inline]
+	fn clip(self) -> Self {
+		self.map(NormalisedComponent::clamp_normalised)
+	}
+
+	#[inline]
+	fn gamut_distance(&self) -> Self::Scalar {
+		let (red, green, blue) = self.map(NormalisedComponent::normalised_distance).get();
+
+		let mut distance = red;
+
+		if green > distance {
+			distance = green;
+		}
+
+		if blue > distance {
+			distance = blue;
+		}
+
+		distance
+	}
+}
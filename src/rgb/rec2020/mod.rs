@@ -0,0 +1,247 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::{BalancedColour, Colour, Component, DefinedGamut, NormalisedComponent};
+#[cfg(feature = "maths")]
+use crate::maths::Maths;
+use crate::rgb::Rgb;
+
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+
+#[cfg(feature = "zerocopy")]
+use zerocopy::{FromZeros, Immutable, IntoBytes};
+
+/// A Rec. 2020 (ITU-R BT.2020) colour.
+///
+/// Rec. 2020 is the colour space of ultra-high-definition television and has a considerably larger gamut than [sRGB](crate::rgb::SRgb).
+///
+/// This type is guaranteed to always have the exact same layout as <code>[Rgb]&lt;T&gt;</code>, although with a different representation.
+/// Namely, channel order is preserved in memory.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
+#[cfg_attr(feature = "zerocopy", derive(FromZeros, Immutable, IntoBytes))]
+pub struct Rec2020<T>(Rgb<T>);
+
+impl<T: Component> Rec2020<T> {
+	/// Constructs a new Rec. 2020 colour.
+	#[inline(always)]
+	#[must_use]
+	pub const fn new(red: T, green: T, blue: T) -> Self {
+		let colour = Rgb::new(red, green, blue);
+		Self(colour)
+	}
+
+	/// Reinterprets a raw RGB colour as Rec. 2020.
+	///
+	/// The provided colour is *scaled* to fit the Rec. 2020 gamut.
+	#[inline(always)]
+	#[must_use]
+	pub const fn from_rgb(colour: Rgb<T>) -> Self {
+		Self(colour)
+	}
+
+	/// Maps the Rec. 2020 colour's channels.
+	#[inline]
+	#[must_use]
+	pub fn map<U, F>(self, mut op: F) -> Rec2020<U>
+	where
+		U: Component,
+		F: FnMut(T) -> U,
+	{
+		let (red, green, blue) = self.get();
+
+		let red   = op(red);
+		let green = op(green);
+		let blue  = op(blue);
+
+		Rec2020::new(red, green, blue)
+	}
+
+	/// Reinterprets the Rec. 2020 colour as a raw RGB colour.
+	#[inline(always)]
+	#[must_use]
+	pub const fn as_rgb(self) -> Rgb<T> {
+		self.0
+	}
+
+	/// Deconstructs the Rec. 2020 colour.
+	#[inline(always)]
+	#[must_use]
+	pub const fn get(self) -> (T, T, T) {
+		self.as_rgb().get()
+	}
+}
+
+macro_rules! impl_conversions {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::rgb::Rec2020<$tys> {
+				/// The matrix transforming linear Rec. 2020 to CIEXYZ.
+				#[allow(clippy::excessive_precision)]
+				#[cfg(feature = "maths")]
+				const TO_XYZ: [[$tys; 0x3]; 0x3] = [
+					[0.636_958_048_301_291_3, 0.144_616_903_586_208_4, 0.168_880_975_164_172_1],
+					[0.262_700_212_011_267_0, 0.677_998_071_518_871_0, 0.059_301_716_469_861_9],
+					[0.000_000_000_000_000_0, 0.028_072_693_049_087_5, 1.060_985_057_710_790_9],
+				];
+
+				/// The matrix transforming CIEXYZ to linear Rec. 2020.
+				#[allow(clippy::excessive_precision)]
+				#[cfg(feature = "maths")]
+				const FROM_XYZ: [[$tys; 0x3]; 0x3] = [
+					[ 1.716_651_187_971_267_6, -0.355_670_783_776_392_4, -0.253_366_281_373_659_8],
+					[-0.666_684_351_832_489_0,  1.616_481_236_634_939_0,  0.015_768_545_813_911_1],
+					[ 0.017_639_857_445_310_9, -0.042_770_613_257_808_7,  0.942_103_121_235_474_0],
+				];
+
+				/// Transfers a raw RGB value to perceptual RGB, scaling to the Rec. 2020 gamut.
+				///
+				/// The transfer is done as by the transfer function of ITU-R BT.2020.
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn transfer(colour: Rgb<$tys>) -> Self {
+					#[allow(clippy::excessive_precision)]
+					const ALPHA: $tys = 1.099_296_826_809_44;
+					#[allow(clippy::excessive_precision)]
+					const BETA:  $tys = 0.018_053_968_510_807;
+
+					let colour = colour.map(|mut colour| {
+						let sign = colour;
+
+						colour = Maths::abs(colour);
+
+						colour = if colour > BETA {
+							ALPHA * Maths::powf(colour, 0.45) - (ALPHA - 1.0)
+						} else {
+							colour * 4.5
+						};
+
+						colour = Maths::copysign(colour, sign);
+
+						colour
+					});
+
+					Self::from_rgb(colour)
+				}
+
+				/// "Untransfers" the gamma-encoded Rec. 2020.
+				///
+				/// This method serves as the inverse of [`transfer`](Self::transfer).
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn untransfer(self) -> Rgb<$tys> {
+					#[allow(clippy::excessive_precision)]
+					const ALPHA: $tys = 1.099_296_826_809_44;
+					#[allow(clippy::excessive_precision)]
+					const BETA:  $tys = 0.018_053_968_510_807;
+
+					self.as_rgb().map(|mut colour| {
+						let sign = colour;
+
+						colour = Maths::abs(colour);
+
+						colour = if colour >= BETA * 4.5 {
+							Maths::powf((colour + ALPHA - 1.0) / ALPHA, const { 1.0 / 0.45 })
+						} else {
+							colour / 4.5
+						};
+
+						colour = Maths::copysign(colour, sign);
+
+						colour
+					})
+				}
+
+				/// Converts a CIEXYZ colour to Rec. 2020.
+				///
+				/// The colour is expected to be relative to the [D65](crate::xyz::CieXyz::D65) white point.
+				/// Note that the returned colour is not clamped to the Rec. 2020 gamut.
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn from_xyz(colour: ::polywave::xyz::CieXyz<$tys>) -> Self {
+					let (x, y, z) = colour.get();
+
+					let [red, green, blue] = Self::FROM_XYZ.map(|[m0, m1, m2]| m0 * x + m1 * y + m2 * z);
+
+					Self::transfer(Rgb::new(red, green, blue))
+				}
+
+				/// Converts the Rec. 2020 colour to CIEXYZ.
+				///
+				/// The returned colour is relative to the [D65](crate::xyz::CieXyz::D65) white point.
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn to_xyz(self) -> ::polywave::xyz::CieXyz<$tys> {
+					let (red, green, blue) = self.untransfer().get();
+
+					let [x, y, z] = Self::TO_XYZ.map(|[m0, m1, m2]| m0 * red + m1 * green + m2 * blue);
+
+					::polywave::xyz::CieXyz::new(x, y, z)
+				}
+			}
+
+			#[cfg(feature = "maths")]
+			impl ::polywave::RgbGamut for ::polywave::rgb::Rec2020<$tys> { }
+		)*
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_conversions!(f16);
+
+impl_conversions!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_conversions!(f128);
+
+unsafe impl<T: Component> BalancedColour for Rec2020<T> {
+	type Component = T;
+}
+
+impl<T: Component> Colour for Rec2020<T> { }
+
+impl<T: NormalisedComponent> DefinedGamut for Rec2020<T> {
+	type Scalar = T;
+
+	#[inline]
+	fn is_in_gamut(&self, epsilon: Self::Scalar) -> bool {
+		let (red, green, blue) = self.get();
+
+		red.is_normalised(epsilon)
+			&& green.is_normalised(epsilon)
+			&& blue.is_normalised(epsilon)
+	}
+
+	#[inline]
+	fn clip(self) -> Self {
+		self.map(NormalisedComponent::clamp_normalised)
+	}
+
+	#[inline]
+	fn gamut_distance(&self) -> Self::Scalar {
+		let (red, green, blue) = self.map(NormalisedComponent::normalised_distance).get();
+
+		let mut distance = red;
+
+		if green > distance {
+			distance = green;
+		}
+
+		if blue > distance {
+			distance = blue;
+		}
+
+		distance
+	}
+}
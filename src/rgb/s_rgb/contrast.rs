@@ -0,0 +1,114 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+#[cfg(feature = "maths")]
+use crate::maths::Maths;
+
+macro_rules! impl_contrast {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::rgb::SRgb<$tys> {
+				/// Computes the relative luminance of the sRGB colour.
+				///
+				/// This is the luminance as defined by WCAG 2.x, i.e. the Y component of the colour in CIEXYZ, normalised so that black is `0` and white is `1`.
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn relative_luminance(self) -> $tys {
+					let (red, green, blue) = self.untransfer().get();
+
+					0.212_6 * red + 0.715_2 * green + 0.072_2 * blue
+				}
+
+				/// Computes the WCAG 2.x contrast ratio between two sRGB colours.
+				///
+				/// The returned value is the left-hand side of the `x:1` ratio and lies in the range `1` to `21`.
+				/// The order of the two colours does not matter.
+				#[cfg(feature = "maths")]
+				#[inline]
+				#[must_use]
+				pub fn contrast_ratio(self, other: Self) -> $tys {
+					let luminance0 = self.relative_luminance();
+					let luminance1 = other.relative_luminance();
+
+					let (lighter, darker) = if luminance0 > luminance1 {
+						(luminance0, luminance1)
+					} else {
+						(luminance1, luminance0)
+					};
+
+					(lighter + 0.05) / (darker + 0.05)
+				}
+
+				/// Computes the APCA lightness contrast (L<sup>c</sup>) of text in this colour on the given background.
+				///
+				/// This implements the 0.0.98G-4g constants of the [Accessible Perceptual Contrast Algorithm](https://github.com/Myndex/apca-w3).
+				/// Unlike the WCAG 2.x contrast ratio, APCA is sensitive to polarity: dark text on a light background yields a positive contrast, whilst light text on a dark background yields a negative contrast.
+				///
+				/// The returned contrast lies roughly in the range `-108` to `106`, and contrasts too low to be meaningful are reported as zero.
+				/// As in APCA itself, channels outside of the sRGB gamut are clamped to the range `0` to `1`.
+				#[cfg(feature = "maths")]
+				#[must_use]
+				pub fn apca_contrast(self, background: Self) -> $tys {
+					const BLACK_THRESHOLD: $tys = 0.022;
+					const BLACK_CLAMP:     $tys = 1.414;
+					const DELTA_Y_MIN:     $tys = 0.000_5;
+					const SCALE:           $tys = 1.14;
+					const OFFSET:          $tys = 0.027;
+					const LOW_CLIP:        $tys = 0.1;
+
+					let luminance = |colour: Self| -> $tys {
+						let (red, green, blue) = colour.map(|channel| channel.clamp(0.0, 1.0)).get();
+
+						let luminance = 0.212_672_9 * Maths::powf(red, 2.4)
+							+ 0.715_152_2 * Maths::powf(green, 2.4)
+							+ 0.072_175_0 * Maths::powf(blue, 2.4);
+
+						// Soft-clamp near-black colours.
+						if luminance > BLACK_THRESHOLD {
+							luminance
+						} else {
+							luminance + Maths::powf(BLACK_THRESHOLD - luminance, BLACK_CLAMP)
+						}
+					};
+
+					let text       = luminance(self);
+					let background = luminance(background);
+
+					if Maths::abs(background - text) < DELTA_Y_MIN {
+						return 0.0;
+					}
+
+					let contrast = if background > text {
+						// Normal polarity, i.e. dark text on a
+						// light background.
+						let contrast = (Maths::powf(background, 0.56) - Maths::powf(text, 0.57)) * SCALE;
+
+						if contrast < LOW_CLIP { 0.0 } else { contrast - OFFSET }
+					} else {
+						// Reverse polarity, i.e. light text on a
+						// dark background.
+						let contrast = (Maths::powf(background, 0.65) - Maths::powf(text, 0.62)) * SCALE;
+
+						if contrast > -LOW_CLIP { 0.0 } else { contrast + OFFSET }
+					};
+
+					contrast * 100.0
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_contrast!(f16);
+
+impl_contrast!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_contrast!(f128);
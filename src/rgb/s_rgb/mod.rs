@@ -6,15 +6,14 @@
 // can obtain one at:
 // <https://mozilla.org/MPL/2.0/>.
 
-use crate::{BalancedColour, Colour, Component, DefinedGamut};
+mod contrast;
+
+use crate::{BalancedColour, Colour, Component, DefinedGamut, NormalisedComponent};
 use crate::rgb::Rgb;
 
 #[cfg(feature = "bytemuck")]
 use bytemuck::{Pod, Zeroable};
 
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
-
 #[cfg(feature = "zerocopy")]
 use zerocopy::{FromZeros, Immutable, IntoBytes};
 
@@ -25,7 +24,6 @@ use zerocopy::{FromZeros, Immutable, IntoBytes};
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
-#[cfg_attr(feature = "serde",    derive(Deserialize, Serialize))]
 #[cfg_attr(feature = "zerocopy", derive(FromZeros, Immutable, IntoBytes))]
 pub struct SRgb<T>(Rgb<T>);
 
@@ -83,59 +81,102 @@ macro_rules! impl_conversions {
 	($($tys:ty),+$(,)?) => {
 		$(
 			impl ::polywave::rgb::SRgb<$tys> {
-				/// Transfers a raw RGB value to perceptual RGB, scaling to the sRGB gamut.
+				/// Converts an sRGB colour with eight-bit channels to one with channels in the range `0` to `1`.
 				///
-				/// The transfer is done as by the sRGB transfer function.
-				#[cfg(feature = "std")]
+				/// This is the inverse of [`to_u8`](Self::to_u8), and the two round-trip exactly.
 				#[inline]
 				#[must_use]
-				pub fn transfer(colour: Rgb<$tys>) -> Self {
-					let colour = colour.map(|mut colour| {
-						let sign = colour;
-
-						colour = colour.abs();
-
-						colour = if colour > 0.003_130_800 {
-							colour.powf(const { 1.0 / 2.4 }) * 1.055 - 0.055
-						} else {
-							colour * 12.920
-						};
+				pub const fn from_u8(colour: ::polywave::rgb::SRgb<u8>) -> Self {
+					let (red, green, blue) = colour.get();
 
-						colour = colour.copysign(sign);
+					Self::new(red as $tys / 255.0, green as $tys / 255.0, blue as $tys / 255.0)
+				}
 
-						colour
-					});
+				/// Converts the sRGB colour to one with eight-bit channels.
+				///
+				/// Each channel is clamped to the range `0` to `1` and rounded to the nearest step, with NaN becoming `0`.
+				#[inline]
+				#[must_use]
+				pub fn to_u8(self) -> ::polywave::rgb::SRgb<u8> {
+					self.map(|channel| (channel.clamp(0.0, 1.0) * 255.0 + 0.5) as u8)
+				}
 
-					Self::from_rgb(colour)
+				/// Transfers a raw RGB value to perceptual RGB, scaling to the sRGB gamut.
+				///
+				/// The transfer is done as by the sRGB transfer function.
+				/// It is computed in double precision using the crate's own maths functions, and it is therefore usable in constant expressions.
+				#[inline]
+				#[must_use]
+				pub const fn transfer(colour: Rgb<$tys>) -> Self {
+					let (red, green, blue) = colour.get();
+
+					Self::new(
+						Self::transfer_channel(red),
+						Self::transfer_channel(green),
+						Self::transfer_channel(blue),
+					)
 				}
 
 				/// "Untransfers" the gamma-encoded sRGB.
 				///
 				/// sRGB channels are encoded using the [*transfer*](https://en.wikipedia.org/wiki/SRGB#Transfer_function_(%22gamma%22)) function (see [`transfer`](Self::transfer)).
 				/// This method serves as the inverse of this function.
+				/// It is computed in double precision using the crate's own maths functions, and it is therefore usable in constant expressions.
 				///
 				/// Note that the returned value is no longer sRGB as sRGB is strictly gamma-encoded.
-				#[cfg(feature = "std")]
 				#[inline]
 				#[must_use]
-				pub fn untransfer(self) -> Rgb<$tys> {
-					self.as_rgb().map(|mut colour| {
-						let sign = colour;
+				pub const fn untransfer(self) -> Rgb<$tys> {
+					let (red, green, blue) = self.get();
+
+					Rgb::new(
+						Self::untransfer_channel(red),
+						Self::untransfer_channel(green),
+						Self::untransfer_channel(blue),
+					)
+				}
 
-						colour = colour.abs();
+				/// Applies the sRGB transfer function to a single channel.
+				#[allow(clippy::cast_lossless, clippy::cast_possible_truncation)]
+				#[must_use]
+				const fn transfer_channel(channel: $tys) -> $tys {
+					use ::polywave::maths::{abs, copysign, powf};
+
+					let sign = channel as f64;
 
-						colour = if colour > 0.040_450 {
-							((colour + 0.055) / 1.055).powf(2.4)
-						} else {
-							colour / 12.920
-						};
+					let mut channel = abs(sign);
 
-						colour = colour.copysign(sign);
+					channel = if channel > 0.003_130_800 {
+						powf(channel, 1.0 / 2.4) * 1.055 - 0.055
+					} else {
+						channel * 12.920
+					};
 
-						colour
-					})
+					copysign(channel, sign) as $tys
+				}
+
+				/// Applies the inverse sRGB transfer function to a single channel.
+				#[allow(clippy::cast_lossless, clippy::cast_possible_truncation)]
+				#[must_use]
+				const fn untransfer_channel(channel: $tys) -> $tys {
+					use ::polywave::maths::{abs, copysign, powf};
+
+					let sign = channel as f64;
+
+					let mut channel = abs(sign);
+
+					channel = if channel > 0.040_450 {
+						powf((channel + 0.055) / 1.055, 2.4)
+					} else {
+						channel / 12.920
+					};
+
+					copysign(channel, sign) as $tys
 				}
 			}
+
+			#[cfg(feature = "maths")]
+			impl ::polywave::RgbGamut for ::polywave::rgb::SRgb<$tys> { }
 		)*
 	};
 }
@@ -154,4 +195,37 @@ unsafe impl<T: Component> BalancedColour for SRgb<T> {
 
 impl<T: Component> Colour for SRgb<T> { }
 
-impl<T: Component> DefinedGamut for SRgb<T> { }
+impl<T: NormalisedComponent> DefinedGamut for SRgb<T> {
+	type Scalar = T;
+
+	#[inline]
+	fn is_in_gamut(&self, epsilon: Self::Scalar) -> bool {
+		let (red, green, blue) = self.get();
+
+		red.is_normalised(epsilon)
+			&& green.is_normalised(epsilon)
+			&& blue.is_normalised(epsilon)
+	}
+
+	#[inline]
+	fn clip(self) -> Self {
+		self.map(NormalisedComponent::clamp_normalised)
+	}
+
+	#[inline]
+	fn gamut_distance(&self) -> Self::Scalar {
+		let (red, green, blue) = self.map(NormalisedComponent::normalised_distance).get();
+
+		let mut distance = red;
+
+		if green > distance {
+			distance = green;
+		}
+
+		if blue > distance {
+			distance = blue;
+		}
+
+		distance
+	}
+}
@@ -0,0 +1,162 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+#![cfg(test)]
+
+use crate::{Alpha, DefinedGamut};
+use crate::rgb::{OpRgb, Rgb, SRgb};
+#[cfg(feature = "maths")]
+use crate::rgb::{DisplayP3, ProPhotoRgb, Rec2020};
+use crate::www::Html;
+
+#[test]
+fn test_s_rgb_gamut() {
+	let inside = SRgb::<f64>::new(0.25, 0.5, 1.0);
+
+	assert!(inside.is_in_gamut(0.0));
+	assert_eq!(inside.clip(), inside);
+	assert_eq!(inside.gamut_distance(), 0.0);
+
+	let outside = SRgb::<f64>::new(-0.125, 0.5, 1.25);
+
+	assert!(!outside.is_in_gamut(0.0));
+	assert!(!outside.is_in_gamut(0.2));
+	assert!(outside.is_in_gamut(0.25));
+	assert_eq!(outside.clip(), SRgb::new(0.0, 0.5, 1.0));
+	assert_eq!(outside.gamut_distance(), 0.25);
+
+	let rounded = SRgb::<f32>::new(1.000_000_1, 0.0, -0.000_000_1);
+
+	assert!(!rounded.is_in_gamut(0.0));
+	assert!(rounded.is_in_gamut(1e-6));
+
+	let integral = SRgb::<u8>::new(0x00, 0x7F, 0xFF);
+
+	assert!(integral.is_in_gamut(0x0));
+	assert_eq!(integral.clip(), integral);
+	assert_eq!(integral.gamut_distance(), 0x0);
+
+	let signed = SRgb::<i8>::new(-0x80, 0x0, 0x7F);
+
+	assert!(!signed.is_in_gamut(0x7F));
+	assert!(SRgb::<i8>::new(-0x1, 0x0, 0x0).is_in_gamut(0x1));
+	assert_eq!(signed.clip(), SRgb::new(0x0, 0x0, 0x7F));
+	assert_eq!(signed.gamut_distance(), 0x7F);
+}
+
+#[test]
+fn test_op_rgb_gamut() {
+	let outside = OpRgb::<f32>::new(1.5, -0.75, 0.0);
+
+	assert!(!outside.is_in_gamut(0.5));
+	assert_eq!(outside.clip(), OpRgb::new(1.0, 0.0, 0.0));
+	assert_eq!(outside.gamut_distance(), 0.75);
+}
+
+#[test]
+fn test_alpha_gamut() {
+	let colour = Alpha::attach(SRgb::<f64>::new(0.5, 0.5, 0.5), 1.5);
+
+	assert!(!colour.is_in_gamut(0.0));
+	assert_eq!(colour.clip(), Alpha::attach(SRgb::new(0.5, 0.5, 0.5), 1.0));
+	assert_eq!(colour.gamut_distance(), 0.5);
+
+	let colour = Alpha::attach(SRgb::<f64>::new(0.5, 2.0, 0.5), 0.5);
+
+	assert!(!colour.is_in_gamut(0.0));
+	assert_eq!(colour.clip(), Alpha::attach(SRgb::new(0.5, 1.0, 0.5), 0.5));
+	assert_eq!(colour.gamut_distance(), 1.0);
+}
+
+#[test]
+fn test_html_gamut() {
+	assert!(Html::REBECCA_PURPLE.is_in_gamut(0x0));
+	assert_eq!(Html::REBECCA_PURPLE.clip(), Html::REBECCA_PURPLE);
+	assert_eq!(Html::REBECCA_PURPLE.gamut_distance(), 0x0);
+}
+
+#[cfg(feature = "maths")]
+#[test]
+fn test_wide_gamut_xyz() {
+	let colour = SRgb::<f64>::new(0.25, 0.5, 0.75).map(|channel| channel.powf(2.2));
+	let colour = crate::xyz::CieXyz::<f64>::from_s_rgb(colour);
+
+	let round_trips = [
+		DisplayP3::<f64>::from_xyz(colour).to_xyz(),
+		OpRgb::<f64>::from_xyz(colour).to_xyz(),
+		ProPhotoRgb::<f64>::from_xyz(colour).to_xyz(),
+		Rec2020::<f64>::from_xyz(colour).to_xyz(),
+	];
+
+	for round_trip in round_trips {
+		let (x, y, z)                   = colour.get();
+		let (other_x, other_y, other_z) = round_trip.get();
+
+		assert!((x - other_x).abs() < 1e-12 && (y - other_y).abs() < 1e-12 && (z - other_z).abs() < 1e-12);
+	}
+
+	let white = DisplayP3::<f64>::from_xyz(crate::xyz::CieXyz::<f64>::D65);
+	assert!(white.is_in_gamut(1e-9));
+
+	let green = Rec2020::<f64>::new(0.0, 1.0, 0.0).to_xyz();
+	assert!(!DisplayP3::<f64>::from_xyz(green).is_in_gamut(0.0));
+}
+
+#[test]
+fn test_s_rgb_u8() {
+	for channel in 0x00..=0xFF {
+		let colour = SRgb::<u8>::new(channel, 0xFF - channel, channel);
+
+		assert_eq!(SRgb::<f32>::from_u8(colour).to_u8(), colour);
+		assert_eq!(SRgb::<f64>::from_u8(colour).to_u8(), colour);
+	}
+
+	assert_eq!(SRgb::<f32>::from_u8(SRgb::new(0x00, 0x33, 0xFF)), SRgb::new(0.0, 0.2, 1.0));
+
+	assert_eq!(SRgb::<f64>::new(-0.5, 0.501_960_784_313_725_4, f64::NAN).to_u8(), SRgb::new(0x00, 0x80, 0x00));
+}
+
+#[test]
+fn test_s_rgb_transfer_const() {
+	const DECODED: Rgb<f32> = SRgb::<f32>::from_u8(SRgb::new(0x66, 0x33, 0x99)).untransfer();
+
+	let (red, green, blue) = DECODED.get();
+
+	assert!((red - 0.132_868).abs() <= 1e-6);
+	assert!((green - 0.033_105).abs() <= 1e-6);
+	assert!((blue - 0.318_547).abs() <= 1e-6);
+
+	const ENCODED: SRgb<f32> = SRgb::<f32>::transfer(DECODED);
+
+	assert_eq!(ENCODED.to_u8(), SRgb::new(0x66, 0x33, 0x99));
+
+	for index in -0x40..=0x140 {
+		let channel = f64::from(index) / 256.0;
+		let colour  = SRgb::new(channel, -channel, channel * 0.01);
+
+		let (red, green, blue) = SRgb::<f64>::transfer(colour.untransfer()).get();
+		let (expected_red, expected_green, expected_blue) = colour.get();
+
+		assert!((red - expected_red).abs() <= 1e-14);
+		assert!((green - expected_green).abs() <= 1e-14);
+		assert!((blue - expected_blue).abs() <= 1e-14);
+	}
+}
+
+#[cfg(feature = "maths")]
+#[test]
+fn test_s_rgb_apca_contrast_out_of_gamut() {
+	let white = SRgb::<f64>::new(1.0, 1.0, 1.0);
+
+	let contrast = SRgb::<f64>::new(-0.2, 0.5, 1.3).apca_contrast(white);
+
+	assert!(contrast.is_finite());
+	assert_eq!(contrast, SRgb::<f64>::new(0.0, 0.5, 1.0).apca_contrast(white));
+
+	assert_eq!(white.apca_contrast(SRgb::new(-0.5, -0.5, -0.5)), white.apca_contrast(SRgb::new(0.0, 0.0, 0.0)));
+}
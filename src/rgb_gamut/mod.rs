@@ -0,0 +1,15 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::{DefinedGamut, OkLabConvert};
+
+/// Denotes an RGB colour space with a bounded gamut.
+///
+/// Colours in such spaces can be converted to and from [`OkLab`](crate::lab::OkLab), which is used as the connection space for perceptual gamut mapping.
+/// See [`OkLch::map_to_gamut`](crate::lch::OkLch::map_to_gamut) for more information.
+pub trait RgbGamut: Copy + DefinedGamut + OkLabConvert<Scalar = <Self as DefinedGamut>::Scalar> { }
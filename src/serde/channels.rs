@@ -0,0 +1,144 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::Component;
+use crate::hsv::{Hsl, Hsv, Hwb, OkHsl, OkHsv};
+use crate::lab::{CieLab, CieLuv, OkLab, OkLrab};
+use crate::lch::{CieLch, CieLchUv, HpLuv, HsLuv, OkLch, OkLrch};
+use crate::rgb::{DisplayP3, OpRgb, ProPhotoRgb, Rec2020, Rgb, SRgb};
+use crate::xyz::CieXyz;
+
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The storage of channels in compact representations.
+trait Channels<T>: Sized {
+	/// Constructs the storage from channels.
+	#[must_use]
+	fn from_channels(channels: (T, T, T)) -> Self;
+
+	/// Deconstructs the storage into channels.
+	#[must_use]
+	fn into_channels(self) -> (T, T, T);
+}
+
+impl<T> Channels<T> for [T; 0x3] {
+	#[inline(always)]
+	fn from_channels((c0, c1, c2): (T, T, T)) -> Self {
+		[c0, c1, c2]
+	}
+
+	#[inline(always)]
+	fn into_channels(self) -> (T, T, T) {
+		let [c0, c1, c2] = self;
+		(c0, c1, c2)
+	}
+}
+
+impl<T: Component> Channels<T> for Rgb<T> {
+	#[inline(always)]
+	fn from_channels((red, green, blue): (T, T, T)) -> Self {
+		Self::new(red, green, blue)
+	}
+
+	#[inline(always)]
+	fn into_channels(self) -> (T, T, T) {
+		self.get()
+	}
+}
+
+/// Implements `Serialize` and `Deserialize` for colours with three channels.
+///
+/// Human-readable formats use structures with the given channel names, and compact formats use the same representation as was derived in earlier versions, i.e. a newtype around the given storage.
+macro_rules! impl_serde {
+	{
+		$(
+			$tys:ident = $names:literal { $c0:ident, $c1:ident, $c2:ident } as $storages:ty;
+		)*
+	} => {
+		$(
+			impl<T: Component + Serialize> Serialize for $tys<T> {
+				fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+					#[derive(Serialize)]
+					#[serde(rename = $names)]
+					struct Named<T> {
+						$c0: T,
+						$c1: T,
+						$c2: T,
+					}
+
+					#[derive(Serialize)]
+					#[serde(rename = $names)]
+					struct Compact<T: Component>($storages);
+
+					let ($c0, $c1, $c2) = self.get();
+
+					if serializer.is_human_readable() {
+						Named { $c0, $c1, $c2 }.serialize(serializer)
+					} else {
+						Compact(Channels::from_channels(($c0, $c1, $c2))).serialize(serializer)
+					}
+				}
+			}
+
+			impl<'de, T: Component + Deserialize<'de>> Deserialize<'de> for $tys<T> {
+				fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+					#[derive(Deserialize)]
+					#[serde(deny_unknown_fields, rename = $names)]
+					struct Named<T> {
+						$c0: T,
+						$c1: T,
+						$c2: T,
+					}
+
+					#[derive(Deserialize)]
+					#[serde(rename = $names)]
+					struct Compact<T: Component>($storages);
+
+					let ($c0, $c1, $c2) = if deserializer.is_human_readable() {
+						let Named { $c0, $c1, $c2 } = Named::deserialize(deserializer)?;
+						($c0, $c1, $c2)
+					} else {
+						let Compact(storage) = Compact::deserialize(deserializer)?;
+						storage.into_channels()
+					};
+
+					Ok(Self::new($c0, $c1, $c2))
+				}
+			}
+		)*
+	};
+}
+
+impl_serde! {
+	Rgb         = "Rgb"         { r, g, b } as [T; 0x3];
+	SRgb        = "SRgb"        { r, g, b } as Rgb<T>;
+	DisplayP3   = "DisplayP3"   { r, g, b } as Rgb<T>;
+	OpRgb       = "OpRgb"       { r, g, b } as Rgb<T>;
+	ProPhotoRgb = "ProPhotoRgb" { r, g, b } as Rgb<T>;
+	Rec2020     = "Rec2020"     { r, g, b } as Rgb<T>;
+
+	Hsl   = "Hsl"   { h, s, l } as [T; 0x3];
+	Hsv   = "Hsv"   { h, s, v } as [T; 0x3];
+	Hwb   = "Hwb"   { h, w, b } as [T; 0x3];
+	OkHsl = "OkHsl" { h, s, l } as [T; 0x3];
+	OkHsv = "OkHsv" { h, s, v } as [T; 0x3];
+
+	CieLab = "CieLab" { l, a, b } as [T; 0x3];
+	CieLuv = "CieLuv" { l, u, v } as [T; 0x3];
+	OkLab  = "OkLab"  { l, a, b } as [T; 0x3];
+	OkLrab = "OkLrab" { l, a, b } as [T; 0x3];
+
+	CieLch   = "CieLch"   { l, c, h } as [T; 0x3];
+	CieLchUv = "CieLchUv" { l, c, h } as [T; 0x3];
+	HpLuv    = "HpLuv"    { h, s, l } as [T; 0x3];
+	HsLuv    = "HsLuv"    { h, s, l } as [T; 0x3];
+	OkLch    = "OkLch"    { l, c, h } as [T; 0x3];
+	OkLrch   = "OkLrch"   { l, c, h } as [T; 0x3];
+
+	CieXyz = "CieXyz" { x, y, z } as [T; 0x3];
+}
@@ -0,0 +1,62 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::Alpha;
+use crate::rgb::SRgb;
+use crate::www::{Html, HtmlDisplay};
+
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use ::serde::de::{self, Visitor};
+use core::fmt::{self, Formatter};
+
+/// The compact representation of HTML colours.
+#[derive(Deserialize, Serialize)]
+#[serde(rename = "Html")]
+struct Compact(Alpha<SRgb<u8>>);
+
+impl Serialize for Html {
+	#[inline]
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serialize_with(*self, self.display().lowercase(true), serializer)
+	}
+}
+
+impl<'de> Deserialize<'de> for Html {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		if deserializer.is_human_readable() {
+			deserializer.deserialize_str(HtmlVisitor)
+		} else {
+			let Compact(colour) = Compact::deserialize(deserializer)?;
+			Ok(Self::from_s_rgba(colour))
+		}
+	}
+}
+
+/// Serialises an HTML colour, using the given formatter in human-readable formats.
+pub(super) fn serialize_with<S: Serializer>(colour: Html, display: HtmlDisplay, serializer: S) -> Result<S::Ok, S::Error> {
+	if serializer.is_human_readable() {
+		serializer.collect_str(&display)
+	} else {
+		Compact(colour.to_s_rgba()).serialize(serializer)
+	}
+}
+
+/// The visitor of human-readable HTML colours.
+struct HtmlVisitor;
+
+impl Visitor<'_> for HtmlVisitor {
+	type Value = Html;
+
+	fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "an html colour")
+	}
+
+	fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+		v.parse().map_err(E::custom)
+	}
+}
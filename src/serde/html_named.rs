@@ -0,0 +1,45 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+//! Named representation of [`Html`].
+//!
+//! In human-readable formats, colours are serialised by their [CSS names](crate::www::NamedColours::CSS) where one exists, e.g. `"rebeccapurple"`, and otherwise in the shortest hexadecimal form (as in [`html_short`](super::html_short)).
+//! Compact formats, as well as deserialisation, are unaffected.
+
+use crate::www::Html;
+
+use ::serde::{Deserialize, Deserializer, Serializer};
+
+/// Serialises an HTML colour.
+///
+/// # Errors
+///
+/// Any error from the serialiser is returned.
+#[inline]
+pub fn serialize<S: Serializer>(colour: &Html, serializer: S) -> Result<S::Ok, S::Error> {
+	let display = colour
+		.display()
+		.lowercase(true)
+		.shorten(true)
+		.omit_opaque_alpha(true)
+		.named(true);
+
+	super::html::serialize_with(*colour, display, serializer)
+}
+
+/// Deserialises an HTML colour.
+///
+/// This is the same as the [`Deserialize`] implementation of [`Html`].
+///
+/// # Errors
+///
+/// If the colour cannot be deserialised, an error is returned.
+#[inline]
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Html, D::Error> {
+	Html::deserialize(deserializer)
+}
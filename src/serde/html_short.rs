@@ -0,0 +1,44 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+//! Shortest hexadecimal representation of [`Html`].
+//!
+//! In human-readable formats, colours are serialised in the shortest hexadecimal form, with the alpha channel omitted if opaque, e.g. `"#639"` or `"#66339980"`.
+//! Compact formats, as well as deserialisation, are unaffected.
+
+use crate::www::Html;
+
+use ::serde::{Deserialize, Deserializer, Serializer};
+
+/// Serialises an HTML colour.
+///
+/// # Errors
+///
+/// Any error from the serialiser is returned.
+#[inline]
+pub fn serialize<S: Serializer>(colour: &Html, serializer: S) -> Result<S::Ok, S::Error> {
+	let display = colour
+		.display()
+		.lowercase(true)
+		.shorten(true)
+		.omit_opaque_alpha(true);
+
+	super::html::serialize_with(*colour, display, serializer)
+}
+
+/// Deserialises an HTML colour.
+///
+/// This is the same as the [`Deserialize`] implementation of [`Html`].
+///
+/// # Errors
+///
+/// If the colour cannot be deserialised, an error is returned.
+#[inline]
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Html, D::Error> {
+	Html::deserialize(deserializer)
+}
@@ -0,0 +1,57 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+//! Serde representations.
+//!
+//! Colours implement [`Serialize`](::serde::Serialize) and [`Deserialize`](::serde::Deserialize) with representations that depend on whether the format is [human-readable](::serde::Serializer::is_human_readable):
+//!
+//! | Type                                  | Human-readable (e.g. JSON and TOML)       | Compact (e.g. bincode)       |
+//! | :------------------------------------ | :---------------------------------------- | :--------------------------- |
+//! | [`Html`](crate::www::Html)            | `"#663399ff"`                             | Nested tuples of bytes       |
+//! | [`OkLch`](crate::lch::OkLch) (etc.)   | `{ "l": 0.5, "c": 0.2, "h": 300.0 }`      | Tuples of channels           |
+//! | [`Alpha`](crate::Alpha)               | `{ "colour": ..., "alpha": 0.5 }`         | Tuples of colour and alpha   |
+//!
+//! The named channels are the initials of the channels in the order of each type's constructor, e.g. `r`, `g`, and `b` for [`SRgb`](crate::rgb::SRgb) and `h`, `s`, and `l` for [`Hsl`](crate::hsv::Hsl).
+//! Human-readable colours may also be deserialised from sequences of channels, and [`Html`](crate::www::Html) may be deserialised from any string that it can be [parsed](core::str::FromStr) from (e.g. `"#639"` and `"rebeccapurple"`).
+//!
+//! The submodules of this module provide alternative representations for use with `#[serde(with = "...")]`:
+//!
+//! ```
+//! use polywave::www::Html;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Deserialize, PartialEq, Serialize)]
+//! struct Theme {
+//!     // "#639"
+//!     #[serde(with = "polywave::serde::html_short")]
+//!     accent: Html,
+//!
+//!     // "rebeccapurple"
+//!     #[serde(with = "polywave::serde::html_named")]
+//!     background: Html,
+//! }
+//!
+//! let theme = Theme {
+//!     accent:     Html::REBECCA_PURPLE,
+//!     background: Html::REBECCA_PURPLE,
+//! };
+//!
+//! let json = serde_json::to_string(&theme)?;
+//! assert_eq!(json, r##"{"accent":"#639","background":"rebeccapurple"}"##);
+//!
+//! assert_eq!(serde_json::from_str::<Theme>(&json)?, theme);
+//! #
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+mod channels;
+mod html;
+mod test;
+
+pub mod html_named;
+pub mod html_short;
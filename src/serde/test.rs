@@ -0,0 +1,102 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+#![cfg(test)]
+
+use crate::lch::OkLch;
+use crate::rgb::SRgb;
+use crate::www::Html;
+
+use serde::Deserialize;
+use serde::de::value::{Error, MapDeserializer, SeqDeserializer, StrDeserializer};
+use serde_test::{assert_tokens, Configure, Token};
+
+#[test]
+fn test_serde_html() {
+	let deserialize = |s: &str| Html::deserialize(StrDeserializer::<Error>::new(s));
+
+	assert_eq!(deserialize("#663399FF"),     Ok(Html::REBECCA_PURPLE));
+	assert_eq!(deserialize("#639"),          Ok(Html::REBECCA_PURPLE));
+	assert_eq!(deserialize("rebeccapurple"), Ok(Html::REBECCA_PURPLE));
+
+	assert!(deserialize("#63").is_err());
+}
+
+#[test]
+fn test_serde_channels() {
+	let channels = [("l", 0.5), ("c", 0.25), ("h", 300.0)];
+
+	let colour = OkLch::<f64>::deserialize(MapDeserializer::<_, Error>::new(channels.into_iter()));
+	assert_eq!(colour, Ok(OkLch::new(0.5, 0.25, 300.0)));
+
+	let channels = [("r", 0x66), ("g", 0x33), ("b", 0x99)];
+
+	let colour = SRgb::<u8>::deserialize(MapDeserializer::<_, Error>::new(channels.into_iter()));
+	assert_eq!(colour, Ok(SRgb::new(0x66, 0x33, 0x99)));
+
+	let colour = SRgb::<u8>::deserialize(SeqDeserializer::<_, Error>::new([0x66_u8, 0x33, 0x99].into_iter()));
+	assert_eq!(colour, Ok(SRgb::new(0x66, 0x33, 0x99)));
+
+	let channels = [("l", 0.5), ("c", 0.25), ("hue", 300.0)];
+
+	assert!(OkLch::<f64>::deserialize(MapDeserializer::<_, Error>::new(channels.into_iter())).is_err());
+}
+
+#[test]
+fn test_serde_compact() {
+	assert_tokens(&SRgb::<u8>::new(0x66, 0x33, 0x99).compact(), &[
+		Token::NewtypeStruct { name: "SRgb" },
+		Token::NewtypeStruct { name: "Rgb" },
+		Token::Tuple { len: 0x3 },
+		Token::U8(0x66),
+		Token::U8(0x33),
+		Token::U8(0x99),
+		Token::TupleEnd,
+	]);
+
+	assert_tokens(&OkLch::<f64>::new(0.5, 0.25, 300.0).compact(), &[
+		Token::NewtypeStruct { name: "OkLch" },
+		Token::Tuple { len: 0x3 },
+		Token::F64(0.5),
+		Token::F64(0.25),
+		Token::F64(300.0),
+		Token::TupleEnd,
+	]);
+
+	assert_tokens(&Html::REBECCA_PURPLE.compact(), &[
+		Token::NewtypeStruct { name: "Html" },
+		Token::Struct { name: "Alpha", len: 0x2 },
+		Token::Str("colour"),
+		Token::NewtypeStruct { name: "SRgb" },
+		Token::NewtypeStruct { name: "Rgb" },
+		Token::Tuple { len: 0x3 },
+		Token::U8(0x66),
+		Token::U8(0x33),
+		Token::U8(0x99),
+		Token::TupleEnd,
+		Token::Str("alpha"),
+		Token::U8(0xFF),
+		Token::StructEnd,
+	]);
+}
+
+#[test]
+fn test_serde_readable() {
+	assert_tokens(&OkLch::<f64>::new(0.5, 0.25, 300.0).readable(), &[
+		Token::Struct { name: "OkLch", len: 0x3 },
+		Token::Str("l"),
+		Token::F64(0.5),
+		Token::Str("c"),
+		Token::F64(0.25),
+		Token::Str("h"),
+		Token::F64(300.0),
+		Token::StructEnd,
+	]);
+
+	assert_tokens(&Html::REBECCA_PURPLE.readable(), &[Token::Str("#663399ff")]);
+}
@@ -0,0 +1,112 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+/// One of the sixteen ANSI colours.
+///
+/// The actual colours are defined by the [palette](crate::terminal::AnsiPalette) of the terminal.
+/// The discriminant of each colour is its index in the palette, which is also its index among the 256 indexed colours.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum AnsiColour {
+	/// Black.
+	Black = 0x0,
+
+	/// Red.
+	Red = 0x1,
+
+	/// Green.
+	Green = 0x2,
+
+	/// Yellow.
+	Yellow = 0x3,
+
+	/// Blue.
+	Blue = 0x4,
+
+	/// Magenta.
+	Magenta = 0x5,
+
+	/// Cyan.
+	Cyan = 0x6,
+
+	/// White.
+	White = 0x7,
+
+	/// Bright black, i.e. grey.
+	BrightBlack = 0x8,
+
+	/// Bright red.
+	BrightRed = 0x9,
+
+	/// Bright green.
+	BrightGreen = 0xA,
+
+	/// Bright yellow.
+	BrightYellow = 0xB,
+
+	/// Bright blue.
+	BrightBlue = 0xC,
+
+	/// Bright magenta.
+	BrightMagenta = 0xD,
+
+	/// Bright cyan.
+	BrightCyan = 0xE,
+
+	/// Bright white.
+	BrightWhite = 0xF,
+}
+
+impl AnsiColour {
+	/// All ANSI colours, in order of their indices.
+	pub const ALL: [Self; 0x10] = [
+		Self::Black,
+		Self::Red,
+		Self::Green,
+		Self::Yellow,
+		Self::Blue,
+		Self::Magenta,
+		Self::Cyan,
+		Self::White,
+		Self::BrightBlack,
+		Self::BrightRed,
+		Self::BrightGreen,
+		Self::BrightYellow,
+		Self::BrightBlue,
+		Self::BrightMagenta,
+		Self::BrightCyan,
+		Self::BrightWhite,
+	];
+
+	/// Retrieves the ANSI colour with the given index.
+	///
+	/// If the index is not less than `16`, [`None`] is returned.
+	#[inline]
+	#[must_use]
+	pub const fn from_index(index: u8) -> Option<Self> {
+		if index < 0x10 {
+			Some(Self::ALL[index as usize])
+		} else {
+			None
+		}
+	}
+
+	/// Retrieves the index of the ANSI colour.
+	#[inline(always)]
+	#[must_use]
+	pub const fn index(self) -> u8 {
+		self as u8
+	}
+
+	/// Checks whether the ANSI colour is one of the bright colours.
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_bright(self) -> bool {
+		self.index() >= 0x8
+	}
+}
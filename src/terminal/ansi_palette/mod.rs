@@ -0,0 +1,238 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+#[cfg(feature = "maths")]
+use crate::FloatComponent;
+#[cfg(feature = "maths")]
+use crate::lab::OkLab;
+#[cfg(feature = "maths")]
+use crate::rgb::SRgb;
+use crate::terminal::AnsiColour;
+use crate::www::Html;
+
+/// A palette of the sixteen ANSI colours.
+///
+/// Terminals differ in which colours they display for the [ANSI colours](AnsiColour), and these are commonly configurable by the user.
+/// This type provides the default palettes of a selection of terminals, and palettes may also be constructed from arbitrary colours.
+///
+/// The palette also determines the first sixteen of the 256 [indexed colours](Self::indexed).
+///
+/// # Examples
+///
+/// ```
+/// use polywave::terminal::{AnsiColour, AnsiPalette};
+/// use polywave::www::Html;
+///
+/// assert_eq!(AnsiPalette::VGA.get(AnsiColour::Yellow), Html::from_u32(0xAA5500FF));
+/// assert_eq!(AnsiPalette::XTERM.indexed(0xD0),         Html::from_u32(0xFF8700FF));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AnsiPalette {
+	colours: [Html; 0x10],
+}
+
+impl AnsiPalette {
+	/// The default palette of xterm.
+	pub const XTERM: Self = Self::new([
+		Html::from_u32(0x000000FF), Html::from_u32(0xCD0000FF), Html::from_u32(0x00CD00FF), Html::from_u32(0xCDCD00FF),
+		Html::from_u32(0x0000EEFF), Html::from_u32(0xCD00CDFF), Html::from_u32(0x00CDCDFF), Html::from_u32(0xE5E5E5FF),
+		Html::from_u32(0x7F7F7FFF), Html::from_u32(0xFF0000FF), Html::from_u32(0x00FF00FF), Html::from_u32(0xFFFF00FF),
+		Html::from_u32(0x5C5CFFFF), Html::from_u32(0xFF00FFFF), Html::from_u32(0x00FFFFFF), Html::from_u32(0xFFFFFFFF),
+	]);
+
+	/// The palette of the VGA text mode.
+	///
+	/// This is also the default palette of the Linux console.
+	pub const VGA: Self = Self::new([
+		Html::from_u32(0x000000FF), Html::from_u32(0xAA0000FF), Html::from_u32(0x00AA00FF), Html::from_u32(0xAA5500FF),
+		Html::from_u32(0x0000AAFF), Html::from_u32(0xAA00AAFF), Html::from_u32(0x00AAAAFF), Html::from_u32(0xAAAAAAFF),
+		Html::from_u32(0x555555FF), Html::from_u32(0xFF5555FF), Html::from_u32(0x55FF55FF), Html::from_u32(0xFFFF55FF),
+		Html::from_u32(0x5555FFFF), Html::from_u32(0xFF55FFFF), Html::from_u32(0x55FFFFFF), Html::from_u32(0xFFFFFFFF),
+	]);
+
+	/// The palette of the Windows console up to Windows 10.
+	pub const WINDOWS_XP: Self = Self::new([
+		Html::from_u32(0x000000FF), Html::from_u32(0x800000FF), Html::from_u32(0x008000FF), Html::from_u32(0x808000FF),
+		Html::from_u32(0x000080FF), Html::from_u32(0x800080FF), Html::from_u32(0x008080FF), Html::from_u32(0xC0C0C0FF),
+		Html::from_u32(0x808080FF), Html::from_u32(0xFF0000FF), Html::from_u32(0x00FF00FF), Html::from_u32(0xFFFF00FF),
+		Html::from_u32(0x0000FFFF), Html::from_u32(0xFF00FFFF), Html::from_u32(0x00FFFFFF), Html::from_u32(0xFFFFFFFF),
+	]);
+
+	/// The "Campbell" palette of the Windows console since Windows 10.
+	///
+	/// This is also the default palette of Windows Terminal.
+	pub const WINDOWS_10: Self = Self::new([
+		Html::from_u32(0x0C0C0CFF), Html::from_u32(0xC50F1FFF), Html::from_u32(0x13A10EFF), Html::from_u32(0xC19C00FF),
+		Html::from_u32(0x0037DAFF), Html::from_u32(0x881798FF), Html::from_u32(0x3A96DDFF), Html::from_u32(0xCCCCCCFF),
+		Html::from_u32(0x767676FF), Html::from_u32(0xE74856FF), Html::from_u32(0x16C60CFF), Html::from_u32(0xF9F1A5FF),
+		Html::from_u32(0x3B78FFFF), Html::from_u32(0xB4009EFF), Html::from_u32(0x61D6D6FF), Html::from_u32(0xF2F2F2FF),
+	]);
+
+	/// The default palette of Terminal.app on macOS.
+	pub const TERMINAL_APP: Self = Self::new([
+		Html::from_u32(0x000000FF), Html::from_u32(0xC23621FF), Html::from_u32(0x25BC24FF), Html::from_u32(0xADAD27FF),
+		Html::from_u32(0x492EE1FF), Html::from_u32(0xD338D3FF), Html::from_u32(0x33BBC8FF), Html::from_u32(0xCBCCCDFF),
+		Html::from_u32(0x818383FF), Html::from_u32(0xFC391FFF), Html::from_u32(0x31E722FF), Html::from_u32(0xEAEC23FF),
+		Html::from_u32(0x5833FFFF), Html::from_u32(0xF935F8FF), Html::from_u32(0x14F0F0FF), Html::from_u32(0xE9EBEBFF),
+	]);
+
+	/// The default palette of PuTTY.
+	pub const PUTTY: Self = Self::new([
+		Html::from_u32(0x000000FF), Html::from_u32(0xBB0000FF), Html::from_u32(0x00BB00FF), Html::from_u32(0xBBBB00FF),
+		Html::from_u32(0x0000BBFF), Html::from_u32(0xBB00BBFF), Html::from_u32(0x00BBBBFF), Html::from_u32(0xBBBBBBFF),
+		Html::from_u32(0x555555FF), Html::from_u32(0xFF5555FF), Html::from_u32(0x55FF55FF), Html::from_u32(0xFFFF55FF),
+		Html::from_u32(0x5555FFFF), Html::from_u32(0xFF55FFFF), Html::from_u32(0x55FFFFFF), Html::from_u32(0xFFFFFFFF),
+	]);
+
+	/// The default palette of the integrated terminal of Visual Studio Code.
+	pub const VS_CODE: Self = Self::new([
+		Html::from_u32(0x000000FF), Html::from_u32(0xCD3131FF), Html::from_u32(0x0DBC79FF), Html::from_u32(0xE5E510FF),
+		Html::from_u32(0x2472C8FF), Html::from_u32(0xBC3FBCFF), Html::from_u32(0x11A8CDFF), Html::from_u32(0xE5E5E5FF),
+		Html::from_u32(0x666666FF), Html::from_u32(0xF14C4CFF), Html::from_u32(0x23D18BFF), Html::from_u32(0xF5F543FF),
+		Html::from_u32(0x3B8EEAFF), Html::from_u32(0xD670D6FF), Html::from_u32(0x29B8DBFF), Html::from_u32(0xE5E5E5FF),
+	]);
+
+	/// The levels of each channel in the 6x6x6 colour cube of the indexed colours.
+	const CUBE_LEVELS: [u8; 0x6] = [0x00, 0x5F, 0x87, 0xAF, 0xD7, 0xFF];
+
+	/// Constructs a new palette.
+	///
+	/// The colours are given in order of their [indices](AnsiColour::index).
+	#[inline(always)]
+	#[must_use]
+	pub const fn new(colours: [Html; 0x10]) -> Self {
+		Self { colours }
+	}
+
+	/// Retrieves the colours of the palette.
+	#[inline(always)]
+	#[must_use]
+	pub const fn colours(self) -> [Html; 0x10] {
+		self.colours
+	}
+
+	/// Retrieves the colour of an ANSI colour.
+	#[inline(always)]
+	#[must_use]
+	pub const fn get(self, colour: AnsiColour) -> Html {
+		self.colours[colour.index() as usize]
+	}
+
+	/// Retrieves the colour of an indexed colour.
+	///
+	/// Indices `0` through `15` denote the colours of the palette, `16` through `231` denote the 6x6x6 colour cube, and `232` through `255` denote the greyscale ramp from `#080808` to `#EEEEEE`.
+	#[must_use]
+	pub const fn indexed(self, index: u8) -> Html {
+		match index {
+			0x00..=0x0F => self.colours[index as usize],
+
+			0x10..=0xE7 => {
+				let index = index - 0x10;
+
+				let red   = Self::CUBE_LEVELS[(index / 0x24) as usize];
+				let green = Self::CUBE_LEVELS[(index / 0x6 % 0x6) as usize];
+				let blue  = Self::CUBE_LEVELS[(index % 0x6) as usize];
+
+				Html::new(red, green, blue, 0xFF)
+			}
+
+			0xE8..=0xFF => {
+				let level = 0x8 + (index - 0xE8) * 0xA;
+
+				Html::new(level, level, level, 0xFF)
+			}
+		}
+	}
+
+	/// Searches for the ANSI colour that is perceptually closest to a colour.
+	///
+	/// The distance is measured as the [Oklab difference](OkLab::delta_e_ok), and the alpha channel of the colour is ignored.
+	/// See [`nearest_s_rgb`](Self::nearest_s_rgb) for colours with floating-point channels.
+	#[cfg(feature = "maths")]
+	#[inline]
+	#[must_use]
+	pub fn nearest(self, colour: Html) -> AnsiColour {
+		self.nearest_s_rgb(html_to_s_rgb(colour))
+	}
+
+	/// Searches for the ANSI colour that is perceptually closest to an sRGB colour.
+	///
+	/// The colour need not be in the sRGB gamut.
+	/// See also [`nearest`](Self::nearest).
+	#[cfg(feature = "maths")]
+	#[must_use]
+	pub fn nearest_s_rgb<T: FloatComponent>(self, colour: SRgb<T>) -> AnsiColour {
+		let index = nearest_index(colour, AnsiColour::ALL.map(|colour| self.get(colour)));
+
+		AnsiColour::ALL[index]
+	}
+
+	/// Searches for the indexed colour that is perceptually closest to a colour.
+	///
+	/// All 256 indexed colours are considered, including those of the palette.
+	/// The distance is measured as the [Oklab difference](OkLab::delta_e_ok), and the alpha channel of the colour is ignored.
+	/// See [`nearest_indexed_s_rgb`](Self::nearest_indexed_s_rgb) for colours with floating-point channels.
+	///
+	/// Note that users commonly reconfigure the colours of indices `0` through `15`.
+	/// If the palette of the terminal is not known, then the returned index may therefore display as a different colour, whereas the colour cube and greyscale ramp are fixed.
+	#[cfg(feature = "maths")]
+	#[inline]
+	#[must_use]
+	pub fn nearest_indexed(self, colour: Html) -> u8 {
+		self.nearest_indexed_s_rgb(html_to_s_rgb(colour))
+	}
+
+	/// Searches for the indexed colour that is perceptually closest to an sRGB colour.
+	///
+	/// The colour need not be in the sRGB gamut.
+	/// See also [`nearest_indexed`](Self::nearest_indexed).
+	#[cfg(feature = "maths")]
+	#[must_use]
+	pub fn nearest_indexed_s_rgb<T: FloatComponent>(self, colour: SRgb<T>) -> u8 {
+		let mut colours = [Html::BLACK; 0x100];
+
+		for (index, entry) in (0x0..=0xFF).zip(&mut colours) {
+			*entry = self.indexed(index);
+		}
+
+		let index = nearest_index(colour, colours);
+
+		// There are exactly 256 candidates.
+		u8::try_from(index).unwrap_or_default()
+	}
+}
+
+impl Default for AnsiPalette {
+	#[inline(always)]
+	fn default() -> Self {
+		Self::XTERM
+	}
+}
+
+/// Converts an HTML colour to sRGB, discarding its alpha channel.
+#[cfg(feature = "maths")]
+#[inline]
+#[must_use]
+fn html_to_s_rgb(colour: Html) -> SRgb<f64> {
+	let (colour, _) = colour.to_s_rgba().detach();
+	SRgb::<f64>::from_u8(colour)
+}
+
+/// Searches for the index of the candidate that is perceptually closest to a colour.
+#[cfg(feature = "maths")]
+#[must_use]
+fn nearest_index<T: FloatComponent, const N: usize>(colour: SRgb<T>, candidates: [Html; N]) -> usize {
+	let target = OkLab::<f64>::from_s_rgb(colour.map(FloatComponent::to_f64));
+
+	candidates
+		.into_iter()
+		.map(|candidate| target.delta_e_ok(OkLab::<f64>::from_s_rgb(html_to_s_rgb(candidate))))
+		.enumerate()
+		.min_by(|(_, lhs), (_, rhs)| lhs.total_cmp(rhs))
+		.map_or(0x0, |(index, _)| index)
+}
@@ -0,0 +1,297 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::error::TerminalSchemeError;
+use crate::terminal::{AnsiPalette, TerminalScheme};
+use crate::terminal::terminal_scheme::parse_hexadecimal;
+use crate::www::Html;
+
+use alloc::string::String;
+use core::fmt::Write;
+
+/// The keys of the Base24 colours.
+///
+/// The first sixteen of these are also the keys of the Base16 colours.
+const KEYS: [&str; 0x18] = [
+	"base00", "base01", "base02", "base03", "base04", "base05", "base06", "base07",
+	"base08", "base09", "base0A", "base0B", "base0C", "base0D", "base0E", "base0F",
+	"base10", "base11", "base12", "base13", "base14", "base15", "base16", "base17",
+];
+
+/// The Base16 colours of the ANSI colours.
+const BASE16_ANSI: [usize; 0x10] = [
+	0x00, 0x08, 0x0B, 0x0A, 0x0D, 0x0E, 0x0C, 0x05,
+	0x03, 0x08, 0x0B, 0x0A, 0x0D, 0x0E, 0x0C, 0x07,
+];
+
+/// The Base24 colours of the ANSI colours.
+const BASE24_ANSI: [usize; 0x10] = [
+	0x00, 0x08, 0x0B, 0x0A, 0x0D, 0x0E, 0x0C, 0x06,
+	0x02, 0x12, 0x14, 0x13, 0x16, 0x17, 0x15, 0x07,
+];
+
+/// A Base16 or Base24 scheme.
+///
+/// [Base16](https://github.com/chriskempson/base16) schemes consist of sixteen colours (`base00` through `base0F`), which are mapped to terminal and editor colours by their role.
+/// Base24 schemes extend these by eight colours (`base10` through `base17`) of which most are bright variants for use as terminal colours.
+///
+/// # Examples
+///
+/// ```
+/// use polywave::terminal::{AnsiColour, BaseScheme};
+/// use polywave::www::Html;
+///
+/// let yaml = "\
+/// scheme: \"Default Dark\"
+/// author: \"Chris Kempson\"
+/// base00: \"181818\"
+/// base01: \"282828\"
+/// base02: \"383838\"
+/// base03: \"585858\"
+/// base04: \"b8b8b8\"
+/// base05: \"d8d8d8\"
+/// base06: \"e8e8e8\"
+/// base07: \"f8f8f8\"
+/// base08: \"ab4642\"
+/// base09: \"dc9656\"
+/// base0A: \"f7ca88\"
+/// base0B: \"a1b56c\"
+/// base0C: \"86c1b9\"
+/// base0D: \"7cafc2\"
+/// base0E: \"ba8baf\"
+/// base0F: \"a16946\"
+/// ";
+///
+/// let scheme = BaseScheme::from_yaml(yaml).unwrap();
+///
+/// assert_eq!(scheme.name(), "Default Dark");
+/// assert_eq!(scheme.to_yaml(), yaml);
+///
+/// let scheme = scheme.to_terminal_scheme();
+///
+/// assert_eq!(scheme.palette().get(AnsiColour::Red), Html::from_u32(0xAB4642FF));
+/// assert_eq!(scheme.background(),                   Html::from_u32(0x181818FF));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BaseScheme {
+	name:   String,
+	author: String,
+
+	colours:   [Html; 0x18],
+	is_base24: bool,
+}
+
+impl BaseScheme {
+	/// Constructs a new Base16 scheme.
+	#[inline]
+	#[must_use]
+	pub fn base16(name: String, author: String, colours: [Html; 0x10]) -> Self {
+		let mut base = [Html::BLACK; 0x18];
+		base[..0x10].copy_from_slice(&colours);
+
+		Self { name, author, colours: base, is_base24: false }
+	}
+
+	/// Constructs a new Base24 scheme.
+	#[inline(always)]
+	#[must_use]
+	pub const fn base24(name: String, author: String, colours: [Html; 0x18]) -> Self {
+		Self { name, author, colours, is_base24: true }
+	}
+
+	/// Retrieves the name of the scheme.
+	#[inline(always)]
+	#[must_use]
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// Retrieves the author of the scheme.
+	#[inline(always)]
+	#[must_use]
+	pub fn author(&self) -> &str {
+		&self.author
+	}
+
+	/// Retrieves the colours of the scheme.
+	///
+	/// These are sixteen colours for Base16 schemes and 24 colours for Base24 schemes.
+	#[inline]
+	#[must_use]
+	pub fn colours(&self) -> &[Html] {
+		let count = if self.is_base24 { 0x18 } else { 0x10 };
+		&self.colours[..count]
+	}
+
+	/// Checks whether the scheme is a Base24 scheme.
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_base24(&self) -> bool {
+		self.is_base24
+	}
+
+	/// Converts the scheme to a terminal scheme.
+	///
+	/// The colours are mapped as by the reference terminal templates of Base16 and Base24, with `base05` as the foreground and cursor colours, `base00` as the background colour, and `base02` as the selection colour.
+	/// For Base16 schemes, the bright ANSI colours are the same as their normal counterparts (except for black and white).
+	#[must_use]
+	pub fn to_terminal_scheme(&self) -> TerminalScheme {
+		let ansi = if self.is_base24 { BASE24_ANSI } else { BASE16_ANSI };
+
+		let palette = AnsiPalette::new(ansi.map(|index| self.colours[index]));
+
+		let mut scheme = TerminalScheme::new(self.name.clone(), palette, self.colours[0x05], self.colours[0x00]);
+
+		scheme.set_cursor(Some(self.colours[0x05]));
+		scheme.set_selection(Some(self.colours[0x02]));
+
+		scheme
+	}
+
+	/// Reads a scheme from YAML.
+	///
+	/// Both the flat layout of the original Base16 specification and the layout of newer schemes (in which the colours are nested in a `palette` mapping) are supported.
+	/// The name is read from either a `scheme` or `name` key, and colours are given as six hexadecimal digits (optionally prefixed by `#`).
+	/// All other keys are ignored.
+	///
+	/// If any of `base10` through `base17` are present, the scheme is read as a Base24 scheme.
+	///
+	/// # Errors
+	///
+	/// If a colour is malformed or missing, an error is returned.
+	pub fn from_yaml(s: &str) -> Result<Self, TerminalSchemeError> {
+		let mut name   = String::new();
+		let mut author = String::new();
+
+		let mut colours = [None; 0x18];
+
+		for (index, line) in s.lines().enumerate() {
+			let line = line.trim();
+
+			if line.starts_with('#') {
+				continue;
+			}
+
+			let Some((key, value)) = line.split_once(':') else {
+				continue;
+			};
+
+			let key   = key.trim().trim_matches(['"', '\'']);
+			let value = parse_scalar(value.trim());
+
+			match key {
+				"scheme" | "name" => name = value,
+
+				"author" => author = value,
+
+				_ => {
+					let Some(slot) = KEYS.iter().position(|other| other.eq_ignore_ascii_case(key)) else {
+						continue;
+					};
+
+					let colour = parse_hexadecimal(&value)
+						.ok_or(TerminalSchemeError::InvalidColour { line: index + 0x1 })?;
+
+					colours[slot] = Some(colour);
+				}
+			}
+		}
+
+		let is_base24 = colours[0x10..].iter().any(Option::is_some);
+		let count     = if is_base24 { 0x18 } else { 0x10 };
+
+		let mut base = [Html::BLACK; 0x18];
+
+		for (slot, colour) in base.iter_mut().enumerate().take(count) {
+			*colour = colours[slot].ok_or(TerminalSchemeError::MissingColour { name: KEYS[slot] })?;
+		}
+
+		Ok(Self { name, author, colours: base, is_base24 })
+	}
+
+	/// Writes the scheme as YAML.
+	///
+	/// The flat layout of the original Base16 specification is used, with colours written as six lowercase hexadecimal digits.
+	#[must_use]
+	pub fn to_yaml(&self) -> String {
+		let mut s = String::new();
+
+		s.push_str("scheme: ");
+		push_quoted(&mut s, &self.name);
+
+		s.push_str("\nauthor: ");
+		push_quoted(&mut s, &self.author);
+
+		s.push('\n');
+
+		for (key, colour) in KEYS.iter().zip(self.colours()) {
+			// Writing to strings cannot fail.
+			let _ = writeln!(s, "{key}: \"{:06x}\"", colour.to_u32() >> 0x8);
+		}
+
+		s
+	}
+}
+
+/// Parses a YAML scalar.
+///
+/// Double-quoted scalars may contain the `\"` and `\\` escapes, and single-quoted scalars may contain the `''` escape.
+/// Comments are removed from plain scalars.
+#[must_use]
+fn parse_scalar(s: &str) -> String {
+	if let Some(rest) = s.strip_prefix('"') {
+		let mut value = String::new();
+
+		let mut chars = rest.chars();
+
+		while let Some(c) = chars.next() {
+			match c {
+				'"'  => break,
+				'\\' => value.extend(chars.next()),
+
+				c => value.push(c),
+			}
+		}
+
+		return value;
+	}
+
+	if let Some(rest) = s.strip_prefix('\'') {
+		let mut value = String::new();
+
+		let mut chars = rest.chars().peekable();
+
+		while let Some(c) = chars.next() {
+			if c == '\'' && chars.next_if_eq(&'\'').is_none() {
+				break;
+			}
+
+			value.push(c);
+		}
+
+		return value;
+	}
+
+	let end = s.find(" #").unwrap_or(s.len());
+	String::from(s[..end].trim())
+}
+
+/// Writes a double-quoted YAML scalar.
+fn push_quoted(s: &mut String, value: &str) {
+	s.push('"');
+
+	for c in value.chars() {
+		if matches!(c, '"' | '\\') {
+			s.push('\\');
+		}
+
+		s.push(c);
+	}
+
+	s.push('"');
+}
@@ -0,0 +1,49 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+#[cfg(feature = "maths")]
+use crate::terminal::{AnsiPalette, TerminalColour};
+#[cfg(feature = "maths")]
+use crate::www::Html;
+
+/// A colour depth supported by a terminal.
+///
+/// The depths are ordered from least to most colours.
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub enum ColourDepth {
+	/// The sixteen ANSI colours.
+	Ansi,
+
+	/// The 256 indexed colours of xterm.
+	Indexed,
+
+	/// 24-bit RGB colours.
+	#[default]
+	TrueColour,
+}
+
+impl ColourDepth {
+	/// Quantises a colour to the colour depth.
+	///
+	/// For the [ANSI](Self::Ansi) and [indexed](Self::Indexed) depths, the perceptually closest colour in the given palette is chosen (see [`AnsiPalette::nearest`] and [`AnsiPalette::nearest_indexed`]).
+	/// The alpha channel of the colour is ignored.
+	#[cfg(feature = "maths")]
+	#[must_use]
+	pub fn quantise(self, colour: Html, palette: AnsiPalette) -> TerminalColour {
+		match self {
+			Self::Ansi => TerminalColour::Ansi(palette.nearest(colour)),
+
+			Self::Indexed => TerminalColour::Indexed(palette.nearest_indexed(colour)),
+
+			Self::TrueColour => {
+				let (colour, _) = colour.to_s_rgba().detach();
+				TerminalColour::Rgb(colour)
+			}
+		}
+	}
+}
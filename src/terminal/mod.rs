@@ -0,0 +1,53 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+//! Terminal-related colour facilities.
+//!
+//! Terminals support colours at one of three [depths](ColourDepth): the sixteen [ANSI colours](AnsiColour), the 256 indexed colours of xterm, or 24-bit "true colour."
+//! The actual colours of the former two depend on the [palette](AnsiPalette) of the terminal.
+//!
+//! Colours can be quantised to each depth and written as SGR ("Select Graphic Rendition") escape sequences:
+//!
+//! ```
+//! # #[cfg(feature = "maths")]
+//! # {
+//! use polywave::terminal::{AnsiPalette, ColourDepth};
+//! use polywave::www::Html;
+//!
+//! let colour = Html::from_u32(0xFF5F00FF);
+//!
+//! let sgr = |depth: ColourDepth| depth.quantise(colour, AnsiPalette::XTERM).foreground().to_string();
+//!
+//! assert_eq!(sgr(ColourDepth::Ansi),       "\x1B[91m");
+//! assert_eq!(sgr(ColourDepth::Indexed),    "\x1B[38;5;202m");
+//! assert_eq!(sgr(ColourDepth::TrueColour), "\x1B[38;2;255;95;0m");
+//! # }
+//! ```
+//!
+//! Colour schemes of terminals and editors can furthermore be read and written as [terminal schemes](TerminalScheme) and [Base16 schemes](BaseScheme) (requires the `alloc` feature).
+
+mod ansi_colour;
+mod ansi_palette;
+#[cfg(feature = "alloc")]
+mod base_scheme;
+mod colour_depth;
+mod sgr;
+mod terminal_colour;
+#[cfg(feature = "alloc")]
+mod terminal_scheme;
+mod test;
+
+pub use ansi_colour::AnsiColour;
+pub use ansi_palette::AnsiPalette;
+#[cfg(feature = "alloc")]
+pub use base_scheme::BaseScheme;
+pub use colour_depth::ColourDepth;
+pub use sgr::Sgr;
+pub use terminal_colour::TerminalColour;
+#[cfg(feature = "alloc")]
+pub use terminal_scheme::TerminalScheme;
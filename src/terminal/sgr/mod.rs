@@ -0,0 +1,87 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::terminal::TerminalColour;
+
+use core::fmt::{self, Display, Formatter};
+
+/// An SGR ("Select Graphic Rendition") escape sequence setting a terminal colour.
+///
+/// This type is constructed by [`TerminalColour::foreground`] and [`TerminalColour::background`] and implements [`Display`].
+/// The sequences are written as follows:
+///
+/// | Colour                             | Foreground                 | Background                 |
+/// | :--------------------------------- | :------------------------- | :------------------------- |
+/// | [Default](TerminalColour::Default) | `CSI 39 m`                 | `CSI 49 m`                 |
+/// | [ANSI](TerminalColour::Ansi)       | `CSI 30..=37 m`            | `CSI 40..=47 m`            |
+/// | [ANSI](TerminalColour::Ansi)       | `CSI 90..=97 m` (bright)   | `CSI 100..=107 m` (bright) |
+/// | [Indexed](TerminalColour::Indexed) | `CSI 38 ; 5 ; n m`         | `CSI 48 ; 5 ; n m`         |
+/// | [RGB](TerminalColour::Rgb)         | `CSI 38 ; 2 ; r ; g ; b m` | `CSI 48 ; 2 ; r ; g ; b m` |
+///
+/// Here, `CSI` denotes the control sequence introducer `ESC [`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[must_use]
+pub struct Sgr {
+	colour:     TerminalColour,
+	background: bool,
+}
+
+impl Sgr {
+	/// Constructs a new escape sequence.
+	///
+	/// If `background` is `true`, the sequence sets the background colour; otherwise, it sets the foreground colour.
+	#[inline(always)]
+	pub const fn new(colour: TerminalColour, background: bool) -> Self {
+		Self { colour, background }
+	}
+
+	/// Retrieves the colour set by the escape sequence.
+	#[inline(always)]
+	#[must_use]
+	pub const fn colour(self) -> TerminalColour {
+		self.colour
+	}
+
+	/// Checks whether the escape sequence sets the background colour.
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_background(self) -> bool {
+		self.background
+	}
+}
+
+impl Display for Sgr {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		let offset = if self.background { 0xA } else { 0x0 };
+
+		match self.colour {
+			TerminalColour::Default => write!(f, "\x1B[{}m", 39 + offset),
+
+			TerminalColour::Ansi(colour) => {
+				let index = colour.index();
+
+				let code = if colour.is_bright() {
+					90 + offset + (index - 0x8)
+				} else {
+					30 + offset + index
+				};
+
+				write!(f, "\x1B[{code}m")
+			}
+
+			TerminalColour::Indexed(index) => write!(f, "\x1B[{};5;{index}m", 38 + offset),
+
+			TerminalColour::Rgb(colour) => {
+				let (red, green, blue) = colour.get();
+
+				write!(f, "\x1B[{};2;{red};{green};{blue}m", 38 + offset)
+			}
+		}
+	}
+}
@@ -0,0 +1,81 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::rgb::SRgb;
+use crate::terminal::{AnsiColour, ColourDepth, Sgr};
+
+/// A terminal colour.
+///
+/// Terminal colours are written as SGR escape sequences using [`foreground`](Self::foreground) and [`background`](Self::background).
+///
+/// # Examples
+///
+/// ```
+/// use polywave::terminal::{AnsiColour, TerminalColour};
+///
+/// assert_eq!(TerminalColour::Ansi(AnsiColour::Red).foreground().to_string(), "\x1B[31m");
+/// assert_eq!(TerminalColour::Indexed(0xD0).background().to_string(),        "\x1B[48;5;208m");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum TerminalColour {
+	/// The default colour of the terminal.
+	#[default]
+	Default,
+
+	/// One of the sixteen ANSI colours.
+	Ansi(AnsiColour),
+
+	/// One of the 256 indexed colours.
+	Indexed(u8),
+
+	/// A 24-bit RGB colour.
+	Rgb(SRgb<u8>),
+}
+
+impl TerminalColour {
+	/// Retrieves the minimum colour depth required by the colour.
+	///
+	/// For the [default](Self::Default) colour, this is [`ColourDepth::Ansi`].
+	#[inline]
+	#[must_use]
+	pub const fn depth(self) -> ColourDepth {
+		match self {
+			Self::Default | Self::Ansi(_) => ColourDepth::Ansi,
+
+			Self::Indexed(_) => ColourDepth::Indexed,
+
+			Self::Rgb(_) => ColourDepth::TrueColour,
+		}
+	}
+
+	/// Constructs an SGR escape sequence setting the foreground colour.
+	#[inline(always)]
+	pub const fn foreground(self) -> Sgr {
+		Sgr::new(self, false)
+	}
+
+	/// Constructs an SGR escape sequence setting the background colour.
+	#[inline(always)]
+	pub const fn background(self) -> Sgr {
+		Sgr::new(self, true)
+	}
+}
+
+impl From<AnsiColour> for TerminalColour {
+	#[inline(always)]
+	fn from(value: AnsiColour) -> Self {
+		Self::Ansi(value)
+	}
+}
+
+impl From<SRgb<u8>> for TerminalColour {
+	#[inline(always)]
+	fn from(value: SRgb<u8>) -> Self {
+		Self::Rgb(value)
+	}
+}
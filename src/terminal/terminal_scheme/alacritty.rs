@@ -0,0 +1,149 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::error::TerminalSchemeError;
+use crate::terminal::TerminalScheme;
+use crate::terminal::terminal_scheme::{parse_hexadecimal, Slots, CURSOR, SLOT_COUNT};
+
+use alloc::string::String;
+use core::fmt::Write;
+
+/// The keys of the colours in Alacritty configurations.
+const KEYS: [&str; SLOT_COUNT] = [
+	"colors.normal.black",
+	"colors.normal.red",
+	"colors.normal.green",
+	"colors.normal.yellow",
+	"colors.normal.blue",
+	"colors.normal.magenta",
+	"colors.normal.cyan",
+	"colors.normal.white",
+	"colors.bright.black",
+	"colors.bright.red",
+	"colors.bright.green",
+	"colors.bright.yellow",
+	"colors.bright.blue",
+	"colors.bright.magenta",
+	"colors.bright.cyan",
+	"colors.bright.white",
+	"colors.primary.foreground",
+	"colors.primary.background",
+	"colors.cursor.cursor",
+	"colors.selection.background",
+];
+
+/// Removes any comment from a TOML line.
+#[must_use]
+fn strip_comment(line: &str) -> &str {
+	let mut quote = None;
+
+	for (index, c) in line.char_indices() {
+		match (quote, c) {
+			(None, '"' | '\'') => quote = Some(c),
+			(None, '#')        => return &line[..index],
+
+			(Some(other), c) if c == other => quote = None,
+
+			_ => { }
+		}
+	}
+
+	line
+}
+
+impl TerminalScheme {
+	/// Reads an Alacritty scheme.
+	///
+	/// Alacritty schemes are the `[colors.primary]`, `[colors.normal]`, `[colors.bright]`, `[colors.cursor]`, and `[colors.selection]` tables of its TOML configuration, with the colours given as `#RRGGBB` or `0xRRGGBB` strings.
+	/// All other tables and keys are ignored, as are cursor and selection colours that are not literal colours (e.g. `CellForeground`).
+	///
+	/// As Alacritty schemes are unnamed, the name of the returned scheme is empty.
+	///
+	/// # Errors
+	///
+	/// If a colour is malformed, or if any of the ANSI, foreground, or background colours are missing, an error is returned.
+	pub fn from_alacritty(s: &str) -> Result<Self, TerminalSchemeError> {
+		let mut table = "";
+		let mut slots: Slots = [None; SLOT_COUNT];
+
+		for (index, line) in s.lines().enumerate() {
+			let line = strip_comment(line).trim();
+
+			if let Some(header) = line.strip_prefix('[') {
+				let header = header.trim_start_matches('[').trim_end_matches(']');
+
+				table = header.trim();
+				continue;
+			}
+
+			let Some((key, value)) = line.split_once('=') else {
+				continue;
+			};
+
+			let key   = key.trim();
+			let value = value.trim();
+
+			let Some(slot) = KEYS.iter().position(|&other| {
+				other
+					.strip_prefix(table)
+					.and_then(|other| if table.is_empty() { Some(other) } else { other.strip_prefix('.') })
+					.is_some_and(|other| other == key)
+			}) else {
+				continue;
+			};
+
+			let colour = value
+				.strip_prefix(['"', '\''])
+				.and_then(|value| value.strip_suffix(['"', '\'']))
+				.and_then(parse_hexadecimal);
+
+			match colour {
+				Some(colour) => slots[slot] = Some(colour),
+
+				None if slot >= CURSOR => { }
+
+				None => return Err(TerminalSchemeError::InvalidColour { line: index + 0x1 }),
+			}
+		}
+
+		Self::from_slots(String::new(), slots, &KEYS)
+	}
+
+	/// Writes the scheme as an Alacritty scheme.
+	///
+	/// The name of the scheme is not written.
+	#[must_use]
+	pub fn to_alacritty(&self) -> String {
+		let slots = self.to_slots();
+
+		let mut s = String::new();
+
+		let mut table = "";
+
+		for (key, colour) in KEYS.iter().zip(slots) {
+			let Some(colour) = colour else { continue };
+
+			let Some((header, key)) = key.rsplit_once('.') else { continue };
+
+			if header != table {
+				if !table.is_empty() {
+					s.push('\n');
+				}
+
+				// Writing to strings cannot fail.
+				let _ = writeln!(s, "[{header}]");
+
+				table = header;
+			}
+
+			let _ = writeln!(s, "{key} = \"#{:06x}\"", colour.to_u32() >> 0x8);
+		}
+
+		s
+	}
+}
@@ -0,0 +1,330 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::error::TerminalSchemeError;
+#[cfg(feature = "maths")]
+use crate::rgb::DisplayP3;
+use crate::rgb::SRgb;
+use crate::terminal::TerminalScheme;
+use crate::terminal::terminal_scheme::{line_of, Slots, SLOT_COUNT};
+use crate::www::Html;
+
+use alloc::string::String;
+use core::fmt::Write;
+
+/// The keys of the colours in iTerm2 schemes.
+const KEYS: [&str; SLOT_COUNT] = [
+	"Ansi 0 Color",
+	"Ansi 1 Color",
+	"Ansi 2 Color",
+	"Ansi 3 Color",
+	"Ansi 4 Color",
+	"Ansi 5 Color",
+	"Ansi 6 Color",
+	"Ansi 7 Color",
+	"Ansi 8 Color",
+	"Ansi 9 Color",
+	"Ansi 10 Color",
+	"Ansi 11 Color",
+	"Ansi 12 Color",
+	"Ansi 13 Color",
+	"Ansi 14 Color",
+	"Ansi 15 Color",
+	"Foreground Color",
+	"Background Color",
+	"Cursor Color",
+	"Selection Color",
+];
+
+/// The keys of the components of iTerm2 colours.
+const COMPONENT_KEYS: [&str; 0x3] = ["Red Component", "Green Component", "Blue Component"];
+
+/// The key of the colour space of iTerm2 colours.
+const COLOUR_SPACE_KEY: &str = "Color Space";
+
+/// A colour space of iTerm2 colours.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ColourSpace {
+	SRgb,
+	DisplayP3,
+}
+
+impl ColourSpace {
+	/// Looks up a colour space by its name.
+	///
+	/// The calibrated colour space of older versions of iTerm2 is taken to be sRGB.
+	#[must_use]
+	fn from_name(name: &str) -> Option<Self> {
+		match name {
+			"sRGB" | "Calibrated" => Some(Self::SRgb),
+			"P3"                  => Some(Self::DisplayP3),
+
+			_ => None,
+		}
+	}
+}
+
+/// An XML tag.
+struct Tag<'a> {
+	name: &'a str,
+
+	is_closing:      bool,
+	is_self_closing: bool,
+}
+
+/// A reader of XML property lists.
+///
+/// Only elements are read, and attributes, declarations, and comments are skipped.
+struct Reader<'a> {
+	source: &'a str,
+	offset: usize,
+}
+
+impl<'a> Reader<'a> {
+	/// Constructs an error at the current offset.
+	#[must_use]
+	fn error(&self) -> TerminalSchemeError {
+		TerminalSchemeError::InvalidSyntax { line: line_of(self.source, self.offset) }
+	}
+
+	/// Reads the text up to the next tag.
+	#[must_use]
+	fn text(&mut self) -> &'a str {
+		let rest = &self.source[self.offset..];
+
+		let length = rest.find('<').unwrap_or(rest.len());
+		self.offset += length;
+
+		rest[..length].trim()
+	}
+
+	/// Reads the next tag, skipping any declarations and comments.
+	fn tag(&mut self) -> Result<Tag<'a>, TerminalSchemeError> {
+		loop {
+			let _ = self.text();
+
+			let rest = &self.source[self.offset..];
+
+			let terminator = if rest.starts_with("<!--") {
+				"-->"
+			} else if rest.starts_with("<?") {
+				"?>"
+			} else {
+				">"
+			};
+
+			let end = rest.find(terminator).ok_or_else(|| self.error())?;
+
+			let tag = &rest[0x1..end];
+			self.offset += end + terminator.len();
+
+			if tag.starts_with(['!', '?']) {
+				continue;
+			}
+
+			let (tag, is_closing) = tag.strip_prefix('/').map_or((tag, false), |tag| (tag, true));
+			let (tag, is_self_closing) = tag.strip_suffix('/').map_or((tag, false), |tag| (tag, true));
+
+			let name = tag.split_ascii_whitespace().next().ok_or_else(|| self.error())?;
+
+			return Ok(Tag { name, is_closing, is_self_closing });
+		}
+	}
+
+	/// Reads a closing tag with the given name.
+	fn close(&mut self, name: &str) -> Result<(), TerminalSchemeError> {
+		let tag = self.tag()?;
+
+		if tag.name != name || !tag.is_closing {
+			return Err(self.error());
+		}
+
+		Ok(())
+	}
+
+	/// Reads a `<key>` element.
+	///
+	/// If the end of the dictionary was reached instead, [`None`] is returned.
+	fn key(&mut self) -> Result<Option<&'a str>, TerminalSchemeError> {
+		let tag = self.tag()?;
+
+		match tag {
+			Tag { name: "dict", is_closing: true, .. } => Ok(None),
+
+			Tag { name: "key", is_closing: false, is_self_closing: false } => {
+				let key = self.text();
+				self.close("key")?;
+
+				Ok(Some(key))
+			}
+
+			_ => Err(self.error()),
+		}
+	}
+
+	/// Skips the rest of an element whose opening tag has been read.
+	fn skip(&mut self, tag: &Tag) -> Result<(), TerminalSchemeError> {
+		if tag.is_self_closing {
+			return Ok(());
+		}
+
+		let mut depth = 0x1usize;
+
+		while depth > 0x0 {
+			let tag = self.tag()?;
+
+			if tag.is_closing {
+				depth -= 0x1;
+			} else if !tag.is_self_closing {
+				depth += 0x1;
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Reads a colour dictionary whose opening tag has been read.
+	fn colour(&mut self) -> Result<Html, TerminalSchemeError> {
+		let line = line_of(self.source, self.offset);
+
+		let mut components = [None; 0x3];
+		let mut space      = ColourSpace::SRgb;
+
+		while let Some(key) = self.key()? {
+			let tag = self.tag()?;
+
+			if key == COLOUR_SPACE_KEY {
+				if tag.name != "string" || tag.is_closing || tag.is_self_closing {
+					return Err(TerminalSchemeError::InvalidColour { line });
+				}
+
+				let name = self.text();
+				self.close(tag.name)?;
+
+				space = ColourSpace::from_name(name).ok_or(TerminalSchemeError::UnsupportedColourSpace { line })?;
+				continue;
+			}
+
+			let Some(index) = COMPONENT_KEYS.iter().position(|&other| other == key) else {
+				self.skip(&tag)?;
+				continue;
+			};
+
+			if !matches!(tag.name, "real" | "integer") || tag.is_closing || tag.is_self_closing {
+				return Err(TerminalSchemeError::InvalidColour { line });
+			}
+
+			let value = self.text().parse::<f32>().ok().filter(|value| value.is_finite());
+			self.close(tag.name)?;
+
+			components[index] = Some(value.ok_or(TerminalSchemeError::InvalidColour { line })?);
+		}
+
+		let [Some(red), Some(green), Some(blue)] = components else {
+			return Err(TerminalSchemeError::InvalidColour { line });
+		};
+
+		let colour = match space {
+			ColourSpace::SRgb => SRgb::new(red, green, blue),
+
+			#[cfg(feature = "maths")]
+			ColourSpace::DisplayP3 => DisplayP3::new(red, green, blue).to_xyz().to_s_rgb(),
+
+			#[cfg(not(feature = "maths"))]
+			ColourSpace::DisplayP3 => return Err(TerminalSchemeError::UnsupportedColourSpace { line }),
+		};
+
+		Ok(Html::from(colour.to_u8()))
+	}
+}
+
+impl TerminalScheme {
+	/// Reads an iTerm2 scheme.
+	///
+	/// iTerm2 schemes (`.itermcolors` files) are XML property lists with a dictionary of colours, each given by its floating-point components.
+	/// The components are in the colour space given by the `Color Space` key of each colour, which may be `sRGB` or `P3` (i.e. [Display P3](DisplayP3)).
+	/// Colours without this key and colours in the `Calibrated` space of older versions of iTerm2 are taken to be sRGB.
+	/// Display P3 colours are converted to sRGB and clipped to its gamut, and any alpha component is ignored.
+	///
+	/// As iTerm2 schemes are unnamed, the name of the returned scheme is empty.
+	///
+	/// # Errors
+	///
+	/// If the property list is malformed, or if any of the ANSI, foreground, or background colours are missing, an error is returned.
+	///
+	/// Colours in other colour spaces are likewise rejected.
+	/// Without the `maths` feature, this includes Display P3.
+	pub fn from_iterm2(s: &str) -> Result<Self, TerminalSchemeError> {
+		let mut reader = Reader { source: s, offset: 0x0 };
+
+		loop {
+			let tag = reader.tag()?;
+
+			if tag.name == "dict" && !tag.is_closing && !tag.is_self_closing {
+				break;
+			}
+		}
+
+		let mut slots: Slots = [None; SLOT_COUNT];
+
+		while let Some(key) = reader.key()? {
+			let tag = reader.tag()?;
+
+			let Some(slot) = KEYS.iter().position(|&other| other == key) else {
+				reader.skip(&tag)?;
+				continue;
+			};
+
+			if tag.name != "dict" || tag.is_closing || tag.is_self_closing {
+				return Err(reader.error());
+			}
+
+			slots[slot] = Some(reader.colour()?);
+		}
+
+		Self::from_slots(String::new(), slots, &KEYS)
+	}
+
+	/// Writes the scheme as an iTerm2 scheme.
+	///
+	/// The colours are written in sRGB and sorted by key, as is done by iTerm2.
+	/// The name of the scheme is not written.
+	#[must_use]
+	pub fn to_iterm2(&self) -> String {
+		let slots = self.to_slots();
+
+		let mut order: [usize; SLOT_COUNT] = core::array::from_fn(|slot| slot);
+		order.sort_unstable_by_key(|&slot| KEYS[slot]);
+
+		let mut s = String::new();
+
+		s.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+		s.push_str("<!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n");
+		s.push_str("<plist version=\"1.0\">\n");
+		s.push_str("<dict>\n");
+
+		for slot in order {
+			let Some(colour) = slots[slot] else { continue };
+
+			let (colour, _) = colour.to_s_rgba().detach();
+			let (red, green, blue) = SRgb::<f32>::from_u8(colour).get();
+
+			// Writing to strings cannot fail.
+			let _ = write!(
+				s,
+				"\t<key>{}</key>\n\t<dict>\n\t\t<key>Alpha Component</key>\n\t\t<real>1</real>\n\t\t<key>Blue Component</key>\n\t\t<real>{blue}</real>\n\t\t<key>Color Space</key>\n\t\t<string>sRGB</string>\n\t\t<key>Green Component</key>\n\t\t<real>{green}</real>\n\t\t<key>Red Component</key>\n\t\t<real>{red}</real>\n\t</dict>\n",
+				KEYS[slot],
+			);
+		}
+
+		s.push_str("</dict>\n");
+		s.push_str("</plist>\n");
+
+		s
+	}
+}
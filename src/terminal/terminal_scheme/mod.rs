@@ -0,0 +1,214 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+mod alacritty;
+mod iterm2;
+mod windows_terminal;
+
+use crate::error::TerminalSchemeError;
+use crate::terminal::{AnsiColour, AnsiPalette};
+use crate::www::Html;
+
+use alloc::string::String;
+
+/// The number of colours in a scheme.
+///
+/// These are the sixteen ANSI colours followed by the foreground, background, cursor, and selection colours.
+const SLOT_COUNT: usize = 0x14;
+
+/// The slot of the foreground colour.
+const FOREGROUND: usize = 0x10;
+
+/// The slot of the background colour.
+const BACKGROUND: usize = 0x11;
+
+/// The slot of the cursor colour.
+const CURSOR: usize = 0x12;
+
+/// The slot of the selection colour.
+const SELECTION: usize = 0x13;
+
+/// The colours of a scheme, in order of their slots.
+type Slots = [Option<Html>; SLOT_COUNT];
+
+/// A terminal colour scheme.
+///
+/// A scheme consists of an [ANSI palette](AnsiPalette), foreground and background colours, and optional cursor and selection colours.
+/// Schemes can be read from and written to the formats of a number of terminals:
+///
+/// | Format                                          | Reader                                         | Writer                                     |
+/// | :---------------------------------------------- | :--------------------------------------------- | :----------------------------------------- |
+/// | iTerm2 (`.itermcolors`)                         | [`from_iterm2`](Self::from_iterm2)             | [`to_iterm2`](Self::to_iterm2)             |
+/// | Windows Terminal (scheme object)                | [`from_windows_terminal`](Self::from_windows_terminal) | [`to_windows_terminal`](Self::to_windows_terminal) |
+/// | Alacritty (`[colors]` tables)                   | [`from_alacritty`](Self::from_alacritty)       | [`to_alacritty`](Self::to_alacritty)       |
+///
+/// See also [`BaseScheme`](crate::terminal::BaseScheme) for Base16 and Base24 schemes.
+///
+/// Floating-point components are converted to and from eight-bit channels using [`SRgb::from_u8`](crate::rgb::SRgb::from_u8) and [`SRgb::to_u8`](crate::rgb::SRgb::to_u8), and so colours round-trip exactly between all formats.
+///
+/// # Examples
+///
+/// ```
+/// use polywave::terminal::{AnsiPalette, TerminalScheme};
+/// use polywave::www::Html;
+///
+/// let scheme = TerminalScheme::new("VGA".into(), AnsiPalette::VGA, Html::from_u32(0xAAAAAAFF), Html::BLACK);
+///
+/// let json = scheme.to_windows_terminal();
+///
+/// assert_eq!(TerminalScheme::from_windows_terminal(&json), Ok(scheme));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TerminalScheme {
+	name: String,
+
+	palette:    AnsiPalette,
+	foreground: Html,
+	background: Html,
+	cursor:     Option<Html>,
+	selection:  Option<Html>,
+}
+
+impl TerminalScheme {
+	/// Constructs a new scheme without cursor and selection colours.
+	#[inline]
+	#[must_use]
+	pub const fn new(name: String, palette: AnsiPalette, foreground: Html, background: Html) -> Self {
+		Self {
+			name,
+
+			palette,
+			foreground,
+			background,
+			cursor:    None,
+			selection: None,
+		}
+	}
+
+	/// Retrieves the name of the scheme.
+	///
+	/// This is empty if the scheme was read from a format without names.
+	#[inline(always)]
+	#[must_use]
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// Retrieves the ANSI palette of the scheme.
+	#[inline(always)]
+	#[must_use]
+	pub const fn palette(&self) -> AnsiPalette {
+		self.palette
+	}
+
+	/// Retrieves the foreground colour of the scheme.
+	#[inline(always)]
+	#[must_use]
+	pub const fn foreground(&self) -> Html {
+		self.foreground
+	}
+
+	/// Retrieves the background colour of the scheme.
+	#[inline(always)]
+	#[must_use]
+	pub const fn background(&self) -> Html {
+		self.background
+	}
+
+	/// Retrieves the cursor colour of the scheme.
+	#[inline(always)]
+	#[must_use]
+	pub const fn cursor(&self) -> Option<Html> {
+		self.cursor
+	}
+
+	/// Retrieves the selection colour of the scheme.
+	#[inline(always)]
+	#[must_use]
+	pub const fn selection(&self) -> Option<Html> {
+		self.selection
+	}
+
+	/// Sets the name of the scheme.
+	#[inline(always)]
+	pub fn set_name(&mut self, name: String) {
+		self.name = name;
+	}
+
+	/// Sets the cursor colour of the scheme.
+	#[inline(always)]
+	pub fn set_cursor(&mut self, cursor: Option<Html>) {
+		self.cursor = cursor;
+	}
+
+	/// Sets the selection colour of the scheme.
+	#[inline(always)]
+	pub fn set_selection(&mut self, selection: Option<Html>) {
+		self.selection = selection;
+	}
+
+	/// Constructs a scheme from its slots.
+	///
+	/// The names of the slots in the format of the scheme are given by `keys`.
+	fn from_slots(name: String, slots: Slots, keys: &[&'static str; SLOT_COUNT]) -> Result<Self, TerminalSchemeError> {
+		let require = |slot: usize| slots[slot].ok_or(TerminalSchemeError::MissingColour { name: keys[slot] });
+
+		let mut colours = [Html::BLACK; 0x10];
+
+		for (slot, colour) in colours.iter_mut().enumerate() {
+			*colour = require(slot)?;
+		}
+
+		let mut scheme = Self::new(name, AnsiPalette::new(colours), require(FOREGROUND)?, require(BACKGROUND)?);
+
+		scheme.cursor    = slots[CURSOR];
+		scheme.selection = slots[SELECTION];
+
+		Ok(scheme)
+	}
+
+	/// Deconstructs the scheme into its slots.
+	#[must_use]
+	fn to_slots(&self) -> Slots {
+		let mut slots = [None; SLOT_COUNT];
+
+		for colour in AnsiColour::ALL {
+			slots[usize::from(colour.index())] = Some(self.palette.get(colour));
+		}
+
+		slots[FOREGROUND] = Some(self.foreground);
+		slots[BACKGROUND] = Some(self.background);
+		slots[CURSOR]     = self.cursor;
+		slots[SELECTION]  = self.selection;
+
+		slots
+	}
+}
+
+/// Parses a six-digit hexadecimal colour, optionally prefixed by `#` or `0x`.
+#[must_use]
+pub(super) fn parse_hexadecimal(s: &str) -> Option<Html> {
+	let digits = s
+		.strip_prefix('#')
+		.or_else(|| s.strip_prefix("0x"))
+		.unwrap_or(s);
+
+	if digits.len() != 0x6 || !digits.bytes().all(|c| c.is_ascii_hexdigit()) {
+		return None;
+	}
+
+	let value = u32::from_str_radix(digits, 0x10).ok()?;
+
+	Some(Html::from_u32(value << 0x8 | 0xFF))
+}
+
+/// Computes the (one-based) line of an offset into a string.
+#[must_use]
+pub(super) fn line_of(s: &str, offset: usize) -> usize {
+	s[..offset].matches('\n').count() + 0x1
+}
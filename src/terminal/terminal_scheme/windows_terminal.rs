@@ -0,0 +1,270 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::error::TerminalSchemeError;
+use crate::terminal::TerminalScheme;
+use crate::terminal::terminal_scheme::{line_of, parse_hexadecimal, Slots, SLOT_COUNT};
+
+use alloc::string::String;
+use core::fmt::Write;
+
+/// The keys of the colours in Windows Terminal schemes.
+const KEYS: [&str; SLOT_COUNT] = [
+	"black",
+	"red",
+	"green",
+	"yellow",
+	"blue",
+	"purple",
+	"cyan",
+	"white",
+	"brightBlack",
+	"brightRed",
+	"brightGreen",
+	"brightYellow",
+	"brightBlue",
+	"brightPurple",
+	"brightCyan",
+	"brightWhite",
+	"foreground",
+	"background",
+	"cursorColor",
+	"selectionBackground",
+];
+
+/// A reader of JSON objects.
+///
+/// Only string members are read, and all other values are skipped.
+struct Reader<'a> {
+	source: &'a str,
+	offset: usize,
+}
+
+impl Reader<'_> {
+	/// Constructs an error at the current offset.
+	#[must_use]
+	fn error(&self) -> TerminalSchemeError {
+		TerminalSchemeError::InvalidSyntax { line: line_of(self.source, self.offset) }
+	}
+
+	/// Skips whitespace and retrieves the next character.
+	#[must_use]
+	fn peek(&mut self) -> Option<char> {
+		let rest = &self.source[self.offset..];
+		self.offset += rest.len() - rest.trim_start().len();
+
+		self.source[self.offset..].chars().next()
+	}
+
+	/// Reads the given character.
+	fn expect(&mut self, c: char) -> Result<(), TerminalSchemeError> {
+		if self.peek() != Some(c) {
+			return Err(self.error());
+		}
+
+		self.offset += c.len_utf8();
+		Ok(())
+	}
+
+	/// Reads a string.
+	fn string(&mut self) -> Result<String, TerminalSchemeError> {
+		self.expect('"')?;
+
+		let mut s = String::new();
+
+		let mut chars = self.source[self.offset..].char_indices();
+
+		loop {
+			let (index, c) = chars.next().ok_or_else(|| self.error())?;
+
+			match c {
+				'"' => {
+					self.offset += index + 0x1;
+					return Ok(s);
+				}
+
+				'\\' => {
+					let (_, escape) = chars.next().ok_or_else(|| self.error())?;
+
+					let c = match escape {
+						'"'  => '"',
+						'\\' => '\\',
+						'/'  => '/',
+						'b'  => '\u{8}',
+						'f'  => '\u{C}',
+						'n'  => '\n',
+						'r'  => '\r',
+						't'  => '\t',
+
+						'u' => {
+							let digits = chars.as_str().get(..0x4).ok_or_else(|| self.error())?;
+
+							let value = u32::from_str_radix(digits, 0x10).map_err(|_| self.error())?;
+
+							for _ in 0x0..0x4 {
+								let _ = chars.next();
+							}
+
+							char::from_u32(value).unwrap_or(char::REPLACEMENT_CHARACTER)
+						}
+
+						_ => return Err(self.error()),
+					};
+
+					s.push(c);
+				}
+
+				c if c.is_control() => return Err(self.error()),
+
+				c => s.push(c),
+			}
+		}
+	}
+
+	/// Skips a value.
+	fn skip(&mut self) -> Result<(), TerminalSchemeError> {
+		let Some(c) = self.peek() else {
+			return Err(self.error());
+		};
+
+		match c {
+			'"' => {
+				let _ = self.string()?;
+			}
+
+			'{' | '[' => {
+				let terminator = if c == '{' { '}' } else { ']' };
+
+				self.offset += 0x1;
+
+				if self.peek() == Some(terminator) {
+					self.offset += 0x1;
+					return Ok(());
+				}
+
+				loop {
+					if c == '{' {
+						let _ = self.string()?;
+						self.expect(':')?;
+					}
+
+					self.skip()?;
+
+					if self.peek() == Some(terminator) {
+						self.offset += 0x1;
+						return Ok(());
+					}
+
+					self.expect(',')?;
+				}
+			}
+
+			_ => {
+				let rest = &self.source[self.offset..];
+
+				let length = rest
+					.find(|c: char| matches!(c, ',' | '}' | ']') || c.is_whitespace())
+					.unwrap_or(rest.len());
+
+				if length == 0x0 {
+					return Err(self.error());
+				}
+
+				self.offset += length;
+			}
+		}
+
+		Ok(())
+	}
+}
+
+impl TerminalScheme {
+	/// Reads a Windows Terminal scheme.
+	///
+	/// Windows Terminal schemes are JSON objects as found in the `schemes` array of its settings, with the colours given as `#RRGGBB` strings.
+	/// Unknown members are ignored.
+	///
+	/// # Errors
+	///
+	/// If the object is malformed, or if any of the ANSI, foreground, or background colours are missing, an error is returned.
+	pub fn from_windows_terminal(s: &str) -> Result<Self, TerminalSchemeError> {
+		let mut reader = Reader { source: s, offset: 0x0 };
+
+		let mut name  = String::new();
+		let mut slots: Slots = [None; SLOT_COUNT];
+
+		reader.expect('{')?;
+
+		if reader.peek() == Some('}') {
+			reader.offset += 0x1;
+		} else {
+			loop {
+				let key = reader.string()?;
+				reader.expect(':')?;
+
+				let line = line_of(s, reader.offset);
+
+				if key == "name" {
+					name = reader.string()?;
+				} else if let Some(slot) = KEYS.iter().position(|&other| other == key) {
+					let value = reader.string()?;
+					slots[slot] = Some(parse_hexadecimal(&value).ok_or(TerminalSchemeError::InvalidColour { line })?);
+				} else {
+					reader.skip()?;
+				}
+
+				if reader.peek() == Some('}') {
+					reader.offset += 0x1;
+					break;
+				}
+
+				reader.expect(',')?;
+			}
+		}
+
+		if reader.peek().is_some() {
+			return Err(reader.error());
+		}
+
+		Self::from_slots(name, slots, &KEYS)
+	}
+
+	/// Writes the scheme as a Windows Terminal scheme.
+	#[must_use]
+	pub fn to_windows_terminal(&self) -> String {
+		let mut s = String::new();
+
+		s.push_str("{\n\t\"name\": \"");
+
+		for c in self.name.chars() {
+			match c {
+				'"'  => s.push_str("\\\""),
+				'\\' => s.push_str("\\\\"),
+
+				// Writing to strings cannot fail.
+				c if c.is_control() => { let _ = write!(s, "\\u{:04X}", u32::from(c)); }
+
+				c => s.push(c),
+			}
+		}
+
+		s.push('"');
+
+		let slots = self.to_slots();
+
+		for (key, colour) in KEYS.iter().zip(slots) {
+			let Some(colour) = colour else { continue };
+
+			let _ = write!(s, ",\n\t\"{key}\": \"#{:06X}\"", colour.to_u32() >> 0x8);
+		}
+
+		s.push_str("\n}\n");
+
+		s
+	}
+}
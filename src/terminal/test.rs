@@ -0,0 +1,288 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+#![cfg(test)]
+
+#[cfg(feature = "alloc")]
+use crate::error::TerminalSchemeError;
+use crate::rgb::SRgb;
+use crate::terminal::{AnsiColour, AnsiPalette, ColourDepth, TerminalColour};
+#[cfg(feature = "alloc")]
+use crate::terminal::{BaseScheme, TerminalScheme};
+use crate::www::Html;
+
+use alloc::format;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+#[test]
+fn test_ansi_colour() {
+	for (index, colour) in (0x0..).zip(AnsiColour::ALL) {
+		assert_eq!(colour.index(), index);
+		assert_eq!(AnsiColour::from_index(index), Some(colour));
+	}
+
+	assert_eq!(AnsiColour::from_index(0x10), None);
+
+	assert!(!AnsiColour::White.is_bright());
+	assert!(AnsiColour::BrightBlack.is_bright());
+}
+
+#[test]
+fn test_ansi_palette_indexed() {
+	let palette = AnsiPalette::WINDOWS_10;
+
+	assert_eq!(palette.indexed(0x01), palette.get(AnsiColour::Red));
+	assert_eq!(palette.indexed(0x10), Html::from_u32(0x000000FF));
+	assert_eq!(palette.indexed(0x15), Html::from_u32(0x0000FFFF));
+	assert_eq!(palette.indexed(0x61), Html::from_u32(0x875FAFFF));
+	assert_eq!(palette.indexed(0xE7), Html::from_u32(0xFFFFFFFF));
+	assert_eq!(palette.indexed(0xE8), Html::from_u32(0x080808FF));
+	assert_eq!(palette.indexed(0xFF), Html::from_u32(0xEEEEEEFF));
+}
+
+#[cfg(feature = "maths")]
+#[test]
+fn test_ansi_palette_nearest() {
+	for palette in [AnsiPalette::XTERM, AnsiPalette::VGA, AnsiPalette::WINDOWS_XP, AnsiPalette::WINDOWS_10] {
+		for colour in AnsiColour::ALL {
+			assert_eq!(palette.nearest(palette.get(colour)), colour);
+		}
+	}
+
+	assert_eq!(AnsiPalette::VGA.nearest(Html::from_u32(0x0000A0FF)), AnsiColour::Blue);
+
+	assert_eq!(AnsiPalette::VGA.nearest_s_rgb(SRgb::<f32>::new(0.0, 0.0, 0.63)),  AnsiColour::Blue);
+	assert_eq!(AnsiPalette::VGA.nearest_s_rgb(SRgb::<f64>::new(1.2, -0.1, -0.1)), AnsiColour::BrightRed);
+
+	for index in 0x10..=0xFF {
+		let colour = AnsiPalette::XTERM.indexed(index);
+		assert_eq!(AnsiPalette::XTERM.indexed(AnsiPalette::XTERM.nearest_indexed(colour)), colour);
+	}
+
+	assert_eq!(AnsiPalette::XTERM.nearest_indexed(Html::from_u32(0x7F7F7FFF)), 0x08);
+	assert_eq!(AnsiPalette::VGA.nearest_indexed(Html::from_u32(0x7F7F7FFF)),   0xF4);
+
+	assert_eq!(AnsiPalette::XTERM.nearest_indexed_s_rgb(SRgb::<f32>::new(1.0, 0.372_549, 0.0)), 0xCA);
+	assert_eq!(AnsiPalette::VGA.nearest_indexed_s_rgb(SRgb::<f64>::new(0.498, 0.498, 0.498)),   0xF4);
+}
+
+#[cfg(feature = "maths")]
+#[test]
+fn test_colour_depth_quantise() {
+	let colour = Html::from_u32(0x3A96DD80);
+
+	assert_eq!(ColourDepth::Ansi.quantise(colour, AnsiPalette::WINDOWS_10),       TerminalColour::Ansi(AnsiColour::Cyan));
+	assert_eq!(ColourDepth::Indexed.quantise(colour, AnsiPalette::WINDOWS_10),    TerminalColour::Indexed(0x06));
+	assert_eq!(ColourDepth::TrueColour.quantise(colour, AnsiPalette::WINDOWS_10), TerminalColour::Rgb(SRgb::new(0x3A, 0x96, 0xDD)));
+}
+
+#[test]
+fn test_sgr() {
+	assert_eq!(format!("{}", TerminalColour::Default.foreground()), "\x1B[39m");
+	assert_eq!(format!("{}", TerminalColour::Default.background()), "\x1B[49m");
+
+	assert_eq!(format!("{}", TerminalColour::Ansi(AnsiColour::Black).foreground()),       "\x1B[30m");
+	assert_eq!(format!("{}", TerminalColour::Ansi(AnsiColour::White).background()),       "\x1B[47m");
+	assert_eq!(format!("{}", TerminalColour::Ansi(AnsiColour::BrightBlack).foreground()), "\x1B[90m");
+	assert_eq!(format!("{}", TerminalColour::Ansi(AnsiColour::BrightWhite).background()), "\x1B[107m");
+
+	assert_eq!(format!("{}", TerminalColour::Indexed(0xFF).foreground()), "\x1B[38;5;255m");
+
+	assert_eq!(format!("{}", TerminalColour::Rgb(SRgb::new(0x1, 0x2, 0x3)).background()), "\x1B[48;2;1;2;3m");
+
+	assert_eq!(TerminalColour::Indexed(0x0).depth(), ColourDepth::Indexed);
+	assert!(ColourDepth::Ansi < ColourDepth::TrueColour);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_terminal_scheme_iterm2() {
+	let mut plist = String::from(
+		"<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!-- A comment. -->\n<plist version=\"1.0\">\n<dict>\n",
+	);
+
+	for (index, colour) in AnsiPalette::WINDOWS_10.colours().into_iter().enumerate() {
+		let (colour, _) = colour.to_s_rgba().detach();
+		let (red, green, blue) = SRgb::<f64>::from_u8(colour).get();
+
+		plist += &format!(
+			"\t<key>Ansi {index} Color</key>\n\t<dict>\n\t\t<key>Color Space</key>\n\t\t<string>sRGB</string>\n\t\t<key>Red Component</key>\n\t\t<real>{red}</real>\n\t\t<key>Green Component</key>\n\t\t<real>{green}</real>\n\t\t<key>Blue Component</key>\n\t\t<real>{blue}</real>\n\t</dict>\n",
+		);
+	}
+
+	plist += "\t<key>Background Color</key>\n\t<dict>\n\t\t<key>Red Component</key>\n\t\t<integer>0</integer>\n\t\t<key>Green Component</key>\n\t\t<real>0</real>\n\t\t<key>Blue Component</key>\n\t\t<real>0.5</real>\n\t</dict>\n";
+	plist += "\t<key>Foreground Color</key>\n\t<dict>\n\t\t<key>Red Component</key>\n\t\t<real>1</real>\n\t\t<key>Green Component</key>\n\t\t<real>1</real>\n\t\t<key>Blue Component</key>\n\t\t<real>1</real>\n\t</dict>\n";
+	plist += "\t<key>Unrelated Setting</key>\n\t<array>\n\t\t<true/>\n\t</array>\n";
+	plist += "</dict>\n</plist>\n";
+
+	let scheme = TerminalScheme::from_iterm2(&plist).unwrap();
+
+	assert_eq!(scheme.name(),       "");
+	assert_eq!(scheme.palette(),    AnsiPalette::WINDOWS_10);
+	assert_eq!(scheme.foreground(), Html::WHITE);
+	assert_eq!(scheme.background(), Html::from_u32(0x000080FF));
+	assert_eq!(scheme.cursor(),     None);
+
+	assert_eq!(TerminalScheme::from_iterm2(&scheme.to_iterm2()), Ok(scheme));
+
+	assert_eq!(
+		TerminalScheme::from_iterm2("<plist><dict></dict></plist>"),
+		Err(TerminalSchemeError::MissingColour { name: "Ansi 0 Color" }),
+	);
+
+	assert_eq!(
+		TerminalScheme::from_iterm2("<plist>\n<dict>\n<key>Ansi 0 Color</key>\n<dict>\n<key>Red Component</key>\n<real>red</real>"),
+		Err(TerminalSchemeError::InvalidColour { line: 0x4 }),
+	);
+
+	let calibrated = plist.replace("<string>sRGB</string>", "<string>Calibrated</string>");
+	assert_eq!(TerminalScheme::from_iterm2(&calibrated).unwrap().palette(), AnsiPalette::WINDOWS_10);
+
+	let generic = plist.replacen("<string>sRGB</string>", "<string>Generic</string>", 0x1);
+
+	assert_eq!(
+		TerminalScheme::from_iterm2(&generic),
+		Err(TerminalSchemeError::UnsupportedColourSpace { line: 0x6 }),
+	);
+
+	let p3 = plist.replace(
+		"<key>Background Color</key>\n\t<dict>\n",
+		"<key>Background Color</key>\n\t<dict>\n\t\t<key>Color Space</key>\n\t\t<string>P3</string>\n",
+	);
+
+	#[cfg(feature = "maths")]
+	{
+		use crate::rgb::DisplayP3;
+
+		let background = Html::from(DisplayP3::<f32>::new(0.0, 0.0, 0.5).to_xyz().to_s_rgb().to_u8());
+
+		assert_ne!(background, Html::from_u32(0x000080FF));
+		assert_eq!(TerminalScheme::from_iterm2(&p3).unwrap().background(), background);
+	}
+
+	#[cfg(not(feature = "maths"))]
+	assert_eq!(
+		TerminalScheme::from_iterm2(&p3),
+		Err(TerminalSchemeError::UnsupportedColourSpace { line: 0xB6 }),
+	);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_terminal_scheme_windows_terminal() {
+	let mut scheme = TerminalScheme::new(String::from("Quote \" \\ Scheme"), AnsiPalette::VGA, Html::WHITE, Html::BLACK);
+	scheme.set_cursor(Some(Html::RED));
+
+	let json = scheme.to_windows_terminal();
+
+	assert!(json.contains("\"cursorColor\": \"#FF0000\""));
+	assert!(!json.contains("selectionBackground"));
+
+	assert_eq!(TerminalScheme::from_windows_terminal(&json), Ok(scheme.clone()));
+
+	let json = json.replace("\"black\"", "\"extra\": [1, {\"a\": null}, true],\n\t\"black\"");
+
+	assert_eq!(TerminalScheme::from_windows_terminal(&json), Ok(scheme));
+
+	assert_eq!(
+		TerminalScheme::from_windows_terminal("{\n\t\"black\": \"black\"\n}"),
+		Err(TerminalSchemeError::InvalidColour { line: 0x2 }),
+	);
+
+	assert_eq!(
+		TerminalScheme::from_windows_terminal("{\n\t\"black\" \"#000000\"\n}"),
+		Err(TerminalSchemeError::InvalidSyntax { line: 0x2 }),
+	);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_terminal_scheme_alacritty() {
+	let mut scheme = TerminalScheme::new(String::new(), AnsiPalette::TERMINAL_APP, Html::WHITE, Html::BLACK);
+	scheme.set_selection(Some(Html::from_u32(0x264F78FF)));
+
+	let toml = scheme.to_alacritty();
+
+	assert!(toml.starts_with("[colors.normal]\nblack = \"#000000\"\n"));
+
+	assert_eq!(TerminalScheme::from_alacritty(&toml), Ok(scheme.clone()));
+
+	let toml = toml
+		.replace("[colors.primary]\n", "[colors] # Primary colours.\nprimary.foreground = '0xffffff'\n[colors.primary]\n")
+		.replace("foreground = \"#ffffff\"\n", "")
+		+ "\n[colors.cursor]\ncursor = \"CellForeground\"\n";
+
+	assert_eq!(TerminalScheme::from_alacritty(&toml), Ok(scheme));
+
+	assert_eq!(
+		TerminalScheme::from_alacritty("[colors.normal]\n\nblack = \"#00000\""),
+		Err(TerminalSchemeError::InvalidColour { line: 0x3 }),
+	);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_base_scheme() {
+	let colours: [Html; 0x18] = core::array::from_fn(|index| {
+		let index = u32::try_from(index).unwrap();
+		Html::from_u32(index << 0x18 | index << 0x10 | index << 0x8 | 0xFF)
+	});
+
+	let scheme = BaseScheme::base24(String::from("It's"), String::from("Somebody"), colours);
+
+	let yaml = scheme.to_yaml();
+
+	assert!(yaml.contains("base17: \"171717\"\n"));
+
+	assert_eq!(BaseScheme::from_yaml(&yaml), Ok(scheme.clone()));
+
+	let terminal = scheme.to_terminal_scheme();
+
+	assert_eq!(terminal.palette().get(AnsiColour::White),       colours[0x06]);
+	assert_eq!(terminal.palette().get(AnsiColour::BrightBlack), colours[0x02]);
+	assert_eq!(terminal.palette().get(AnsiColour::BrightRed),   colours[0x12]);
+	assert_eq!(terminal.selection(),                            Some(colours[0x02]));
+
+	let yaml = "\
+system: \"base16\"
+name: 'It''s'
+author: Somebody # Comment.
+palette:
+  base00: \"#000000\"
+  base01: \"#010101\"
+  base02: \"#020202\"
+  base03: \"#030303\"
+  base04: \"#040404\"
+  base05: \"#050505\"
+  base06: \"#060606\"
+  base07: \"#070707\"
+  base08: \"#080808\"
+  base09: \"#090909\"
+  base0a: \"#0A0A0A\"
+  base0b: \"#0B0B0B\"
+  base0c: \"#0C0C0C\"
+  base0d: \"#0D0D0D\"
+  base0e: \"#0E0E0E\"
+  base0f: \"#0F0F0F\"
+";
+
+	let scheme = BaseScheme::from_yaml(yaml).unwrap();
+
+	assert_eq!(scheme.name(),   "It's");
+	assert_eq!(scheme.author(), "Somebody");
+	assert_eq!(scheme.colours(), &colours[..0x10]);
+	assert!(!scheme.is_base24());
+
+	assert_eq!(scheme.to_terminal_scheme().palette().get(AnsiColour::BrightRed), colours[0x08]);
+
+	assert_eq!(
+		BaseScheme::from_yaml(&yaml.replace("  base03: \"#030303\"\n", "")),
+		Err(TerminalSchemeError::MissingColour { name: "base03" }),
+	);
+}
@@ -0,0 +1,49 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+#![cfg(test)]
+
+use core::fmt::Debug;
+
+/// Asserts that two tuples of components are equal within some epsilon.
+macro_rules! assert_approx_eq {
+	($left:expr, $right:expr, $epsilon:expr$(,)?) => {{
+		let (left, right) = ($left, $right);
+
+		assert!(
+			::polywave::test::Components::approx_eq(left, right, $epsilon),
+			"assertion `left ~= right` failed\n  left: {left:?}\n right: {right:?}",
+		);
+	}};
+}
+
+pub(crate) use assert_approx_eq;
+
+/// Tuples of components that can be compared by [`assert_approx_eq`].
+pub trait Components: Copy + Debug {
+	/// Checks whether all components are within `epsilon` of each other.
+	#[must_use]
+	fn approx_eq(self, other: Self, epsilon: f64) -> bool;
+}
+
+impl Components for (f64, f64, f64) {
+	#[inline]
+	fn approx_eq(self, other: Self, epsilon: f64) -> bool {
+		(self.0 - other.0).abs() <= epsilon
+			&& (self.1 - other.1).abs() <= epsilon
+			&& (self.2 - other.2).abs() <= epsilon
+	}
+}
+
+impl Components for (f64, f64, f64, f64) {
+	#[inline]
+	fn approx_eq(self, other: Self, epsilon: f64) -> bool {
+		(self.0, self.1, self.2).approx_eq((other.0, other.1, other.2), epsilon)
+			&& (self.3 - other.3).abs() <= epsilon
+	}
+}
@@ -0,0 +1,206 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+//! Evaluation of `calc()` expressions.
+//!
+//! Only the arithmetic operators are supported, and operands are typed as either numbers, percentages, or angles.
+//! Sums require operands of the same type, products require at least one number, and divisors must be numbers.
+
+use crate::www::css_colour::from_str::Value;
+use crate::www::css_tokens::{parse_angle, parse_function, parse_number_prefix};
+
+/// A recursive-descent parser of `calc()` expressions.
+struct Parser<'a> {
+	rest: &'a str,
+
+	/// The values of the channel keywords.
+	keywords: &'a [(&'a str, f64)],
+}
+
+impl Parser<'_> {
+	/// Skips leading whitespace and checks whether the input starts with a character.
+	#[must_use]
+	fn peek(&mut self) -> Option<char> {
+		self.rest = self.rest.trim_start();
+		self.rest.chars().next()
+	}
+
+	/// Parses a sum of products.
+	fn parse_sum(&mut self) -> Option<Value> {
+		let mut value = self.parse_product()?;
+
+		while let Some(c @ ('+' | '-')) = self.peek() {
+			self.rest = &self.rest[0x1..];
+
+			let rhs = self.parse_product()?;
+
+			let sign = if c == '+' { 1.0 } else { -1.0 };
+
+			value = match (value, rhs) {
+				(Value::Number(lhs),     Value::Number(rhs))     => Value::Number(lhs + sign * rhs),
+				(Value::Percentage(lhs), Value::Percentage(rhs)) => Value::Percentage(lhs + sign * rhs),
+				(Value::Angle(lhs),      Value::Angle(rhs))      => Value::Angle(lhs + sign * rhs),
+
+				_ => return None,
+			};
+		}
+
+		Some(value)
+	}
+
+	/// Parses a product of factors.
+	fn parse_product(&mut self) -> Option<Value> {
+		let mut value = self.parse_factor()?;
+
+		while let Some(c @ ('*' | '/')) = self.peek() {
+			self.rest = &self.rest[0x1..];
+
+			let rhs = self.parse_factor()?;
+
+			value = match (c, value, rhs) {
+				('*', Value::Number(lhs), rhs) => rhs.scale(lhs)?,
+				('*', lhs, Value::Number(rhs)) => lhs.scale(rhs)?,
+
+				('/', lhs, Value::Number(rhs)) => lhs.scale(rhs.recip())?,
+
+				_ => return None,
+			};
+		}
+
+		Some(value)
+	}
+
+	/// Parses a signed operand, a parenthesised sum, or a nested `calc()` function.
+	fn parse_factor(&mut self) -> Option<Value> {
+		match self.peek()? {
+			'-' => {
+				self.rest = &self.rest[0x1..];
+				self.parse_factor()?.scale(-1.0)
+			}
+
+			'+' => {
+				self.rest = &self.rest[0x1..];
+				self.parse_factor()
+			}
+
+			'(' => {
+				self.rest = &self.rest[0x1..];
+
+				let value = self.parse_sum()?;
+
+				if self.peek()? != ')' {
+					return None;
+				}
+
+				self.rest = &self.rest[0x1..];
+				Some(value)
+			}
+
+			c if c.is_ascii_digit() || c == '.' => self.parse_dimension(),
+
+			_ => self.parse_identifier(),
+		}
+	}
+
+	/// Parses a number, percentage, or angle.
+	fn parse_dimension(&mut self) -> Option<Value> {
+		let (value, end) = parse_number_prefix(self.rest)?;
+
+		let unit_length = self.rest[end..]
+			.find(|c: char| !c.is_ascii_alphabetic() && c != '%')
+			.unwrap_or(self.rest.len() - end);
+
+		let (token, rest) = self.rest.split_at(end + unit_length);
+		self.rest = rest;
+
+		match &token[end..] {
+			""  => Some(Value::Number(value)),
+			"%" => Some(Value::Percentage(value)),
+
+			_ => parse_angle(token).map(Value::Angle),
+		}
+	}
+
+	/// Parses a keyword, a constant, or a nested `calc()` function.
+	fn parse_identifier(&mut self) -> Option<Value> {
+		let length = self.rest
+			.find(|c: char| !c.is_ascii_alphanumeric() && c != '-')
+			.unwrap_or(self.rest.len());
+
+		let (identifier, rest) = self.rest.split_at(length);
+
+		if identifier.is_empty() {
+			return None;
+		}
+
+		if let Some(rest) = rest.strip_prefix('(') {
+			if !identifier.eq_ignore_ascii_case("calc") {
+				return None;
+			}
+
+			self.rest = rest;
+
+			let value = self.parse_sum()?;
+
+			if self.peek()? != ')' {
+				return None;
+			}
+
+			self.rest = &self.rest[0x1..];
+			return Some(value);
+		}
+
+		self.rest = rest;
+
+		let constants = [
+			("e",        core::f64::consts::E),
+			("infinity", f64::INFINITY),
+			("pi",       core::f64::consts::PI),
+		];
+
+		self.keywords
+			.iter()
+			.copied()
+			.chain(constants)
+			.find(|(name, _)| name.eq_ignore_ascii_case(identifier))
+			.map(|(_, value)| Value::Number(value))
+	}
+}
+
+impl Value {
+	/// Multiplies a number, percentage, or angle by a factor.
+	#[inline]
+	#[must_use]
+	fn scale(self, factor: f64) -> Option<Self> {
+		match self {
+			Self::Number(value)     => Some(Self::Number(value * factor)),
+			Self::Percentage(value) => Some(Self::Percentage(value * factor)),
+			Self::Angle(value)      => Some(Self::Angle(value * factor)),
+
+			Self::None => None,
+		}
+	}
+}
+
+/// Evaluates a `calc()` function.
+///
+/// Channel keywords are looked up (case-insensitively) in `keywords`.
+#[must_use]
+pub(super) fn evaluate(s: &str, keywords: &[(&str, f64)]) -> Option<Value> {
+	let (name, expression) = parse_function(s)?;
+
+	if !name.eq_ignore_ascii_case("calc") {
+		return None;
+	}
+
+	let mut parser = Parser { rest: expression, keywords };
+
+	let value = parser.parse_sum()?;
+
+	parser.peek().is_none().then_some(value)
+}
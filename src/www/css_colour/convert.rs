@@ -0,0 +1,229 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::{HueInterpolate, HueInterpolation, Interpolate};
+use crate::hsv::{Hsl, Hwb};
+use crate::lab::{CieLab, OkLab};
+use crate::lch::{CieLch, OkLch};
+use crate::rgb::{DisplayP3, OpRgb, ProPhotoRgb, Rec2020, Rgb, SRgb};
+use crate::www::{CssColour, CssColourSpace};
+use crate::www::css_colour::{hue_index, is_hue_powerless};
+use crate::xyz::CieXyz;
+
+/// The D50 white point.
+const D50: CieXyz<f64> = CieXyz::<f64>::D50;
+
+/// The D65 white point.
+const D65: CieXyz<f64> = CieXyz::<f64>::D65;
+
+/// A category of analogous components.
+///
+/// Missing components are carried forward between colour spaces if they belong to the same category.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Category {
+	Red,
+	Green,
+	Blue,
+	Lightness,
+	Colourfulness,
+	Hue,
+	OpponentA,
+	OpponentB,
+}
+
+impl Category {
+	/// Retrieves the categories of the channels of a colour space.
+	#[must_use]
+	const fn of(space: CssColourSpace) -> [Option<Self>; 0x3] {
+		match space {
+			CssColourSpace::SRgb
+			| CssColourSpace::SRgbLinear
+			| CssColourSpace::DisplayP3
+			| CssColourSpace::A98Rgb
+			| CssColourSpace::ProPhotoRgb
+			| CssColourSpace::Rec2020
+			| CssColourSpace::XyzD50
+			| CssColourSpace::XyzD65
+			=> [Some(Self::Red), Some(Self::Green), Some(Self::Blue)],
+
+			CssColourSpace::Lab | CssColourSpace::OkLab
+			=> [Some(Self::Lightness), Some(Self::OpponentA), Some(Self::OpponentB)],
+
+			CssColourSpace::Lch | CssColourSpace::OkLch
+			=> [Some(Self::Lightness), Some(Self::Colourfulness), Some(Self::Hue)],
+
+			CssColourSpace::Hsl
+			=> [Some(Self::Hue), Some(Self::Colourfulness), Some(Self::Lightness)],
+
+			CssColourSpace::Hwb
+			=> [Some(Self::Hue), None, None],
+		}
+	}
+}
+
+/// Converts channels of a colour space to CIEXYZ relative to the D65 white point.
+#[must_use]
+fn to_xyz(space: CssColourSpace, channels: [f64; 0x3]) -> CieXyz<f64> {
+	let [c0, c1, c2] = channels;
+
+	match space {
+		CssColourSpace::SRgb        => CieXyz::<f64>::from_s_rgb(SRgb::new(c0, c1, c2)),
+		CssColourSpace::SRgbLinear  => CieXyz::<f64>::from_s_rgb(SRgb::<f64>::transfer(Rgb::new(c0, c1, c2))),
+		CssColourSpace::DisplayP3   => DisplayP3::new(c0, c1, c2).to_xyz(),
+		CssColourSpace::A98Rgb      => OpRgb::new(c0, c1, c2).to_xyz(),
+		CssColourSpace::ProPhotoRgb => ProPhotoRgb::new(c0, c1, c2).to_xyz().adapt(D50, D65),
+		CssColourSpace::Rec2020     => Rec2020::new(c0, c1, c2).to_xyz(),
+		CssColourSpace::Lab         => CieLab::new(c0, c1, c2).to_xyz(D50).adapt(D50, D65),
+		CssColourSpace::OkLab       => CieXyz::<f64>::from_s_rgb(OkLab::new(c0, c1, c2).to_s_rgb()),
+		CssColourSpace::XyzD50      => CieXyz::new(c0, c1, c2).adapt(D50, D65),
+		CssColourSpace::XyzD65      => CieXyz::new(c0, c1, c2),
+		CssColourSpace::Hsl         => CieXyz::<f64>::from_s_rgb(Hsl::new(c0, c1, c2).to_s_rgb()),
+		CssColourSpace::Hwb         => CieXyz::<f64>::from_s_rgb(Hwb::new(c0, c1, c2).to_s_rgb()),
+		CssColourSpace::Lch         => CieLch::new(c0, c1, c2).to_lab().to_xyz(D50).adapt(D50, D65),
+		CssColourSpace::OkLch       => CieXyz::<f64>::from_s_rgb(OkLch::new(c0, c1, c2).to_ok_lab().to_s_rgb()),
+	}
+}
+
+/// Converts a CIEXYZ colour relative to the D65 white point to channels of a colour space.
+#[must_use]
+fn from_xyz(space: CssColourSpace, colour: CieXyz<f64>) -> [f64; 0x3] {
+	let (c0, c1, c2) = match space {
+		CssColourSpace::SRgb        => colour.to_s_rgb().get(),
+		CssColourSpace::SRgbLinear  => colour.to_s_rgb().untransfer().get(),
+		CssColourSpace::DisplayP3   => DisplayP3::<f64>::from_xyz(colour).get(),
+		CssColourSpace::A98Rgb      => OpRgb::<f64>::from_xyz(colour).get(),
+		CssColourSpace::ProPhotoRgb => ProPhotoRgb::<f64>::from_xyz(colour.adapt(D65, D50)).get(),
+		CssColourSpace::Rec2020     => Rec2020::<f64>::from_xyz(colour).get(),
+		CssColourSpace::Lab         => CieLab::<f64>::from_xyz(colour.adapt(D65, D50), D50).get(),
+		CssColourSpace::OkLab       => OkLab::<f64>::from_s_rgb(colour.to_s_rgb()).get(),
+		CssColourSpace::XyzD50      => colour.adapt(D65, D50).get(),
+		CssColourSpace::XyzD65      => colour.get(),
+		CssColourSpace::Hsl         => Hsl::<f64>::from_s_rgb(colour.to_s_rgb()).get(),
+		CssColourSpace::Hwb         => Hwb::<f64>::from_s_rgb(colour.to_s_rgb()).get(),
+		CssColourSpace::Lch         => CieLch::<f64>::from_lab(CieLab::<f64>::from_xyz(colour.adapt(D65, D50), D50)).get(),
+		CssColourSpace::OkLch       => OkLch::<f64>::from_ok_lab(OkLab::<f64>::from_s_rgb(colour.to_s_rgb())).get(),
+	};
+
+	[c0, c1, c2]
+}
+
+impl CssColour {
+	/// Converts the colour to another colour space.
+	///
+	/// Missing components are carried forward if the new colour space has an analogous component (e.g. the lightness of [Lab](CssColourSpace::Lab) and [Oklch](CssColourSpace::OkLch)), and are otherwise treated as zero.
+	/// Legacy sRGB colours are converted to [`SRgb`](Self::SRgb).
+	///
+	/// Note that the returned colour is not clamped to any gamut.
+	#[must_use]
+	pub fn convert(self, space: CssColourSpace) -> Self {
+		let (source, channels, alpha) = self.components();
+
+		if source == space {
+			return Self::from_components(space, channels, alpha);
+		}
+
+		let mut resolved = channels;
+
+		for channel in &mut resolved {
+			if channel.is_nan() {
+				*channel = 0.0;
+			}
+		}
+
+		let mut converted = from_xyz(space, to_xyz(source, resolved));
+
+		// Carry forward missing components.
+
+		let source_categories = Category::of(source);
+		let categories        = Category::of(space);
+
+		for (index, category) in categories.into_iter().enumerate() {
+			let is_missing = category.is_some_and(|category| {
+				source_categories
+					.into_iter()
+					.zip(channels)
+					.any(|(other, channel)| other == Some(category) && channel.is_nan())
+			});
+
+			if is_missing {
+				converted[index] = f64::NAN;
+			}
+		}
+
+		Self::from_components(space, converted, alpha)
+	}
+
+	/// Mixes the colour with another colour.
+	///
+	/// This is done as by the CSS `color-mix()` function, i.e. both colours are [converted](Self::convert) to the given colour space and interpolated using premultiplied alpha.
+	/// The factor is the proportion of `other` in the mix, such that `0` yields `self`.
+	///
+	/// A missing component takes on the value of the same component of the other colour, and powerless hues are treated as missing.
+	/// The hue-interpolation method is ignored for spaces without a hue channel.
+	#[must_use]
+	pub fn mix(self, other: Self, factor: f64, space: CssColourSpace, method: HueInterpolation) -> Self {
+		let (_, mut this, mut this_alpha)  = self.convert(space).components();
+		let (_, mut that, mut other_alpha)  = other.convert(space).components();
+
+		if let Some(hue) = hue_index(space) {
+			if is_hue_powerless(space, this) {
+				this[hue] = f64::NAN;
+			}
+
+			if is_hue_powerless(space, that) {
+				that[hue] = f64::NAN;
+			}
+		}
+
+		for (this, that) in this.iter_mut().zip(&mut that).chain([(&mut this_alpha, &mut other_alpha)]) {
+			if this.is_nan() {
+				*this = *that;
+			} else if that.is_nan() {
+				*that = *this;
+			}
+		}
+
+		// Premultiplication is meaningless if both alphas
+		// are missing.
+		let is_alpha_missing = this_alpha.is_nan();
+
+		if is_alpha_missing {
+			this_alpha  = 1.0;
+			other_alpha = 1.0;
+		}
+
+		let this = Self::from_components(space, this, this_alpha);
+		let that = Self::from_components(space, that, other_alpha);
+
+		let mixed = match (this, that) {
+			(Self::SRgb(this),        Self::SRgb(that))        => Self::SRgb(this.interpolate(that, factor)),
+			(Self::SRgbLinear(this),  Self::SRgbLinear(that))  => Self::SRgbLinear(this.interpolate(that, factor)),
+			(Self::DisplayP3(this),   Self::DisplayP3(that))   => Self::DisplayP3(this.interpolate(that, factor)),
+			(Self::A98Rgb(this),      Self::A98Rgb(that))      => Self::A98Rgb(this.interpolate(that, factor)),
+			(Self::ProPhotoRgb(this), Self::ProPhotoRgb(that)) => Self::ProPhotoRgb(this.interpolate(that, factor)),
+			(Self::Rec2020(this),     Self::Rec2020(that))     => Self::Rec2020(this.interpolate(that, factor)),
+			(Self::Lab(this),         Self::Lab(that))         => Self::Lab(this.interpolate(that, factor)),
+			(Self::OkLab(this),       Self::OkLab(that))       => Self::OkLab(this.interpolate(that, factor)),
+			(Self::XyzD50(this),      Self::XyzD50(that))      => Self::XyzD50(this.interpolate(that, factor)),
+			(Self::XyzD65(this),      Self::XyzD65(that))      => Self::XyzD65(this.interpolate(that, factor)),
+			(Self::Hsl(this),         Self::Hsl(that))         => Self::Hsl(this.interpolate_hue(that, factor, method)),
+			(Self::Hwb(this),         Self::Hwb(that))         => Self::Hwb(this.interpolate_hue(that, factor, method)),
+			(Self::Lch(this),         Self::Lch(that))         => Self::Lch(this.interpolate_hue(that, factor, method)),
+			(Self::OkLch(this),       Self::OkLch(that))       => Self::OkLch(this.interpolate_hue(that, factor, method)),
+
+			_ => unreachable!(),
+		};
+
+		if is_alpha_missing {
+			let (space, channels, _) = mixed.components();
+			return Self::from_components(space, channels, f64::NAN);
+		}
+
+		mixed
+	}
+}
@@ -0,0 +1,311 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::Alpha;
+use crate::hsv::{Hsl, Hsv, Hwb};
+use crate::lab::{CieLab, OkLab};
+use crate::lch::{CieLch, OkLch};
+use crate::rgb::{DisplayP3, OpRgb, ProPhotoRgb, Rec2020, Rgb, SRgb};
+use crate::www::CssColour;
+use crate::www::css_colour::{hue_index, is_hue_powerless};
+use crate::xyz::CieXyz;
+
+use core::fmt::{self, Display, Formatter, Write};
+use core::str;
+
+/// The default number of significant digits of serialised numbers.
+const SIGNIFICANT_DIGITS: usize = 0x6;
+
+/// A buffer for formatting short numbers.
+#[derive(Debug)]
+struct Buffer {
+	data: [u8; 0x10],
+	len:  usize,
+}
+
+impl Buffer {
+	/// Constructs a new, empty buffer.
+	#[inline(always)]
+	#[must_use]
+	const fn new() -> Self {
+		Self { data: [0x0; 0x10], len: 0x0 }
+	}
+
+	/// Borrows the formatted string.
+	#[inline]
+	#[must_use]
+	fn as_str(&self) -> &str {
+		// SAFETY: Only complete strings are ever written
+		// to the buffer.
+		unsafe { str::from_utf8_unchecked(&self.data[..self.len]) }
+	}
+}
+
+impl Write for Buffer {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		let end = self.len + s.len();
+
+		let Some(data) = self.data.get_mut(self.len..end) else {
+			return Err(fmt::Error);
+		};
+
+		data.copy_from_slice(s.as_bytes());
+		self.len = end;
+
+		Ok(())
+	}
+}
+
+/// A serialised CSS number, percentage, or missing component.
+#[derive(Clone, Copy, Debug)]
+struct Number {
+	value:     f64,
+	unit:      &'static str,
+	precision: Option<usize>,
+}
+
+impl Display for Number {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		let Self { value, unit, precision } = *self;
+
+		if value.is_nan() {
+			return write!(f, "none");
+		}
+
+		if value.is_infinite() {
+			let sign = if value.is_sign_negative() { "-" } else { "" };
+
+			return if unit.is_empty() {
+				write!(f, "calc({sign}infinity)")
+			} else {
+				write!(f, "calc({sign}infinity * 1{unit})")
+			};
+		}
+
+		// Normalise negative zero.
+		let value = value + 0.0;
+
+		if let Some(precision) = precision {
+			return write!(f, "{value:.precision$}{unit}");
+		}
+
+		// Count the integral digits so that the value is
+		// rounded to the default number of significant
+		// digits. Smaller values are rounded to as many
+		// fractional digits instead.
+		let mut digits    = 0x1;
+		let mut magnitude = value.abs();
+
+		while magnitude >= 10.0 && digits < SIGNIFICANT_DIGITS {
+			magnitude /= 10.0;
+			digits    += 0x1;
+		}
+
+		let precision = if value.abs() < 1.0 { SIGNIFICANT_DIGITS } else { SIGNIFICANT_DIGITS - digits };
+
+		if precision == 0x0 {
+			return write!(f, "{value:.0}{unit}");
+		}
+
+		let mut buffer = Buffer::new();
+		write!(buffer, "{value:.precision$}")?;
+
+		let value = buffer.as_str().trim_end_matches('0').trim_end_matches('.');
+		let value = if value == "-0" { "0" } else { value };
+
+		write!(f, "{value}{unit}")
+	}
+}
+
+impl Display for CssColour {
+	/// Serialises the colour as CSS.
+	///
+	/// Each variant is serialised in its own notation, e.g. `rgb()` for [`Rgb`](Self::Rgb) and `color(display-p3 ...)` for [`DisplayP3`](Self::DisplayP3), using the modern (space-separated) syntax.
+	/// The result may be parsed back using the [`FromStr`](core::str::FromStr) implementation.
+	///
+	/// The precision of the formatter (e.g. `{:.3}`) gives the number of fractional digits of each component.
+	/// Without it, each component is rounded to six significant digits -- or six fractional digits if its magnitude is less than one -- with trailing zeros removed.
+	/// A larger precision may thus be given to serialise components exactly.
+	///
+	/// Missing components and powerless hues are serialised as `none`, and the alpha component is omitted if it is one.
+	///
+	/// The colour types of the crate that have a CSS notation are likewise serialised through this implementation, as is [`Hsv`] (using `hwb()`).
+	/// Other colours -- i.e. [`CieLuv`](crate::lab::CieLuv), [`CieLchUv`](crate::lch::CieLchUv), [`OkHsl`](crate::hsv::OkHsl), [`OkHsv`](crate::hsv::OkHsv), [`OkLrab`](crate::lab::OkLrab), [`OkLrch`](crate::lch::OkLrch), [`HsLuv`](crate::lch::HsLuv), and [`HpLuv`](crate::lch::HpLuv) -- do not have one and must be converted to a CSS colour space first.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use polywave::www::CssColour;
+	///
+	/// let colour: CssColour = "hsl(120deg 75% 25% / 100%)".parse().unwrap();
+	/// assert_eq!(format!("{colour}"), "hsl(120 75% 25%)");
+	///
+	/// let colour: CssColour = "oklch(0.62796 0.25768 29.23388 / 0.5)".parse().unwrap();
+	/// assert_eq!(format!("{colour:.2}"), "oklch(0.63 0.26 29.23 / 0.50)");
+	///
+	/// let colour: CssColour = "lch(50 0 120)".parse().unwrap();
+	/// assert_eq!(format!("{colour}"), "lch(50 0 none)");
+	/// ```
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		let (space, mut channels, alpha) = self.components();
+
+		if let Some(hue) = hue_index(space).filter(|_| is_hue_powerless(space, channels)) {
+			channels[hue] = f64::NAN;
+		}
+
+		let precision = f.precision();
+
+		let [c0, c1, c2] = channels;
+
+		let number     = |value: f64| Number { value, unit: "",  precision };
+		let percentage = |value: f64| Number { value: value * 100.0, unit: "%", precision };
+
+		match *self {
+			Self::Rgb(_) => {
+				let channel = |value: f64| number(value * 255.0);
+
+				write!(f, "rgb({} {} {}", channel(c0), channel(c1), channel(c2))?;
+			}
+
+			Self::Hsl(_) => write!(f, "hsl({} {} {}", number(c0), percentage(c1), percentage(c2))?,
+			Self::Hwb(_) => write!(f, "hwb({} {} {}", number(c0), percentage(c1), percentage(c2))?,
+
+			Self::Lab(_)   => write!(f, "lab({} {} {}",   number(c0), number(c1), number(c2))?,
+			Self::Lch(_)   => write!(f, "lch({} {} {}",   number(c0), number(c1), number(c2))?,
+			Self::OkLab(_) => write!(f, "oklab({} {} {}", number(c0), number(c1), number(c2))?,
+			Self::OkLch(_) => write!(f, "oklch({} {} {}", number(c0), number(c1), number(c2))?,
+
+			_ => write!(f, "color({} {} {} {}", space.name(), number(c0), number(c1), number(c2))?,
+		}
+
+		if alpha != 1.0 {
+			write!(f, " / {}", number(alpha))?;
+		}
+
+		write!(f, ")")
+	}
+}
+
+macro_rules! impl_display {
+	($($tys:ty),+$(,)?) => {$(
+		impl_display!(@colour $tys, Rgb         => SRgbLinear);
+		impl_display!(@colour $tys, Hsl         => Hsl);
+		impl_display!(@colour $tys, Hwb         => Hwb);
+		impl_display!(@colour $tys, OkLab       => OkLab);
+		impl_display!(@colour $tys, OkLch       => OkLch);
+		impl_display!(@colour $tys, DisplayP3   => DisplayP3);
+		impl_display!(@colour $tys, OpRgb       => A98Rgb);
+		impl_display!(@colour $tys, ProPhotoRgb => ProPhotoRgb);
+		impl_display!(@colour $tys, Rec2020     => Rec2020);
+
+		impl_display! {
+			@colour $tys, Hsv.to_hwb() => Hwb,
+			"The colour is serialised using `hwb()`, which denotes the same colour.",
+		}
+
+		impl_display! {
+			@colour $tys, CieLab => Lab,
+			"The colour is assumed to be relative to the [D50](CieXyz::D50) white point, as is `lab()`.",
+			"Colours relative to other white points should be [adapted](CieXyz::adapt) first.",
+		}
+
+		impl_display! {
+			@colour $tys, CieLch => Lch,
+			"The colour is assumed to be relative to the [D50](CieXyz::D50) white point, as is `lch()`.",
+			"Colours relative to other white points should be [adapted](CieXyz::adapt) first.",
+		}
+
+		impl_display! {
+			@colour $tys, CieXyz => XyzD65,
+			"The colour is assumed to be relative to the [D65](CieXyz::D65) white point and is thus serialised using `color(xyz-d65 ...)`.",
+			"Colours relative to other white points should be [adapted](CieXyz::adapt) first.",
+		}
+
+		impl Display for SRgb<$tys> {
+			/// Serialises the colour as CSS.
+			///
+			/// Colours inside of the sRGB gamut are serialised using `rgb()`, whereas other colours are serialised using `color(srgb ...)` so as to not be clamped when parsed.
+			/// See [`CssColour`] for more information.
+			#[inline]
+			fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+				Display::fmt(&Alpha::attach(*self, 1.0), f)
+			}
+		}
+
+		impl Display for Alpha<SRgb<$tys>> {
+			/// Serialises the colour as CSS.
+			///
+			/// See [`SRgb`] for more information.
+			fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+				let (colour, alpha) = self.detach();
+
+				#[allow(clippy::cast_lossless, clippy::cast_possible_truncation)]
+				let (colour, alpha) = (colour.map(|channel| channel as f64), alpha as f64);
+
+				let (red, green, blue) = colour.get();
+
+				let is_in_gamut = [red, green, blue].into_iter().all(|channel| (0.0..=1.0).contains(&channel));
+
+				let colour = Alpha::attach(colour, alpha);
+
+				let colour = if is_in_gamut {
+					CssColour::Rgb(colour)
+				} else {
+					CssColour::SRgb(colour)
+				};
+
+				Display::fmt(&colour, f)
+			}
+		}
+	)*};
+
+	(@colour $ty:ty, $colour:ident$(.$convert:ident())? => $variant:ident$(, $docs:literal)*$(,)?) => {
+		impl Display for $colour<$ty> {
+			/// Serialises the colour as CSS.
+			///
+			/// See [`CssColour`] for more information.
+			$(
+				///
+				#[doc = $docs]
+			)*
+			#[inline]
+			fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+				Display::fmt(&Alpha::attach(*self, 1.0), f)
+			}
+		}
+
+		impl Display for Alpha<$colour<$ty>> {
+			/// Serialises the colour as CSS.
+			///
+			/// See [`CssColour`] for more information.
+			$(
+				///
+				#[doc = $docs]
+			)*
+			#[inline]
+			fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+				let (colour, alpha) = self.detach();
+
+				$(let colour = colour.$convert();)?
+
+				#[allow(clippy::cast_lossless, clippy::cast_possible_truncation)]
+				let colour = Alpha::attach(colour.map(|channel| channel as f64), alpha as f64);
+
+				Display::fmt(&CssColour::$variant(colour), f)
+			}
+		}
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_display!(f16);
+
+impl_display!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_display!(f128);
@@ -0,0 +1,621 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::Alpha;
+#[cfg(feature = "maths")]
+use crate::HueInterpolation;
+use crate::error::CssColourFromStrError;
+use crate::hsv::{Hsl, Hwb};
+use crate::lab::{CieLab, OkLab};
+use crate::lch::{CieLch, OkLch};
+use crate::rgb::SRgb;
+use crate::www::{CssColour, CssColourScheme, CssColourSpace, Html};
+use crate::www::css_colour::calc;
+use crate::www::css_tokens::{
+	parse_angle,
+	parse_dimension,
+	parse_function,
+	split_commas,
+	split_first_token,
+	split_slashes,
+	split_whitespace,
+};
+
+use core::str::FromStr;
+
+/// A single component of a colour function.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum Value {
+	Number(f64),
+	Percentage(f64),
+
+	/// An angle in degrees.
+	Angle(f64),
+
+	/// The `none` keyword.
+	None,
+}
+
+impl Value {
+	/// Parses a component.
+	///
+	/// The channel keywords of relative colours are looked up in `keywords`.
+	#[must_use]
+	fn parse(token: &str, keywords: &[(&str, f64)]) -> Option<Self> {
+		if token.eq_ignore_ascii_case("none") {
+			return Some(Self::None);
+		}
+
+		if let Some(&(_, value)) = keywords.iter().find(|(name, _)| name.eq_ignore_ascii_case(token)) {
+			return Some(Self::Number(value));
+		}
+
+		if parse_function(token).is_some() {
+			return calc::evaluate(token, keywords);
+		}
+
+		match parse_dimension(token)? {
+			(value, "")  => Some(Self::Number(value)),
+			(value, "%") => Some(Self::Percentage(value)),
+
+			_ => parse_angle(token).map(Self::Angle),
+		}
+	}
+
+	/// Resolves a number or percentage.
+	///
+	/// Percentages are relative to `reference`.
+	#[inline]
+	fn number(self, reference: f64) -> Result<f64, CssColourFromStrError> {
+		match self {
+			Self::Number(value)     => Ok(value),
+			Self::Percentage(value) => Ok(value / 100.0 * reference),
+			Self::None              => Ok(f64::NAN),
+
+			Self::Angle(_) => Err(CssColourFromStrError::InvalidSyntax),
+		}
+	}
+
+	/// Resolves a hue in degrees.
+	#[inline]
+	fn hue(self) -> Result<f64, CssColourFromStrError> {
+		match self {
+			Self::Number(value) | Self::Angle(value) => Ok(value),
+			Self::None                               => Ok(f64::NAN),
+
+			Self::Percentage(_) => Err(CssColourFromStrError::InvalidSyntax),
+		}
+	}
+}
+
+/// A parser of the arguments of a colour function.
+type ParseFn = fn(Arguments) -> Result<CssColour, CssColourFromStrError>;
+
+/// A colour function.
+#[derive(Clone, Copy)]
+struct Function {
+	name:  &'static str,
+	parse: ParseFn,
+
+	/// The colour space of relative colours.
+	#[cfg_attr(not(feature = "maths"), allow(dead_code))]
+	space: CssColourSpace,
+
+	/// The channel keywords of relative colours.
+	///
+	/// These are given as their names and the factors by which the channels of [`space`](Self::space) are scaled.
+	#[cfg_attr(not(feature = "maths"), allow(dead_code))]
+	keywords: [(&'static str, f64); 0x3],
+}
+
+/// The colour functions, excluding `color()`.
+const FUNCTIONS: [Function; 0x7] = [
+	Function { name: "rgb",   parse: parse_rgb,    space: CssColourSpace::SRgb,  keywords: [("r", 255.0), ("g", 255.0), ("b", 255.0)] },
+	Function { name: "hsl",   parse: parse_hsl,    space: CssColourSpace::Hsl,   keywords: [("h", 1.0),   ("s", 100.0), ("l", 100.0)] },
+	Function { name: "hwb",   parse: parse_hwb,    space: CssColourSpace::Hwb,   keywords: [("h", 1.0),   ("w", 100.0), ("b", 100.0)] },
+	Function { name: "lab",   parse: parse_lab,    space: CssColourSpace::Lab,   keywords: [("l", 1.0),   ("a", 1.0),   ("b", 1.0)] },
+	Function { name: "lch",   parse: parse_lch,    space: CssColourSpace::Lch,   keywords: [("l", 1.0),   ("c", 1.0),   ("h", 1.0)] },
+	Function { name: "oklab", parse: parse_ok_lab, space: CssColourSpace::OkLab, keywords: [("l", 1.0),   ("a", 1.0),   ("b", 1.0)] },
+	Function { name: "oklch", parse: parse_ok_lch, space: CssColourSpace::OkLch, keywords: [("l", 1.0),   ("c", 1.0),   ("h", 1.0)] },
+];
+
+/// The components of a colour function.
+#[derive(Clone, Copy, Debug)]
+struct Arguments {
+	channels: [Value; 0x3],
+	alpha:    Value,
+
+	/// Whether the legacy, comma-separated syntax was
+	/// used.
+	legacy: bool,
+}
+
+impl Arguments {
+	/// Resolves the alpha component.
+	#[inline]
+	fn alpha(self) -> Result<f64, CssColourFromStrError> {
+		self.alpha.number(1.0).map(|alpha| clamp(alpha, 0.0, 1.0))
+	}
+}
+
+impl CssColour {
+	/// Parses a CSS colour from a string using a given colour scheme.
+	///
+	/// The colour scheme decides which colour of a `light-dark()` function is used.
+	/// See also the [`FromStr`] implementation, which assumes the [light](CssColourScheme::Light) scheme.
+	///
+	/// # Errors
+	///
+	/// If the string is not a valid CSS colour, an error is returned.
+	pub fn parse_with_scheme(s: &str, scheme: CssColourScheme) -> Result<Self, CssColourFromStrError> {
+		let Some((name, arguments)) = parse_function(s) else {
+			return Html::from_str(s)
+				.map(Into::into)
+				.map_err(CssColourFromStrError::InvalidHtml);
+		};
+
+		if name.eq_ignore_ascii_case("light-dark") {
+			return parse_light_dark(arguments, scheme);
+		}
+
+		#[cfg(feature = "maths")]
+		if name.eq_ignore_ascii_case("color-mix") {
+			return parse_color_mix(arguments, scheme);
+		}
+
+		let name = name.strip_suffix(['a', 'A']).filter(|name| {
+			name.eq_ignore_ascii_case("rgb") || name.eq_ignore_ascii_case("hsl")
+		}).unwrap_or(name);
+
+		if name.eq_ignore_ascii_case("color") {
+			return parse_color(arguments, scheme);
+		}
+
+		let function = FUNCTIONS
+			.into_iter()
+			.find(|function| function.name.eq_ignore_ascii_case(name))
+			.ok_or(CssColourFromStrError::UnknownFunction)?;
+
+		#[cfg(feature = "maths")]
+		if let Some((_, rest)) = split_first_token(arguments).filter(|(token, _)| token.eq_ignore_ascii_case("from")) {
+			let (origin, rest) = split_first_token(rest).ok_or(CssColourFromStrError::InvalidSyntax)?;
+
+			let arguments = parse_relative(origin, function.space, function.keywords, rest, scheme)?;
+
+			return (function.parse)(arguments);
+		}
+
+		let arguments = parse_arguments(arguments, &[])?;
+
+		// Only `rgb()` and `hsl()` accept the legacy
+		// syntax.
+		if arguments.legacy && function.name != "rgb" && function.name != "hsl" {
+			return Err(CssColourFromStrError::InvalidSyntax);
+		}
+
+		(function.parse)(arguments)
+	}
+}
+
+impl FromStr for CssColour {
+	type Err = CssColourFromStrError;
+
+	/// Parses a CSS colour from a string.
+	///
+	/// Function names, keywords, and units are matched case-insensitively.
+	/// Values that are not functions are parsed as [HTML colours](Html).
+	///
+	/// As per CSS, `rgb()` channels and alpha values are clamped to their valid ranges, as are lightness and chroma/saturation values that would otherwise be out of range.
+	/// Components of `color()` are not clamped.
+	///
+	/// Components may be given as `calc()` expressions.
+	/// The `light-dark()` function resolves to its first colour; use [`parse_with_scheme`](Self::parse_with_scheme) to select the dark colour instead.
+	///
+	/// With the `maths` feature, the `color-mix()` function and the relative colour syntax (e.g. `oklch(from #0AF calc(l * 0.8) c h)`) of [CSS Color 5](https://www.w3.org/TR/css-color-5/) are also accepted.
+	#[inline]
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::parse_with_scheme(s, CssColourScheme::Light)
+	}
+}
+
+/// Clamps a value, preserving NaN.
+#[inline(always)]
+#[must_use]
+fn clamp(value: f64, minimum: f64, maximum: f64) -> f64 {
+	if value < minimum {
+		minimum
+	} else if value > maximum {
+		maximum
+	} else {
+		value
+	}
+}
+
+/// Parses the components of a colour function.
+///
+/// Both the modern (space-separated) and the legacy (comma-separated) syntaxes are accepted.
+/// The channel keywords of relative colours are given by `keywords`, in which case a missing alpha defaults to the `alpha` keyword.
+fn parse_arguments(arguments: &str, keywords: &[(&str, f64)]) -> Result<Arguments, CssColourFromStrError> {
+	/// Parses a component into the next free slot.
+	fn push(
+		values:   &mut [Value; 0x4],
+		count:    &mut usize,
+		token:    &str,
+		keywords: &[(&str, f64)],
+	) -> Result<(), CssColourFromStrError> {
+		let value = Value::parse(token, keywords).ok_or(CssColourFromStrError::InvalidSyntax)?;
+
+		let slot = values.get_mut(*count).ok_or(CssColourFromStrError::InvalidSyntax)?;
+		*slot = value;
+
+		*count += 0x1;
+		Ok(())
+	}
+
+	let mut values = [Value::None; 0x4];
+	let mut count  = 0x0;
+
+	let legacy = split_commas(arguments).nth(0x1).is_some();
+
+	if legacy {
+		for part in split_commas(arguments) {
+			let mut tokens = split_whitespace(part);
+
+			let token = tokens.next().ok_or(CssColourFromStrError::InvalidSyntax)?;
+
+			if tokens.next().is_some() || token.eq_ignore_ascii_case("none") {
+				return Err(CssColourFromStrError::InvalidSyntax);
+			}
+
+			push(&mut values, &mut count, token, keywords)?;
+		}
+	} else {
+		let mut parts = split_slashes(arguments);
+
+		let channels = parts.next().unwrap_or_default();
+
+		for token in split_whitespace(channels) {
+			push(&mut values, &mut count, token, keywords)?;
+		}
+
+		if count != 0x3 {
+			return Err(CssColourFromStrError::InvalidSyntax);
+		}
+
+		if let Some(alpha) = parts.next() {
+			let mut tokens = split_whitespace(alpha);
+
+			let token = tokens.next().ok_or(CssColourFromStrError::InvalidSyntax)?;
+
+			if tokens.next().is_some() || parts.next().is_some() {
+				return Err(CssColourFromStrError::InvalidSyntax);
+			}
+
+			push(&mut values, &mut count, token, keywords)?;
+		}
+	}
+
+	let alpha = match count {
+		0x3 => Value::parse("alpha", keywords).unwrap_or(Value::Number(1.0)),
+		0x4 => values[0x3],
+
+		_ => return Err(CssColourFromStrError::InvalidSyntax),
+	};
+
+	let arguments = Arguments {
+		channels: [values[0x0], values[0x1], values[0x2]],
+		alpha,
+		legacy,
+	};
+
+	Ok(arguments)
+}
+
+/// Parses the arguments of `rgb()` and `rgba()`.
+fn parse_rgb(arguments: Arguments) -> Result<CssColour, CssColourFromStrError> {
+	let [red, green, blue] = arguments.channels;
+
+	// Legacy channels must be either all numbers or
+	// all percentages.
+	if arguments.legacy
+		&& !matches!(arguments.channels, [Value::Number(_), Value::Number(_), Value::Number(_)])
+		&& !matches!(arguments.channels, [Value::Percentage(_), Value::Percentage(_), Value::Percentage(_)])
+	{
+		return Err(CssColourFromStrError::InvalidSyntax);
+	}
+
+	let channel = |value: Value| value.number(255.0).map(|value| clamp(value / 255.0, 0.0, 1.0));
+
+	let colour = SRgb::new(channel(red)?, channel(green)?, channel(blue)?);
+
+	Ok(CssColour::Rgb(Alpha::attach(colour, arguments.alpha()?)))
+}
+
+/// Parses the arguments of `hsl()` and `hsla()`.
+fn parse_hsl(arguments: Arguments) -> Result<CssColour, CssColourFromStrError> {
+	let [hue, saturation, lightness] = arguments.channels;
+
+	// Legacy saturation and lightness must be
+	// percentages.
+	if arguments.legacy && !matches!((saturation, lightness), (Value::Percentage(_), Value::Percentage(_))) {
+		return Err(CssColourFromStrError::InvalidSyntax);
+	}
+
+	let hue        = hue.hue()?;
+	let saturation = clamp(saturation.number(100.0)? / 100.0, 0.0, f64::INFINITY);
+	let lightness  = lightness.number(100.0)? / 100.0;
+
+	let colour = Hsl::new(hue, saturation, lightness);
+
+	Ok(CssColour::Hsl(Alpha::attach(colour, arguments.alpha()?)))
+}
+
+/// Parses the arguments of `hwb()`.
+fn parse_hwb(arguments: Arguments) -> Result<CssColour, CssColourFromStrError> {
+	let [hue, whiteness, blackness] = arguments.channels;
+
+	let hue       = hue.hue()?;
+	let whiteness = whiteness.number(100.0)? / 100.0;
+	let blackness = blackness.number(100.0)? / 100.0;
+
+	let colour = Hwb::new(hue, whiteness, blackness);
+
+	Ok(CssColour::Hwb(Alpha::attach(colour, arguments.alpha()?)))
+}
+
+/// Parses the arguments of `lab()`.
+fn parse_lab(arguments: Arguments) -> Result<CssColour, CssColourFromStrError> {
+	let [luminance, a_star, b_star] = arguments.channels;
+
+	let luminance = clamp(luminance.number(100.0)?, 0.0, 100.0);
+	let a_star    = a_star.number(125.0)?;
+	let b_star    = b_star.number(125.0)?;
+
+	let colour = CieLab::new(luminance, a_star, b_star);
+
+	Ok(CssColour::Lab(Alpha::attach(colour, arguments.alpha()?)))
+}
+
+/// Parses the arguments of `lch()`.
+fn parse_lch(arguments: Arguments) -> Result<CssColour, CssColourFromStrError> {
+	let [luminance, chroma, hue] = arguments.channels;
+
+	let luminance = clamp(luminance.number(100.0)?, 0.0, 100.0);
+	let chroma    = clamp(chroma.number(150.0)?, 0.0, f64::INFINITY);
+	let hue       = hue.hue()?;
+
+	let colour = CieLch::new(luminance, chroma, hue);
+
+	Ok(CssColour::Lch(Alpha::attach(colour, arguments.alpha()?)))
+}
+
+/// Parses the arguments of `oklab()`.
+fn parse_ok_lab(arguments: Arguments) -> Result<CssColour, CssColourFromStrError> {
+	let [luminance, a_star, b_star] = arguments.channels;
+
+	let luminance = clamp(luminance.number(1.0)?, 0.0, 1.0);
+	let a_star    = a_star.number(0.4)?;
+	let b_star    = b_star.number(0.4)?;
+
+	let colour = OkLab::new(luminance, a_star, b_star);
+
+	Ok(CssColour::OkLab(Alpha::attach(colour, arguments.alpha()?)))
+}
+
+/// Parses the arguments of `oklch()`.
+fn parse_ok_lch(arguments: Arguments) -> Result<CssColour, CssColourFromStrError> {
+	let [luminance, chroma, hue] = arguments.channels;
+
+	let luminance = clamp(luminance.number(1.0)?, 0.0, 1.0);
+	let chroma    = clamp(chroma.number(0.4)?, 0.0, f64::INFINITY);
+	let hue       = hue.hue()?;
+
+	let colour = OkLch::new(luminance, chroma, hue);
+
+	Ok(CssColour::OkLch(Alpha::attach(colour, arguments.alpha()?)))
+}
+
+/// Parses the arguments of `color()`.
+#[cfg_attr(not(feature = "maths"), allow(unused_variables))]
+fn parse_color(arguments: &str, scheme: CssColourScheme) -> Result<CssColour, CssColourFromStrError> {
+	let (mut name, mut rest) = split_first_token(arguments).ok_or(CssColourFromStrError::InvalidSyntax)?;
+
+	let mut origin = None;
+
+	if name.eq_ignore_ascii_case("from") {
+		let (token, new_rest) = split_first_token(rest).ok_or(CssColourFromStrError::InvalidSyntax)?;
+		origin = Some(token);
+
+		(name, rest) = split_first_token(new_rest).ok_or(CssColourFromStrError::InvalidSyntax)?;
+	}
+
+	let space = CssColourSpace::from_name(name)
+		.filter(|space| space.is_predefined())
+		.ok_or(CssColourFromStrError::UnknownColourSpace)?;
+
+	let arguments = match origin {
+		#[cfg(feature = "maths")]
+		Some(origin) => {
+			let keywords = if matches!(space, CssColourSpace::XyzD50 | CssColourSpace::XyzD65) {
+				[("x", 1.0), ("y", 1.0), ("z", 1.0)]
+			} else {
+				[("r", 1.0), ("g", 1.0), ("b", 1.0)]
+			};
+
+			parse_relative(origin, space, keywords, rest, scheme)?
+		}
+
+		#[cfg(not(feature = "maths"))]
+		Some(_) => return Err(CssColourFromStrError::InvalidSyntax),
+
+		None => parse_arguments(rest, &[])?,
+	};
+
+	if arguments.legacy {
+		return Err(CssColourFromStrError::InvalidSyntax);
+	}
+
+	let [c0, c1, c2] = arguments.channels;
+
+	let (c0, c1, c2) = (c0.number(1.0)?, c1.number(1.0)?, c2.number(1.0)?);
+
+	let alpha = arguments.alpha()?;
+
+	let colour = CssColour::from_components(space, [c0, c1, c2], alpha);
+
+	Ok(colour)
+}
+
+/// Parses the arguments of a relative colour following its origin colour.
+///
+/// The origin colour is converted to the colour space of the function, and its channels are scaled to give the values of the channel keywords.
+#[cfg(feature = "maths")]
+fn parse_relative(
+	origin:   &str,
+	space:    CssColourSpace,
+	keywords: [(&str, f64); 0x3],
+	rest:     &str,
+	scheme:   CssColourScheme,
+) -> Result<Arguments, CssColourFromStrError> {
+	use crate::www::css_colour::resolve_missing;
+
+	let origin = CssColour::parse_with_scheme(origin, scheme)?;
+
+	let (_, channels, alpha) = origin.convert(space).components();
+
+	let [(k0, s0), (k1, s1), (k2, s2)] = keywords;
+
+	let keywords = [
+		(k0,      resolve_missing(channels[0x0]) * s0),
+		(k1,      resolve_missing(channels[0x1]) * s1),
+		(k2,      resolve_missing(channels[0x2]) * s2),
+		("alpha", resolve_missing(alpha)),
+	];
+
+	let arguments = parse_arguments(rest, &keywords)?;
+
+	if arguments.legacy {
+		return Err(CssColourFromStrError::InvalidSyntax);
+	}
+
+	Ok(arguments)
+}
+
+/// Parses the arguments of `color-mix()`.
+#[cfg(feature = "maths")]
+fn parse_color_mix(arguments: &str, scheme: CssColourScheme) -> Result<CssColour, CssColourFromStrError> {
+	/// Parses a colour and its optional percentage.
+	fn parse_item(item: &str, scheme: CssColourScheme) -> Result<(CssColour, Option<f64>), CssColourFromStrError> {
+		let percentage = |token: &str| match parse_dimension(token) {
+			Some((value, "%")) if (0.0..=100.0).contains(&value) => Some(value),
+
+			_ => None,
+		};
+
+		let mut tokens = split_whitespace(item);
+
+		let (colour, percentage) = match (tokens.next(), tokens.next(), tokens.next()) {
+			(Some(colour), None, None) => (colour, None),
+
+			(Some(first), Some(second), None) => {
+				if let Some(value) = percentage(first) {
+					(second, Some(value))
+				} else {
+					(first, Some(percentage(second).ok_or(CssColourFromStrError::InvalidSyntax)?))
+				}
+			}
+
+			_ => return Err(CssColourFromStrError::InvalidSyntax),
+		};
+
+		let colour = CssColour::parse_with_scheme(colour, scheme)?;
+
+		Ok((colour, percentage))
+	}
+
+	let mut parts = split_commas(arguments);
+
+	let (Some(method), Some(first), Some(second), None) = (parts.next(), parts.next(), parts.next(), parts.next()) else {
+		return Err(CssColourFromStrError::InvalidSyntax);
+	};
+
+	let mut tokens = split_whitespace(method);
+
+	if !tokens.next().is_some_and(|token| token.eq_ignore_ascii_case("in")) {
+		return Err(CssColourFromStrError::InvalidSyntax);
+	}
+
+	let space = tokens.next().ok_or(CssColourFromStrError::InvalidSyntax)?;
+	let space = CssColourSpace::from_name(space).ok_or(CssColourFromStrError::UnknownColourSpace)?;
+
+	let methods = [
+		("shorter",    HueInterpolation::Shorter),
+		("longer",     HueInterpolation::Longer),
+		("increasing", HueInterpolation::Increasing),
+		("decreasing", HueInterpolation::Decreasing),
+	];
+
+	let method = match (tokens.next(), tokens.next(), tokens.next()) {
+		(None, None, None) => HueInterpolation::Shorter,
+
+		(Some(method), Some(hue), None) if space.is_polar() && hue.eq_ignore_ascii_case("hue") => {
+			methods
+				.into_iter()
+				.find(|(name, _)| name.eq_ignore_ascii_case(method))
+				.map(|(_, method)| method)
+				.ok_or(CssColourFromStrError::InvalidSyntax)?
+		}
+
+		_ => return Err(CssColourFromStrError::InvalidSyntax),
+	};
+
+	let (first, first_percentage)   = parse_item(first, scheme)?;
+	let (second, second_percentage) = parse_item(second, scheme)?;
+
+	let (first_percentage, second_percentage) = match (first_percentage, second_percentage) {
+		(None, None) => (50.0, 50.0),
+
+		(Some(first), None)  => (first, 100.0 - first),
+		(None, Some(second)) => (100.0 - second, second),
+
+		(Some(first), Some(second)) => (first, second),
+	};
+
+	let sum = first_percentage + second_percentage;
+
+	if sum <= 0.0 {
+		return Err(CssColourFromStrError::InvalidSyntax);
+	}
+
+	let colour = first.mix(second, second_percentage / sum, space, method);
+
+	// Percentages summing to less than 100% make the
+	// result transparent.
+	if sum < 100.0 {
+		let (space, channels, alpha) = colour.components();
+		return Ok(CssColour::from_components(space, channels, alpha * sum / 100.0));
+	}
+
+	Ok(colour)
+}
+
+/// Parses the arguments of `light-dark()`.
+fn parse_light_dark(arguments: &str, scheme: CssColourScheme) -> Result<CssColour, CssColourFromStrError> {
+	let mut parts = split_commas(arguments);
+
+	let (Some(light), Some(dark), None) = (parts.next(), parts.next(), parts.next()) else {
+		return Err(CssColourFromStrError::InvalidSyntax);
+	};
+
+	let colour = match scheme {
+		CssColourScheme::Light => light,
+		CssColourScheme::Dark  => dark,
+	};
+
+	CssColour::parse_with_scheme(colour, scheme)
+}
@@ -0,0 +1,289 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+mod calc;
+#[cfg(feature = "maths")]
+mod convert;
+mod display;
+mod from_str;
+mod test;
+
+use crate::Alpha;
+use crate::hsv::{Hsl, Hwb};
+use crate::hue_interpolate::{POWERLESS_CIE_CHROMA, POWERLESS_OK_CHROMA, POWERLESS_SATURATION};
+use crate::lab::{CieLab, OkLab};
+use crate::lch::{CieLch, OkLch};
+use crate::rgb::{DisplayP3, OpRgb, ProPhotoRgb, Rec2020, Rgb, SRgb};
+use crate::www::{CssColourSpace, Html};
+use crate::xyz::CieXyz;
+
+/// A CSS colour value.
+///
+/// This type represents the colour values of [CSS Color 4](https://www.w3.org/TR/css-color-4/), i.e. hexadecimal and named colours as well as the `rgb()`, `hsl()`, `hwb()`, `lab()`, `lch()`, `oklab()`, `oklch()`, and `color()` functions.
+/// Each variant holds the colour in the crate type matching its notation, with all channels given in the units of that type (e.g. [`Hsl`] with saturation and lightness in the range `0` to `1`).
+///
+/// Missing components (i.e. `none`) are represented as NaN.
+/// When converting to other colours, these are treated as zero.
+///
+/// # Examples
+///
+/// ```
+/// use polywave::Alpha;
+/// use polywave::lch::OkLch;
+/// use polywave::www::CssColour;
+///
+/// let colour: CssColour = "oklch(62.8% 0.2577 29.23 / 50%)".parse().unwrap();
+///
+/// assert_eq!(colour, CssColour::OkLch(Alpha::attach(OkLch::new(0.628, 0.2577, 29.23), 0.5)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CssColour {
+	/// A hexadecimal, named, or `rgb()` colour.
+	///
+	/// Unlike [`SRgb`](Self::SRgb), this is a legacy colour.
+	Rgb(Alpha<SRgb<f64>>),
+
+	/// An `hsl()` colour.
+	Hsl(Alpha<Hsl<f64>>),
+
+	/// An `hwb()` colour.
+	Hwb(Alpha<Hwb<f64>>),
+
+	/// A `lab()` colour, relative to the [D50](CieXyz::D50) white point.
+	Lab(Alpha<CieLab<f64>>),
+
+	/// An `lch()` colour, relative to the [D50](CieXyz::D50) white point.
+	Lch(Alpha<CieLch<f64>>),
+
+	/// An `oklab()` colour.
+	OkLab(Alpha<OkLab<f64>>),
+
+	/// An `oklch()` colour.
+	OkLch(Alpha<OkLch<f64>>),
+
+	/// A `color(srgb ...)` colour.
+	SRgb(Alpha<SRgb<f64>>),
+
+	/// A `color(srgb-linear ...)` colour, i.e. sRGB without the transfer function.
+	SRgbLinear(Alpha<Rgb<f64>>),
+
+	/// A `color(display-p3 ...)` colour.
+	DisplayP3(Alpha<DisplayP3<f64>>),
+
+	/// A `color(a98-rgb ...)` colour.
+	A98Rgb(Alpha<OpRgb<f64>>),
+
+	/// A `color(prophoto-rgb ...)` colour.
+	ProPhotoRgb(Alpha<ProPhotoRgb<f64>>),
+
+	/// A `color(rec2020 ...)` colour.
+	Rec2020(Alpha<Rec2020<f64>>),
+
+	/// A `color(xyz-d50 ...)` colour.
+	XyzD50(Alpha<CieXyz<f64>>),
+
+	/// A `color(xyz-d65 ...)` (or `color(xyz ...)`) colour.
+	XyzD65(Alpha<CieXyz<f64>>),
+}
+
+impl CssColour {
+	/// Retrieves the colour space of the colour.
+	#[must_use]
+	pub const fn colour_space(&self) -> CssColourSpace {
+		match *self {
+			Self::Rgb(_)         => CssColourSpace::SRgb,
+			Self::Hsl(_)         => CssColourSpace::Hsl,
+			Self::Hwb(_)         => CssColourSpace::Hwb,
+			Self::Lab(_)         => CssColourSpace::Lab,
+			Self::Lch(_)         => CssColourSpace::Lch,
+			Self::OkLab(_)       => CssColourSpace::OkLab,
+			Self::OkLch(_)       => CssColourSpace::OkLch,
+			Self::SRgb(_)        => CssColourSpace::SRgb,
+			Self::SRgbLinear(_)  => CssColourSpace::SRgbLinear,
+			Self::DisplayP3(_)   => CssColourSpace::DisplayP3,
+			Self::A98Rgb(_)      => CssColourSpace::A98Rgb,
+			Self::ProPhotoRgb(_) => CssColourSpace::ProPhotoRgb,
+			Self::Rec2020(_)     => CssColourSpace::Rec2020,
+			Self::XyzD50(_)      => CssColourSpace::XyzD50,
+			Self::XyzD65(_)      => CssColourSpace::XyzD65,
+		}
+	}
+
+	/// Constructs a colour from its colour space, channels, and alpha.
+	///
+	/// sRGB colours are constructed as [`SRgb`](Self::SRgb), i.e. not as legacy colours.
+	#[must_use]
+	pub(crate) const fn from_components(space: CssColourSpace, channels: [f64; 0x3], alpha: f64) -> Self {
+		let [c0, c1, c2] = channels;
+
+		match space {
+			CssColourSpace::SRgb        => Self::SRgb(Alpha::attach(SRgb::new(c0, c1, c2), alpha)),
+			CssColourSpace::SRgbLinear  => Self::SRgbLinear(Alpha::attach(Rgb::new(c0, c1, c2), alpha)),
+			CssColourSpace::DisplayP3   => Self::DisplayP3(Alpha::attach(DisplayP3::new(c0, c1, c2), alpha)),
+			CssColourSpace::A98Rgb      => Self::A98Rgb(Alpha::attach(OpRgb::new(c0, c1, c2), alpha)),
+			CssColourSpace::ProPhotoRgb => Self::ProPhotoRgb(Alpha::attach(ProPhotoRgb::new(c0, c1, c2), alpha)),
+			CssColourSpace::Rec2020     => Self::Rec2020(Alpha::attach(Rec2020::new(c0, c1, c2), alpha)),
+			CssColourSpace::Lab         => Self::Lab(Alpha::attach(CieLab::new(c0, c1, c2), alpha)),
+			CssColourSpace::OkLab       => Self::OkLab(Alpha::attach(OkLab::new(c0, c1, c2), alpha)),
+			CssColourSpace::XyzD50      => Self::XyzD50(Alpha::attach(CieXyz::new(c0, c1, c2), alpha)),
+			CssColourSpace::XyzD65      => Self::XyzD65(Alpha::attach(CieXyz::new(c0, c1, c2), alpha)),
+			CssColourSpace::Hsl         => Self::Hsl(Alpha::attach(Hsl::new(c0, c1, c2), alpha)),
+			CssColourSpace::Hwb         => Self::Hwb(Alpha::attach(Hwb::new(c0, c1, c2), alpha)),
+			CssColourSpace::Lch         => Self::Lch(Alpha::attach(CieLch::new(c0, c1, c2), alpha)),
+			CssColourSpace::OkLch       => Self::OkLch(Alpha::attach(OkLch::new(c0, c1, c2), alpha)),
+		}
+	}
+
+	/// Deconstructs the colour into its colour space, channels, and alpha.
+	#[must_use]
+	pub(crate) const fn components(self) -> (CssColourSpace, [f64; 0x3], f64) {
+		/// Deconstructs a single colour.
+		macro_rules! components {
+			($colour:expr$(,)?) => {{
+				let (colour, alpha) = $colour.detach();
+				let (c0, c1, c2)    = colour.get();
+
+				(self.colour_space(), [c0, c1, c2], alpha)
+			}};
+		}
+
+		match self {
+			Self::Rgb(colour) | Self::SRgb(colour) => components!(colour),
+
+			Self::Hsl(colour)         => components!(colour),
+			Self::Hwb(colour)         => components!(colour),
+			Self::Lab(colour)         => components!(colour),
+			Self::Lch(colour)         => components!(colour),
+			Self::OkLab(colour)       => components!(colour),
+			Self::OkLch(colour)       => components!(colour),
+			Self::SRgbLinear(colour)  => components!(colour),
+			Self::DisplayP3(colour)   => components!(colour),
+			Self::A98Rgb(colour)      => components!(colour),
+			Self::ProPhotoRgb(colour) => components!(colour),
+			Self::Rec2020(colour)     => components!(colour),
+
+			Self::XyzD50(colour) | Self::XyzD65(colour) => components!(colour),
+		}
+	}
+
+	/// Checks whether the colour is a legacy colour.
+	///
+	/// Legacy colours are those of the hexadecimal and named notations and of the `rgb()`, `hsl()`, and `hwb()` functions.
+	/// CSS interpolates these in sRGB by default, whereas other colours are interpolated in Oklab.
+	#[must_use]
+	pub const fn is_legacy(&self) -> bool {
+		matches!(*self, Self::Rgb(_) | Self::Hsl(_) | Self::Hwb(_))
+	}
+
+	/// Converts the colour to sRGB.
+	///
+	/// Missing components are treated as zero.
+	/// Note that the returned colour is not clamped to the sRGB gamut.
+	#[cfg(feature = "maths")]
+	#[must_use]
+	pub fn to_s_rgb(self) -> Alpha<SRgb<f64>> {
+		const D50: CieXyz<f64> = CieXyz::<f64>::D50;
+		const D65: CieXyz<f64> = CieXyz::<f64>::D65;
+
+		/// Resolves missing components and converts the
+		/// colour.
+		macro_rules! convert {
+			($colour:expr, $op:expr$(,)?) => {{
+				let (colour, alpha) = $colour.detach();
+
+				let colour = colour.map(resolve_missing);
+				let alpha  = resolve_missing(alpha);
+
+				Alpha::attach($op(colour), alpha)
+			}};
+		}
+
+		match self {
+			Self::Rgb(colour) | Self::SRgb(colour) => convert!(colour, |colour| colour),
+
+			Self::Hsl(colour) => convert!(colour, Hsl::<f64>::to_s_rgb),
+
+			Self::Hwb(colour) => convert!(colour, Hwb::<f64>::to_s_rgb),
+
+			Self::Lab(colour) => convert!(colour, |colour: CieLab<f64>| colour.to_xyz(D50).adapt(D50, D65).to_s_rgb()),
+
+			Self::Lch(colour) => convert!(colour, |colour: CieLch<f64>| colour.to_lab().to_xyz(D50).adapt(D50, D65).to_s_rgb()),
+
+			Self::OkLab(colour) => convert!(colour, OkLab::<f64>::to_s_rgb),
+
+			Self::OkLch(colour) => convert!(colour, |colour: OkLch<f64>| colour.to_ok_lab().to_s_rgb()),
+
+			Self::SRgbLinear(colour) => convert!(colour, SRgb::<f64>::transfer),
+
+			Self::DisplayP3(colour) => convert!(colour, |colour: DisplayP3<f64>| colour.to_xyz().to_s_rgb()),
+
+			Self::A98Rgb(colour) => convert!(colour, |colour: OpRgb<f64>| colour.to_xyz().to_s_rgb()),
+
+			Self::ProPhotoRgb(colour) => convert!(colour, |colour: ProPhotoRgb<f64>| colour.to_xyz().adapt(D50, D65).to_s_rgb()),
+
+			Self::Rec2020(colour) => convert!(colour, |colour: Rec2020<f64>| colour.to_xyz().to_s_rgb()),
+
+			Self::XyzD50(colour) => convert!(colour, |colour: CieXyz<f64>| colour.adapt(D50, D65).to_s_rgb()),
+
+			Self::XyzD65(colour) => convert!(colour, CieXyz::<f64>::to_s_rgb),
+		}
+	}
+}
+
+impl From<Html> for CssColour {
+	#[inline]
+	fn from(value: Html) -> Self {
+		let (colour, alpha) = value.to_s_rgba().detach();
+
+		let colour = colour.map(|channel| f64::from(channel) / 255.0);
+		let alpha  = f64::from(alpha) / 255.0;
+
+		Self::Rgb(Alpha::attach(colour, alpha))
+	}
+}
+
+/// Retrieves the index of the hue channel of a colour space.
+#[inline]
+#[must_use]
+const fn hue_index(space: CssColourSpace) -> Option<usize> {
+	match space {
+		CssColourSpace::Hsl | CssColourSpace::Hwb   => Some(0x0),
+		CssColourSpace::Lch | CssColourSpace::OkLch => Some(0x2),
+
+		_ => None,
+	}
+}
+
+/// Checks whether the hue of a polar colour is powerless.
+///
+/// This uses the same thresholds as the [`HueInterpolate`](crate::HueInterpolate) implementations of the respective colour types.
+#[must_use]
+fn is_hue_powerless(space: CssColourSpace, channels: [f64; 0x3]) -> bool {
+	let [_, c1, c2] = channels;
+
+	match space {
+		CssColourSpace::Hsl   => c1.abs() < POWERLESS_SATURATION,
+		CssColourSpace::Hwb   => c1 + c2 >= 1.0 - POWERLESS_SATURATION,
+		CssColourSpace::Lch   => c1.abs() < POWERLESS_CIE_CHROMA,
+		CssColourSpace::OkLch => c1.abs() < POWERLESS_OK_CHROMA,
+
+		_ => false,
+	}
+}
+
+/// Replaces a missing component with zero.
+#[cfg(feature = "maths")]
+#[inline(always)]
+#[must_use]
+fn resolve_missing(value: f64) -> f64 {
+	if value.is_nan() {
+		0.0
+	} else {
+		value
+	}
+}
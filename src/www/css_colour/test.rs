@@ -0,0 +1,367 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+#![cfg(test)]
+
+use crate::Alpha;
+use crate::error::{CssColourFromStrError, HtmlFromStrError};
+use crate::hsv::{Hsl, Hwb};
+use crate::lab::{CieLab, OkLab};
+use crate::lch::{CieLch, OkLch};
+use crate::rgb::{DisplayP3, SRgb};
+#[cfg(feature = "maths")]
+use crate::test::assert_approx_eq;
+use crate::www::{CssColour, CssColourScheme, CssColourSpace};
+use crate::xyz::CieXyz;
+
+use core::str::FromStr;
+
+#[cfg(feature = "maths")]
+#[must_use]
+fn to_s_rgba(colour: CssColour) -> (f64, f64, f64, f64) {
+	let (colour, alpha) = colour.to_s_rgb().detach();
+	let (red, green, blue) = colour.get();
+
+	(red, green, blue, alpha)
+}
+
+#[test]
+fn test_css_colour_from_str_rgb() {
+	let red = CssColour::Rgb(Alpha::attach(SRgb::new(1.0, 0.0, 0.0), 1.0));
+
+	assert_eq!(CssColour::from_str("rgb(255 0 0)"), Ok(red));
+	assert_eq!(CssColour::from_str("RGB(255, 0, 0)"), Ok(red));
+	assert_eq!(CssColour::from_str("rgb(100% 0% 0% / 1)"), Ok(red));
+	assert_eq!(CssColour::from_str("rgb(300 -20 0)"), Ok(red));
+	assert_eq!(CssColour::from_str("#F00"), Ok(red));
+	assert_eq!(CssColour::from_str("red"), Ok(red));
+
+	assert_eq!(
+		CssColour::from_str("rgba(255, 0, 0, 50%)"),
+		Ok(CssColour::Rgb(Alpha::attach(SRgb::new(1.0, 0.0, 0.0), 0.5))),
+	);
+
+	assert_eq!(
+		CssColour::from_str("rgb(0 0 0/0.25)"),
+		Ok(CssColour::Rgb(Alpha::attach(SRgb::new(0.0, 0.0, 0.0), 0.25))),
+	);
+
+	let Ok(CssColour::Rgb(colour)) = CssColour::from_str("rgb(none 255 0)") else {
+		panic!("`none` was not accepted");
+	};
+
+	assert!(colour.detach().0.get().0.is_nan());
+
+	assert_eq!(CssColour::from_str("rgb(255, 0%, 0)"), Err(CssColourFromStrError::InvalidSyntax));
+	assert_eq!(CssColour::from_str("rgb(none, 0, 0)"), Err(CssColourFromStrError::InvalidSyntax));
+	assert_eq!(CssColour::from_str("rgb(255, 0 0)"),   Err(CssColourFromStrError::InvalidSyntax));
+	assert_eq!(CssColour::from_str("rgb(255 0)"),      Err(CssColourFromStrError::InvalidSyntax));
+	assert_eq!(CssColour::from_str("rgb(255 0 0 0)"),  Err(CssColourFromStrError::InvalidSyntax));
+	assert_eq!(CssColour::from_str("rgb(1 2 3 / 4 / 5)"), Err(CssColourFromStrError::InvalidSyntax));
+}
+
+#[test]
+fn test_css_colour_from_str_polar() {
+	assert_eq!(
+		CssColour::from_str("hsl(120deg 100% 50%)"),
+		Ok(CssColour::Hsl(Alpha::attach(Hsl::new(120.0, 1.0, 0.5), 1.0))),
+	);
+
+	assert_eq!(
+		CssColour::from_str("hsla(0.5turn, 100%, 25%, 0.5)"),
+		Ok(CssColour::Hsl(Alpha::attach(Hsl::new(180.0, 1.0, 0.25), 0.5))),
+	);
+
+	assert_eq!(CssColour::from_str("hsl(120, 100, 50)"), Err(CssColourFromStrError::InvalidSyntax));
+
+	assert_eq!(
+		CssColour::from_str("hwb(90 25% 50)"),
+		Ok(CssColour::Hwb(Alpha::attach(Hwb::new(90.0, 0.25, 0.5), 1.0))),
+	);
+
+	assert_eq!(CssColour::from_str("hwb(90, 25%, 50%)"), Err(CssColourFromStrError::InvalidSyntax));
+
+	assert_eq!(
+		CssColour::from_str("lch(50% 75% 270)"),
+		Ok(CssColour::Lch(Alpha::attach(CieLch::new(50.0, 112.5, 270.0), 1.0))),
+	);
+
+	assert_eq!(
+		CssColour::from_str("oklch(62.8% 0.2577 29.23 / 50%)"),
+		Ok(CssColour::OkLch(Alpha::attach(OkLch::new(0.628, 0.2577, 29.23), 0.5))),
+	);
+
+	assert_eq!(CssColour::from_str("oklch(50% 0.1 10%)"), Err(CssColourFromStrError::InvalidSyntax));
+}
+
+#[test]
+fn test_css_colour_from_str_lab() {
+	assert_eq!(
+		CssColour::from_str("lab(150% 40 -100%)"),
+		Ok(CssColour::Lab(Alpha::attach(CieLab::new(100.0, 40.0, -125.0), 1.0))),
+	);
+
+	assert_eq!(
+		CssColour::from_str("oklab(0.5 100% -50%)"),
+		Ok(CssColour::OkLab(Alpha::attach(OkLab::new(0.5, 0.4, -0.2), 1.0))),
+	);
+}
+
+#[test]
+fn test_css_colour_from_str_color() {
+	assert_eq!(
+		CssColour::from_str("color(display-p3 1 50% 0)"),
+		Ok(CssColour::DisplayP3(Alpha::attach(DisplayP3::new(1.0, 0.5, 0.0), 1.0))),
+	);
+
+	assert_eq!(
+		CssColour::from_str("color(xyz 0.5 1.5 -0.5 / 0.5)"),
+		Ok(CssColour::XyzD65(Alpha::attach(CieXyz::new(0.5, 1.5, -0.5), 0.5))),
+	);
+
+	assert_eq!(CssColour::from_str("color(xyz-d50 1 1 1)").map(|colour| colour.colour_space()), Ok(CssColourSpace::XyzD50));
+
+	assert_eq!(CssColour::from_str("color(lab 1 2 3)"),  Err(CssColourFromStrError::UnknownColourSpace));
+	assert_eq!(CssColour::from_str("color(cmyk 1 2 3)"), Err(CssColourFromStrError::UnknownColourSpace));
+	assert_eq!(CssColour::from_str("color(srgb 1, 2, 3)"), Err(CssColourFromStrError::InvalidSyntax));
+}
+
+#[test]
+fn test_css_colour_from_str_error() {
+	assert_eq!(CssColour::from_str("device-cmyk(0 0 0 1)"), Err(CssColourFromStrError::UnknownFunction));
+
+	assert_eq!(
+		CssColour::from_str("bogus"),
+		Err(CssColourFromStrError::InvalidHtml(HtmlFromStrError::UnknownName { span: 0x0..0x5, suggestion: None })),
+	);
+}
+
+#[test]
+fn test_css_colour_is_legacy() {
+	assert!(CssColour::from_str("hsl(0 0 0)").unwrap().is_legacy());
+
+	assert!(!CssColour::from_str("color(srgb 0 0 0)").unwrap().is_legacy());
+}
+
+#[cfg(feature = "maths")]
+#[test]
+fn test_css_colour_to_s_rgb() {
+	assert_approx_eq!(to_s_rgba(CssColour::from_str("hsl(120 100% 50%)").unwrap()), (0.0, 1.0, 0.0, 1.0), 1e-12);
+
+	assert_approx_eq!(to_s_rgba(CssColour::from_str("hwb(0 20% 20% / none)").unwrap()), (0.8, 0.2, 0.2, 0.0), 1e-12);
+
+	assert_approx_eq!(to_s_rgba(CssColour::from_str("oklch(62.8% 0.2577 29.23)").unwrap()), (1.0, 0.0, 0.0, 1.0), 1e-3);
+
+	assert_approx_eq!(to_s_rgba(CssColour::from_str("lab(100 0 0)").unwrap()), (1.0, 1.0, 1.0, 1.0), 1e-6);
+
+	assert_approx_eq!(to_s_rgba(CssColour::from_str("lch(100 none none)").unwrap()), (1.0, 1.0, 1.0, 1.0), 1e-6);
+
+	assert_approx_eq!(
+		to_s_rgba(CssColour::from_str("color(display-p3 1 0 0)").unwrap()),
+		(1.093_066_362_435_161_5, -0.226_741_973_569_754_1, -0.150_134_580_937_119_5, 1.0),
+		1e-9,
+	);
+
+	assert_approx_eq!(
+		to_s_rgba(CssColour::from_str("color(rec2020 0 1 0)").unwrap()),
+		(-0.790_374_621_855_411_9, 1.056_302_437_368_773_5, -0.350_163_508_624_565_5, 1.0),
+		1e-9,
+	);
+
+	assert_approx_eq!(to_s_rgba(CssColour::from_str("color(prophoto-rgb 1 1 1)").unwrap()), (1.0, 1.0, 1.0, 1.0), 1e-6);
+	assert_approx_eq!(to_s_rgba(CssColour::from_str("color(a98-rgb 1 1 1)").unwrap()),      (1.0, 1.0, 1.0, 1.0), 1e-6);
+	assert_approx_eq!(to_s_rgba(CssColour::from_str("color(xyz-d50 0.9643 1 0.8251)").unwrap()), (1.0, 1.0, 1.0, 1.0), 1e-3);
+}
+
+#[test]
+fn test_css_colour_from_str_calc() {
+	assert_eq!(
+		CssColour::from_str("rgb(calc(255 / 2) 0 calc(50% * 2))"),
+		Ok(CssColour::Rgb(Alpha::attach(SRgb::new(0.5, 0.0, 1.0), 1.0))),
+	);
+
+	assert_eq!(
+		CssColour::from_str("hsl(calc(90deg + 0.25turn) 100% calc((20% + 30%)))"),
+		Ok(CssColour::Hsl(Alpha::attach(Hsl::new(180.0, 1.0, 0.5), 1.0))),
+	);
+
+	assert_eq!(CssColour::from_str("rgb(calc(1 + 1%) 0 0)"), Err(CssColourFromStrError::InvalidSyntax));
+	assert_eq!(CssColour::from_str("rgb(calc(r) 0 0)"),      Err(CssColourFromStrError::InvalidSyntax));
+}
+
+#[test]
+fn test_css_colour_light_dark() {
+	let white = CssColour::Rgb(Alpha::attach(SRgb::new(1.0, 1.0, 1.0), 1.0));
+	let black = CssColour::Rgb(Alpha::attach(SRgb::new(0.0, 0.0, 0.0), 1.0));
+
+	assert_eq!(CssColour::from_str("light-dark(white, black)"), Ok(white));
+	assert_eq!(CssColour::parse_with_scheme("light-dark(white, black)", CssColourScheme::Light), Ok(white));
+	assert_eq!(CssColour::parse_with_scheme("LIGHT-DARK(white, black)", CssColourScheme::Dark), Ok(black));
+
+	assert_eq!(CssColour::from_str("light-dark(white)"), Err(CssColourFromStrError::InvalidSyntax));
+}
+
+#[cfg(feature = "maths")]
+#[test]
+fn test_css_colour_mix() {
+	assert_eq!(
+		CssColour::from_str("color-mix(in srgb, red, #00F)"),
+		Ok(CssColour::SRgb(Alpha::attach(SRgb::new(0.5, 0.0, 0.5), 1.0))),
+	);
+
+	assert_eq!(
+		CssColour::from_str("color-mix(in srgb, 25% red, #00F)"),
+		Ok(CssColour::SRgb(Alpha::attach(SRgb::new(0.25, 0.0, 0.75), 1.0))),
+	);
+
+	assert_eq!(
+		CssColour::from_str("color-mix(in srgb, red 20%, #00F 20%)"),
+		Ok(CssColour::SRgb(Alpha::attach(SRgb::new(0.5, 0.0, 0.5), 0.4))),
+	);
+
+	assert_eq!(
+		CssColour::from_str("color-mix(in hsl longer hue, hsl(0 100% 50%), hsl(90 100% 50%))"),
+		Ok(CssColour::Hsl(Alpha::attach(Hsl::new(225.0, 1.0, 0.5), 1.0))),
+	);
+
+	// The missing hue takes on that of the other colour.
+	assert_eq!(
+		CssColour::from_str("color-mix(in oklch, oklch(0.5 0.1 none), oklch(0.7 0.2 40))"),
+		Ok(CssColour::OkLch(Alpha::attach(OkLch::new(0.6, 0.15000000000000002, 40.0), 1.0))),
+	);
+
+	assert_eq!(CssColour::from_str("color-mix(in srgb longer hue, red, blue)"), Err(CssColourFromStrError::InvalidSyntax));
+	assert_eq!(CssColour::from_str("color-mix(in srgb, red 0%, blue 0%)"),      Err(CssColourFromStrError::InvalidSyntax));
+	assert_eq!(CssColour::from_str("color-mix(in foo, red, blue)"),             Err(CssColourFromStrError::UnknownColourSpace));
+}
+
+#[cfg(feature = "maths")]
+#[test]
+fn test_css_colour_relative() {
+	assert_eq!(
+		CssColour::from_str("rgb(from red r g b / 50%)"),
+		Ok(CssColour::Rgb(Alpha::attach(SRgb::new(1.0, 0.0, 0.0), 0.5))),
+	);
+
+	assert_eq!(
+		CssColour::from_str("rgb(from rgb(255 0 0 / 0.5) b g r)"),
+		Ok(CssColour::Rgb(Alpha::attach(SRgb::new(0.0, 0.0, 1.0), 0.5))),
+	);
+
+	assert_approx_eq!(
+		to_s_rgba(CssColour::from_str("hsl(from #00F calc(h + 180) s l)").unwrap()),
+		(1.0, 1.0, 0.0, 1.0),
+		1e-9,
+	);
+
+	assert_approx_eq!(
+		to_s_rgba(CssColour::from_str("color(from red xyz x y z / calc(alpha / 2))").unwrap()),
+		(1.0, 0.0, 0.0, 0.5),
+		1e-9,
+	);
+
+	let origin = CssColour::from_str("#0AF").unwrap().convert(CssColourSpace::OkLch);
+
+	let CssColour::OkLch(origin) = origin else { panic!() };
+	let (origin, _) = origin.detach();
+	let (lightness, chroma, hue) = origin.get();
+
+	assert_eq!(
+		CssColour::from_str("oklch(from #0AF calc(l * 0.8) c h)"),
+		Ok(CssColour::OkLch(Alpha::attach(OkLch::new(lightness * 0.8, chroma, hue), 1.0))),
+	);
+
+	assert_eq!(CssColour::from_str("rgb(from red r g q)"), Err(CssColourFromStrError::InvalidSyntax));
+	assert_eq!(
+		CssColour::from_str("rgb(from red, r, g, b)"),
+		Err(CssColourFromStrError::InvalidHtml(HtmlFromStrError::InvalidCharacter { offset: 0x3, character: ',' })),
+	);
+}
+
+#[cfg(feature = "maths")]
+#[test]
+fn test_css_colour_convert_missing() {
+	let colour = CssColour::from_str("oklch(0.5 none 30)").unwrap().convert(CssColourSpace::Lch);
+
+	let CssColour::Lch(colour) = colour else { panic!() };
+	let (lightness, chroma, hue) = colour.detach().0.get();
+
+	assert!(!lightness.is_nan());
+	assert!(chroma.is_nan());
+	assert!(!hue.is_nan());
+
+	let colour = CssColour::from_str("lab(none 20 30)").unwrap().convert(CssColourSpace::SRgb);
+
+	let CssColour::SRgb(colour) = colour else { panic!() };
+	let (red, green, blue) = colour.detach().0.get();
+
+	assert!(!red.is_nan() && !green.is_nan() && !blue.is_nan());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_css_colour_display() {
+	use crate::hsv::Hsv;
+
+	use alloc::format;
+
+	let values = [
+		"rgb(255 0 127.5)",
+		"rgb(255 0 0 / 0.5)",
+		"hsl(120 75% 25%)",
+		"hsl(none 0% 50%)",
+		"hwb(30 10% 20% / none)",
+		"lab(50 -20 30)",
+		"lch(50 40 none)",
+		"oklab(0.5 0.1 -0.1)",
+		"oklch(0.7 0.15 200 / 0.25)",
+		"color(srgb 1.5 0 -0.25)",
+		"color(srgb-linear 0.5 0.5 0.5)",
+		"color(display-p3 1 0 0)",
+		"color(a98-rgb 0 1 0)",
+		"color(prophoto-rgb 0 0 1)",
+		"color(rec2020 0.25 none 0.75)",
+		"color(xyz-d50 0.1 0.2 0.3)",
+		"color(xyz-d65 0.95 1 1.09)",
+	];
+
+	for value in values {
+		let colour = CssColour::from_str(value).unwrap();
+
+		assert_eq!(format!("{colour}"), value);
+	}
+
+	let colour = CssColour::from_str("color(display-p3 0.123456 1 0 / 0.5)").unwrap();
+	assert_eq!(format!("{colour:.2}"), "color(display-p3 0.12 1.00 0.00 / 0.50)");
+
+	// Powerless hues are serialised as `none`.
+	assert_eq!(format!("{}", CssColour::from_str("hsl(120 0% 50%)").unwrap()),  "hsl(none 0% 50%)");
+	assert_eq!(format!("{}", CssColour::from_str("hwb(120 60% 40%)").unwrap()), "hwb(none 60% 40%)");
+	assert_eq!(format!("{}", CssColour::from_str("oklch(0.5 0 120)").unwrap()), "oklch(0.5 0 none)");
+
+	assert_eq!(format!("{}", SRgb::new(1.0_f32, 0.5, 0.0)),                        "rgb(255 127.5 0)");
+	assert_eq!(format!("{}", SRgb::new(1.25_f64, 0.5, 0.0)),                       "color(srgb 1.25 0.5 0)");
+	assert_eq!(format!("{}", Alpha::attach(Hsl::new(120.0_f64, 1.0, 0.5), 0.5)),   "hsl(120 100% 50% / 0.5)");
+	assert_eq!(format!("{}", Alpha::attach(Hsv::new(120.0_f64, 0.5, 1.0), 0.5)),   "hwb(120 50% 0% / 0.5)");
+	assert_eq!(format!("{:.1}", OkLch::new(0.62796_f64, 0.25768, 29.23388)),       "oklch(0.6 0.3 29.2)");
+	assert_eq!(format!("{}", DisplayP3::new(1.0_f64, 0.0, 0.0)),                   "color(display-p3 1 0 0)");
+	assert_eq!(format!("{}", CieXyz::new(0.5_f64, 0.25, 0.125)),                   "color(xyz-d65 0.5 0.25 0.125)");
+
+	// Float noise is rounded away by default.
+	assert_eq!(format!("{}", CieXyz::new(0.1_f64 + 0.2, 0.25, 0.125)), "color(xyz-d65 0.3 0.25 0.125)");
+
+	#[cfg(feature = "maths")]
+	{
+		let colour = CssColour::from_str("hsl(from color(srgb-linear 1 0 0) h s l)").unwrap();
+		assert_eq!(format!("{colour}"), "hsl(0 100% 50%)");
+	}
+
+	assert_eq!(format!("{}", OkLch::new(0.627_955_4_f64, 0.257_683_1, 29.233_885_5)), "oklch(0.627955 0.257683 29.2339)");
+	assert_eq!(format!("{}", CieLab::new(-0.000_000_1_f64, 123_456.7, 0.000_000_6)),  "lab(0 123457 0.000001)");
+	assert_eq!(format!("{:.16}", Hsl::new(0.000_000_000_01_f64, 1.0, 0.5)),           "hsl(0.0000000000100000 100.0000000000000000% 50.0000000000000000%)");
+}
@@ -0,0 +1,23 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+/// A CSS colour scheme.
+///
+/// This decides which of the two colours of a `light-dark()` function is used.
+/// See [`CssColour::parse_with_scheme`](crate::www::CssColour::parse_with_scheme) for more information.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CssColourScheme {
+	/// The light colour scheme.
+	///
+	/// This is used when no colour scheme is specified.
+	#[default]
+	Light,
+
+	/// The dark colour scheme.
+	Dark,
+}
@@ -0,0 +1,329 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+#[cfg(feature = "maths")]
+use crate::{Alpha, BalancedColour, HueInterpolate, HueInterpolation, Interpolate};
+#[cfg(feature = "maths")]
+use crate::hsv::{Hsl, Hwb};
+#[cfg(feature = "maths")]
+use crate::lab::{CieLab, OkLab};
+#[cfg(feature = "maths")]
+use crate::lch::{CieLch, OkLch};
+#[cfg(feature = "maths")]
+use crate::rgb::{DisplayP3, OpRgb, ProPhotoRgb, Rec2020, SRgb};
+#[cfg(feature = "maths")]
+use crate::xyz::CieXyz;
+
+/// A CSS colour space.
+///
+/// These are the colour spaces that may be named in CSS colour-interpolation methods, e.g. `in oklch`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CssColourSpace {
+	/// `srgb`, i.e. [`SRgb`](crate::rgb::SRgb).
+	SRgb,
+
+	/// `srgb-linear`, i.e. sRGB without the transfer function.
+	SRgbLinear,
+
+	/// `display-p3`, i.e. [`DisplayP3`](crate::rgb::DisplayP3).
+	DisplayP3,
+
+	/// `a98-rgb`, i.e. [`OpRgb`](crate::rgb::OpRgb).
+	A98Rgb,
+
+	/// `prophoto-rgb`, i.e. [`ProPhotoRgb`](crate::rgb::ProPhotoRgb).
+	ProPhotoRgb,
+
+	/// `rec2020`, i.e. [`Rec2020`](crate::rgb::Rec2020).
+	Rec2020,
+
+	/// `lab`, i.e. [`CieLab`](crate::lab::CieLab) relative to the D50 white point.
+	Lab,
+
+	/// `oklab`, i.e. [`OkLab`](crate::lab::OkLab).
+	///
+	/// This is the default interpolation space of CSS.
+	#[default]
+	OkLab,
+
+	/// `xyz-d50`, i.e. [`CieXyz`](crate::xyz::CieXyz) relative to the D50 white point.
+	XyzD50,
+
+	/// `xyz-d65` (or `xyz`), i.e. [`CieXyz`](crate::xyz::CieXyz) relative to the D65 white point.
+	XyzD65,
+
+	/// `hsl`, i.e. [`Hsl`](crate::hsv::Hsl).
+	Hsl,
+
+	/// `hwb`, i.e. [`Hwb`](crate::hsv::Hwb).
+	Hwb,
+
+	/// `lch`, i.e. [`CieLch`](crate::lch::CieLch) relative to the D50 white point.
+	Lch,
+
+	/// `oklch`, i.e. [`OkLch`](crate::lch::OkLch).
+	OkLch,
+}
+
+impl CssColourSpace {
+	/// Looks up a colour space by its CSS name.
+	///
+	/// The name is matched case-insensitively.
+	#[must_use]
+	pub fn from_name(name: &str) -> Option<Self> {
+		let spaces = [
+			("srgb",         Self::SRgb),
+			("srgb-linear",  Self::SRgbLinear),
+			("display-p3",   Self::DisplayP3),
+			("a98-rgb",      Self::A98Rgb),
+			("prophoto-rgb", Self::ProPhotoRgb),
+			("rec2020",      Self::Rec2020),
+			("lab",          Self::Lab),
+			("oklab",        Self::OkLab),
+			("xyz-d50",      Self::XyzD50),
+			("xyz-d65",      Self::XyzD65),
+			("xyz",          Self::XyzD65),
+			("hsl",          Self::Hsl),
+			("hwb",          Self::Hwb),
+			("lch",          Self::Lch),
+			("oklch",        Self::OkLch),
+		];
+
+		spaces
+			.into_iter()
+			.find(|(other, _)| other.eq_ignore_ascii_case(name))
+			.map(|(_, space)| space)
+	}
+
+	/// Retrieves the CSS name of the colour space.
+	#[must_use]
+	pub const fn name(self) -> &'static str {
+		match self {
+			Self::SRgb        => "srgb",
+			Self::SRgbLinear  => "srgb-linear",
+			Self::DisplayP3   => "display-p3",
+			Self::A98Rgb      => "a98-rgb",
+			Self::ProPhotoRgb => "prophoto-rgb",
+			Self::Rec2020     => "rec2020",
+			Self::Lab         => "lab",
+			Self::OkLab       => "oklab",
+			Self::XyzD50      => "xyz-d50",
+			Self::XyzD65      => "xyz-d65",
+			Self::Hsl         => "hsl",
+			Self::Hwb         => "hwb",
+			Self::Lch         => "lch",
+			Self::OkLch       => "oklch",
+		}
+	}
+
+	/// Checks whether the colour space may be named in the `color()` function.
+	///
+	/// These are the RGB and CIEXYZ spaces.
+	#[must_use]
+	pub const fn is_predefined(self) -> bool {
+		matches!(
+			self,
+			Self::SRgb
+				| Self::SRgbLinear
+				| Self::DisplayP3
+				| Self::A98Rgb
+				| Self::ProPhotoRgb
+				| Self::Rec2020
+				| Self::XyzD50
+				| Self::XyzD65,
+		)
+	}
+
+	/// Checks whether the colour space has a hue channel.
+	///
+	/// Only such spaces accept a hue-interpolation method.
+	#[must_use]
+	pub const fn is_polar(self) -> bool {
+		matches!(self, Self::Hsl | Self::Hwb | Self::Lch | Self::OkLch)
+	}
+
+	/// Interpolates between two sRGB colours in the colour space.
+	///
+	/// Colours are converted to the space, interpolated using premultiplied alpha (see [`Interpolate`]), and converted back.
+	/// The hue-interpolation method is ignored for spaces without a hue channel.
+	/// The returned colour is not clamped to the sRGB gamut.
+	#[cfg(feature = "maths")]
+	#[must_use]
+	pub fn interpolate(
+		self,
+		colour: Alpha<SRgb<f64>>,
+		other:  Alpha<SRgb<f64>>,
+		factor: f64,
+		method: HueInterpolation,
+	) -> Alpha<SRgb<f64>> {
+		const D50: CieXyz<f64> = CieXyz::<f64>::D50;
+		const D65: CieXyz<f64> = CieXyz::<f64>::D65;
+
+		let to_xyz_d50   = |colour: SRgb<f64>| CieXyz::<f64>::from_s_rgb(colour).adapt(D65, D50);
+		let from_xyz_d50 = |colour: CieXyz<f64>| colour.adapt(D50, D65).to_s_rgb();
+
+		match self {
+			Self::SRgb => {
+				interpolate_via(colour, other, factor, |colour| colour, |colour| colour)
+			}
+
+			Self::SRgbLinear => {
+				interpolate_via(colour, other, factor, SRgb::<f64>::untransfer, SRgb::<f64>::transfer)
+			}
+
+			Self::DisplayP3 => {
+				interpolate_via(
+					colour,
+					other,
+					factor,
+					|colour| DisplayP3::<f64>::from_xyz(CieXyz::<f64>::from_s_rgb(colour)),
+					|colour: DisplayP3<f64>| colour.to_xyz().to_s_rgb(),
+				)
+			}
+
+			Self::A98Rgb => {
+				interpolate_via(
+					colour,
+					other,
+					factor,
+					|colour| OpRgb::<f64>::from_xyz(CieXyz::<f64>::from_s_rgb(colour)),
+					|colour: OpRgb<f64>| colour.to_xyz().to_s_rgb(),
+				)
+			}
+
+			Self::ProPhotoRgb => {
+				interpolate_via(
+					colour,
+					other,
+					factor,
+					|colour| ProPhotoRgb::<f64>::from_xyz(to_xyz_d50(colour)),
+					|colour: ProPhotoRgb<f64>| from_xyz_d50(colour.to_xyz()),
+				)
+			}
+
+			Self::Rec2020 => {
+				interpolate_via(
+					colour,
+					other,
+					factor,
+					|colour| Rec2020::<f64>::from_xyz(CieXyz::<f64>::from_s_rgb(colour)),
+					|colour: Rec2020<f64>| colour.to_xyz().to_s_rgb(),
+				)
+			}
+
+			Self::Lab => {
+				interpolate_via(
+					colour,
+					other,
+					factor,
+					|colour| CieLab::<f64>::from_xyz(to_xyz_d50(colour), D50),
+					|colour: CieLab<f64>| from_xyz_d50(colour.to_xyz(D50)),
+				)
+			}
+
+			Self::OkLab => {
+				interpolate_via(colour, other, factor, OkLab::<f64>::from_s_rgb, OkLab::<f64>::to_s_rgb)
+			}
+
+			Self::XyzD50 => {
+				interpolate_via(colour, other, factor, to_xyz_d50, from_xyz_d50)
+			}
+
+			Self::XyzD65 => {
+				interpolate_via(colour, other, factor, CieXyz::<f64>::from_s_rgb, CieXyz::<f64>::to_s_rgb)
+			}
+
+			Self::Hsl => {
+				interpolate_hue_via(colour, other, factor, method, Hsl::<f64>::from_s_rgb, Hsl::<f64>::to_s_rgb)
+			}
+
+			Self::Hwb => {
+				interpolate_hue_via(colour, other, factor, method, Hwb::<f64>::from_s_rgb, Hwb::<f64>::to_s_rgb)
+			}
+
+			Self::Lch => {
+				interpolate_hue_via(
+					colour,
+					other,
+					factor,
+					method,
+					|colour| CieLch::<f64>::from_lab(CieLab::<f64>::from_xyz(to_xyz_d50(colour), D50)),
+					|colour: CieLch<f64>| from_xyz_d50(colour.to_lab().to_xyz(D50)),
+				)
+			}
+
+			Self::OkLch => {
+				interpolate_hue_via(
+					colour,
+					other,
+					factor,
+					method,
+					|colour| OkLch::<f64>::from_ok_lab(OkLab::<f64>::from_s_rgb(colour)),
+					|colour: OkLch<f64>| colour.to_ok_lab().to_s_rgb(),
+				)
+			}
+		}
+	}
+}
+
+/// Interpolates two sRGB colours in another colour
+/// space.
+#[cfg(feature = "maths")]
+#[inline]
+#[must_use]
+fn interpolate_via<T, F, G>(
+	colour: Alpha<SRgb<f64>>,
+	other:  Alpha<SRgb<f64>>,
+	factor: f64,
+	from:   F,
+	to:     G,
+) -> Alpha<SRgb<f64>>
+where
+	T: BalancedColour<Component = f64> + Interpolate<Scalar = f64>,
+	F: Fn(SRgb<f64>) -> T,
+	G: Fn(T) -> SRgb<f64>,
+{
+	let (colour, alpha)      = colour.detach();
+	let (other, other_alpha) = other.detach();
+
+	let colour = Alpha::attach(from(colour), alpha);
+	let other  = Alpha::attach(from(other),  other_alpha);
+
+	let (colour, alpha) = colour.interpolate(other, factor).detach();
+
+	Alpha::attach(to(colour), alpha)
+}
+
+/// Interpolates two sRGB colours in a colour space
+/// with a hue channel.
+#[cfg(feature = "maths")]
+#[inline]
+#[must_use]
+fn interpolate_hue_via<T, F, G>(
+	colour: Alpha<SRgb<f64>>,
+	other:  Alpha<SRgb<f64>>,
+	factor: f64,
+	method: HueInterpolation,
+	from:   F,
+	to:     G,
+) -> Alpha<SRgb<f64>>
+where
+	T: BalancedColour<Component = f64> + HueInterpolate<Scalar = f64>,
+	F: Fn(SRgb<f64>) -> T,
+	G: Fn(T) -> SRgb<f64>,
+{
+	let (colour, alpha)      = colour.detach();
+	let (other, other_alpha) = other.detach();
+
+	let colour = Alpha::attach(from(colour), alpha);
+	let other  = Alpha::attach(from(other),  other_alpha);
+
+	let (colour, alpha) = colour.interpolate_hue(other, factor, method).detach();
+
+	Alpha::attach(to(colour), alpha)
+}
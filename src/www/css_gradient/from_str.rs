@@ -0,0 +1,499 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::HueInterpolation;
+use crate::error::CssGradientFromStrError;
+use crate::www::{CssColour, CssColourSpace, CssGradient};
+use crate::www::css_gradient::{
+	CENTRE,
+	Item,
+	Kind,
+	LengthPercentage,
+	LinearDirection,
+	Position,
+	RadialSize,
+};
+use crate::www::css_tokens::{
+	parse_angle,
+	parse_dimension,
+	parse_function,
+	split_commas,
+	split_whitespace,
+};
+
+use alloc::vec::Vec;
+use core::iter::Peekable;
+use core::str::FromStr;
+
+/// Keywords that may start the first argument of a
+/// gradient function.
+const PRELUDE_KEYWORDS: &[&str] = &[
+	"at",
+	"circle",
+	"closest-corner",
+	"closest-side",
+	"ellipse",
+	"farthest-corner",
+	"farthest-side",
+	"from",
+	"in",
+	"to",
+];
+
+impl FromStr for CssGradient {
+	type Err = CssGradientFromStrError;
+
+	/// Parses a CSS gradient from a string.
+	///
+	/// Function names and keywords are matched case-insensitively.
+	/// Colour stops are parsed as [CSS colours](CssColour).
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let (name, arguments) = parse_function(s).ok_or(CssGradientFromStrError::InvalidSyntax)?;
+
+		let (repeating, name) = match name.get(..0xA) {
+			Some(prefix) if prefix.eq_ignore_ascii_case("repeating-") => (true, &name[0xA..]),
+
+			_ => (false, name),
+		};
+
+		let mut arguments = split_commas(arguments).peekable();
+
+		let first = arguments.peek().copied().ok_or(CssGradientFromStrError::InvalidSyntax)?;
+
+		let has_prelude = split_whitespace(first).next().is_some_and(|token| {
+			parse_dimension(token).is_some()
+				|| PRELUDE_KEYWORDS.iter().any(|keyword| keyword.eq_ignore_ascii_case(token))
+		});
+
+		let prelude = if has_prelude {
+			arguments.next()
+		} else {
+			None
+		};
+
+		let mut space  = None;
+		let mut method = HueInterpolation::Shorter;
+
+		let (kind, is_conic) = if name.eq_ignore_ascii_case("linear-gradient") {
+			let kind = parse_linear(prelude.unwrap_or_default(), &mut space, &mut method)?;
+			(kind, false)
+		} else if name.eq_ignore_ascii_case("radial-gradient") {
+			let kind = parse_radial(prelude.unwrap_or_default(), &mut space, &mut method)?;
+			(kind, false)
+		} else if name.eq_ignore_ascii_case("conic-gradient") {
+			let kind = parse_conic(prelude.unwrap_or_default(), &mut space, &mut method)?;
+			(kind, true)
+		} else {
+			return Err(CssGradientFromStrError::UnknownFunction);
+		};
+
+		let mut items = Vec::new();
+
+		for argument in arguments {
+			parse_item(argument, is_conic, &mut items)?;
+		}
+
+		validate_items(&items)?;
+
+		let this = Self {
+			kind,
+			repeating,
+			space,
+			method,
+			items,
+		};
+
+		Ok(this)
+	}
+}
+
+/// Parses a length or percentage.
+#[must_use]
+fn parse_length_percentage(token: &str) -> Option<LengthPercentage> {
+	let (value, unit) = parse_dimension(token)?;
+
+	if unit == "%" {
+		Some(LengthPercentage::Percentage(value))
+	} else if unit.eq_ignore_ascii_case("px") || (unit.is_empty() && value == 0.0) {
+		Some(LengthPercentage::Length(value))
+	} else {
+		None
+	}
+}
+
+/// Parses an angle or percentage.
+///
+/// Angles are converted to percentages of a full turn.
+#[must_use]
+fn parse_angle_percentage(token: &str) -> Option<LengthPercentage> {
+	if let Some((value, "%")) = parse_dimension(token) {
+		return Some(LengthPercentage::Percentage(value));
+	}
+
+	parse_angle(token).map(|angle| LengthPercentage::Percentage(angle / 3.6))
+}
+
+/// Parses a colour-interpolation method following
+/// the `in` keyword.
+fn parse_method<'a, I>(
+	tokens: &mut Peekable<I>,
+	space:  &mut Option<CssColourSpace>,
+	method: &mut HueInterpolation,
+) -> Result<(), CssGradientFromStrError>
+where
+	I: Iterator<Item = &'a str>,
+{
+	if space.is_some() {
+		return Err(CssGradientFromStrError::InvalidSyntax);
+	}
+
+	let name = tokens.next().ok_or(CssGradientFromStrError::InvalidSyntax)?;
+
+	let new_space = CssColourSpace::from_name(name).ok_or(CssGradientFromStrError::UnknownColourSpace)?;
+
+	*space = Some(new_space);
+
+	let methods = [
+		("shorter",    HueInterpolation::Shorter),
+		("longer",     HueInterpolation::Longer),
+		("increasing", HueInterpolation::Increasing),
+		("decreasing", HueInterpolation::Decreasing),
+	];
+
+	let Some(&token) = tokens.peek() else {
+		return Ok(());
+	};
+
+	if let Some((_, new_method)) = methods.into_iter().find(|(name, _)| name.eq_ignore_ascii_case(token)) {
+		tokens.next();
+
+		if !new_space.is_polar() || !tokens.next().is_some_and(|token| token.eq_ignore_ascii_case("hue")) {
+			return Err(CssGradientFromStrError::InvalidSyntax);
+		}
+
+		*method = new_method;
+	}
+
+	Ok(())
+}
+
+/// Parses a position following the `at` keyword.
+fn parse_position<'a, I>(tokens: &mut Peekable<I>) -> Result<Position, CssGradientFromStrError>
+where
+	I: Iterator<Item = &'a str>,
+{
+	/// A single component of a position.
+	#[derive(Clone, Copy)]
+	enum Component {
+		Horizontal(LengthPercentage),
+		Vertical(LengthPercentage),
+		Either(LengthPercentage),
+	}
+
+	let parse = |token: &str| -> Option<Component> {
+		let keywords = [
+			("left",   Component::Horizontal(LengthPercentage::Percentage(0.0))),
+			("right",  Component::Horizontal(LengthPercentage::Percentage(100.0))),
+			("top",    Component::Vertical(LengthPercentage::Percentage(0.0))),
+			("bottom", Component::Vertical(LengthPercentage::Percentage(100.0))),
+			("center", Component::Either(LengthPercentage::Percentage(50.0))),
+		];
+
+		keywords
+			.into_iter()
+			.find(|(name, _)| name.eq_ignore_ascii_case(token))
+			.map(|(_, component)| component)
+			.or_else(|| parse_length_percentage(token).map(Component::Either))
+	};
+
+	let first = tokens.next().and_then(parse).ok_or(CssGradientFromStrError::InvalidSyntax)?;
+
+	let second = match tokens.peek().copied().and_then(parse) {
+		Some(component) => {
+			tokens.next();
+			Some(component)
+		}
+
+		None => None,
+	};
+
+	let centre = LengthPercentage::Percentage(50.0);
+
+	let position = match (first, second) {
+		(Component::Vertical(y), None) => (centre, y),
+
+		(Component::Horizontal(x) | Component::Either(x), None) => (x, centre),
+
+		(Component::Horizontal(x) | Component::Either(x), Some(Component::Vertical(y) | Component::Either(y))) => (x, y),
+
+		(Component::Vertical(y) | Component::Either(y), Some(Component::Horizontal(x))) => (x, y),
+
+		(Component::Vertical(y), Some(Component::Either(x))) => (x, y),
+
+		_ => return Err(CssGradientFromStrError::InvalidSyntax),
+	};
+
+	Ok(position)
+}
+
+/// Parses the prelude of `linear-gradient()`.
+fn parse_linear(
+	prelude: &str,
+	space:   &mut Option<CssColourSpace>,
+	method:  &mut HueInterpolation,
+) -> Result<Kind, CssGradientFromStrError> {
+	let mut direction = None;
+
+	let mut tokens = split_whitespace(prelude).peekable();
+
+	while let Some(token) = tokens.next() {
+		if token.eq_ignore_ascii_case("in") {
+			parse_method(&mut tokens, space, method)?;
+			continue;
+		}
+
+		if direction.is_some() {
+			return Err(CssGradientFromStrError::InvalidSyntax);
+		}
+
+		if !token.eq_ignore_ascii_case("to") {
+			let angle = parse_angle(token).ok_or(CssGradientFromStrError::InvalidSyntax)?;
+
+			direction = Some(LinearDirection::Angle(angle));
+			continue;
+		}
+
+		let mut horizontal = None;
+		let mut vertical   = None;
+
+		while let Some(&token) = tokens.peek() {
+			let (slot, value) = if token.eq_ignore_ascii_case("left") {
+				(&mut horizontal, false)
+			} else if token.eq_ignore_ascii_case("right") {
+				(&mut horizontal, true)
+			} else if token.eq_ignore_ascii_case("top") {
+				(&mut vertical, false)
+			} else if token.eq_ignore_ascii_case("bottom") {
+				(&mut vertical, true)
+			} else {
+				break;
+			};
+
+			if slot.replace(value).is_some() {
+				return Err(CssGradientFromStrError::InvalidSyntax);
+			}
+
+			tokens.next();
+		}
+
+		direction = Some(match (horizontal, vertical) {
+			(None,        Some(false)) => LinearDirection::Angle(0.0),
+			(Some(true),  None)        => LinearDirection::Angle(90.0),
+			(None,        Some(true))  => LinearDirection::Angle(180.0),
+			(Some(false), None)        => LinearDirection::Angle(270.0),
+
+			(Some(right), Some(bottom)) => LinearDirection::Corner { right, bottom },
+
+			(None, None) => return Err(CssGradientFromStrError::InvalidSyntax),
+		});
+	}
+
+	Ok(Kind::Linear(direction.unwrap_or(LinearDirection::Angle(180.0))))
+}
+
+/// Parses the prelude of `radial-gradient()`.
+fn parse_radial(
+	prelude: &str,
+	space:   &mut Option<CssColourSpace>,
+	method:  &mut HueInterpolation,
+) -> Result<Kind, CssGradientFromStrError> {
+	let mut circle   = None;
+	let mut size     = None;
+	let mut position = None;
+
+	let mut tokens = split_whitespace(prelude).peekable();
+
+	while let Some(token) = tokens.next() {
+		if token.eq_ignore_ascii_case("in") {
+			parse_method(&mut tokens, space, method)?;
+			continue;
+		}
+
+		// The position must follow the shape and size.
+		if position.is_some() {
+			return Err(CssGradientFromStrError::InvalidSyntax);
+		}
+
+		if token.eq_ignore_ascii_case("at") {
+			position = Some(parse_position(&mut tokens)?);
+			continue;
+		}
+
+		if token.eq_ignore_ascii_case("circle") || token.eq_ignore_ascii_case("ellipse") {
+			if circle.replace(token.eq_ignore_ascii_case("circle")).is_some() {
+				return Err(CssGradientFromStrError::InvalidSyntax);
+			}
+
+			continue;
+		}
+
+		let keywords = [
+			("closest-side",    RadialSize::ClosestSide),
+			("farthest-side",   RadialSize::FarthestSide),
+			("closest-corner",  RadialSize::ClosestCorner),
+			("farthest-corner", RadialSize::FarthestCorner),
+		];
+
+		let new_size = if let Some((_, new_size)) = keywords.into_iter().find(|(name, _)| name.eq_ignore_ascii_case(token)) {
+			new_size
+		} else {
+			let x = parse_length_percentage(token).ok_or(CssGradientFromStrError::InvalidSyntax)?;
+
+			match tokens.peek().copied().and_then(parse_length_percentage) {
+				Some(y) => {
+					tokens.next();
+					RadialSize::Explicit(x, y)
+				}
+
+				None => {
+					// A single radius denotes a circle and
+					// must be a length.
+					if matches!(x, LengthPercentage::Percentage(_)) || circle == Some(false) {
+						return Err(CssGradientFromStrError::InvalidSyntax);
+					}
+
+					circle = Some(true);
+					RadialSize::Explicit(x, x)
+				}
+			}
+		};
+
+		if size.replace(new_size).is_some() {
+			return Err(CssGradientFromStrError::InvalidSyntax);
+		}
+	}
+
+	// Circles cannot have two explicit radii.
+	if let (Some(true), Some(RadialSize::Explicit(x, y))) = (circle, size) {
+		if x != y {
+			return Err(CssGradientFromStrError::InvalidSyntax);
+		}
+	}
+
+	let kind = Kind::Radial {
+		circle:   circle.unwrap_or(false),
+		size:     size.unwrap_or(RadialSize::FarthestCorner),
+		position: position.unwrap_or(CENTRE),
+	};
+
+	Ok(kind)
+}
+
+/// Parses the prelude of `conic-gradient()`.
+fn parse_conic(
+	prelude: &str,
+	space:   &mut Option<CssColourSpace>,
+	method:  &mut HueInterpolation,
+) -> Result<Kind, CssGradientFromStrError> {
+	let mut from     = None;
+	let mut position = None;
+
+	let mut tokens = split_whitespace(prelude).peekable();
+
+	while let Some(token) = tokens.next() {
+		if token.eq_ignore_ascii_case("in") {
+			parse_method(&mut tokens, space, method)?;
+		} else if token.eq_ignore_ascii_case("from") && from.is_none() && position.is_none() {
+			let angle = tokens.next().and_then(parse_angle).ok_or(CssGradientFromStrError::InvalidSyntax)?;
+			from = Some(angle);
+		} else if token.eq_ignore_ascii_case("at") && position.is_none() {
+			position = Some(parse_position(&mut tokens)?);
+		} else {
+			return Err(CssGradientFromStrError::InvalidSyntax);
+		}
+	}
+
+	let kind = Kind::Conic {
+		from:     from.unwrap_or(0.0),
+		position: position.unwrap_or(CENTRE),
+	};
+
+	Ok(kind)
+}
+
+/// Parses a colour stop or a transition hint.
+fn parse_item(argument: &str, is_conic: bool, items: &mut Vec<Item>) -> Result<(), CssGradientFromStrError> {
+	let parse_position = if is_conic {
+		parse_angle_percentage
+	} else {
+		parse_length_percentage
+	};
+
+	let mut tokens = split_whitespace(argument);
+
+	let first = tokens.next().ok_or(CssGradientFromStrError::InvalidSyntax)?;
+
+	if let Some(hint) = parse_position(first) {
+		if tokens.next().is_some() {
+			return Err(CssGradientFromStrError::InvalidSyntax);
+		}
+
+		items.push(Item::Hint(hint));
+		return Ok(());
+	}
+
+	let colour = CssColour::from_str(first).map_err(CssGradientFromStrError::InvalidColour)?;
+
+	let mut positions = 0x0;
+
+	for token in tokens {
+		let position = parse_position(token).ok_or(CssGradientFromStrError::InvalidSyntax)?;
+
+		positions += 0x1;
+
+		if positions > 0x2 {
+			return Err(CssGradientFromStrError::InvalidSyntax);
+		}
+
+		items.push(Item::Stop(colour, Some(position)));
+	}
+
+	if positions == 0x0 {
+		items.push(Item::Stop(colour, None));
+	}
+
+	Ok(())
+}
+
+/// Checks that hints are surrounded by stops and that
+/// there are enough stops.
+fn validate_items(items: &[Item]) -> Result<(), CssGradientFromStrError> {
+	let stops = items.iter().filter(|item| matches!(item, Item::Stop(..))).count();
+
+	if stops < 0x2 {
+		return Err(CssGradientFromStrError::TooFewStops);
+	}
+
+	let mut previous_is_stop = false;
+
+	for item in items {
+		let is_stop = matches!(item, Item::Stop(..));
+
+		if !is_stop && !previous_is_stop {
+			return Err(CssGradientFromStrError::InvalidSyntax);
+		}
+
+		previous_is_stop = is_stop;
+	}
+
+	if !previous_is_stop {
+		return Err(CssGradientFromStrError::InvalidSyntax);
+	}
+
+	Ok(())
+}
@@ -0,0 +1,165 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+mod from_str;
+#[cfg(feature = "maths")]
+mod render;
+mod test;
+
+use crate::HueInterpolation;
+use crate::www::{CssColour, CssColourSpace};
+
+use alloc::vec::Vec;
+
+/// A length or a percentage of some reference length.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum LengthPercentage {
+	/// A length in pixels.
+	Length(f64),
+
+	/// A percentage of the reference length.
+	Percentage(f64),
+}
+
+impl LengthPercentage {
+	/// Resolves the value to pixels.
+	#[cfg(feature = "maths")]
+	#[inline]
+	#[must_use]
+	const fn resolve(self, reference: f64) -> f64 {
+		match self {
+			Self::Length(value)     => value,
+			Self::Percentage(value) => value / 100.0 * reference,
+		}
+	}
+}
+
+/// A position within the gradient box.
+type Position = (LengthPercentage, LengthPercentage);
+
+/// The centre of the gradient box.
+const CENTRE: Position = (LengthPercentage::Percentage(50.0), LengthPercentage::Percentage(50.0));
+
+/// The direction of a linear gradient.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum LinearDirection {
+	/// An angle in degrees, clockwise from the top.
+	Angle(f64),
+
+	/// A corner of the gradient box.
+	Corner {
+		right:  bool,
+		bottom: bool,
+	},
+}
+
+/// The size of a radial gradient's ending shape.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum RadialSize {
+	ClosestSide,
+	FarthestSide,
+	ClosestCorner,
+	FarthestCorner,
+
+	/// The horizontal and vertical radii.
+	Explicit(LengthPercentage, LengthPercentage),
+}
+
+/// The geometry of a gradient.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Kind {
+	Linear(LinearDirection),
+
+	Radial {
+		circle:   bool,
+		size:     RadialSize,
+		position: Position,
+	},
+
+	Conic {
+		from:     f64,
+		position: Position,
+	},
+}
+
+/// An item of a colour-stop list.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Item {
+	Stop(CssColour, Option<LengthPercentage>),
+	Hint(LengthPercentage),
+}
+
+/// A CSS gradient.
+///
+/// This type represents the `linear-gradient()`, `radial-gradient()`, and `conic-gradient()` functions of [CSS Images 4](https://www.w3.org/TR/css-images-4/#gradients), as well as their repeating variants.
+/// Gradients are parsed using [`FromStr`](core::str::FromStr) and can be rasterised into buffers of sRGBA colours.
+///
+/// Colour-interpolation methods (e.g. `in oklch longer hue`) are supported.
+/// If no method is given, then the gradient is interpolated in [sRGB](CssColourSpace::SRgb) if all colour stops are [legacy colours](CssColour::is_legacy), and in [Oklab](CssColourSpace::OkLab) otherwise.
+///
+/// Lengths must be given in pixels (`px`) or as percentages, with pixels referring to those of the rasterised buffer.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "maths")]
+/// # {
+/// use polywave::www::CssGradient;
+///
+/// let gradient: CssGradient = "linear-gradient(in oklch longer hue to right, #FF0000, #0000FF)".parse().unwrap();
+///
+/// let image = gradient.render(0x40, 0x10);
+/// assert_eq!(image.len(), 0x400);
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct CssGradient {
+	kind:      Kind,
+	repeating: bool,
+	space:     Option<CssColourSpace>,
+	method:    HueInterpolation,
+	items:     Vec<Item>,
+}
+
+impl CssGradient {
+	/// Checks whether the gradient is repeating.
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_repeating(&self) -> bool {
+		self.repeating
+	}
+
+	/// Retrieves the colour space used for interpolation.
+	///
+	/// This is the space given by the colour-interpolation method, if any.
+	/// Otherwise, it is sRGB if all colour stops are legacy colours and Oklab if not.
+	#[must_use]
+	pub fn colour_space(&self) -> CssColourSpace {
+		if let Some(space) = self.space {
+			return space;
+		}
+
+		let is_legacy = self.items.iter().all(|item| match *item {
+			Item::Stop(colour, _) => colour.is_legacy(),
+			Item::Hint(_)         => true,
+		});
+
+		if is_legacy {
+			CssColourSpace::SRgb
+		} else {
+			CssColourSpace::OkLab
+		}
+	}
+
+	/// Retrieves the hue-interpolation method.
+	#[inline(always)]
+	#[must_use]
+	pub const fn hue_interpolation(&self) -> HueInterpolation {
+		self.method
+	}
+}
@@ -0,0 +1,440 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::Alpha;
+#[cfg(feature = "maths")]
+use crate::maths::Maths;
+use crate::rgb::SRgb;
+use crate::www::{CssColourSpace, CssGradient};
+use crate::www::css_gradient::{
+	Item,
+	Kind,
+	LengthPercentage,
+	LinearDirection,
+	RadialSize,
+};
+
+use alloc::vec::Vec;
+
+/// A resolved colour stop.
+#[derive(Clone, Copy, Debug)]
+struct Stop {
+	position: f64,
+	colour:   Alpha<SRgb<f64>>,
+
+	/// The absolute position of the hint following
+	/// the stop.
+	hint: Option<f64>,
+}
+
+/// A colour stop whose position may not yet be resolved.
+type PartialStop = (Option<f64>, Alpha<SRgb<f64>>, Option<f64>);
+
+/// The resolved geometry of a gradient.
+#[derive(Clone, Copy, Debug)]
+enum Geometry {
+	Linear {
+		sin:    f64,
+		cos:    f64,
+		length: f64,
+		width:  f64,
+		height: f64,
+	},
+
+	Radial {
+		centre_x: f64,
+		centre_y: f64,
+		radius_x: f64,
+		radius_y: f64,
+	},
+
+	Conic {
+		centre_x: f64,
+		centre_y: f64,
+		from:     f64,
+	},
+}
+
+impl Geometry {
+	/// Retrieves the length of the gradient line or ray.
+	///
+	/// For conic gradients, this is one.
+	#[inline]
+	#[must_use]
+	const fn length(self) -> f64 {
+		match self {
+			Self::Linear { length, .. } => length,
+
+			Self::Radial { radius_x, .. } => radius_x,
+
+			Self::Conic { .. } => 1.0,
+		}
+	}
+
+	/// Maps a point to a fraction of the gradient line, ray, or turn.
+	#[inline]
+	#[must_use]
+	fn fraction(self, x: f64, y: f64) -> f64 {
+		match self {
+			Self::Linear { sin, cos, length, width, height } => {
+				let distance = (x - width / 2.0) * sin - (y - height / 2.0) * cos;
+				(distance + length / 2.0) / length
+			}
+
+			Self::Radial { centre_x, centre_y, radius_x, radius_y } => {
+				let dx = x - centre_x;
+				let dy = (y - centre_y) * radius_x / radius_y;
+
+				Maths::hypot(dx, dy) / radius_x
+			}
+
+			Self::Conic { centre_x, centre_y, from } => {
+				let angle = Maths::atan2(x - centre_x, centre_y - y).to_degrees();
+				Maths::rem_euclid((angle - from) / 360.0, 1.0)
+			}
+		}
+	}
+}
+
+impl CssGradient {
+	/// Resolves the geometry of the gradient for a given box size.
+	#[must_use]
+	fn geometry(&self, width: f64, height: f64) -> Geometry {
+		match self.kind {
+			Kind::Linear(direction) => {
+				let angle = match direction {
+					LinearDirection::Angle(angle) => angle,
+
+					LinearDirection::Corner { right, bottom } => {
+						let angle = Maths::atan2(height, width).to_degrees();
+
+						match (right, bottom) {
+							(true,  false) => angle,
+							(true,  true)  => 180.0 - angle,
+							(false, true)  => 180.0 + angle,
+							(false, false) => 360.0 - angle,
+						}
+					}
+				};
+
+				let (sin, cos) = Maths::sin_cos(angle.to_radians());
+
+				let length = (Maths::abs(width * sin) + Maths::abs(height * cos)).max(f64::EPSILON);
+
+				Geometry::Linear { sin, cos, length, width, height }
+			}
+
+			Kind::Radial { circle, size, position } => {
+				const SQRT_2: f64 = core::f64::consts::SQRT_2;
+
+				let centre_x = position.0.resolve(width);
+				let centre_y = position.1.resolve(height);
+
+				let (near_x, far_x) = {
+					let (left, right) = (Maths::abs(centre_x), Maths::abs(width - centre_x));
+					(left.min(right), left.max(right))
+				};
+
+				let (near_y, far_y) = {
+					let (top, bottom) = (Maths::abs(centre_y), Maths::abs(height - centre_y));
+					(top.min(bottom), top.max(bottom))
+				};
+
+				let (radius_x, radius_y) = match (size, circle) {
+					(RadialSize::ClosestSide,    false) => (near_x, near_y),
+					(RadialSize::FarthestSide,   false) => (far_x, far_y),
+					(RadialSize::ClosestCorner,  false) => (near_x * SQRT_2, near_y * SQRT_2),
+					(RadialSize::FarthestCorner, false) => (far_x * SQRT_2, far_y * SQRT_2),
+
+					(RadialSize::ClosestSide,    true) => (near_x.min(near_y), near_x.min(near_y)),
+					(RadialSize::FarthestSide,   true) => (far_x.max(far_y), far_x.max(far_y)),
+					(RadialSize::ClosestCorner,  true) => (Maths::hypot(near_x, near_y), Maths::hypot(near_x, near_y)),
+					(RadialSize::FarthestCorner, true) => (Maths::hypot(far_x, far_y), Maths::hypot(far_x, far_y)),
+
+					(RadialSize::Explicit(x, y), _) => (x.resolve(width), y.resolve(height)),
+				};
+
+				// Degenerate shapes are given a minimal size.
+				let radius_x = radius_x.max(f64::EPSILON);
+				let radius_y = radius_y.max(f64::EPSILON);
+
+				Geometry::Radial { centre_x, centre_y, radius_x, radius_y }
+			}
+
+			Kind::Conic { from, position } => {
+				let centre_x = position.0.resolve(width);
+				let centre_y = position.1.resolve(height);
+
+				Geometry::Conic { centre_x, centre_y, from }
+			}
+		}
+	}
+
+	/// Resolves the positions of all stops.
+	///
+	/// Positions are given as fractions of the gradient line, ray, or turn, whose length is given in pixels.
+	#[must_use]
+	fn resolve_stops(&self, length: f64) -> Vec<Stop> {
+		let length = length.max(f64::EPSILON);
+
+		let fraction = |value: LengthPercentage| value.resolve(length) / length;
+
+		let mut stops: Vec<PartialStop> = Vec::new();
+
+		for item in &self.items {
+			match *item {
+				Item::Stop(colour, position) => stops.push((position.map(fraction), colour.to_s_rgb(), None)),
+
+				Item::Hint(position) => {
+					if let Some(last) = stops.last_mut() {
+						last.2 = Some(fraction(position));
+					}
+				}
+			}
+		}
+
+		// Missing positions of the first and last stops
+		// default to the ends.
+
+		if let Some(first) = stops.first_mut() {
+			first.0.get_or_insert(0.0);
+		}
+
+		if let Some(last) = stops.last_mut() {
+			last.0.get_or_insert(1.0);
+		}
+
+		// Positions may not decrease.
+
+		let mut maximum = f64::NEG_INFINITY;
+
+		for stop in &mut stops {
+			if let Some(ref mut position) = stop.0 {
+				*position = position.max(maximum);
+				maximum = *position;
+			}
+
+			if let Some(ref mut hint) = stop.2 {
+				*hint = hint.max(maximum);
+				maximum = *hint;
+			}
+		}
+
+		// Missing positions are spread evenly between the
+		// surrounding stops.
+
+		let mut index = 0x0;
+
+		while index < stops.len() {
+			if stops[index].0.is_some() {
+				index += 0x1;
+				continue;
+			}
+
+			let start = index - 0x1;
+			let end   = (index..stops.len()).find(|&index| stops[index].0.is_some()).unwrap_or(stops.len() - 0x1);
+
+			let start_position = stops[start].0.unwrap_or_default();
+			let end_position   = stops[end].0.unwrap_or_default();
+
+			#[allow(clippy::cast_precision_loss)]
+			for (offset, stop) in stops[start + 0x1..end].iter_mut().enumerate() {
+				let factor = (offset + 0x1) as f64 / (end - start) as f64;
+				stop.0 = Some(start_position + (end_position - start_position) * factor);
+			}
+
+			index = end;
+		}
+
+		let stops: Vec<Stop> = stops
+			.into_iter()
+			.map(|(position, colour, hint)| Stop { position: position.unwrap_or_default(), colour, hint })
+			.collect();
+
+		// Repeating gradients whose first and last stops
+		// coincide are rendered as the average colour of
+		// the stops spread evenly.
+
+		if let (true, Some(first), Some(last)) = (self.repeating, stops.first(), stops.last()) {
+			if last.position - first.position <= 0.0 {
+				let stop = Stop {
+					position: first.position,
+					colour:   self.average_colour(&stops),
+					hint:     None,
+				};
+
+				return alloc::vec![stop];
+			}
+		}
+
+		stops
+	}
+
+	/// Computes the average colour of a gradient with evenly-spaced stops.
+	///
+	/// Each pair of adjacent stops contributes the colour at its midpoint, and these are averaged in the interpolation space.
+	/// Hints are ignored.
+	#[must_use]
+	fn average_colour(&self, stops: &[Stop]) -> Alpha<SRgb<f64>> {
+		let space = self.colour_space();
+
+		let mut average = stops[0x0].colour;
+
+		#[allow(clippy::cast_precision_loss)]
+		for (index, window) in stops.windows(0x2).enumerate() {
+			let midpoint = space.interpolate(window[0x0].colour, window[0x1].colour, 0.5, self.method);
+
+			// Accumulate a running mean of the midpoints.
+			let factor = 1.0 / (index + 0x1) as f64;
+
+			average = space.interpolate(average, midpoint, factor, self.method);
+		}
+
+		average
+	}
+
+	/// Samples resolved stops at a position.
+	#[must_use]
+	fn sample(&self, stops: &[Stop], space: CssColourSpace, mut position: f64) -> Alpha<SRgb<f64>> {
+		let first = stops[0x0];
+		let last  = stops[stops.len() - 0x1];
+
+		if self.repeating {
+			let length = last.position - first.position;
+
+			// Degenerate gradients are resolved to a single
+			// stop.
+			if length <= 0.0 {
+				return last.colour;
+			}
+
+			position = first.position + Maths::rem_euclid(position - first.position, length);
+		}
+
+		if position <= first.position {
+			return first.colour;
+		}
+
+		if position >= last.position {
+			return last.colour;
+		}
+
+		let Some(window) = stops.windows(0x2).find(|window| {
+			window[0x0].position <= position && position < window[0x1].position
+		}) else {
+			return last.colour;
+		};
+
+		let start = window[0x0];
+		let end   = window[0x1];
+
+		let length = end.position - start.position;
+
+		let mut progress = (position - start.position) / length;
+
+		if let Some(hint) = start.hint {
+			let hint = (hint - start.position) / length;
+
+			progress = if hint <= 0.0 {
+				1.0
+			} else if hint >= 1.0 {
+				0.0
+			} else {
+				Maths::powf(progress, Maths::ln(0.5_f64) / Maths::ln(hint))
+			};
+		}
+
+		space.interpolate(start.colour, end.colour, progress, self.method)
+	}
+
+	/// Renders the gradient into a buffer of sRGBA colours.
+	///
+	/// The buffer is laid out in row-major order with `width` columns and `height` rows, and each pixel is sampled at its centre.
+	/// Colours are clipped to the sRGB gamut.
+	///
+	/// # Panics
+	///
+	/// This method panics if the buffer does not contain exactly `width * height` pixels.
+	pub fn render_into(&self, buffer: &mut [Alpha<SRgb<u8>>], width: usize, height: usize) {
+		assert_eq!(buffer.len(), width * height, "buffer does not match dimensions");
+
+		#[allow(clippy::cast_precision_loss)]
+		let geometry = self.geometry(width as f64, height as f64);
+
+		let stops = self.resolve_stops(geometry.length());
+		let space = self.colour_space();
+
+		#[allow(clippy::cast_precision_loss)]
+		for (index, pixel) in buffer.iter_mut().enumerate() {
+			let x = (index % width) as f64 + 0.5;
+			let y = (index / width) as f64 + 0.5;
+
+			*pixel = to_s_rgba(self.sample(&stops, space, geometry.fraction(x, y)));
+		}
+	}
+
+	/// Renders the gradient into a new buffer of sRGBA colours.
+	///
+	/// See [`render_into`](Self::render_into) for more information.
+	#[must_use]
+	pub fn render(&self, width: usize, height: usize) -> Vec<Alpha<SRgb<u8>>> {
+		let mut buffer = alloc::vec![Alpha::attach(SRgb::new(0x00, 0x00, 0x00), 0x00); width * height];
+		self.render_into(&mut buffer, width, height);
+
+		buffer
+	}
+
+	/// Renders the gradient into a one-dimensional ramp.
+	///
+	/// The gradient line (or ray, or turn) is sampled at evenly-spaced positions from its start to its end, inclusive, with lengths resolving against the length of the buffer.
+	/// The geometry of the gradient is otherwise ignored.
+	pub fn render_ramp_into(&self, buffer: &mut [Alpha<SRgb<u8>>]) {
+		#[allow(clippy::cast_precision_loss)]
+		let length = buffer.len() as f64;
+
+		let length = match self.kind {
+			Kind::Conic { .. } => 1.0,
+
+			_ => length,
+		};
+
+		let stops = self.resolve_stops(length);
+		let space = self.colour_space();
+
+		#[allow(clippy::cast_precision_loss)]
+		let step = 1.0 / buffer.len().saturating_sub(0x1).max(0x1) as f64;
+
+		#[allow(clippy::cast_precision_loss)]
+		for (index, pixel) in buffer.iter_mut().enumerate() {
+			*pixel = to_s_rgba(self.sample(&stops, space, step * index as f64));
+		}
+	}
+
+	/// Renders the gradient into a new one-dimensional ramp.
+	///
+	/// See [`render_ramp_into`](Self::render_ramp_into) for more information.
+	#[must_use]
+	pub fn render_ramp(&self, len: usize) -> Vec<Alpha<SRgb<u8>>> {
+		let mut buffer = alloc::vec![Alpha::attach(SRgb::new(0x00, 0x00, 0x00), 0x00); len];
+		self.render_ramp_into(&mut buffer);
+
+		buffer
+	}
+}
+
+/// Clips and quantises an sRGBA colour.
+#[inline]
+#[must_use]
+fn to_s_rgba(colour: Alpha<SRgb<f64>>) -> Alpha<SRgb<u8>> {
+	#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+	let quantise = |channel: f64| Maths::round(channel.clamp(0.0, 1.0) * 255.0) as u8;
+
+	let (colour, alpha) = colour.detach();
+
+	Alpha::attach(colour.map(quantise), quantise(alpha))
+}
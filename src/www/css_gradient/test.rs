@@ -0,0 +1,123 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+#![cfg(all(test, feature = "maths"))]
+
+use crate::{Alpha, HueInterpolation};
+use crate::error::CssGradientFromStrError;
+use crate::rgb::SRgb;
+use crate::www::{CssColourSpace, CssGradient};
+
+use core::str::FromStr;
+
+#[must_use]
+fn rgba(red: u8, green: u8, blue: u8, alpha: u8) -> Alpha<SRgb<u8>> {
+	Alpha::attach(SRgb::new(red, green, blue), alpha)
+}
+
+#[test]
+fn test_css_gradient_from_str() {
+	let gradient = CssGradient::from_str("linear-gradient(#FF0000, #0000FF)").unwrap();
+	assert!(!gradient.is_repeating());
+	assert_eq!(gradient.colour_space(), CssColourSpace::SRgb);
+
+	let gradient = CssGradient::from_str("Repeating-Radial-Gradient(circle 4px at left top, #FFF, #000 50%)").unwrap();
+	assert!(gradient.is_repeating());
+
+	let gradient = CssGradient::from_str("conic-gradient(from 90deg at 25% 75% in oklch longer hue, red, blue 0.5turn, red)").unwrap();
+	assert_eq!(gradient.colour_space(), CssColourSpace::OkLch);
+	assert_eq!(gradient.hue_interpolation(), HueInterpolation::Longer);
+
+	assert_eq!(CssGradient::from_str("linear-gradient(#FFF)"), Err(CssGradientFromStrError::TooFewStops));
+
+	assert_eq!(CssGradient::from_str("linear-gradient(#FFF, 50%, 25%, #000)"), Err(CssGradientFromStrError::InvalidSyntax));
+
+	assert_eq!(CssGradient::from_str("linear-gradient(in srgb longer hue, #FFF, #000)"), Err(CssGradientFromStrError::InvalidSyntax));
+
+	assert_eq!(CssGradient::from_str("linear-gradient(in cmyk, #FFF, #000)"), Err(CssGradientFromStrError::UnknownColourSpace));
+
+	assert_eq!(CssGradient::from_str("elliptic-gradient(#FFF, #000)"), Err(CssGradientFromStrError::UnknownFunction));
+
+	assert!(matches!(CssGradient::from_str("linear-gradient(#FFF, #GGG)"), Err(CssGradientFromStrError::InvalidColour(_))));
+
+	let gradient = CssGradient::from_str("linear-gradient(rgb(255 0 0), hsl(240 100% 50%))").unwrap();
+	assert_eq!(gradient.colour_space(), CssColourSpace::SRgb);
+
+	let gradient = CssGradient::from_str("linear-gradient(rgb(255 0 0), oklch(45% 0.31 264))").unwrap();
+	assert_eq!(gradient.colour_space(), CssColourSpace::OkLab);
+}
+
+#[test]
+fn test_css_gradient_render_ramp() {
+	let gradient = CssGradient::from_str("linear-gradient(to right, #000000, #FFFFFF)").unwrap();
+
+	let ramp = gradient.render_ramp(0x3);
+	assert_eq!(ramp, [rgba(0x00, 0x00, 0x00, 0xFF), rgba(0x80, 0x80, 0x80, 0xFF), rgba(0xFF, 0xFF, 0xFF, 0xFF)]);
+
+	let gradient = CssGradient::from_str("linear-gradient(#FF0000 50%, #0000FF 50%)").unwrap();
+
+	let ramp = gradient.render_ramp(0x4);
+	assert_eq!(ramp, [rgba(0xFF, 0x00, 0x00, 0xFF), rgba(0xFF, 0x00, 0x00, 0xFF), rgba(0x00, 0x00, 0xFF, 0xFF), rgba(0x00, 0x00, 0xFF, 0xFF)]);
+
+	let gradient = CssGradient::from_str("linear-gradient(#000000, 25%, #FFFFFF)").unwrap();
+
+	let ramp = gradient.render_ramp(0x5);
+	assert_eq!(ramp[0x1], rgba(0x80, 0x80, 0x80, 0xFF));
+
+	let gradient = CssGradient::from_str("repeating-linear-gradient(#000000, #FFFFFF 50%)").unwrap();
+
+	let ramp = gradient.render_ramp(0x5);
+	assert_eq!(ramp[0x1], rgba(0x80, 0x80, 0x80, 0xFF));
+	assert_eq!(ramp[0x3], rgba(0x80, 0x80, 0x80, 0xFF));
+}
+
+#[test]
+fn test_css_gradient_render_degenerate_repeating() {
+	// Repeating gradients without length are rendered
+	// as the average colour of their stops.
+
+	let gradient = CssGradient::from_str("repeating-linear-gradient(to right, #FF0000 50%, #0000FF 50%)").unwrap();
+
+	let image = gradient.render(0x4, 0x2);
+	assert!(image.iter().all(|&pixel| pixel == rgba(0x80, 0x00, 0x80, 0xFF)));
+
+	let gradient = CssGradient::from_str("repeating-linear-gradient(#000000 30%, #FFFFFF 30%, #FFFFFF 30%)").unwrap();
+
+	let ramp = gradient.render_ramp(0x5);
+	assert!(ramp.iter().all(|&pixel| pixel == rgba(0xBF, 0xBF, 0xBF, 0xFF)));
+}
+
+#[test]
+fn test_css_gradient_render() {
+	let gradient = CssGradient::from_str("linear-gradient(to right, #FF0000 50%, #0000FF 50%)").unwrap();
+
+	let image = gradient.render(0x4, 0x2);
+	assert_eq!(image[0x1], rgba(0xFF, 0x00, 0x00, 0xFF));
+	assert_eq!(image[0x6], rgba(0x00, 0x00, 0xFF, 0xFF));
+
+	let gradient = CssGradient::from_str("radial-gradient(circle closest-side, #FFFFFF, #000000)").unwrap();
+
+	let image = gradient.render(0x8, 0x8);
+	assert_eq!(image[0x0], rgba(0x00, 0x00, 0x00, 0xFF));
+	assert!(image[0x1B].detach().0.get().0 > 0xC0);
+
+	let gradient = CssGradient::from_str("conic-gradient(#FF0000 25%, #0000FF 25%)").unwrap();
+
+	let image = gradient.render(0x2, 0x2);
+	assert_eq!(image, [rgba(0x00, 0x00, 0xFF, 0xFF), rgba(0xFF, 0x00, 0x00, 0xFF), rgba(0x00, 0x00, 0xFF, 0xFF), rgba(0x00, 0x00, 0xFF, 0xFF)]);
+}
+
+#[test]
+fn test_css_gradient_hue_interpolation() {
+	let shorter = CssGradient::from_str("linear-gradient(in hsl, #FF0000, #0000FF)").unwrap();
+	let longer  = CssGradient::from_str("linear-gradient(in hsl longer hue, #FF0000, #0000FF)").unwrap();
+
+	// Shorter goes through magenta, longer through green.
+	assert_eq!(shorter.render_ramp(0x3)[0x1], rgba(0xFF, 0x00, 0xFF, 0xFF));
+	assert_eq!(longer.render_ramp(0x3)[0x1],  rgba(0x00, 0xFF, 0x00, 0xFF));
+}
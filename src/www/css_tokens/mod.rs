@@ -0,0 +1,222 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+//! Minimal tokenisation of CSS values.
+//!
+//! These facilities only cover what is needed for parsing colour-related values, and they operate directly on string slices.
+
+/// An iterator over the top-level parts of a CSS value.
+///
+/// Separators inside of parentheses are ignored.
+#[derive(Clone, Debug)]
+pub(crate) struct Split<'a> {
+	rest:      Option<&'a str>,
+	separator: fn(char) -> bool,
+}
+
+impl<'a> Iterator for Split<'a> {
+	type Item = &'a str;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let rest = self.rest?;
+
+		let mut depth = 0x0usize;
+
+		for (index, c) in rest.char_indices() {
+			match c {
+				'(' => depth += 0x1,
+				')' => depth = depth.saturating_sub(0x1),
+
+				_ if depth == 0x0 && (self.separator)(c) => {
+					self.rest = Some(&rest[index + c.len_utf8()..]);
+					return Some(&rest[..index]);
+				}
+
+				_ => { }
+			}
+		}
+
+		self.rest = None;
+		Some(rest)
+	}
+}
+
+/// Splits a value at its top-level commas.
+///
+/// The parts are trimmed of whitespace, and empty parts are retained.
+#[inline]
+pub(crate) fn split_commas(s: &str) -> impl Iterator<Item = &str> {
+	let split = Split {
+		rest:      Some(s),
+		separator: |c| c == ',',
+	};
+
+	split.map(str::trim)
+}
+
+/// Splits a value at its top-level slashes.
+///
+/// The parts are trimmed of whitespace, and empty parts are retained.
+#[inline]
+pub(crate) fn split_slashes(s: &str) -> impl Iterator<Item = &str> {
+	let split = Split {
+		rest:      Some(s),
+		separator: |c| c == '/',
+	};
+
+	split.map(str::trim)
+}
+
+/// Splits a value at its top-level whitespace.
+///
+/// Empty tokens are skipped.
+#[inline]
+pub(crate) fn split_whitespace(s: &str) -> impl Iterator<Item = &str> {
+	let split = Split {
+		rest:      Some(s),
+		separator: char::is_whitespace,
+	};
+
+	split.filter(|token| !token.is_empty())
+}
+
+/// Splits the first top-level token off a value.
+///
+/// The token is returned together with the rest of the value, and leading whitespace is skipped.
+#[must_use]
+pub(crate) fn split_first_token(s: &str) -> Option<(&str, &str)> {
+	let mut split = Split {
+		rest:      Some(s.trim_start()),
+		separator: char::is_whitespace,
+	};
+
+	let token = split.next().filter(|token| !token.is_empty())?;
+
+	Some((token, split.rest.unwrap_or_default()))
+}
+
+/// Splits a functional notation into its name and arguments.
+///
+/// The parentheses must be balanced, and the closing parenthesis must end the value.
+#[must_use]
+pub(crate) fn parse_function(s: &str) -> Option<(&str, &str)> {
+	let s = s.trim();
+
+	let (name, rest) = s.split_once('(')?;
+
+	if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+		return None;
+	}
+
+	let arguments = rest.strip_suffix(')')?;
+
+	let mut depth = 0x0usize;
+
+	for c in arguments.chars() {
+		match c {
+			'(' => depth += 0x1,
+			')' => depth = depth.checked_sub(0x1)?,
+
+			_ => { }
+		}
+	}
+
+	(depth == 0x0).then_some((name, arguments))
+}
+
+/// Parses the number at the start of a string.
+///
+/// The number is returned together with the length of its representation.
+#[must_use]
+pub(crate) fn parse_number_prefix(s: &str) -> Option<(f64, usize)> {
+	let bytes = s.as_bytes();
+
+	let mut end = 0x0;
+
+	if matches!(bytes.first(), Some(b'+' | b'-')) {
+		end += 0x1;
+	}
+
+	let digits_start = end;
+
+	while bytes.get(end).is_some_and(u8::is_ascii_digit) {
+		end += 0x1;
+	}
+
+	if bytes.get(end) == Some(&b'.') {
+		end += 0x1;
+
+		while bytes.get(end).is_some_and(u8::is_ascii_digit) {
+			end += 0x1;
+		}
+	}
+
+	// Require at least one digit.
+	if !s[digits_start..end].bytes().any(|c| c.is_ascii_digit()) {
+		return None;
+	}
+
+	// Accept an exponent only if it is complete, as
+	// the `e` may otherwise start a unit (e.g. `em`).
+	if matches!(bytes.get(end), Some(b'e' | b'E')) {
+		let mut exponent_end = end + 0x1;
+
+		if matches!(bytes.get(exponent_end), Some(b'+' | b'-')) {
+			exponent_end += 0x1;
+		}
+
+		if bytes.get(exponent_end).is_some_and(u8::is_ascii_digit) {
+			while bytes.get(exponent_end).is_some_and(u8::is_ascii_digit) {
+				exponent_end += 0x1;
+			}
+
+			end = exponent_end;
+		}
+	}
+
+	let value = s[..end].parse().ok()?;
+
+	Some((value, end))
+}
+
+/// Splits a token into its numeric part and its unit.
+///
+/// Percentages are given the unit `%`.
+#[must_use]
+pub(crate) fn parse_dimension(s: &str) -> Option<(f64, &str)> {
+	let (value, end) = parse_number_prefix(s)?;
+
+	let unit = &s[end..];
+
+	Some((value, unit))
+}
+
+/// Parses an angle in degrees.
+///
+/// The units `deg`, `grad`, `rad`, and `turn` are supported, and a unitless zero is accepted.
+#[must_use]
+pub(crate) fn parse_angle(s: &str) -> Option<f64> {
+	let (value, unit) = parse_dimension(s)?;
+
+	let units = [
+		("deg",  1.0),
+		("grad", 0.9),
+		("rad",  const { 180.0 / core::f64::consts::PI }),
+		("turn", 360.0),
+	];
+
+	let value = if let Some((_, scale)) = units.into_iter().find(|(other, _)| other.eq_ignore_ascii_case(unit)) {
+		value * scale
+	} else if unit.is_empty() && value == 0.0 {
+		0.0
+	} else {
+		return None;
+	};
+
+	Some(value)
+}
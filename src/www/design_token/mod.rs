@@ -0,0 +1,55 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::www::DesignTokenValue;
+
+use alloc::string::String;
+
+/// A colour design token.
+///
+/// See [`DesignTokens`](crate::www::DesignTokens) for more information.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DesignToken {
+	value:       DesignTokenValue,
+	description: Option<String>,
+}
+
+impl DesignToken {
+	/// Constructs a new design token without a description.
+	#[inline(always)]
+	#[must_use]
+	pub const fn new(value: DesignTokenValue) -> Self {
+		Self { value, description: None }
+	}
+
+	/// Retrieves the value of the token.
+	#[inline(always)]
+	#[must_use]
+	pub const fn value(&self) -> &DesignTokenValue {
+		&self.value
+	}
+
+	/// Retrieves the description of the token (i.e. `$description`), if any.
+	#[inline(always)]
+	#[must_use]
+	pub fn description(&self) -> Option<&str> {
+		self.description.as_deref()
+	}
+
+	/// Sets the value of the token.
+	#[inline(always)]
+	pub fn set_value(&mut self, value: DesignTokenValue) {
+		self.value = value;
+	}
+
+	/// Sets the description of the token.
+	#[inline(always)]
+	pub fn set_description(&mut self, description: Option<String>) {
+		self.description = description;
+	}
+}
@@ -0,0 +1,60 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::www::{CssColour, Html};
+
+/// The value of a design-token colour.
+///
+/// This is the `$value` of tokens of the `color` type as specified by the [Design Tokens Community Group](https://www.designtokens.org/tr/drafts/color/).
+/// Both the legacy hexadecimal strings and the object form (with `colorSpace`, `components`, `alpha`, and `hex` fields) are supported, and colours are written back in the form they were read in.
+///
+/// Components of the object form are given in the units of CSS, except that the saturation, lightness, whiteness, and blackness of `hsl` and `hwb` are in the range `0` to `100`.
+/// Missing components (i.e. `"none"`) are represented as NaN, as in [`CssColour`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DesignTokenColour {
+	/// A legacy hexadecimal colour, e.g. `"#663399"`.
+	Hex(Html),
+
+	/// A colour in the object form.
+	Components {
+		/// The colour.
+		colour: CssColour,
+
+		/// The hexadecimal fallback of the colour, if any.
+		hex: Option<Html>,
+	},
+}
+
+impl DesignTokenColour {
+	/// Converts the colour to a CSS colour.
+	///
+	/// The hexadecimal fallback is ignored.
+	#[inline]
+	#[must_use]
+	pub fn to_css_colour(self) -> CssColour {
+		match self {
+			Self::Hex(colour) => colour.into(),
+
+			Self::Components { colour, .. } => colour,
+		}
+	}
+}
+
+impl From<Html> for DesignTokenColour {
+	#[inline(always)]
+	fn from(value: Html) -> Self {
+		Self::Hex(value)
+	}
+}
+
+impl From<CssColour> for DesignTokenColour {
+	#[inline(always)]
+	fn from(value: CssColour) -> Self {
+		Self::Components { colour: value, hex: None }
+	}
+}
@@ -0,0 +1,28 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::www::DesignTokenColour;
+
+use alloc::string::String;
+
+/// The value of a design token.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DesignTokenValue {
+	/// A colour.
+	Colour(DesignTokenColour),
+
+	/// An alias of another token, given by its dot-separated path (without the surrounding braces).
+	Alias(String),
+}
+
+impl From<DesignTokenColour> for DesignTokenValue {
+	#[inline(always)]
+	fn from(value: DesignTokenColour) -> Self {
+		Self::Colour(value)
+	}
+}
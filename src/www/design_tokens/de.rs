@@ -0,0 +1,233 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::error::DesignTokenError;
+use crate::www::{
+	CssColour,
+	CssColourSpace,
+	DesignToken,
+	DesignTokenColour,
+	DesignTokenValue,
+	DesignTokens,
+	Html,
+};
+use crate::www::design_tokens::node::Node;
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::de::{Deserialize, Deserializer, Error};
+
+impl DesignTokens {
+	/// Interprets a token file.
+	pub(crate) fn from_node(node: &Node) -> Result<Self, DesignTokenError> {
+		let mut reader = TreeReader { tokens: BTreeMap::new(), untyped_aliases: Vec::new() };
+
+		reader.read_group(node, "", None)?;
+
+		let TreeReader { mut tokens, mut untyped_aliases } = reader;
+
+		// Untyped aliases are kept if they (transitively) reference colour tokens.
+		loop {
+			let count = untyped_aliases.len();
+
+			untyped_aliases.retain(|(path, token): &(String, DesignToken)| {
+				let DesignTokenValue::Alias(ref target) = *token.value() else {
+					return false;
+				};
+
+				if !tokens.contains_key(target) {
+					return true;
+				}
+
+				let _ = tokens.insert(path.clone(), token.clone());
+				false
+			});
+
+			if untyped_aliases.len() == count {
+				break;
+			}
+		}
+
+		Ok(Self { tokens })
+	}
+}
+
+impl<'de> Deserialize<'de> for DesignTokens {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let node = Node::deserialize(deserializer)?;
+		Self::from_node(&node).map_err(D::Error::custom)
+	}
+}
+
+impl DesignTokenColour {
+	/// Interprets the value of a colour token.
+	pub(crate) fn from_node(node: &Node, path: &str) -> Result<Self, DesignTokenError> {
+		let error = || DesignTokenError::InvalidColour { path: String::from(path) };
+
+		let parse_hex = |node: &Node| -> Result<Html, DesignTokenError> {
+			node
+				.as_str()
+				.filter(|s| s.starts_with('#'))
+				.and_then(|s| s.parse().ok())
+				.ok_or_else(error)
+		};
+
+		if let Node::String(_) = *node {
+			return parse_hex(node).map(Self::Hex);
+		}
+
+		let space = node
+			.get("colorSpace")
+			.and_then(Node::as_str)
+			.and_then(CssColourSpace::from_name)
+			.ok_or_else(error)?;
+
+		let component = |node: &Node| match *node {
+			Node::Number(value) => Some(value),
+
+			Node::String(ref s) if s == "none" => Some(f64::NAN),
+
+			_ => None,
+		};
+
+		let Some(Node::Array(components)) = node.get("components") else {
+			return Err(error());
+		};
+
+		let mut channels = [0.0; 0x3];
+
+		if components.len() != channels.len() {
+			return Err(error());
+		}
+
+		for (channel, node) in channels.iter_mut().zip(components) {
+			*channel = component(node).ok_or_else(error)?;
+		}
+
+		if matches!(space, CssColourSpace::Hsl | CssColourSpace::Hwb) {
+			channels[0x1] /= 100.0;
+			channels[0x2] /= 100.0;
+		}
+
+		let alpha = match node.get("alpha") {
+			Some(node) => component(node).ok_or_else(error)?,
+
+			None => 1.0,
+		};
+
+		let hex = node.get("hex").map(parse_hex).transpose()?;
+
+		let colour = CssColour::from_components(space, channels, alpha);
+
+		Ok(Self::Components { colour, hex })
+	}
+}
+
+impl<'de> Deserialize<'de> for DesignTokenColour {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let node = Node::deserialize(deserializer)?;
+		Self::from_node(&node, "").map_err(D::Error::custom)
+	}
+}
+
+/// A reader of token trees.
+struct TreeReader {
+	tokens: BTreeMap<String, DesignToken>,
+
+	/// The aliases without a type, which are only kept if they reference colour tokens.
+	untyped_aliases: Vec<(String, DesignToken)>,
+}
+
+impl TreeReader {
+	/// Reads the tokens of a group.
+	fn read_group(&mut self, node: &Node, path: &str, inherited_type: Option<&str>) -> Result<(), DesignTokenError> {
+		let Node::Object(ref members) = *node else {
+			return Err(DesignTokenError::InvalidGroup { path: String::from(path) });
+		};
+
+		let group_type = node.get("$type").and_then(Node::as_str).or(inherited_type);
+
+		for (name, child) in members {
+			if name.starts_with('$') {
+				continue;
+			}
+
+			let child_path = if path.is_empty() {
+				name.clone()
+			} else {
+				let mut child_path = String::from(path);
+				child_path.push('.');
+				child_path.push_str(name);
+
+				child_path
+			};
+
+			if name.is_empty() || name.contains(['.', '{', '}']) {
+				return Err(DesignTokenError::InvalidGroup { path: child_path });
+			}
+
+			if child.get("$value").is_some() {
+				self.read_token(child, child_path, group_type)?;
+			} else {
+				self.read_group(child, &child_path, group_type)?;
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Reads a token.
+	///
+	/// Tokens that are neither colour tokens nor untyped aliases are ignored.
+	fn read_token(&mut self, node: &Node, path: String, group_type: Option<&str>) -> Result<(), DesignTokenError> {
+		let token_type = node.get("$type").and_then(Node::as_str).or(group_type);
+
+		// This is checked by the caller.
+		let Some(value) = node.get("$value") else {
+			return Ok(());
+		};
+
+		let alias = value
+			.as_str()
+			.and_then(|s| s.strip_prefix('{'))
+			.and_then(|s| s.strip_suffix('}'))
+			.map(String::from);
+
+		let description = node.get("$description").and_then(Node::as_str).map(String::from);
+
+		match (token_type, alias) {
+			(Some("color"), Some(alias)) => {
+				let mut token = DesignToken::new(DesignTokenValue::Alias(alias));
+				token.set_description(description);
+
+				let _ = self.tokens.insert(path, token);
+			}
+
+			(Some("color"), None) => {
+				let colour = DesignTokenColour::from_node(value, &path)?;
+
+				let mut token = DesignToken::new(DesignTokenValue::Colour(colour));
+				token.set_description(description);
+
+				let _ = self.tokens.insert(path, token);
+			}
+
+			(None, Some(alias)) => {
+				let mut token = DesignToken::new(DesignTokenValue::Alias(alias));
+				token.set_description(description);
+
+				self.untyped_aliases.push((path, token));
+			}
+
+			_ => { }
+		}
+
+		Ok(())
+	}
+}
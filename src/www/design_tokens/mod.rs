@@ -0,0 +1,166 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+mod de;
+mod node;
+mod ser;
+mod test;
+
+use crate::error::DesignTokenError;
+use crate::www::{DesignToken, DesignTokenColour, DesignTokenValue};
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+
+/// A tree of colour design tokens.
+///
+/// This type reads and writes token files of the [Design Tokens Community Group](https://www.designtokens.org/tr/drafts/format/) (DTCG) format through [`serde`], e.g. using `serde_json`.
+/// The tree is stored flattened, with each token keyed by the dot-separated names of its groups and itself (e.g. `colour.brand.primary`).
+///
+/// Only colour tokens are kept, i.e. tokens whose `$type` (which may be inherited from their groups) is `color`, and untyped aliases of colour tokens.
+/// Other tokens, as well as any `$extensions` and the descriptions of groups, are ignored when reading and are thus not written back.
+/// Tokens are written with an explicit `$type`.
+///
+/// Aliases (e.g. `"{colour.brand.primary}"`) are kept as such, and they can be resolved using [`resolve`](Self::resolve) or [`resolve_all`](Self::resolve_all).
+///
+/// # Examples
+///
+/// Assuming a token file like:
+///
+/// ```json
+/// {
+///     "brand": {
+///         "$type": "color",
+///         "primary": {
+///             "$value": { "colorSpace": "oklch", "components": [0.5, 0.2, 300], "hex": "#7a34ba" }
+///         },
+///         "accent": { "$value": "{brand.primary}" }
+///     }
+/// }
+/// ```
+///
+/// The alias can be resolved after deserialising the file:
+///
+/// ```
+/// use polywave::www::{CssColour, DesignTokens};
+///
+/// # let json = r##"{
+/// #     "brand": {
+/// #         "$type": "color",
+/// #         "primary": {
+/// #             "$value": { "colorSpace": "oklch", "components": [0.5, 0.2, 300], "hex": "#7a34ba" }
+/// #         },
+/// #         "accent": { "$value": "{brand.primary}" }
+/// #     }
+/// # }"##;
+/// #
+/// let tokens: DesignTokens = serde_json::from_str(json)?;
+///
+/// let accent = tokens.resolve("brand.accent")?;
+/// assert!(matches!(accent.to_css_colour(), CssColour::OkLch(_)));
+/// #
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DesignTokens {
+	tokens: BTreeMap<String, DesignToken>,
+}
+
+impl DesignTokens {
+	/// Constructs a new, empty token tree.
+	#[inline(always)]
+	#[must_use]
+	pub const fn new() -> Self {
+		Self { tokens: BTreeMap::new() }
+	}
+
+	/// Retrieves the number of tokens.
+	#[inline(always)]
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.tokens.len()
+	}
+
+	/// Checks whether the tree has no tokens.
+	#[inline(always)]
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.tokens.is_empty()
+	}
+
+	/// Looks up a token by its path.
+	#[inline]
+	#[must_use]
+	pub fn get(&self, path: &str) -> Option<&DesignToken> {
+		self.tokens.get(path)
+	}
+
+	/// Inserts a token, returning any token previously at the same path.
+	#[inline]
+	pub fn insert(&mut self, path: String, token: DesignToken) -> Option<DesignToken> {
+		self.tokens.insert(path, token)
+	}
+
+	/// Removes a token.
+	#[inline]
+	pub fn remove(&mut self, path: &str) -> Option<DesignToken> {
+		self.tokens.remove(path)
+	}
+
+	/// Iterates over the paths and tokens, sorted by path.
+	#[inline]
+	pub fn iter(&self) -> impl Iterator<Item = (&str, &DesignToken)> {
+		self.tokens.iter().map(|(path, token)| (path.as_str(), token))
+	}
+
+	/// Resolves the colour of a token, following any aliases.
+	///
+	/// # Errors
+	///
+	/// If an alias references an unknown token, or if aliases are circular, an error is returned.
+	/// If `path` is itself unknown, [`UnknownReference`](DesignTokenError::UnknownReference) is returned with both paths being `path`.
+	pub fn resolve(&self, path: &str) -> Result<DesignTokenColour, DesignTokenError> {
+		let mut referrer = path;
+		let mut current  = path;
+
+		// A chain longer than the number of tokens must be circular.
+		for _ in 0x0..=self.tokens.len() {
+			let Some(token) = self.tokens.get(current) else {
+				return Err(DesignTokenError::UnknownReference { path: String::from(referrer), reference: String::from(current) });
+			};
+
+			match *token.value() {
+				DesignTokenValue::Colour(colour) => return Ok(colour),
+
+				DesignTokenValue::Alias(ref target) => {
+					referrer = current;
+					current  = target;
+				}
+			}
+		}
+
+		Err(DesignTokenError::CircularReference { path: String::from(path) })
+	}
+
+	/// Resolves all aliases, replacing them with the colours that they reference.
+	///
+	/// # Errors
+	///
+	/// If any alias cannot be [resolved](Self::resolve), an error is returned.
+	pub fn resolve_all(&self) -> Result<Self, DesignTokenError> {
+		let mut tokens = self.clone();
+
+		for (path, token) in &mut tokens.tokens {
+			if matches!(*token.value(), DesignTokenValue::Alias(_)) {
+				token.set_value(DesignTokenValue::Colour(self.resolve(path)?));
+			}
+		}
+
+		Ok(tokens)
+	}
+}
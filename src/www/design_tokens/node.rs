@@ -0,0 +1,174 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+//! Self-describing values of token files.
+//!
+//! Tokens are buffered as nodes before being interpreted, as the `$type` of a token may follow its `$value` and may furthermore be inherited from its groups.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Formatter};
+use serde::de::{Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
+
+/// A JSON-like value.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Node {
+	Null,
+	Bool(bool),
+	Number(f64),
+	String(String),
+	Array(Vec<Node>),
+
+	/// An object, with its members in their original order.
+	Object(Vec<(String, Node)>),
+}
+
+impl Node {
+	/// Looks up a member of an object.
+	#[must_use]
+	pub(crate) fn get(&self, key: &str) -> Option<&Self> {
+		let Self::Object(ref members) = *self else {
+			return None;
+		};
+
+		members
+			.iter()
+			.find(|(other, _)| other == key)
+			.map(|(_, value)| value)
+	}
+
+	/// Retrieves the string of a string node.
+	#[inline]
+	#[must_use]
+	pub(crate) fn as_str(&self) -> Option<&str> {
+		match *self {
+			Self::String(ref s) => Some(s),
+
+			_ => None,
+		}
+	}
+}
+
+impl<'de> Deserialize<'de> for Node {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserializer.deserialize_any(NodeVisitor)
+	}
+}
+
+impl Serialize for Node {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		match *self {
+			Self::Null => serializer.serialize_unit(),
+
+			Self::Bool(value) => serializer.serialize_bool(value),
+
+			Self::Number(value) => serializer.serialize_f64(value),
+
+			Self::String(ref value) => serializer.serialize_str(value),
+
+			Self::Array(ref values) => {
+				let mut seq = serializer.serialize_seq(Some(values.len()))?;
+
+				for value in values {
+					seq.serialize_element(value)?;
+				}
+
+				seq.end()
+			}
+
+			Self::Object(ref members) => {
+				let mut map = serializer.serialize_map(Some(members.len()))?;
+
+				for (key, value) in members {
+					map.serialize_entry(key.as_str(), value)?;
+				}
+
+				map.end()
+			}
+		}
+	}
+}
+
+/// The visitor of [`Node`].
+struct NodeVisitor;
+
+impl<'de> Visitor<'de> for NodeVisitor {
+	type Value = Node;
+
+	fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "a json value")
+	}
+
+	fn visit_unit<E>(self) -> Result<Self::Value, E> {
+		Ok(Node::Null)
+	}
+
+	fn visit_none<E>(self) -> Result<Self::Value, E> {
+		Ok(Node::Null)
+	}
+
+	fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+		Node::deserialize(deserializer)
+	}
+
+	fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E> {
+		Ok(Node::Bool(v))
+	}
+
+	fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E> {
+		Ok(Node::Number(v as f64))
+	}
+
+	fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E> {
+		Ok(Node::Number(v as f64))
+	}
+
+	fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E> {
+		Ok(Node::Number(v))
+	}
+
+	fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
+		Ok(Node::String(String::from(v)))
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		let mut values = Vec::new();
+
+		while let Some(value) = seq.next_element()? {
+			values.push(value);
+		}
+
+		Ok(Node::Array(values))
+	}
+
+	fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+		let mut members = Vec::new();
+
+		while let Some((key, value)) = map.next_entry::<KeyNode, Node>()? {
+			members.push((key.0, value));
+		}
+
+		Ok(Node::Object(members))
+	}
+}
+
+/// A string key of an object.
+///
+/// This is needed as `serde` only implements [`Deserialize`] for [`String`] with its `alloc` feature.
+struct KeyNode(String);
+
+impl<'de> Deserialize<'de> for KeyNode {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		match Node::deserialize(deserializer)? {
+			Node::String(key) => Ok(Self(key)),
+
+			_ => Err(serde::de::Error::custom("expected string key")),
+		}
+	}
+}
@@ -0,0 +1,153 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::error::DesignTokenError;
+use crate::www::{CssColourSpace, DesignToken, DesignTokenColour, DesignTokenValue, DesignTokens, Html};
+use crate::www::design_tokens::node::Node;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use serde::ser::{Error, Serialize, Serializer};
+
+impl DesignTokens {
+	/// Constructs the token file of the tree.
+	pub(crate) fn to_node(&self) -> Result<Node, DesignTokenError> {
+		let mut root = Vec::new();
+
+		for (path, token) in &self.tokens {
+			let segments: Vec<&str> = path.split('.').collect();
+
+			insert_token(&mut root, &segments, token.to_node())
+				.ok_or_else(|| DesignTokenError::InvalidGroup { path: path.clone() })?;
+		}
+
+		Ok(Node::Object(root))
+	}
+}
+
+impl Serialize for DesignTokens {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		self.to_node().map_err(S::Error::custom)?.serialize(serializer)
+	}
+}
+
+impl DesignToken {
+	/// Constructs the object of the token.
+	#[must_use]
+	fn to_node(&self) -> Node {
+		let value = match *self.value() {
+			DesignTokenValue::Colour(colour) => colour.to_node(),
+
+			DesignTokenValue::Alias(ref target) => Node::String(format!("{{{target}}}")),
+		};
+
+		let mut members = vec![
+			(String::from("$type"),  Node::String(String::from("color"))),
+			(String::from("$value"), value),
+		];
+
+		if let Some(description) = self.description() {
+			members.push((String::from("$description"), Node::String(String::from(description))));
+		}
+
+		Node::Object(members)
+	}
+}
+
+impl DesignTokenColour {
+	/// Constructs the value of the colour.
+	#[must_use]
+	pub(crate) fn to_node(self) -> Node {
+		let hex = |colour: Html| Node::String(format!("{}", colour.display().lowercase(true).omit_opaque_alpha(true)));
+
+		let component = |value: f64| {
+			if value.is_nan() {
+				Node::String(String::from("none"))
+			} else {
+				Node::Number(value)
+			}
+		};
+
+		let (colour, fallback) = match self {
+			Self::Hex(colour) => return hex(colour),
+
+			Self::Components { colour, hex } => (colour, hex),
+		};
+
+		let (space, mut channels, alpha) = colour.components();
+
+		if matches!(space, CssColourSpace::Hsl | CssColourSpace::Hwb) {
+			channels[0x1] *= 100.0;
+			channels[0x2] *= 100.0;
+		}
+
+		let mut members = vec![
+			(String::from("colorSpace"), Node::String(String::from(space.name()))),
+			(String::from("components"), Node::Array(channels.into_iter().map(component).collect())),
+		];
+
+		if alpha != 1.0 {
+			members.push((String::from("alpha"), component(alpha)));
+		}
+
+		if let Some(fallback) = fallback {
+			members.push((String::from("hex"), hex(fallback)));
+		}
+
+		Node::Object(members)
+	}
+}
+
+impl Serialize for DesignTokenColour {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		self.to_node().serialize(serializer)
+	}
+}
+
+/// Inserts a token into a group, creating any missing subgroups.
+///
+/// [`None`] is returned if the path is empty or conflicts with another token.
+#[must_use]
+fn insert_token(members: &mut Vec<(String, Node)>, segments: &[&str], token: Node) -> Option<()> {
+	let (&name, rest) = segments.split_first()?;
+
+	if name.is_empty() || name.starts_with('$') || name.contains(['{', '}']) {
+		return None;
+	}
+
+	let index = members.iter().position(|(other, _)| other == name);
+
+	if rest.is_empty() {
+		if index.is_some() {
+			return None;
+		}
+
+		members.push((String::from(name), token));
+		return Some(());
+	}
+
+	let index = index.unwrap_or_else(|| {
+		members.push((String::from(name), Node::Object(Vec::new())));
+		members.len() - 0x1
+	});
+
+	let (_, ref mut group) = members[index];
+
+	// Tokens cannot contain other tokens.
+	if group.get("$value").is_some() {
+		return None;
+	}
+
+	let Node::Object(ref mut members) = *group else {
+		return None;
+	};
+
+	insert_token(members, rest, token)
+}
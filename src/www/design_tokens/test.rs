@@ -0,0 +1,191 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+#![cfg(test)]
+
+use crate::Alpha;
+use crate::error::DesignTokenError;
+use crate::hsv::Hsl;
+use crate::lch::OkLch;
+use crate::www::{CssColour, DesignToken, DesignTokenColour, DesignTokenValue, DesignTokens, Html};
+use crate::www::design_tokens::node::Node;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Constructs an object node.
+#[must_use]
+fn object(members: &[(&str, Node)]) -> Node {
+	let members = members
+		.iter()
+		.map(|(key, value)| (String::from(*key), value.clone()))
+		.collect();
+
+	Node::Object(members)
+}
+
+/// Constructs a string node.
+#[must_use]
+fn string(s: &str) -> Node {
+	Node::String(String::from(s))
+}
+
+/// Constructs an array node of numbers.
+#[must_use]
+fn numbers(values: &[f64]) -> Node {
+	Node::Array(values.iter().copied().map(Node::Number).collect())
+}
+
+#[test]
+fn test_design_tokens_from_node() {
+	let node = object(&[
+		("brand", object(&[
+			("$type", string("color")),
+
+			("primary", object(&[
+				("$value", object(&[
+					("colorSpace", string("oklch")),
+					("components", numbers(&[0.5, 0.2, 300.0])),
+					("alpha",      Node::Number(0.5)),
+					("hex",        string("#7a34ba")),
+				])),
+				("$description", string("Primary brand colour")),
+			])),
+
+			("muted", object(&[
+				("$value", object(&[
+					("colorSpace", string("hsl")),
+					("components", Node::Array(Vec::from([Node::Number(270.0), string("none"), Node::Number(40.0)]))),
+				])),
+			])),
+
+			("legacy", object(&[("$value", string("#663399"))])),
+			("accent", object(&[("$value", string("{brand.primary}"))])),
+		])),
+
+		("spacing", object(&[
+			("small", object(&[("$type", string("dimension")), ("$value", string("4px"))])),
+		])),
+
+		("link",      object(&[("$value", string("{brand.accent}"))])),
+		("dangling",  object(&[("$value", string("{spacing.small}"))])),
+	]);
+
+	let tokens = DesignTokens::from_node(&node).unwrap();
+
+	let paths: Vec<&str> = tokens.iter().map(|(path, _)| path).collect();
+	assert_eq!(paths, ["brand.accent", "brand.legacy", "brand.muted", "brand.primary", "link"]);
+
+	let primary = tokens.get("brand.primary").unwrap();
+
+	assert_eq!(primary.description(), Some("Primary brand colour"));
+
+	assert_eq!(
+		*primary.value(),
+		DesignTokenValue::Colour(DesignTokenColour::Components {
+			colour: CssColour::OkLch(Alpha::attach(OkLch::new(0.5, 0.2, 300.0), 0.5)),
+			hex:    Some(Html::new(0x7A, 0x34, 0xBA, 0xFF)),
+		}),
+	);
+
+	let Some(DesignTokenValue::Colour(DesignTokenColour::Components { colour: CssColour::Hsl(muted), hex: None })) = tokens.get("brand.muted").map(DesignToken::value) else {
+		panic!("muted colour is not hsl");
+	};
+
+	let (muted, alpha) = muted.detach();
+	let (hue, saturation, lightness) = muted.get();
+
+	assert_eq!((hue, lightness, alpha), (270.0, 0.4, 1.0));
+	assert!(saturation.is_nan());
+
+	assert_eq!(
+		*tokens.get("brand.legacy").unwrap().value(),
+		DesignTokenValue::Colour(DesignTokenColour::Hex(Html::REBECCA_PURPLE)),
+	);
+
+	let DesignTokenValue::Colour(primary) = *primary.value() else {
+		panic!("primary colour is an alias");
+	};
+
+	assert_eq!(tokens.resolve("link"), Ok(primary));
+
+	let resolved = tokens.resolve_all().unwrap();
+
+	assert!(resolved.iter().all(|(_, token)| matches!(*token.value(), DesignTokenValue::Colour(_))));
+}
+
+#[test]
+fn test_design_tokens_round_trip() {
+	let mut tokens = DesignTokens::new();
+
+	let mut token = DesignToken::new(DesignTokenValue::Colour(DesignTokenColour::Hex(Html::new(0x12, 0x34, 0x56, 0x78))));
+	token.set_description(Some(String::from("Translucent")));
+
+	let _ = tokens.insert(String::from("base.translucent"), token);
+
+	let colour = CssColour::Hsl(Alpha::attach(Hsl::new(120.0, 0.25, f64::NAN), 1.0));
+
+	let _ = tokens.insert(String::from("base.hsl"), DesignToken::new(DesignTokenValue::Colour(colour.into())));
+	let _ = tokens.insert(String::from("alias"),    DesignToken::new(DesignTokenValue::Alias(String::from("base.hsl"))));
+
+	let node = tokens.to_node().unwrap();
+
+	assert_eq!(
+		node.get("base").and_then(|node| node.get("hsl")).and_then(|node| node.get("$value")),
+		Some(&object(&[
+			("colorSpace", string("hsl")),
+			("components", Node::Array(Vec::from([Node::Number(120.0), Node::Number(25.0), string("none")]))),
+		])),
+	);
+
+	assert_eq!(
+		node.get("base").and_then(|node| node.get("translucent")).and_then(|node| node.get("$value")),
+		Some(&string("#12345678")),
+	);
+
+	let read = DesignTokens::from_node(&node).unwrap();
+
+	assert_eq!(read.len(), 0x3);
+	assert_eq!(read.get("alias"), tokens.get("alias"));
+	assert_eq!(read.get("base.translucent"), tokens.get("base.translucent"));
+
+	let _ = tokens.insert(String::from("alias.nested"), DesignToken::new(DesignTokenValue::Alias(String::from("base.hsl"))));
+
+	assert_eq!(tokens.to_node(), Err(DesignTokenError::InvalidGroup { path: String::from("alias.nested") }));
+}
+
+#[test]
+fn test_design_tokens_errors() {
+	let node = object(&[
+		("a", object(&[("$type", string("color")), ("$value", string("{b}"))])),
+		("b", object(&[("$type", string("color")), ("$value", string("{a}"))])),
+		("c", object(&[("$type", string("color")), ("$value", string("{d}"))])),
+	]);
+
+	let tokens = DesignTokens::from_node(&node).unwrap();
+
+	assert_eq!(tokens.resolve("a"), Err(DesignTokenError::CircularReference { path: String::from("a") }));
+	assert_eq!(tokens.resolve("c"), Err(DesignTokenError::UnknownReference { path: String::from("c"), reference: String::from("d") }));
+	assert_eq!(tokens.resolve("e"), Err(DesignTokenError::UnknownReference { path: String::from("e"), reference: String::from("e") }));
+
+	let invalid = |value: Node| {
+		let node = object(&[("x", object(&[("$type", string("color")), ("$value", value)]))]);
+		DesignTokens::from_node(&node)
+	};
+
+	let error = Err(DesignTokenError::InvalidColour { path: String::from("x") });
+
+	assert_eq!(invalid(string("rebeccapurple")), error);
+	assert_eq!(invalid(object(&[("colorSpace", string("cmyk")), ("components", numbers(&[0.0; 0x3]))])), error);
+	assert_eq!(invalid(object(&[("colorSpace", string("srgb")), ("components", numbers(&[0.0; 0x4]))])), error);
+	assert_eq!(invalid(object(&[("colorSpace", string("srgb")), ("components", numbers(&[0.0; 0x3])), ("hex", string("663399"))])), error);
+
+	let node = object(&[("a.b", object(&[("$type", string("color")), ("$value", string("#000"))]))]);
+
+	assert_eq!(DesignTokens::from_node(&node), Err(DesignTokenError::InvalidGroup { path: String::from("a.b") }));
+}
@@ -0,0 +1,83 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::Alpha;
+use crate::rgb::SRgb;
+use crate::www::{Html, WcagLevel, WcagTarget};
+
+impl Html {
+	/// Composites the HTML colour over an opaque background.
+	///
+	/// The alpha channel of `background` is ignored.
+	#[must_use]
+	const fn composite_over_opaque(self, background: Self) -> SRgb<f64> {
+		let (red, green, blue, alpha) = self.get();
+		let (background_red, background_green, background_blue, _) = background.get();
+
+		let colour = SRgb::new(red as f64 / 255.0, green as f64 / 255.0, blue as f64 / 255.0);
+		let colour = Alpha::attach(colour, alpha as f64 / 255.0);
+
+		let background = SRgb::new(background_red as f64 / 255.0, background_green as f64 / 255.0, background_blue as f64 / 255.0);
+		let background = Alpha::attach(background, 1.0);
+
+		let (colour, _) = colour.composite_over(background).detach();
+		colour
+	}
+
+	/// Computes the relative luminance of the HTML colour.
+	///
+	/// The alpha channel is ignored.
+	/// See [`SRgb::relative_luminance`] for more information.
+	#[inline]
+	#[must_use]
+	pub fn relative_luminance(self) -> f64 {
+		let (red, green, blue, _) = self.get();
+
+		let colour = SRgb::new(red, green, blue).map(|channel| f64::from(channel) / 255.0);
+		colour.relative_luminance()
+	}
+
+	/// Computes the WCAG 2.x contrast ratio of the HTML colour against a background.
+	///
+	/// The colour is first composited over the background, which itself is taken to be opaque (i.e. its alpha channel is ignored).
+	/// See [`SRgb::contrast_ratio`] for more information.
+	#[inline]
+	#[must_use]
+	pub fn contrast_ratio(self, background: Self) -> f64 {
+		let colour = self.composite_over_opaque(background);
+
+		let (red, green, blue, _) = background.get();
+		let background = SRgb::new(red, green, blue).map(|channel| f64::from(channel) / 255.0);
+
+		colour.contrast_ratio(background)
+	}
+
+	/// Checks whether the HTML colour has sufficient contrast against a background.
+	///
+	/// The contrast ratio is computed as by [`contrast_ratio`](Self::contrast_ratio).
+	#[inline]
+	#[must_use]
+	pub fn meets_wcag(self, background: Self, level: WcagLevel, target: WcagTarget) -> bool {
+		self.contrast_ratio(background) >= level.minimum_ratio(target)
+	}
+
+	/// Computes the APCA lightness contrast of text in the HTML colour on a background.
+	///
+	/// The colour is first composited over the background, which itself is taken to be opaque (i.e. its alpha channel is ignored).
+	/// See [`SRgb::apca_contrast`] for more information.
+	#[inline]
+	#[must_use]
+	pub fn apca_contrast(self, background: Self) -> f64 {
+		let colour = self.composite_over_opaque(background);
+
+		let (red, green, blue, _) = background.get();
+		let background = SRgb::new(red, green, blue).map(|channel| f64::from(channel) / 255.0);
+
+		colour.apca_contrast(background)
+	}
+}
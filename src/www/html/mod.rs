@@ -6,21 +6,21 @@
 // can obtain one at:
 // <https://mozilla.org/MPL/2.0/>.
 
+#[cfg(feature = "maths")]
+mod contrast;
 mod from_str;
 mod named;
 mod test;
 
 use crate::{Alpha, BalancedColour, Colour, DefinedGamut};
 use crate::rgb::SRgb;
+use crate::www::HtmlDisplay;
 
 use core::fmt::{self, Debug, Display, Formatter};
 
 #[cfg(feature = "bytemuck")]
 use bytemuck::{Pod, Zeroable};
 
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
-
 #[cfg(feature = "zerocopy")]
 use zerocopy::{FromZeros, Immutable, IntoBytes};
 
@@ -32,7 +32,6 @@ use zerocopy::{FromZeros, Immutable, IntoBytes};
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
-#[cfg_attr(feature = "serde",    derive(Deserialize, Serialize))]
 #[cfg_attr(feature = "zerocopy", derive(FromZeros, Immutable, IntoBytes))]
 pub struct Html(Alpha<SRgb<u8>>);
 
@@ -92,6 +91,14 @@ impl Html {
 		(red, green, blue, alpha)
 	}
 
+	/// Constructs a configurable formatter of the HTML colour.
+	///
+	/// See [`HtmlDisplay`] for more information.
+	#[inline(always)]
+	pub const fn display(self) -> HtmlDisplay {
+		HtmlDisplay::new(self)
+	}
+
 	/// Converts an HTML colour to [`u32`].
 	///
 	/// This function is the inverse of [`from_u32`](Self::from_u32) (see there for more information).
@@ -120,11 +127,36 @@ unsafe impl BalancedColour for Html {
 
 impl Colour for Html { }
 
-impl DefinedGamut for Html { }
+impl DefinedGamut for Html {
+	type Scalar = u8;
+
+	#[inline(always)]
+	fn is_in_gamut(&self, _epsilon: Self::Scalar) -> bool {
+		true
+	}
+
+	#[inline(always)]
+	fn clip(self) -> Self {
+		self
+	}
+
+	#[inline(always)]
+	fn gamut_distance(&self) -> Self::Scalar {
+		0x0
+	}
+}
 
 impl Display for Html {
+	/// Formats the HTML colour as an eight-digit hexadecimal code, e.g. `#FF0000FF`.
+	///
+	/// With the alternate flag (`{:#}`), the shortest code is used instead, i.e. the alpha channel of opaque colours is omitted and the three- and four-digit forms are used when possible (e.g. `#F00`).
+	/// See [`display`](Self::display) for further options.
 	#[inline]
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		if f.alternate() {
+			return Display::fmt(&self.display().shorten(true).omit_opaque_alpha(true), f);
+		}
+
 		let value = self.to_u32();
 
 		write!(f, "#{value:08X}")
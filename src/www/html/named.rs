@@ -6,6 +6,8 @@
 // can obtain one at:
 // <https://mozilla.org/MPL/2.0/>.
 
+use crate::www::{Html, NamedColours};
+
 macro_rules! def_named_colour {
 	{
 		$($rust_name:ident: $html_name:ident = $value:expr;)*
@@ -22,6 +24,14 @@ macro_rules! def_named_colour {
 				)]
 				pub const $rust_name: Self = $value;
 			)*
+
+			/// The HTML named colours together with their names.
+			///
+			/// The table is sorted by name, so it may be searched using [`binary_search_by`](slice::binary_search_by).
+			/// Colours with multiple names (e.g. `gray` and `grey`) occur once per name.
+			pub const NAMED_COLOURS: &'static [(&'static str, Self)] = &[
+				$((::core::stringify!($html_name), Self::$rust_name),)*
+			];
 		}
 	};
 }
@@ -36,16 +46,16 @@ def_named_colour! {
 	BISQUE:                 bisque               = Self::from_u32(0xFFE4C4FF);
 	BLACK:                  black                = Self::from_u32(0x000000FF);
 	BLANCHED_ALMOND:        blanchedalmond       = Self::from_u32(0xFFEBCDFF);
-	BLUE_VIOLET:            blue                 = Self::from_u32(0x8A2BE2FF);
-	BLUE:                   blueviolet           = Self::from_u32(0x0000FFFF);
+	BLUE:                   blue                 = Self::from_u32(0x0000FFFF);
+	BLUE_VIOLET:            blueviolet           = Self::from_u32(0x8A2BE2FF);
 	BROWN:                  brown                = Self::from_u32(0xA52A2AFF);
 	BURLYWOOD:              burlywood            = Self::from_u32(0xDEB887FF);
 	CADET_BLUE:             cadetblue            = Self::from_u32(0x5F9EA0FF);
 	CHARTREUSE:             chartreuse           = Self::from_u32(0x7FFF00FF);
 	CHOCOLATE:              chocolate            = Self::from_u32(0xD2691EFF);
 	CORAL:                  coral                = Self::from_u32(0xFF7F50FF);
-	CORN_SILK:              cornflowerblue       = Self::from_u32(0xFFF8DCFF);
-	CORNFLOWER_BLUE:        cornsilk             = Self::from_u32(0x6495EDFF);
+	CORNFLOWER_BLUE:        cornflowerblue       = Self::from_u32(0x6495EDFF);
+	CORN_SILK:              cornsilk             = Self::from_u32(0xFFF8DCFF);
 	CRIMSON:                crimson              = Self::from_u32(0xDC143CFF);
 	CYAN:                   cyan                 = Self::AQUA;
 	DARK_BLUE:              darkblue             = Self::from_u32(0x00008BFF);
@@ -53,7 +63,7 @@ def_named_colour! {
 	DARK_GOLDENROD:         darkgoldenrod        = Self::from_u32(0xB8860BFF);
 	DARK_GRAY:              darkgray             = Self::from_u32(0xA9A9A9FF);
 	DARK_GREEN:             darkgreen            = Self::from_u32(0x006400FF);
-	DARK_GREY:              darkgrey             = Self::GREY;
+	DARK_GREY:              darkgrey             = Self::DARK_GRAY;
 	DARK_KHAKI:             darkkhaki            = Self::from_u32(0xBDB76BFF);
 	DARK_MAGENTA:           darkmagenta          = Self::from_u32(0x8B008BFF);
 	DARK_OLIVEGREEN:        darkolivegreen       = Self::from_u32(0x556B2FFF);
@@ -177,3 +187,38 @@ def_named_colour! {
 	YELLOW:                 yellow               = Self::from_u32(0xFFFF00FF);
 	YELLOW_GREEN:           yellowgreen          = Self::from_u32(0x9ACD32FF);
 }
+
+impl Html {
+	/// Looks up the name of the HTML colour.
+	///
+	/// This is the inverse of parsing a named colour, and [`None`] is returned if the colour is not a [named colour](Self::NAMED_COLOURS).
+	/// If multiple names exist for the same colour (e.g. `gray` and `grey`), the alphabetically first name is returned.
+	///
+	/// See [`NamedColours`] for other dictionaries.
+	#[inline]
+	#[must_use]
+	pub fn name(self) -> Option<&'static str> {
+		NamedColours::CSS.name(self)
+	}
+
+	/// Searches for the named colour that is perceptually closest to the HTML colour.
+	///
+	/// The distance is measured as the [Oklab difference](crate::lab::OkLab::delta_e_ok).
+	/// The alpha channel is ignored, and `transparent` is never returned.
+	///
+	/// See [`NamedColours`] for other dictionaries.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use polywave::www::Html;
+	///
+	/// assert_eq!(Html::from_u32(0xFE0102FF).nearest_named_colour(), ("red", Html::RED));
+	/// ```
+	#[cfg(feature = "maths")]
+	#[inline]
+	#[must_use]
+	pub fn nearest_named_colour(self) -> (&'static str, Self) {
+		NamedColours::CSS.nearest(self).unwrap_or(("black", Self::BLACK))
+	}
+}
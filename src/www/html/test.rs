@@ -8,7 +8,10 @@
 
 #![cfg(test)]
 
+use crate::error::HtmlFromStrError;
 use crate::www::Html;
+#[cfg(feature = "maths")]
+use crate::www::{WcagLevel, WcagTarget};
 
 use alloc::format;
 use core::str::FromStr;
@@ -33,3 +36,139 @@ fn test_html_from_str() {
 fn test_html_from_u32() {
 	assert_eq!(Html::from_u32(0x80808080), Html::new(0x80, 0x80, 0x80, 0x80));
 }
+
+#[cfg(feature = "maths")]
+#[test]
+fn test_html_contrast_ratio() {
+	assert!((Html::BLACK.contrast_ratio(Html::WHITE) - 21.0).abs() < 1e-9);
+	assert!((Html::WHITE.contrast_ratio(Html::BLACK) - 21.0).abs() < 1e-9);
+
+	let grey = Html::from_u32(0x777777FF);
+	assert!((grey.contrast_ratio(Html::WHITE) - 4.478).abs() < 1e-3);
+
+	assert!(!grey.meets_wcag(Html::WHITE, WcagLevel::Aa, WcagTarget::NormalText));
+	assert!(grey.meets_wcag(Html::WHITE, WcagLevel::Aa, WcagTarget::LargeText));
+	assert!(grey.meets_wcag(Html::WHITE, WcagLevel::Aaa, WcagTarget::NonText));
+
+	// Half-transparent black over white is composited
+	// to mid-grey first.
+	let colour = Html::from_u32(0x00000080);
+	let expected = Html::from_u32(0x7F7F7FFF).contrast_ratio(Html::WHITE);
+	assert!((colour.contrast_ratio(Html::WHITE) - expected).abs() < 0.01);
+}
+
+#[cfg(feature = "maths")]
+#[test]
+fn test_html_apca_contrast() {
+	let cases = [
+		(0x888888FF, 0xFFFFFFFF,  63.056_469_930_209_424),
+		(0xFFFFFFFF, 0x888888FF, -68.541_464_366_449_62),
+		(0x000000FF, 0xAAAAAAFF,  58.146_262_578_561_334),
+		(0xAAAAAAFF, 0x000000FF, -56.241_133_368_397_42),
+		(0x112233FF, 0xDDEEFFFF,  91.668_308_114_816_31),
+		(0xDDEEFFFF, 0x112233FF, -93.067_700_494_842_75),
+	];
+
+	for (text, background, expected) in cases {
+		let contrast = Html::from_u32(text).apca_contrast(Html::from_u32(background));
+		assert!((contrast - expected).abs() < 1e-6, "{contrast} != {expected}");
+	}
+}
+
+#[test]
+fn test_html_display_options() {
+	let red = Html::from_u32(0xFF0000FF);
+
+	assert_eq!(format!("{red:#}"), "#F00");
+	assert_eq!(format!("{:#}", Html::from_u32(0xFF0000AA)), "#F00A");
+	assert_eq!(format!("{:#}", Html::from_u32(0xFF0001FF)), "#FF0001");
+	assert_eq!(format!("{:#}", Html::from_u32(0xFF000080)), "#FF000080");
+
+	assert_eq!(format!("{}", red.display()), "#FF0000FF");
+	assert_eq!(format!("{}", red.display().lowercase(true)), "#ff0000ff");
+	assert_eq!(format!("{}", red.display().omit_opaque_alpha(true)), "#FF0000");
+	assert_eq!(format!("{}", red.display().shorten(true)), "#F00F");
+
+	assert_eq!(format!("{}", red.display().named(true)), "red");
+	assert_eq!(format!("{}", Html::CYAN.display().named(true)), "aqua");
+	assert_eq!(format!("{}", Html::TRANSPARENT.display().named(true)), "transparent");
+	assert_eq!(format!("{}", Html::from_u32(0xFF000080).display().named(true)), "#FF000080");
+}
+
+#[test]
+fn test_html_named_colours() {
+	assert!(Html::NAMED_COLOURS.is_sorted_by_key(|&(name, _)| name));
+
+	for &(name, colour) in Html::NAMED_COLOURS {
+		assert_eq!(Html::from_str(name), Ok(colour));
+	}
+
+	assert_eq!(Html::from_str("blue"), Ok(Html::from_u32(0x0000FFFF)));
+	assert_eq!(Html::from_str("blueviolet"), Ok(Html::from_u32(0x8A2BE2FF)));
+	assert_eq!(Html::from_str("cornflowerblue"), Ok(Html::from_u32(0x6495EDFF)));
+	assert_eq!(Html::from_str("cornsilk"), Ok(Html::from_u32(0xFFF8DCFF)));
+	assert_eq!(Html::DARK_GREY, Html::DARK_GRAY);
+
+	assert_eq!(Html::BLUE.name(), Some("blue"));
+	assert_eq!(Html::GREY.name(), Some("gray"));
+	assert_eq!(Html::MAGENTA.name(), Some("fuchsia"));
+	assert_eq!(Html::from_u32(0x0000FF80).name(), None);
+}
+
+#[cfg(feature = "maths")]
+#[test]
+fn test_html_nearest_named_colour() {
+	assert_eq!(Html::REBECCA_PURPLE.nearest_named_colour(), ("rebeccapurple", Html::REBECCA_PURPLE));
+	assert_eq!(Html::from_u32(0x0101FE80).nearest_named_colour(), ("blue", Html::BLUE));
+	assert_eq!(Html::from_u32(0x7F7F7FFF).nearest_named_colour(), ("gray", Html::GRAY));
+}
+
+#[test]
+fn test_html_from_str_lenient() {
+	assert_eq!(Html::from_str("  RebeccaPurple\t"), Ok(Html::REBECCA_PURPLE));
+	assert_eq!(Html::from_str("BLUE"), Ok(Html::BLUE));
+	assert_eq!(Html::from_str(" #fF0 "), Ok(Html::from_u32(0xFFFF00FF)));
+}
+
+#[test]
+fn test_html_from_str_error() {
+	assert_eq!(Html::from_str(" \n"), Err(HtmlFromStrError::Empty));
+
+	assert_eq!(Html::from_str("FF0000"), Err(HtmlFromStrError::MissingHash { offset: 0x0 }));
+	assert_eq!(Html::from_str(" $F00"),  Err(HtmlFromStrError::MissingHash { offset: 0x1 }));
+
+	assert_eq!(
+		Html::from_str("#FF00G0"),
+		Err(HtmlFromStrError::InvalidCharacter { offset: 0x5, character: 'G' }),
+	);
+
+	assert_eq!(
+		Html::from_str("  #FF000"),
+		Err(HtmlFromStrError::InvalidLength { span: 0x3..0x8, count: 0x5 }),
+	);
+
+	assert_eq!(
+		Html::from_str("light-blue"),
+		Err(HtmlFromStrError::InvalidCharacter { offset: 0x5, character: '-' }),
+	);
+
+	assert_eq!(
+		Html::from_str("Cornflowerblu"),
+		Err(HtmlFromStrError::UnknownName { span: 0x0..0xD, suggestion: Some("cornflowerblue") }),
+	);
+
+	assert_eq!(
+		Html::from_str("gery"),
+		Err(HtmlFromStrError::UnknownName { span: 0x0..0x4, suggestion: Some("grey") }),
+	);
+
+	assert_eq!(
+		Html::from_str("qwertyuiop"),
+		Err(HtmlFromStrError::UnknownName { span: 0x0..0xA, suggestion: None }),
+	);
+
+	let error = Html::from_str("#12345").unwrap_err();
+
+	assert_eq!(error.span(), 0x1..0x6);
+	assert_eq!(format!("{error}"), "html hexadecimal colour at offset 1 has 5 digit(s), expected 3, 4, 6, or 8");
+}
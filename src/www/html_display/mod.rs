@@ -0,0 +1,125 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::www::Html;
+
+use core::fmt::{self, Display, Formatter};
+
+/// A configurable formatter of HTML colours.
+///
+/// This type is constructed by [`Html::display`] and implements [`Display`].
+/// By default, it formats colours identically to the [`Display`] implementation of [`Html`], i.e. as eight uppercase hexadecimal digits.
+///
+/// # Examples
+///
+/// ```
+/// use polywave::www::Html;
+///
+/// let colour = Html::from_u32(0xFF0000FF);
+///
+/// assert_eq!(colour.display().to_string(), "#FF0000FF");
+///
+/// assert_eq!(
+///     colour.display().shorten(true).omit_opaque_alpha(true).lowercase(true).to_string(),
+///     "#f00",
+/// );
+///
+/// assert_eq!(colour.display().named(true).to_string(), "red");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[must_use]
+pub struct HtmlDisplay {
+	colour: Html,
+
+	shorten:           bool,
+	lowercase:         bool,
+	omit_opaque_alpha: bool,
+	named:             bool,
+}
+
+impl HtmlDisplay {
+	/// Constructs a new formatter with the default options.
+	#[inline(always)]
+	pub const fn new(colour: Html) -> Self {
+		Self {
+			colour,
+
+			shorten:           false,
+			lowercase:         false,
+			omit_opaque_alpha: false,
+			named:             false,
+		}
+	}
+
+	/// Sets whether to use the three- and four-digit forms when possible.
+	///
+	/// These may be used if each channel has two identical digits, e.g. `#FF0000` may be shortened to `#F00`.
+	#[inline(always)]
+	pub const fn shorten(mut self, value: bool) -> Self {
+		self.shorten = value;
+		self
+	}
+
+	/// Sets whether to use lowercase hexadecimal digits.
+	#[inline(always)]
+	pub const fn lowercase(mut self, value: bool) -> Self {
+		self.lowercase = value;
+		self
+	}
+
+	/// Sets whether to omit the alpha channel of opaque colours.
+	#[inline(always)]
+	pub const fn omit_opaque_alpha(mut self, value: bool) -> Self {
+		self.omit_opaque_alpha = value;
+		self
+	}
+
+	/// Sets whether to use the names of [named colours](Html#implementations) instead of hexadecimal codes.
+	///
+	/// See [`Html::name`] for more information.
+	#[inline(always)]
+	pub const fn named(mut self, value: bool) -> Self {
+		self.named = value;
+		self
+	}
+}
+
+impl Display for HtmlDisplay {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		if self.named {
+			if let Some(name) = self.colour.name() {
+				return write!(f, "{name}");
+			}
+		}
+
+		let (red, green, blue, alpha) = self.colour.get();
+
+		let channels = [red, green, blue, alpha];
+
+		let channels = if self.omit_opaque_alpha && alpha == 0xFF {
+			&channels[..0x3]
+		} else {
+			&channels[..]
+		};
+
+		let shorten = self.shorten && channels.iter().all(|&channel| channel % 0x11 == 0x0);
+
+		write!(f, "#")?;
+
+		for &channel in channels {
+			match (shorten, self.lowercase) {
+				(false, false) => write!(f, "{channel:02X}")?,
+				(false, true)  => write!(f, "{channel:02x}")?,
+				(true,  false) => write!(f, "{:X}", channel / 0x11)?,
+				(true,  true)  => write!(f, "{:x}", channel / 0x11)?,
+			}
+		}
+
+		Ok(())
+	}
+}
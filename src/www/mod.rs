@@ -8,6 +8,41 @@
 
 //! WWW-related colour facilities.
 
+mod css_colour;
+mod css_colour_scheme;
+mod css_colour_space;
+#[cfg(feature = "alloc")]
+mod css_gradient;
+mod css_tokens;
+#[cfg(all(feature = "alloc", feature = "serde"))]
+mod design_token;
+#[cfg(all(feature = "alloc", feature = "serde"))]
+mod design_token_colour;
+#[cfg(all(feature = "alloc", feature = "serde"))]
+mod design_token_value;
+#[cfg(all(feature = "alloc", feature = "serde"))]
+mod design_tokens;
 mod html;
+mod html_display;
+mod named_colours;
+mod wcag_level;
+mod wcag_target;
 
+pub use css_colour::CssColour;
+pub use css_colour_scheme::CssColourScheme;
+pub use css_colour_space::CssColourSpace;
+#[cfg(feature = "alloc")]
+pub use css_gradient::CssGradient;
+#[cfg(all(feature = "alloc", feature = "serde"))]
+pub use design_token::DesignToken;
+#[cfg(all(feature = "alloc", feature = "serde"))]
+pub use design_token_colour::DesignTokenColour;
+#[cfg(all(feature = "alloc", feature = "serde"))]
+pub use design_token_value::DesignTokenValue;
+#[cfg(all(feature = "alloc", feature = "serde"))]
+pub use design_tokens::DesignTokens;
 pub use html::Html;
+pub use html_display::HtmlDisplay;
+pub use named_colours::NamedColours;
+pub use wcag_level::WcagLevel;
+pub use wcag_target::WcagTarget;
@@ -0,0 +1,267 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+mod test;
+
+#[cfg(feature = "ral-names")]
+mod ral;
+#[cfg(feature = "x11-names")]
+mod x11;
+#[cfg(feature = "xkcd-names")]
+mod xkcd;
+
+#[cfg(feature = "maths")]
+use crate::lab::OkLab;
+use crate::www::Html;
+
+use core::cmp::Ordering;
+
+/// The length of the longest name that is considered for suggestions.
+const MAXIMUM_SUGGESTION_LENGTH: usize = 0x20;
+
+/// A dictionary of named colours.
+///
+/// Each dictionary is a table of names and [HTML colours](Html), sorted by name with whitespace ignored.
+/// Names are stored in lowercase, and they are looked up case-insensitively and with any ASCII whitespace ignored.
+/// Most dictionaries store names without whitespace, but the xkcd dictionary stores them verbatim as some of its names only differ by spaces.
+///
+/// The [CSS](Self::CSS) dictionary is always available, whereas other dictionaries are enabled by their respective features:
+///
+/// | Dictionary          | Feature      |
+/// | :------------------ | :----------- |
+/// | `X11`               | `x11-names`  |
+/// | `XKCD`              | `xkcd-names` |
+/// | `RAL`               | `ral-names`  |
+///
+/// # Examples
+///
+/// ```
+/// use polywave::www::{Html, NamedColours};
+///
+/// assert_eq!(NamedColours::CSS.get("Rebecca Purple"), Some(Html::REBECCA_PURPLE));
+/// assert_eq!(NamedColours::CSS.name(Html::REBECCA_PURPLE), Some("rebeccapurple"));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct NamedColours {
+	entries: &'static [(&'static str, Html)],
+	prefix:  &'static str,
+}
+
+impl NamedColours {
+	/// The named colours of [CSS Color 4](https://www.w3.org/TR/css-color-4/#named-colors).
+	///
+	/// These are the same as [`Html::NAMED_COLOURS`], and they include `transparent`.
+	pub const CSS: Self = Self { entries: Html::NAMED_COLOURS, prefix: "" };
+
+	/// The colours of the X11 colour database (`rgb.txt`).
+	///
+	/// These include the numbered variants, e.g. `gray42` and `seagreen3`.
+	/// Note that some names (e.g. `gray` and `green`) denote different colours than in [CSS](Self::CSS).
+	#[cfg(feature = "x11-names")]
+	pub const X11: Self = Self { entries: &x11::ENTRIES, prefix: "" };
+
+	/// The colours of the [xkcd colour survey](https://xkcd.com/color/rgb/).
+	///
+	/// These are the same as the `xkcd:` colours of Matplotlib, e.g. `xkcd:cloudy blue`, and names may be given with or without this prefix.
+	/// Some names of the survey only differ by spaces (e.g. `blue green` and `bluegreen`), and these are therefore stored verbatim.
+	#[cfg(feature = "xkcd-names")]
+	pub const XKCD: Self = Self { entries: &xkcd::ENTRIES, prefix: "xkcd:" };
+
+	/// The colours of the RAL Classic collection.
+	///
+	/// Each colour is named both by its code and by its English name, e.g. `RAL 1000` and `Green beige`.
+	/// Note that the collection is defined by physical samples, and these colours are thus only approximations.
+	#[cfg(feature = "ral-names")]
+	pub const RAL: Self = Self { entries: &ral::ENTRIES, prefix: "" };
+
+	/// Retrieves the entries of the dictionary.
+	///
+	/// These are sorted by name with whitespace ignored, and colours with multiple names occur once per name.
+	#[inline(always)]
+	#[must_use]
+	pub const fn entries(self) -> &'static [(&'static str, Html)] {
+		self.entries
+	}
+
+	/// Iterates over the entries of the dictionary.
+	#[inline]
+	pub fn iter(self) -> impl Iterator<Item = (&'static str, Html)> {
+		self.entries.iter().copied()
+	}
+
+	/// Looks up a colour by its name.
+	///
+	/// The name is matched case-insensitively.
+	/// Names that match exactly are preferred, after which ASCII whitespace is ignored.
+	/// If the dictionary has a prefix (e.g. `xkcd:`), it may optionally precede the name.
+	#[must_use]
+	pub fn get(self, name: &str) -> Option<Html> {
+		let name = strip_prefix_ignore_case(name.trim(), self.prefix);
+
+		let start = self.entries
+			.partition_point(|&(other, _)| normalise(other).cmp(normalise(name)) == Ordering::Less);
+
+		let mut candidates = self.entries[start..]
+			.iter()
+			.take_while(|&&(other, _)| normalise(other).eq(normalise(name)));
+
+		let exact = candidates
+			.clone()
+			.find(|&&(other, _)| other.eq_ignore_ascii_case(name));
+
+		let &(_, colour) = exact.or_else(|| candidates.next())?;
+		Some(colour)
+	}
+
+	/// Looks up the name of a colour.
+	///
+	/// [`None`] is returned if the colour is not in the dictionary.
+	/// If multiple names exist for the same colour (e.g. `gray` and `grey`), the first name of the dictionary is returned.
+	#[must_use]
+	pub fn name(self, colour: Html) -> Option<&'static str> {
+		self.iter()
+			.find(|&(_, other)| other == colour)
+			.map(|(name, _)| name)
+	}
+
+	/// Searches for the opaque named colour that is perceptually closest to a colour.
+	///
+	/// The distance is measured as the [Oklab difference](crate::lab::OkLab::delta_e_ok), and the alpha channel of the colour is ignored.
+	/// [`None`] is only returned if the dictionary contains no opaque colours.
+	#[cfg(feature = "maths")]
+	#[must_use]
+	pub fn nearest(self, colour: Html) -> Option<(&'static str, Html)> {
+		let to_ok_lab = |colour: Html| {
+			let (colour, _) = colour.to_s_rgba().detach();
+			OkLab::<f64>::from_s_rgb(colour.map(|channel| f64::from(channel) / 255.0))
+		};
+
+		let target = to_ok_lab(colour);
+
+		self.iter()
+			.filter(|&(_, colour)| colour.get().3 == 0xFF)
+			.map(|(name, colour)| (name, colour, target.delta_e_ok(to_ok_lab(colour))))
+			.min_by(|(_, _, lhs), (_, _, rhs)| lhs.total_cmp(rhs))
+			.map(|(name, colour, _)| (name, colour))
+	}
+
+	/// Suggests the name most similar to an unknown name.
+	///
+	/// Similarity is measured as the case-insensitive edit distance (with transpositions counted as single edits), and only names within a third of their length are suggested.
+	#[must_use]
+	pub fn suggest(self, name: &str) -> Option<&'static str> {
+		let name = strip_prefix_ignore_case(name.trim(), self.prefix);
+
+		let mut best: Option<(&'static str, usize)> = None;
+
+		for (other, _) in self.iter() {
+			if other.len() > MAXIMUM_SUGGESTION_LENGTH {
+				continue;
+			}
+
+			let distance = edit_distance(name, other);
+
+			if distance * 0x3 > other.len() {
+				continue;
+			}
+
+			if best.is_none_or(|(_, best)| distance < best) {
+				best = Some((other, distance));
+			}
+		}
+
+		best.map(|(name, _)| name)
+	}
+}
+
+impl IntoIterator for NamedColours {
+	type Item = (&'static str, Html);
+
+	type IntoIter = core::iter::Copied<core::slice::Iter<'static, (&'static str, Html)>>;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.entries.iter().copied()
+	}
+}
+
+/// Normalises a name by lowercasing it and removing any ASCII whitespace.
+#[inline]
+fn normalise(name: &str) -> impl Iterator<Item = u8> + '_ {
+	name
+		.bytes()
+		.filter(|c| !c.is_ascii_whitespace())
+		.map(|c| c.to_ascii_lowercase())
+}
+
+/// Strips a prefix from a name, ignoring case.
+#[must_use]
+fn strip_prefix_ignore_case<'a>(name: &'a str, prefix: &str) -> &'a str {
+	match name.get(..prefix.len()) {
+		Some(head) if head.eq_ignore_ascii_case(prefix) => &name[prefix.len()..],
+
+		_ => name,
+	}
+}
+
+/// Computes the case-insensitive edit distance between a name and a known name.
+///
+/// This is the optimal string-alignment distance, i.e. the Levenshtein distance with transpositions of adjacent characters counted as single edits.
+/// Whitespace in either name is ignored.
+#[must_use]
+fn edit_distance(name: &str, known: &str) -> usize {
+	debug_assert!(known.len() <= MAXIMUM_SUGGESTION_LENGTH);
+
+	let mut buffer = [0x0u8; MAXIMUM_SUGGESTION_LENGTH];
+	let mut length = 0x0;
+
+	for c in known.bytes().filter(|c| !c.is_ascii_whitespace()) {
+		buffer[length] = c;
+		length += 0x1;
+	}
+
+	let known = &buffer[..length];
+
+	let mut previous = [0x0usize; MAXIMUM_SUGGESTION_LENGTH + 0x1];
+	let mut current  = [0x0usize; MAXIMUM_SUGGESTION_LENGTH + 0x1];
+
+	for (index, distance) in current.iter_mut().enumerate() {
+		*distance = index;
+	}
+
+	let mut last = None;
+
+	let name = name.bytes().filter(|c| !c.is_ascii_whitespace());
+
+	for (index, c) in name.enumerate() {
+		let c = c.to_ascii_lowercase();
+
+		let mut next = [0x0usize; MAXIMUM_SUGGESTION_LENGTH + 0x1];
+		next[0x0] = index + 0x1;
+
+		for (column, &other) in known.iter().enumerate() {
+			let substitution = current[column] + usize::from(c != other);
+			let deletion     = current[column + 0x1] + 0x1;
+			let insertion    = next[column] + 0x1;
+
+			let mut distance = substitution.min(deletion).min(insertion);
+
+			if column > 0x0 && last == Some(other) && known[column - 0x1] == c {
+				distance = distance.min(previous[column - 0x1] + 0x1);
+			}
+
+			next[column + 0x1] = distance;
+		}
+
+		previous = current;
+		current  = next;
+		last     = Some(c);
+	}
+
+	current[known.len()]
+}
@@ -0,0 +1,450 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+//! The RAL Classic colour collection.
+//!
+//! Each colour is given both by its code and by its English name, such that e.g. `RAL 1000` and `Green beige` are given as `ral1000` and `greenbeige`, respectively.
+//! The colours are common sRGB approximations, and as the collection is defined by physical samples, these are not exact.
+
+use crate::www::Html;
+
+/// The entries of the RAL Classic collection, sorted by name.
+pub(super) static ENTRIES: [(&str, Html); 0x1B0] = [
+	("agategrey",            Html::from_u32(0xB0B0A9FF)),
+	("anthracitegrey",       Html::from_u32(0x383E42FF)),
+	("antiquepink",          Html::from_u32(0xCB7375FF)),
+	("azureblue",            Html::from_u32(0x225F78FF)),
+	("basaltgrey",           Html::from_u32(0x575D5EFF)),
+	("beige",                Html::from_u32(0xD0B084FF)),
+	("beigebrown",           Html::from_u32(0x795038FF)),
+	("beigegrey",            Html::from_u32(0x766A5EFF)),
+	("beigered",             Html::from_u32(0xC5856DFF)),
+	("blackblue",            Html::from_u32(0x191E28FF)),
+	("blackbrown",           Html::from_u32(0x1A1719FF)),
+	("blackgreen",           Html::from_u32(0x2F3D3AFF)),
+	("blackgrey",            Html::from_u32(0x2F3234FF)),
+	("blackolive",           Html::from_u32(0x3D3D36FF)),
+	("blackred",             Html::from_u32(0x3E2022FF)),
+	("bluegreen",            Html::from_u32(0x024442FF)),
+	("bluegrey",             Html::from_u32(0x5B686DFF)),
+	("bluelilac",            Html::from_u32(0x76689AFF)),
+	("bottlegreen",          Html::from_u32(0x2C3222FF)),
+	("brightredorange",      Html::from_u32(0xED6B21FF)),
+	("brilliantblue",        Html::from_u32(0x376B8CFF)),
+	("broomyellow",          Html::from_u32(0xE2A300FF)),
+	("brownbeige",           Html::from_u32(0xAF804FFF)),
+	("browngreen",           Html::from_u32(0x37342AFF)),
+	("browngrey",            Html::from_u32(0x575044FF)),
+	("brownred",             Html::from_u32(0x782423FF)),
+	("capriblue",            Html::from_u32(0x005E83FF)),
+	("carminered",           Html::from_u32(0x9B2321FF)),
+	("cementgrey",           Html::from_u32(0x7F8274FF)),
+	("chestnutbrown",        Html::from_u32(0x5E2F26FF)),
+	("chocolatebrown",       Html::from_u32(0x442F29FF)),
+	("chromegreen",          Html::from_u32(0x37422FFF)),
+	("claretviolet",         Html::from_u32(0x651E38FF)),
+	("claybrown",            Html::from_u32(0x7E4B26FF)),
+	("cleanroomwhite",       Html::from_u32(0xF8F2E1FF)),
+	("cobaltblue",           Html::from_u32(0x193153FF)),
+	("concretegrey",         Html::from_u32(0x808076FF)),
+	("copperbrown",          Html::from_u32(0x8D4931FF)),
+	("coralred",             Html::from_u32(0xA63D30FF)),
+	("cream",                Html::from_u32(0xE9E0D2FF)),
+	("curry",                Html::from_u32(0xA77F0EFF)),
+	("daffodilyellow",       Html::from_u32(0xE88C00FF)),
+	("dahliayellow",         Html::from_u32(0xF99A1CFF)),
+	("deeporange",           Html::from_u32(0xE26E0EFF)),
+	("distantblue",          Html::from_u32(0x42698CFF)),
+	("dustygrey",            Html::from_u32(0x7A7B7AFF)),
+	("emeraldgreen",         Html::from_u32(0x366735FF)),
+	("fawnbrown",            Html::from_u32(0x70452AFF)),
+	("ferngreen",            Html::from_u32(0x5E6E3BFF)),
+	("fibrousgreen",         Html::from_u32(0xB3C43EFF)),
+	("firgreen",             Html::from_u32(0x27352AFF)),
+	("flamered",             Html::from_u32(0xA72920FF)),
+	("gentianblue",          Html::from_u32(0x004F7CFF)),
+	("goldenyellow",         Html::from_u32(0xE49E00FF)),
+	("granitegrey",          Html::from_u32(0x374345FF)),
+	("graphiteblack",        Html::from_u32(0x27292BFF)),
+	("graphitegrey",         Html::from_u32(0x45494EFF)),
+	("grassgreen",           Html::from_u32(0x4D6F39FF)),
+	("greenbeige",           Html::from_u32(0xCDBA88FF)),
+	("greenblue",            Html::from_u32(0x0F4C64FF)),
+	("greenbrown",           Html::from_u32(0x89693EFF)),
+	("greengrey",            Html::from_u32(0x5D6058FF)),
+	("greyaluminium",        Html::from_u32(0x878581FF)),
+	("greybeige",            Html::from_u32(0xA48F7AFF)),
+	("greyblue",             Html::from_u32(0x2B3A44FF)),
+	("greybrown",            Html::from_u32(0x3D3635FF)),
+	("greyolive",            Html::from_u32(0x3C392EFF)),
+	("greywhite",            Html::from_u32(0xD7D5CBFF)),
+	("heatherviolet",        Html::from_u32(0xC4618CFF)),
+	("honeyyellow",          Html::from_u32(0xCB8E00FF)),
+	("irongrey",             Html::from_u32(0x52595DFF)),
+	("ivory",                Html::from_u32(0xDDC49AFF)),
+	("jetblack",             Html::from_u32(0x0E0E10FF)),
+	("khakigrey",            Html::from_u32(0x745E3DFF)),
+	("leafgreen",            Html::from_u32(0x325928FF)),
+	("lemonyellow",          Html::from_u32(0xDDAF27FF)),
+	("lightblue",            Html::from_u32(0x0089B6FF)),
+	("lightgreen",           Html::from_u32(0x7EBAB5FF)),
+	("lightgrey",            Html::from_u32(0xC5C7C4FF)),
+	("lightivory",           Html::from_u32(0xE6D2B5FF)),
+	("lightpink",            Html::from_u32(0xD8A0A6FF)),
+	("luminousbrightorange", Html::from_u32(0xFFB200FF)),
+	("luminousbrightred",    Html::from_u32(0xFF2A1CFF)),
+	("luminousgreen",        Html::from_u32(0x00B51AFF)),
+	("luminousorange",       Html::from_u32(0xFF4D06FF)),
+	("luminousred",          Html::from_u32(0xFF2D21FF)),
+	("luminousyellow",       Html::from_u32(0xFFFF00FF)),
+	("mahoganybrown",        Html::from_u32(0x4C2B20FF)),
+	("maizeyellow",          Html::from_u32(0xE29000FF)),
+	("maygreen",             Html::from_u32(0x587F40FF)),
+	("melonyellow",          Html::from_u32(0xFF9B00FF)),
+	("mintgreen",            Html::from_u32(0x006F3DFF)),
+	("mintturquoise",        Html::from_u32(0x46877FFF)),
+	("mossgreen",            Html::from_u32(0x114232FF)),
+	("mossgrey",             Html::from_u32(0x7A7669FF)),
+	("mousegrey",            Html::from_u32(0x6C6E6BFF)),
+	("nightblue",            Html::from_u32(0x222D5AFF)),
+	("nutbrown",             Html::from_u32(0x5A3826FF)),
+	("oceanblue",            Html::from_u32(0x00414BFF)),
+	("ochrebrown",           Html::from_u32(0x9D622BFF)),
+	("ochreyellow",          Html::from_u32(0xBA8F4CFF)),
+	("olivebrown",           Html::from_u32(0x724A25FF)),
+	("olivedrab",            Html::from_u32(0x3A3327FF)),
+	("olivegreen",           Html::from_u32(0x50533CFF)),
+	("olivegrey",            Html::from_u32(0x817863FF)),
+	("oliveyellow",          Html::from_u32(0xA08F65FF)),
+	("opalgreen",            Html::from_u32(0x005F4EFF)),
+	("orangebrown",          Html::from_u32(0xA45729FF)),
+	("orientred",            Html::from_u32(0xA63437FF)),
+	("oxidered",             Html::from_u32(0x6D342DFF)),
+	("oysterwhite",          Html::from_u32(0xE3D9C6FF)),
+	("palebrown",            Html::from_u32(0x755847FF)),
+	("palegreen",            Html::from_u32(0x8A9977FF)),
+	("papyruswhite",         Html::from_u32(0xC8CBC4FF)),
+	("pastelblue",           Html::from_u32(0x6093ACFF)),
+	("pastelgreen",          Html::from_u32(0xB9CEACFF)),
+	("pastelorange",         Html::from_u32(0xF67828FF)),
+	("pastelturquoise",      Html::from_u32(0x7AACACFF)),
+	("pastelviolet",         Html::from_u32(0x9D8692FF)),
+	("pastelyellow",         Html::from_u32(0xEB9C52FF)),
+	("patinagreen",          Html::from_u32(0x3C7460FF)),
+	("pearlbeige",           Html::from_u32(0x908370FF)),
+	("pearlblackberry",      Html::from_u32(0x6B6B7FFF)),
+	("pearlcopper",          Html::from_u32(0x7F4031FF)),
+	("pearldarkgrey",        Html::from_u32(0x797B7AFF)),
+	("pearlgentianblue",     Html::from_u32(0x21697CFF)),
+	("pearlgold",            Html::from_u32(0x80643FFF)),
+	("pearlgreen",           Html::from_u32(0x194D25FF)),
+	("pearllightgrey",       Html::from_u32(0x858583FF)),
+	("pearlmousegrey",       Html::from_u32(0x817B73FF)),
+	("pearlnightblue",       Html::from_u32(0x0F3052FF)),
+	("pearlopalgreen",       Html::from_u32(0x04574BFF)),
+	("pearlorange",          Html::from_u32(0x923E25FF)),
+	("pearlpink",            Html::from_u32(0xA53A2EFF)),
+	("pearlrubyred",         Html::from_u32(0x701D24FF)),
+	("pearlviolet",          Html::from_u32(0x6E6387FF)),
+	("pebblegrey",           Html::from_u32(0xB5B0A1FF)),
+	("pigeonblue",           Html::from_u32(0x637D96FF)),
+	("pinegreen",            Html::from_u32(0x315442FF)),
+	("platinumgrey",         Html::from_u32(0x979392FF)),
+	("puregreen",            Html::from_u32(0x008B29FF)),
+	("pureorange",           Html::from_u32(0xE25303FF)),
+	("purered",              Html::from_u32(0xCC2C24FF)),
+	("purewhite",            Html::from_u32(0xF1ECE1FF)),
+	("purplered",            Html::from_u32(0x6B1C23FF)),
+	("purpleviolet",         Html::from_u32(0x47243CFF)),
+	("quartzgrey",           Html::from_u32(0x6B665EFF)),
+	("ral1000",              Html::from_u32(0xCDBA88FF)),
+	("ral1001",              Html::from_u32(0xD0B084FF)),
+	("ral1002",              Html::from_u32(0xD2AA6DFF)),
+	("ral1003",              Html::from_u32(0xF9A800FF)),
+	("ral1004",              Html::from_u32(0xE49E00FF)),
+	("ral1005",              Html::from_u32(0xCB8E00FF)),
+	("ral1006",              Html::from_u32(0xE29000FF)),
+	("ral1007",              Html::from_u32(0xE88C00FF)),
+	("ral1011",              Html::from_u32(0xAF804FFF)),
+	("ral1012",              Html::from_u32(0xDDAF27FF)),
+	("ral1013",              Html::from_u32(0xE3D9C6FF)),
+	("ral1014",              Html::from_u32(0xDDC49AFF)),
+	("ral1015",              Html::from_u32(0xE6D2B5FF)),
+	("ral1016",              Html::from_u32(0xF1DD38FF)),
+	("ral1017",              Html::from_u32(0xF6A950FF)),
+	("ral1018",              Html::from_u32(0xFACA30FF)),
+	("ral1019",              Html::from_u32(0xA48F7AFF)),
+	("ral1020",              Html::from_u32(0xA08F65FF)),
+	("ral1021",              Html::from_u32(0xF6B600FF)),
+	("ral1023",              Html::from_u32(0xF7B500FF)),
+	("ral1024",              Html::from_u32(0xBA8F4CFF)),
+	("ral1026",              Html::from_u32(0xFFFF00FF)),
+	("ral1027",              Html::from_u32(0xA77F0EFF)),
+	("ral1028",              Html::from_u32(0xFF9B00FF)),
+	("ral1032",              Html::from_u32(0xE2A300FF)),
+	("ral1033",              Html::from_u32(0xF99A1CFF)),
+	("ral1034",              Html::from_u32(0xEB9C52FF)),
+	("ral1035",              Html::from_u32(0x908370FF)),
+	("ral1036",              Html::from_u32(0x80643FFF)),
+	("ral1037",              Html::from_u32(0xF09200FF)),
+	("ral2000",              Html::from_u32(0xDA6E00FF)),
+	("ral2001",              Html::from_u32(0xBA481BFF)),
+	("ral2002",              Html::from_u32(0xBF3922FF)),
+	("ral2003",              Html::from_u32(0xF67828FF)),
+	("ral2004",              Html::from_u32(0xE25303FF)),
+	("ral2005",              Html::from_u32(0xFF4D06FF)),
+	("ral2007",              Html::from_u32(0xFFB200FF)),
+	("ral2008",              Html::from_u32(0xED6B21FF)),
+	("ral2009",              Html::from_u32(0xDE5307FF)),
+	("ral2010",              Html::from_u32(0xD05D28FF)),
+	("ral2011",              Html::from_u32(0xE26E0EFF)),
+	("ral2012",              Html::from_u32(0xD5654DFF)),
+	("ral2013",              Html::from_u32(0x923E25FF)),
+	("ral2017",              Html::from_u32(0xFC5500FF)),
+	("ral3000",              Html::from_u32(0xA72920FF)),
+	("ral3001",              Html::from_u32(0x9B2423FF)),
+	("ral3002",              Html::from_u32(0x9B2321FF)),
+	("ral3003",              Html::from_u32(0x861A22FF)),
+	("ral3004",              Html::from_u32(0x6B1C23FF)),
+	("ral3005",              Html::from_u32(0x59191FFF)),
+	("ral3007",              Html::from_u32(0x3E2022FF)),
+	("ral3009",              Html::from_u32(0x6D342DFF)),
+	("ral3011",              Html::from_u32(0x782423FF)),
+	("ral3012",              Html::from_u32(0xC5856DFF)),
+	("ral3013",              Html::from_u32(0x972E25FF)),
+	("ral3014",              Html::from_u32(0xCB7375FF)),
+	("ral3015",              Html::from_u32(0xD8A0A6FF)),
+	("ral3016",              Html::from_u32(0xA63D30FF)),
+	("ral3017",              Html::from_u32(0xCA555DFF)),
+	("ral3018",              Html::from_u32(0xC63F4AFF)),
+	("ral3020",              Html::from_u32(0xBB1E10FF)),
+	("ral3022",              Html::from_u32(0xCF6955FF)),
+	("ral3024",              Html::from_u32(0xFF2D21FF)),
+	("ral3026",              Html::from_u32(0xFF2A1CFF)),
+	("ral3027",              Html::from_u32(0xAB273CFF)),
+	("ral3028",              Html::from_u32(0xCC2C24FF)),
+	("ral3031",              Html::from_u32(0xA63437FF)),
+	("ral3032",              Html::from_u32(0x701D24FF)),
+	("ral3033",              Html::from_u32(0xA53A2EFF)),
+	("ral4001",              Html::from_u32(0x816183FF)),
+	("ral4002",              Html::from_u32(0x8D3C4BFF)),
+	("ral4003",              Html::from_u32(0xC4618CFF)),
+	("ral4004",              Html::from_u32(0x651E38FF)),
+	("ral4005",              Html::from_u32(0x76689AFF)),
+	("ral4006",              Html::from_u32(0x903373FF)),
+	("ral4007",              Html::from_u32(0x47243CFF)),
+	("ral4008",              Html::from_u32(0x844C82FF)),
+	("ral4009",              Html::from_u32(0x9D8692FF)),
+	("ral4010",              Html::from_u32(0xBC4077FF)),
+	("ral4011",              Html::from_u32(0x6E6387FF)),
+	("ral4012",              Html::from_u32(0x6B6B7FFF)),
+	("ral5000",              Html::from_u32(0x314F6FFF)),
+	("ral5001",              Html::from_u32(0x0F4C64FF)),
+	("ral5002",              Html::from_u32(0x00387BFF)),
+	("ral5003",              Html::from_u32(0x1F3855FF)),
+	("ral5004",              Html::from_u32(0x191E28FF)),
+	("ral5005",              Html::from_u32(0x005387FF)),
+	("ral5007",              Html::from_u32(0x376B8CFF)),
+	("ral5008",              Html::from_u32(0x2B3A44FF)),
+	("ral5009",              Html::from_u32(0x225F78FF)),
+	("ral5010",              Html::from_u32(0x004F7CFF)),
+	("ral5011",              Html::from_u32(0x1A2B3CFF)),
+	("ral5012",              Html::from_u32(0x0089B6FF)),
+	("ral5013",              Html::from_u32(0x193153FF)),
+	("ral5014",              Html::from_u32(0x637D96FF)),
+	("ral5015",              Html::from_u32(0x007CB0FF)),
+	("ral5017",              Html::from_u32(0x005B8CFF)),
+	("ral5018",              Html::from_u32(0x058B8CFF)),
+	("ral5019",              Html::from_u32(0x005E83FF)),
+	("ral5020",              Html::from_u32(0x00414BFF)),
+	("ral5021",              Html::from_u32(0x007577FF)),
+	("ral5022",              Html::from_u32(0x222D5AFF)),
+	("ral5023",              Html::from_u32(0x42698CFF)),
+	("ral5024",              Html::from_u32(0x6093ACFF)),
+	("ral5025",              Html::from_u32(0x21697CFF)),
+	("ral5026",              Html::from_u32(0x0F3052FF)),
+	("ral6000",              Html::from_u32(0x3C7460FF)),
+	("ral6001",              Html::from_u32(0x366735FF)),
+	("ral6002",              Html::from_u32(0x325928FF)),
+	("ral6003",              Html::from_u32(0x50533CFF)),
+	("ral6004",              Html::from_u32(0x024442FF)),
+	("ral6005",              Html::from_u32(0x114232FF)),
+	("ral6006",              Html::from_u32(0x3C392EFF)),
+	("ral6007",              Html::from_u32(0x2C3222FF)),
+	("ral6008",              Html::from_u32(0x37342AFF)),
+	("ral6009",              Html::from_u32(0x27352AFF)),
+	("ral6010",              Html::from_u32(0x4D6F39FF)),
+	("ral6011",              Html::from_u32(0x6B7C59FF)),
+	("ral6012",              Html::from_u32(0x2F3D3AFF)),
+	("ral6013",              Html::from_u32(0x7C765AFF)),
+	("ral6014",              Html::from_u32(0x474135FF)),
+	("ral6015",              Html::from_u32(0x3D3D36FF)),
+	("ral6016",              Html::from_u32(0x00694CFF)),
+	("ral6017",              Html::from_u32(0x587F40FF)),
+	("ral6018",              Html::from_u32(0x61993BFF)),
+	("ral6019",              Html::from_u32(0xB9CEACFF)),
+	("ral6020",              Html::from_u32(0x37422FFF)),
+	("ral6021",              Html::from_u32(0x8A9977FF)),
+	("ral6022",              Html::from_u32(0x3A3327FF)),
+	("ral6024",              Html::from_u32(0x008351FF)),
+	("ral6025",              Html::from_u32(0x5E6E3BFF)),
+	("ral6026",              Html::from_u32(0x005F4EFF)),
+	("ral6027",              Html::from_u32(0x7EBAB5FF)),
+	("ral6028",              Html::from_u32(0x315442FF)),
+	("ral6029",              Html::from_u32(0x006F3DFF)),
+	("ral6032",              Html::from_u32(0x237F52FF)),
+	("ral6033",              Html::from_u32(0x46877FFF)),
+	("ral6034",              Html::from_u32(0x7AACACFF)),
+	("ral6035",              Html::from_u32(0x194D25FF)),
+	("ral6036",              Html::from_u32(0x04574BFF)),
+	("ral6037",              Html::from_u32(0x008B29FF)),
+	("ral6038",              Html::from_u32(0x00B51AFF)),
+	("ral6039",              Html::from_u32(0xB3C43EFF)),
+	("ral7000",              Html::from_u32(0x7A888EFF)),
+	("ral7001",              Html::from_u32(0x8C979CFF)),
+	("ral7002",              Html::from_u32(0x817863FF)),
+	("ral7003",              Html::from_u32(0x7A7669FF)),
+	("ral7004",              Html::from_u32(0x9B9B9BFF)),
+	("ral7005",              Html::from_u32(0x6C6E6BFF)),
+	("ral7006",              Html::from_u32(0x766A5EFF)),
+	("ral7008",              Html::from_u32(0x745E3DFF)),
+	("ral7009",              Html::from_u32(0x5D6058FF)),
+	("ral7010",              Html::from_u32(0x585C56FF)),
+	("ral7011",              Html::from_u32(0x52595DFF)),
+	("ral7012",              Html::from_u32(0x575D5EFF)),
+	("ral7013",              Html::from_u32(0x575044FF)),
+	("ral7015",              Html::from_u32(0x4F5358FF)),
+	("ral7016",              Html::from_u32(0x383E42FF)),
+	("ral7021",              Html::from_u32(0x2F3234FF)),
+	("ral7022",              Html::from_u32(0x4C4A44FF)),
+	("ral7023",              Html::from_u32(0x808076FF)),
+	("ral7024",              Html::from_u32(0x45494EFF)),
+	("ral7026",              Html::from_u32(0x374345FF)),
+	("ral7030",              Html::from_u32(0x928E85FF)),
+	("ral7031",              Html::from_u32(0x5B686DFF)),
+	("ral7032",              Html::from_u32(0xB5B0A1FF)),
+	("ral7033",              Html::from_u32(0x7F8274FF)),
+	("ral7034",              Html::from_u32(0x92886FFF)),
+	("ral7035",              Html::from_u32(0xC5C7C4FF)),
+	("ral7036",              Html::from_u32(0x979392FF)),
+	("ral7037",              Html::from_u32(0x7A7B7AFF)),
+	("ral7038",              Html::from_u32(0xB0B0A9FF)),
+	("ral7039",              Html::from_u32(0x6B665EFF)),
+	("ral7040",              Html::from_u32(0x989EA1FF)),
+	("ral7042",              Html::from_u32(0x8E9291FF)),
+	("ral7043",              Html::from_u32(0x4F5250FF)),
+	("ral7044",              Html::from_u32(0xB7B3A8FF)),
+	("ral7045",              Html::from_u32(0x8D9295FF)),
+	("ral7046",              Html::from_u32(0x7F868AFF)),
+	("ral7047",              Html::from_u32(0xC8C8C7FF)),
+	("ral7048",              Html::from_u32(0x817B73FF)),
+	("ral8000",              Html::from_u32(0x89693EFF)),
+	("ral8001",              Html::from_u32(0x9D622BFF)),
+	("ral8002",              Html::from_u32(0x794D3EFF)),
+	("ral8003",              Html::from_u32(0x7E4B26FF)),
+	("ral8004",              Html::from_u32(0x8D4931FF)),
+	("ral8007",              Html::from_u32(0x70452AFF)),
+	("ral8008",              Html::from_u32(0x724A25FF)),
+	("ral8011",              Html::from_u32(0x5A3826FF)),
+	("ral8012",              Html::from_u32(0x66332BFF)),
+	("ral8014",              Html::from_u32(0x4A3526FF)),
+	("ral8015",              Html::from_u32(0x5E2F26FF)),
+	("ral8016",              Html::from_u32(0x4C2B20FF)),
+	("ral8017",              Html::from_u32(0x442F29FF)),
+	("ral8019",              Html::from_u32(0x3D3635FF)),
+	("ral8022",              Html::from_u32(0x1A1719FF)),
+	("ral8023",              Html::from_u32(0xA45729FF)),
+	("ral8024",              Html::from_u32(0x795038FF)),
+	("ral8025",              Html::from_u32(0x755847FF)),
+	("ral8028",              Html::from_u32(0x513A2AFF)),
+	("ral8029",              Html::from_u32(0x7F4031FF)),
+	("ral9001",              Html::from_u32(0xE9E0D2FF)),
+	("ral9002",              Html::from_u32(0xD7D5CBFF)),
+	("ral9003",              Html::from_u32(0xECECE7FF)),
+	("ral9004",              Html::from_u32(0x2B2B2CFF)),
+	("ral9005",              Html::from_u32(0x0E0E10FF)),
+	("ral9006",              Html::from_u32(0xA1A1A0FF)),
+	("ral9007",              Html::from_u32(0x878581FF)),
+	("ral9010",              Html::from_u32(0xF1ECE1FF)),
+	("ral9011",              Html::from_u32(0x27292BFF)),
+	("ral9012",              Html::from_u32(0xF8F2E1FF)),
+	("ral9016",              Html::from_u32(0xF1F0EAFF)),
+	("ral9017",              Html::from_u32(0x2A292AFF)),
+	("ral9018",              Html::from_u32(0xC8CBC4FF)),
+	("ral9022",              Html::from_u32(0x858583FF)),
+	("ral9023",              Html::from_u32(0x797B7AFF)),
+	("ralorange",            Html::from_u32(0xFC5500FF)),
+	("rapeyellow",           Html::from_u32(0xF6B600FF)),
+	("raspberryred",         Html::from_u32(0xAB273CFF)),
+	("redbrown",             Html::from_u32(0x66332BFF)),
+	("redlilac",             Html::from_u32(0x816183FF)),
+	("redorange",            Html::from_u32(0xBA481BFF)),
+	("redviolet",            Html::from_u32(0x8D3C4BFF)),
+	("reedgreen",            Html::from_u32(0x7C765AFF)),
+	("resedagreen",          Html::from_u32(0x6B7C59FF)),
+	("rose",                 Html::from_u32(0xCA555DFF)),
+	("rubyred",              Html::from_u32(0x861A22FF)),
+	("saffronyellow",        Html::from_u32(0xF6A950FF)),
+	("salmonorange",         Html::from_u32(0xD5654DFF)),
+	("salmonpink",           Html::from_u32(0xCF6955FF)),
+	("sandyellow",           Html::from_u32(0xD2AA6DFF)),
+	("sapphireblue",         Html::from_u32(0x1F3855FF)),
+	("sepiabrown",           Html::from_u32(0x4A3526FF)),
+	("signalblack",          Html::from_u32(0x2B2B2CFF)),
+	("signalblue",           Html::from_u32(0x005387FF)),
+	("signalbrown",          Html::from_u32(0x794D3EFF)),
+	("signalgreen",          Html::from_u32(0x237F52FF)),
+	("signalgrey",           Html::from_u32(0x9B9B9BFF)),
+	("signalorange",         Html::from_u32(0xD05D28FF)),
+	("signalred",            Html::from_u32(0x9B2423FF)),
+	("signalviolet",         Html::from_u32(0x844C82FF)),
+	("signalwhite",          Html::from_u32(0xECECE7FF)),
+	("signalyellow",         Html::from_u32(0xF9A800FF)),
+	("silkgrey",             Html::from_u32(0xB7B3A8FF)),
+	("silvergrey",           Html::from_u32(0x8C979CFF)),
+	("skyblue",              Html::from_u32(0x007CB0FF)),
+	("slategrey",            Html::from_u32(0x4F5358FF)),
+	("squirrelgrey",         Html::from_u32(0x7A888EFF)),
+	("steelblue",            Html::from_u32(0x1A2B3CFF)),
+	("stonegrey",            Html::from_u32(0x928E85FF)),
+	("strawberryred",        Html::from_u32(0xC63F4AFF)),
+	("sulfuryellow",         Html::from_u32(0xF1DD38FF)),
+	("sunyellow",            Html::from_u32(0xF09200FF)),
+	("tarpaulingrey",        Html::from_u32(0x585C56FF)),
+	("telegrey1",            Html::from_u32(0x8D9295FF)),
+	("telegrey2",            Html::from_u32(0x7F868AFF)),
+	("telegrey4",            Html::from_u32(0xC8C8C7FF)),
+	("telemagenta",          Html::from_u32(0xBC4077FF)),
+	("terrabrown",           Html::from_u32(0x513A2AFF)),
+	("tomatored",            Html::from_u32(0x972E25FF)),
+	("trafficblack",         Html::from_u32(0x2A292AFF)),
+	("trafficblue",          Html::from_u32(0x005B8CFF)),
+	("trafficgreen",         Html::from_u32(0x008351FF)),
+	("trafficgreya",         Html::from_u32(0x8E9291FF)),
+	("trafficgreyb",         Html::from_u32(0x4F5250FF)),
+	("trafficorange",        Html::from_u32(0xDE5307FF)),
+	("trafficpurple",        Html::from_u32(0x903373FF)),
+	("trafficred",           Html::from_u32(0xBB1E10FF)),
+	("trafficwhite",         Html::from_u32(0xF1F0EAFF)),
+	("trafficyellow",        Html::from_u32(0xF7B500FF)),
+	("turquoiseblue",        Html::from_u32(0x058B8CFF)),
+	("turquoisegreen",       Html::from_u32(0x00694CFF)),
+	("ultramarineblue",      Html::from_u32(0x00387BFF)),
+	("umbragrey",            Html::from_u32(0x4C4A44FF)),
+	("vermilion",            Html::from_u32(0xBF3922FF)),
+	("violetblue",           Html::from_u32(0x314F6FFF)),
+	("waterblue",            Html::from_u32(0x007577FF)),
+	("whitealuminium",       Html::from_u32(0xA1A1A0FF)),
+	("windowgrey",           Html::from_u32(0x989EA1FF)),
+	("winered",              Html::from_u32(0x59191FFF)),
+	("yellowgreen",          Html::from_u32(0x61993BFF)),
+	("yellowgrey",           Html::from_u32(0x92886FFF)),
+	("yellowolive",          Html::from_u32(0x474135FF)),
+	("yelloworange",         Html::from_u32(0xDA6E00FF)),
+	("zincyellow",           Html::from_u32(0xFACA30FF)),
+];
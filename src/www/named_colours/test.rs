@@ -0,0 +1,107 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+#![cfg(test)]
+
+use crate::www::{Html, NamedColours};
+
+#[test]
+fn test_named_colours_css() {
+	let css = NamedColours::CSS;
+
+	assert!(css.entries().is_sorted_by(|(lhs, _), (rhs, _)| lhs < rhs));
+
+	assert_eq!(css.get("aliceblue"),    Some(Html::ALICE_BLUE));
+	assert_eq!(css.get("Alice Blue"),   Some(Html::ALICE_BLUE));
+	assert_eq!(css.get("  DarkGrey\t"), Some(Html::DARK_GREY));
+	assert_eq!(css.get("navyblue"),     None);
+	assert_eq!(css.get(""),             None);
+
+	assert_eq!(css.name(Html::GRAY),                 Some("gray"));
+	assert_eq!(css.name(Html::from_u32(0x010203FF)), None);
+
+	assert_eq!(css.suggest("Alice Bleu"), Some("aliceblue"));
+	assert_eq!(css.suggest("xyzzy"),      None);
+}
+
+#[cfg(feature = "maths")]
+#[test]
+fn test_named_colours_nearest() {
+	assert_eq!(NamedColours::CSS.nearest(Html::from_u32(0x00007FFF)), Some(("navy", Html::NAVY)));
+	assert_eq!(NamedColours::CSS.nearest(Html::from_u32(0x00000000)), Some(("black", Html::BLACK)));
+}
+
+#[cfg(feature = "x11-names")]
+#[test]
+fn test_named_colours_x11() {
+	let x11 = NamedColours::X11;
+
+	assert!(x11.entries().is_sorted_by(|(lhs, _), (rhs, _)| lhs < rhs));
+
+	assert_eq!(x11.get("Alice Blue"), Some(Html::ALICE_BLUE));
+	assert_eq!(x11.get("gray"),       Some(Html::from_u32(0xBEBEBEFF)));
+	assert_eq!(x11.get("Gray42"),     Some(Html::from_u32(0x6B6B6BFF)));
+	assert_eq!(x11.get("navy blue"),  Some(Html::NAVY));
+
+	assert_eq!(x11.name(Html::NAVY), Some("navy"));
+
+	assert_eq!(x11.suggest("sea gren"), Some("seagreen"));
+}
+
+#[cfg(feature = "xkcd-names")]
+#[test]
+fn test_named_colours_xkcd() {
+	let xkcd = NamedColours::XKCD;
+
+	assert_eq!(xkcd.entries().len(), 0x3B5);
+
+	assert!(xkcd.entries().is_sorted_by(|(lhs, _), (rhs, _)| {
+		let key = |name: &'static str| name.bytes().filter(|&c| c != b' ');
+		key(lhs).le(key(rhs))
+	}));
+
+	assert_eq!(xkcd.get("Cloudy Blue"),       Some(Html::from_u32(0xACC2D9FF)));
+	assert_eq!(xkcd.get("cloudyblue"),        Some(Html::from_u32(0xACC2D9FF)));
+	assert_eq!(xkcd.get("robin's egg"),       Some(Html::from_u32(0x6DEDFDFF)));
+	assert_eq!(xkcd.get("green/yellow"),      Some(Html::from_u32(0xB5CE08FF)));
+	assert_eq!(xkcd.get("blue green"),        Some(Html::from_u32(0x137E6DFF)));
+	assert_eq!(xkcd.get("bluegreen"),         Some(Html::from_u32(0x017A79FF)));
+	assert_eq!(xkcd.get("xkcd:blue green"),   Some(Html::from_u32(0x137E6DFF)));
+	assert_eq!(xkcd.get("XKCD:Cloudy Blue"),  Some(Html::from_u32(0xACC2D9FF)));
+	assert_eq!(xkcd.get("xkcd:"),             None);
+	assert_eq!(NamedColours::CSS.get("xkcd:red"), None);
+
+	assert_eq!(xkcd.name(Html::from_u32(0x7E1E9CFF)), Some("purple"));
+	assert_eq!(xkcd.name(Html::from_u32(0x137E6DFF)), Some("blue green"));
+
+	assert_eq!(xkcd.suggest("cloudy bleu"),      Some("cloudy blue"));
+	assert_eq!(xkcd.suggest("xkcd:cloudy bleu"), Some("cloudy blue"));
+}
+
+#[cfg(feature = "ral-names")]
+#[test]
+fn test_named_colours_ral() {
+	let ral = NamedColours::RAL;
+
+	assert!(ral.entries().is_sorted_by(|(lhs, _), (rhs, _)| lhs < rhs));
+
+	assert_eq!(ral.get("RAL 1000"),      Some(Html::from_u32(0xCDBA88FF)));
+	assert_eq!(ral.get("Traffic white"), Some(Html::from_u32(0xF1F0EAFF)));
+	assert_eq!(ral.get("ral 9016"),      ral.get("traffic white"));
+
+	assert_eq!(ral.name(Html::from_u32(0xF1F0EAFF)), Some("ral9016"));
+
+	assert_eq!(ral.suggest("trafic white"), Some("trafficwhite"));
+}
+
+#[cfg(all(feature = "maths", feature = "xkcd-names", feature = "ral-names"))]
+#[test]
+fn test_named_colours_nearest_xkcd_ral() {
+	assert_eq!(NamedColours::XKCD.nearest(Html::from_u32(0x7F1F9BFF)), Some(("purple", Html::from_u32(0x7E1E9CFF))));
+	assert_eq!(NamedColours::RAL.nearest(Html::from_u32(0xF2F1EBFF)).map(|(_, colour)| colour), Some(Html::from_u32(0xF1F0EAFF)));
+}
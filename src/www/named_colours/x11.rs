@@ -0,0 +1,676 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+//! The X11 colour database.
+//!
+//! This is derived from the `rgb.txt` file of the X.Org server.
+//! Names are given in lowercase and without spaces, such that e.g. `alice blue` and `AliceBlue` are both given as `aliceblue`.
+
+use crate::www::Html;
+
+/// The entries of the X11 colour database, sorted by name.
+pub(super) static ENTRIES: [(&str, Html); 0x292] = [
+	("aliceblue",            Html::from_u32(0xF0F8FFFF)),
+	("antiquewhite",         Html::from_u32(0xFAEBD7FF)),
+	("antiquewhite1",        Html::from_u32(0xFFEFDBFF)),
+	("antiquewhite2",        Html::from_u32(0xEEDFCCFF)),
+	("antiquewhite3",        Html::from_u32(0xCDC0B0FF)),
+	("antiquewhite4",        Html::from_u32(0x8B8378FF)),
+	("aquamarine",           Html::from_u32(0x7FFFD4FF)),
+	("aquamarine1",          Html::from_u32(0x7FFFD4FF)),
+	("aquamarine2",          Html::from_u32(0x76EEC6FF)),
+	("aquamarine3",          Html::from_u32(0x66CDAAFF)),
+	("aquamarine4",          Html::from_u32(0x458B74FF)),
+	("azure",                Html::from_u32(0xF0FFFFFF)),
+	("azure1",               Html::from_u32(0xF0FFFFFF)),
+	("azure2",               Html::from_u32(0xE0EEEEFF)),
+	("azure3",               Html::from_u32(0xC1CDCDFF)),
+	("azure4",               Html::from_u32(0x838B8BFF)),
+	("beige",                Html::from_u32(0xF5F5DCFF)),
+	("bisque",               Html::from_u32(0xFFE4C4FF)),
+	("bisque1",              Html::from_u32(0xFFE4C4FF)),
+	("bisque2",              Html::from_u32(0xEED5B7FF)),
+	("bisque3",              Html::from_u32(0xCDB79EFF)),
+	("bisque4",              Html::from_u32(0x8B7D6BFF)),
+	("black",                Html::from_u32(0x000000FF)),
+	("blanchedalmond",       Html::from_u32(0xFFEBCDFF)),
+	("blue",                 Html::from_u32(0x0000FFFF)),
+	("blue1",                Html::from_u32(0x0000FFFF)),
+	("blue2",                Html::from_u32(0x0000EEFF)),
+	("blue3",                Html::from_u32(0x0000CDFF)),
+	("blue4",                Html::from_u32(0x00008BFF)),
+	("blueviolet",           Html::from_u32(0x8A2BE2FF)),
+	("brown",                Html::from_u32(0xA52A2AFF)),
+	("brown1",               Html::from_u32(0xFF4040FF)),
+	("brown2",               Html::from_u32(0xEE3B3BFF)),
+	("brown3",               Html::from_u32(0xCD3333FF)),
+	("brown4",               Html::from_u32(0x8B2323FF)),
+	("burlywood",            Html::from_u32(0xDEB887FF)),
+	("burlywood1",           Html::from_u32(0xFFD39BFF)),
+	("burlywood2",           Html::from_u32(0xEEC591FF)),
+	("burlywood3",           Html::from_u32(0xCDAA7DFF)),
+	("burlywood4",           Html::from_u32(0x8B7355FF)),
+	("cadetblue",            Html::from_u32(0x5F9EA0FF)),
+	("cadetblue1",           Html::from_u32(0x98F5FFFF)),
+	("cadetblue2",           Html::from_u32(0x8EE5EEFF)),
+	("cadetblue3",           Html::from_u32(0x7AC5CDFF)),
+	("cadetblue4",           Html::from_u32(0x53868BFF)),
+	("chartreuse",           Html::from_u32(0x7FFF00FF)),
+	("chartreuse1",          Html::from_u32(0x7FFF00FF)),
+	("chartreuse2",          Html::from_u32(0x76EE00FF)),
+	("chartreuse3",          Html::from_u32(0x66CD00FF)),
+	("chartreuse4",          Html::from_u32(0x458B00FF)),
+	("chocolate",            Html::from_u32(0xD2691EFF)),
+	("chocolate1",           Html::from_u32(0xFF7F24FF)),
+	("chocolate2",           Html::from_u32(0xEE7621FF)),
+	("chocolate3",           Html::from_u32(0xCD661DFF)),
+	("chocolate4",           Html::from_u32(0x8B4513FF)),
+	("coral",                Html::from_u32(0xFF7F50FF)),
+	("coral1",               Html::from_u32(0xFF7256FF)),
+	("coral2",               Html::from_u32(0xEE6A50FF)),
+	("coral3",               Html::from_u32(0xCD5B45FF)),
+	("coral4",               Html::from_u32(0x8B3E2FFF)),
+	("cornflowerblue",       Html::from_u32(0x6495EDFF)),
+	("cornsilk",             Html::from_u32(0xFFF8DCFF)),
+	("cornsilk1",            Html::from_u32(0xFFF8DCFF)),
+	("cornsilk2",            Html::from_u32(0xEEE8CDFF)),
+	("cornsilk3",            Html::from_u32(0xCDC8B1FF)),
+	("cornsilk4",            Html::from_u32(0x8B8878FF)),
+	("cyan",                 Html::from_u32(0x00FFFFFF)),
+	("cyan1",                Html::from_u32(0x00FFFFFF)),
+	("cyan2",                Html::from_u32(0x00EEEEFF)),
+	("cyan3",                Html::from_u32(0x00CDCDFF)),
+	("cyan4",                Html::from_u32(0x008B8BFF)),
+	("darkblue",             Html::from_u32(0x00008BFF)),
+	("darkcyan",             Html::from_u32(0x008B8BFF)),
+	("darkgoldenrod",        Html::from_u32(0xB8860BFF)),
+	("darkgoldenrod1",       Html::from_u32(0xFFB90FFF)),
+	("darkgoldenrod2",       Html::from_u32(0xEEAD0EFF)),
+	("darkgoldenrod3",       Html::from_u32(0xCD950CFF)),
+	("darkgoldenrod4",       Html::from_u32(0x8B6508FF)),
+	("darkgray",             Html::from_u32(0xA9A9A9FF)),
+	("darkgreen",            Html::from_u32(0x006400FF)),
+	("darkgrey",             Html::from_u32(0xA9A9A9FF)),
+	("darkkhaki",            Html::from_u32(0xBDB76BFF)),
+	("darkmagenta",          Html::from_u32(0x8B008BFF)),
+	("darkolivegreen",       Html::from_u32(0x556B2FFF)),
+	("darkolivegreen1",      Html::from_u32(0xCAFF70FF)),
+	("darkolivegreen2",      Html::from_u32(0xBCEE68FF)),
+	("darkolivegreen3",      Html::from_u32(0xA2CD5AFF)),
+	("darkolivegreen4",      Html::from_u32(0x6E8B3DFF)),
+	("darkorange",           Html::from_u32(0xFF8C00FF)),
+	("darkorange1",          Html::from_u32(0xFF7F00FF)),
+	("darkorange2",          Html::from_u32(0xEE7600FF)),
+	("darkorange3",          Html::from_u32(0xCD6600FF)),
+	("darkorange4",          Html::from_u32(0x8B4500FF)),
+	("darkorchid",           Html::from_u32(0x9932CCFF)),
+	("darkorchid1",          Html::from_u32(0xBF3EFFFF)),
+	("darkorchid2",          Html::from_u32(0xB23AEEFF)),
+	("darkorchid3",          Html::from_u32(0x9A32CDFF)),
+	("darkorchid4",          Html::from_u32(0x68228BFF)),
+	("darkred",              Html::from_u32(0x8B0000FF)),
+	("darksalmon",           Html::from_u32(0xE9967AFF)),
+	("darkseagreen",         Html::from_u32(0x8FBC8FFF)),
+	("darkseagreen1",        Html::from_u32(0xC1FFC1FF)),
+	("darkseagreen2",        Html::from_u32(0xB4EEB4FF)),
+	("darkseagreen3",        Html::from_u32(0x9BCD9BFF)),
+	("darkseagreen4",        Html::from_u32(0x698B69FF)),
+	("darkslateblue",        Html::from_u32(0x483D8BFF)),
+	("darkslategray",        Html::from_u32(0x2F4F4FFF)),
+	("darkslategray1",       Html::from_u32(0x97FFFFFF)),
+	("darkslategray2",       Html::from_u32(0x8DEEEEFF)),
+	("darkslategray3",       Html::from_u32(0x79CDCDFF)),
+	("darkslategray4",       Html::from_u32(0x528B8BFF)),
+	("darkslategrey",        Html::from_u32(0x2F4F4FFF)),
+	("darkturquoise",        Html::from_u32(0x00CED1FF)),
+	("darkviolet",           Html::from_u32(0x9400D3FF)),
+	("debianred",            Html::from_u32(0xD70751FF)),
+	("deeppink",             Html::from_u32(0xFF1493FF)),
+	("deeppink1",            Html::from_u32(0xFF1493FF)),
+	("deeppink2",            Html::from_u32(0xEE1289FF)),
+	("deeppink3",            Html::from_u32(0xCD1076FF)),
+	("deeppink4",            Html::from_u32(0x8B0A50FF)),
+	("deepskyblue",          Html::from_u32(0x00BFFFFF)),
+	("deepskyblue1",         Html::from_u32(0x00BFFFFF)),
+	("deepskyblue2",         Html::from_u32(0x00B2EEFF)),
+	("deepskyblue3",         Html::from_u32(0x009ACDFF)),
+	("deepskyblue4",         Html::from_u32(0x00688BFF)),
+	("dimgray",              Html::from_u32(0x696969FF)),
+	("dimgrey",              Html::from_u32(0x696969FF)),
+	("dodgerblue",           Html::from_u32(0x1E90FFFF)),
+	("dodgerblue1",          Html::from_u32(0x1E90FFFF)),
+	("dodgerblue2",          Html::from_u32(0x1C86EEFF)),
+	("dodgerblue3",          Html::from_u32(0x1874CDFF)),
+	("dodgerblue4",          Html::from_u32(0x104E8BFF)),
+	("firebrick",            Html::from_u32(0xB22222FF)),
+	("firebrick1",           Html::from_u32(0xFF3030FF)),
+	("firebrick2",           Html::from_u32(0xEE2C2CFF)),
+	("firebrick3",           Html::from_u32(0xCD2626FF)),
+	("firebrick4",           Html::from_u32(0x8B1A1AFF)),
+	("floralwhite",          Html::from_u32(0xFFFAF0FF)),
+	("forestgreen",          Html::from_u32(0x228B22FF)),
+	("gainsboro",            Html::from_u32(0xDCDCDCFF)),
+	("ghostwhite",           Html::from_u32(0xF8F8FFFF)),
+	("gold",                 Html::from_u32(0xFFD700FF)),
+	("gold1",                Html::from_u32(0xFFD700FF)),
+	("gold2",                Html::from_u32(0xEEC900FF)),
+	("gold3",                Html::from_u32(0xCDAD00FF)),
+	("gold4",                Html::from_u32(0x8B7500FF)),
+	("goldenrod",            Html::from_u32(0xDAA520FF)),
+	("goldenrod1",           Html::from_u32(0xFFC125FF)),
+	("goldenrod2",           Html::from_u32(0xEEB422FF)),
+	("goldenrod3",           Html::from_u32(0xCD9B1DFF)),
+	("goldenrod4",           Html::from_u32(0x8B6914FF)),
+	("gray",                 Html::from_u32(0xBEBEBEFF)),
+	("gray0",                Html::from_u32(0x000000FF)),
+	("gray1",                Html::from_u32(0x030303FF)),
+	("gray10",               Html::from_u32(0x1A1A1AFF)),
+	("gray100",              Html::from_u32(0xFFFFFFFF)),
+	("gray11",               Html::from_u32(0x1C1C1CFF)),
+	("gray12",               Html::from_u32(0x1F1F1FFF)),
+	("gray13",               Html::from_u32(0x212121FF)),
+	("gray14",               Html::from_u32(0x242424FF)),
+	("gray15",               Html::from_u32(0x262626FF)),
+	("gray16",               Html::from_u32(0x292929FF)),
+	("gray17",               Html::from_u32(0x2B2B2BFF)),
+	("gray18",               Html::from_u32(0x2E2E2EFF)),
+	("gray19",               Html::from_u32(0x303030FF)),
+	("gray2",                Html::from_u32(0x050505FF)),
+	("gray20",               Html::from_u32(0x333333FF)),
+	("gray21",               Html::from_u32(0x363636FF)),
+	("gray22",               Html::from_u32(0x383838FF)),
+	("gray23",               Html::from_u32(0x3B3B3BFF)),
+	("gray24",               Html::from_u32(0x3D3D3DFF)),
+	("gray25",               Html::from_u32(0x404040FF)),
+	("gray26",               Html::from_u32(0x424242FF)),
+	("gray27",               Html::from_u32(0x454545FF)),
+	("gray28",               Html::from_u32(0x474747FF)),
+	("gray29",               Html::from_u32(0x4A4A4AFF)),
+	("gray3",                Html::from_u32(0x080808FF)),
+	("gray30",               Html::from_u32(0x4D4D4DFF)),
+	("gray31",               Html::from_u32(0x4F4F4FFF)),
+	("gray32",               Html::from_u32(0x525252FF)),
+	("gray33",               Html::from_u32(0x545454FF)),
+	("gray34",               Html::from_u32(0x575757FF)),
+	("gray35",               Html::from_u32(0x595959FF)),
+	("gray36",               Html::from_u32(0x5C5C5CFF)),
+	("gray37",               Html::from_u32(0x5E5E5EFF)),
+	("gray38",               Html::from_u32(0x616161FF)),
+	("gray39",               Html::from_u32(0x636363FF)),
+	("gray4",                Html::from_u32(0x0A0A0AFF)),
+	("gray40",               Html::from_u32(0x666666FF)),
+	("gray41",               Html::from_u32(0x696969FF)),
+	("gray42",               Html::from_u32(0x6B6B6BFF)),
+	("gray43",               Html::from_u32(0x6E6E6EFF)),
+	("gray44",               Html::from_u32(0x707070FF)),
+	("gray45",               Html::from_u32(0x737373FF)),
+	("gray46",               Html::from_u32(0x757575FF)),
+	("gray47",               Html::from_u32(0x787878FF)),
+	("gray48",               Html::from_u32(0x7A7A7AFF)),
+	("gray49",               Html::from_u32(0x7D7D7DFF)),
+	("gray5",                Html::from_u32(0x0D0D0DFF)),
+	("gray50",               Html::from_u32(0x7F7F7FFF)),
+	("gray51",               Html::from_u32(0x828282FF)),
+	("gray52",               Html::from_u32(0x858585FF)),
+	("gray53",               Html::from_u32(0x878787FF)),
+	("gray54",               Html::from_u32(0x8A8A8AFF)),
+	("gray55",               Html::from_u32(0x8C8C8CFF)),
+	("gray56",               Html::from_u32(0x8F8F8FFF)),
+	("gray57",               Html::from_u32(0x919191FF)),
+	("gray58",               Html::from_u32(0x949494FF)),
+	("gray59",               Html::from_u32(0x969696FF)),
+	("gray6",                Html::from_u32(0x0F0F0FFF)),
+	("gray60",               Html::from_u32(0x999999FF)),
+	("gray61",               Html::from_u32(0x9C9C9CFF)),
+	("gray62",               Html::from_u32(0x9E9E9EFF)),
+	("gray63",               Html::from_u32(0xA1A1A1FF)),
+	("gray64",               Html::from_u32(0xA3A3A3FF)),
+	("gray65",               Html::from_u32(0xA6A6A6FF)),
+	("gray66",               Html::from_u32(0xA8A8A8FF)),
+	("gray67",               Html::from_u32(0xABABABFF)),
+	("gray68",               Html::from_u32(0xADADADFF)),
+	("gray69",               Html::from_u32(0xB0B0B0FF)),
+	("gray7",                Html::from_u32(0x121212FF)),
+	("gray70",               Html::from_u32(0xB3B3B3FF)),
+	("gray71",               Html::from_u32(0xB5B5B5FF)),
+	("gray72",               Html::from_u32(0xB8B8B8FF)),
+	("gray73",               Html::from_u32(0xBABABAFF)),
+	("gray74",               Html::from_u32(0xBDBDBDFF)),
+	("gray75",               Html::from_u32(0xBFBFBFFF)),
+	("gray76",               Html::from_u32(0xC2C2C2FF)),
+	("gray77",               Html::from_u32(0xC4C4C4FF)),
+	("gray78",               Html::from_u32(0xC7C7C7FF)),
+	("gray79",               Html::from_u32(0xC9C9C9FF)),
+	("gray8",                Html::from_u32(0x141414FF)),
+	("gray80",               Html::from_u32(0xCCCCCCFF)),
+	("gray81",               Html::from_u32(0xCFCFCFFF)),
+	("gray82",               Html::from_u32(0xD1D1D1FF)),
+	("gray83",               Html::from_u32(0xD4D4D4FF)),
+	("gray84",               Html::from_u32(0xD6D6D6FF)),
+	("gray85",               Html::from_u32(0xD9D9D9FF)),
+	("gray86",               Html::from_u32(0xDBDBDBFF)),
+	("gray87",               Html::from_u32(0xDEDEDEFF)),
+	("gray88",               Html::from_u32(0xE0E0E0FF)),
+	("gray89",               Html::from_u32(0xE3E3E3FF)),
+	("gray9",                Html::from_u32(0x171717FF)),
+	("gray90",               Html::from_u32(0xE5E5E5FF)),
+	("gray91",               Html::from_u32(0xE8E8E8FF)),
+	("gray92",               Html::from_u32(0xEBEBEBFF)),
+	("gray93",               Html::from_u32(0xEDEDEDFF)),
+	("gray94",               Html::from_u32(0xF0F0F0FF)),
+	("gray95",               Html::from_u32(0xF2F2F2FF)),
+	("gray96",               Html::from_u32(0xF5F5F5FF)),
+	("gray97",               Html::from_u32(0xF7F7F7FF)),
+	("gray98",               Html::from_u32(0xFAFAFAFF)),
+	("gray99",               Html::from_u32(0xFCFCFCFF)),
+	("green",                Html::from_u32(0x00FF00FF)),
+	("green1",               Html::from_u32(0x00FF00FF)),
+	("green2",               Html::from_u32(0x00EE00FF)),
+	("green3",               Html::from_u32(0x00CD00FF)),
+	("green4",               Html::from_u32(0x008B00FF)),
+	("greenyellow",          Html::from_u32(0xADFF2FFF)),
+	("grey",                 Html::from_u32(0xBEBEBEFF)),
+	("grey0",                Html::from_u32(0x000000FF)),
+	("grey1",                Html::from_u32(0x030303FF)),
+	("grey10",               Html::from_u32(0x1A1A1AFF)),
+	("grey100",              Html::from_u32(0xFFFFFFFF)),
+	("grey11",               Html::from_u32(0x1C1C1CFF)),
+	("grey12",               Html::from_u32(0x1F1F1FFF)),
+	("grey13",               Html::from_u32(0x212121FF)),
+	("grey14",               Html::from_u32(0x242424FF)),
+	("grey15",               Html::from_u32(0x262626FF)),
+	("grey16",               Html::from_u32(0x292929FF)),
+	("grey17",               Html::from_u32(0x2B2B2BFF)),
+	("grey18",               Html::from_u32(0x2E2E2EFF)),
+	("grey19",               Html::from_u32(0x303030FF)),
+	("grey2",                Html::from_u32(0x050505FF)),
+	("grey20",               Html::from_u32(0x333333FF)),
+	("grey21",               Html::from_u32(0x363636FF)),
+	("grey22",               Html::from_u32(0x383838FF)),
+	("grey23",               Html::from_u32(0x3B3B3BFF)),
+	("grey24",               Html::from_u32(0x3D3D3DFF)),
+	("grey25",               Html::from_u32(0x404040FF)),
+	("grey26",               Html::from_u32(0x424242FF)),
+	("grey27",               Html::from_u32(0x454545FF)),
+	("grey28",               Html::from_u32(0x474747FF)),
+	("grey29",               Html::from_u32(0x4A4A4AFF)),
+	("grey3",                Html::from_u32(0x080808FF)),
+	("grey30",               Html::from_u32(0x4D4D4DFF)),
+	("grey31",               Html::from_u32(0x4F4F4FFF)),
+	("grey32",               Html::from_u32(0x525252FF)),
+	("grey33",               Html::from_u32(0x545454FF)),
+	("grey34",               Html::from_u32(0x575757FF)),
+	("grey35",               Html::from_u32(0x595959FF)),
+	("grey36",               Html::from_u32(0x5C5C5CFF)),
+	("grey37",               Html::from_u32(0x5E5E5EFF)),
+	("grey38",               Html::from_u32(0x616161FF)),
+	("grey39",               Html::from_u32(0x636363FF)),
+	("grey4",                Html::from_u32(0x0A0A0AFF)),
+	("grey40",               Html::from_u32(0x666666FF)),
+	("grey41",               Html::from_u32(0x696969FF)),
+	("grey42",               Html::from_u32(0x6B6B6BFF)),
+	("grey43",               Html::from_u32(0x6E6E6EFF)),
+	("grey44",               Html::from_u32(0x707070FF)),
+	("grey45",               Html::from_u32(0x737373FF)),
+	("grey46",               Html::from_u32(0x757575FF)),
+	("grey47",               Html::from_u32(0x787878FF)),
+	("grey48",               Html::from_u32(0x7A7A7AFF)),
+	("grey49",               Html::from_u32(0x7D7D7DFF)),
+	("grey5",                Html::from_u32(0x0D0D0DFF)),
+	("grey50",               Html::from_u32(0x7F7F7FFF)),
+	("grey51",               Html::from_u32(0x828282FF)),
+	("grey52",               Html::from_u32(0x858585FF)),
+	("grey53",               Html::from_u32(0x878787FF)),
+	("grey54",               Html::from_u32(0x8A8A8AFF)),
+	("grey55",               Html::from_u32(0x8C8C8CFF)),
+	("grey56",               Html::from_u32(0x8F8F8FFF)),
+	("grey57",               Html::from_u32(0x919191FF)),
+	("grey58",               Html::from_u32(0x949494FF)),
+	("grey59",               Html::from_u32(0x969696FF)),
+	("grey6",                Html::from_u32(0x0F0F0FFF)),
+	("grey60",               Html::from_u32(0x999999FF)),
+	("grey61",               Html::from_u32(0x9C9C9CFF)),
+	("grey62",               Html::from_u32(0x9E9E9EFF)),
+	("grey63",               Html::from_u32(0xA1A1A1FF)),
+	("grey64",               Html::from_u32(0xA3A3A3FF)),
+	("grey65",               Html::from_u32(0xA6A6A6FF)),
+	("grey66",               Html::from_u32(0xA8A8A8FF)),
+	("grey67",               Html::from_u32(0xABABABFF)),
+	("grey68",               Html::from_u32(0xADADADFF)),
+	("grey69",               Html::from_u32(0xB0B0B0FF)),
+	("grey7",                Html::from_u32(0x121212FF)),
+	("grey70",               Html::from_u32(0xB3B3B3FF)),
+	("grey71",               Html::from_u32(0xB5B5B5FF)),
+	("grey72",               Html::from_u32(0xB8B8B8FF)),
+	("grey73",               Html::from_u32(0xBABABAFF)),
+	("grey74",               Html::from_u32(0xBDBDBDFF)),
+	("grey75",               Html::from_u32(0xBFBFBFFF)),
+	("grey76",               Html::from_u32(0xC2C2C2FF)),
+	("grey77",               Html::from_u32(0xC4C4C4FF)),
+	("grey78",               Html::from_u32(0xC7C7C7FF)),
+	("grey79",               Html::from_u32(0xC9C9C9FF)),
+	("grey8",                Html::from_u32(0x141414FF)),
+	("grey80",               Html::from_u32(0xCCCCCCFF)),
+	("grey81",               Html::from_u32(0xCFCFCFFF)),
+	("grey82",               Html::from_u32(0xD1D1D1FF)),
+	("grey83",               Html::from_u32(0xD4D4D4FF)),
+	("grey84",               Html::from_u32(0xD6D6D6FF)),
+	("grey85",               Html::from_u32(0xD9D9D9FF)),
+	("grey86",               Html::from_u32(0xDBDBDBFF)),
+	("grey87",               Html::from_u32(0xDEDEDEFF)),
+	("grey88",               Html::from_u32(0xE0E0E0FF)),
+	("grey89",               Html::from_u32(0xE3E3E3FF)),
+	("grey9",                Html::from_u32(0x171717FF)),
+	("grey90",               Html::from_u32(0xE5E5E5FF)),
+	("grey91",               Html::from_u32(0xE8E8E8FF)),
+	("grey92",               Html::from_u32(0xEBEBEBFF)),
+	("grey93",               Html::from_u32(0xEDEDEDFF)),
+	("grey94",               Html::from_u32(0xF0F0F0FF)),
+	("grey95",               Html::from_u32(0xF2F2F2FF)),
+	("grey96",               Html::from_u32(0xF5F5F5FF)),
+	("grey97",               Html::from_u32(0xF7F7F7FF)),
+	("grey98",               Html::from_u32(0xFAFAFAFF)),
+	("grey99",               Html::from_u32(0xFCFCFCFF)),
+	("honeydew",             Html::from_u32(0xF0FFF0FF)),
+	("honeydew1",            Html::from_u32(0xF0FFF0FF)),
+	("honeydew2",            Html::from_u32(0xE0EEE0FF)),
+	("honeydew3",            Html::from_u32(0xC1CDC1FF)),
+	("honeydew4",            Html::from_u32(0x838B83FF)),
+	("hotpink",              Html::from_u32(0xFF69B4FF)),
+	("hotpink1",             Html::from_u32(0xFF6EB4FF)),
+	("hotpink2",             Html::from_u32(0xEE6AA7FF)),
+	("hotpink3",             Html::from_u32(0xCD6090FF)),
+	("hotpink4",             Html::from_u32(0x8B3A62FF)),
+	("indianred",            Html::from_u32(0xCD5C5CFF)),
+	("indianred1",           Html::from_u32(0xFF6A6AFF)),
+	("indianred2",           Html::from_u32(0xEE6363FF)),
+	("indianred3",           Html::from_u32(0xCD5555FF)),
+	("indianred4",           Html::from_u32(0x8B3A3AFF)),
+	("ivory",                Html::from_u32(0xFFFFF0FF)),
+	("ivory1",               Html::from_u32(0xFFFFF0FF)),
+	("ivory2",               Html::from_u32(0xEEEEE0FF)),
+	("ivory3",               Html::from_u32(0xCDCDC1FF)),
+	("ivory4",               Html::from_u32(0x8B8B83FF)),
+	("khaki",                Html::from_u32(0xF0E68CFF)),
+	("khaki1",               Html::from_u32(0xFFF68FFF)),
+	("khaki2",               Html::from_u32(0xEEE685FF)),
+	("khaki3",               Html::from_u32(0xCDC673FF)),
+	("khaki4",               Html::from_u32(0x8B864EFF)),
+	("lavender",             Html::from_u32(0xE6E6FAFF)),
+	("lavenderblush",        Html::from_u32(0xFFF0F5FF)),
+	("lavenderblush1",       Html::from_u32(0xFFF0F5FF)),
+	("lavenderblush2",       Html::from_u32(0xEEE0E5FF)),
+	("lavenderblush3",       Html::from_u32(0xCDC1C5FF)),
+	("lavenderblush4",       Html::from_u32(0x8B8386FF)),
+	("lawngreen",            Html::from_u32(0x7CFC00FF)),
+	("lemonchiffon",         Html::from_u32(0xFFFACDFF)),
+	("lemonchiffon1",        Html::from_u32(0xFFFACDFF)),
+	("lemonchiffon2",        Html::from_u32(0xEEE9BFFF)),
+	("lemonchiffon3",        Html::from_u32(0xCDC9A5FF)),
+	("lemonchiffon4",        Html::from_u32(0x8B8970FF)),
+	("lightblue",            Html::from_u32(0xADD8E6FF)),
+	("lightblue1",           Html::from_u32(0xBFEFFFFF)),
+	("lightblue2",           Html::from_u32(0xB2DFEEFF)),
+	("lightblue3",           Html::from_u32(0x9AC0CDFF)),
+	("lightblue4",           Html::from_u32(0x68838BFF)),
+	("lightcoral",           Html::from_u32(0xF08080FF)),
+	("lightcyan",            Html::from_u32(0xE0FFFFFF)),
+	("lightcyan1",           Html::from_u32(0xE0FFFFFF)),
+	("lightcyan2",           Html::from_u32(0xD1EEEEFF)),
+	("lightcyan3",           Html::from_u32(0xB4CDCDFF)),
+	("lightcyan4",           Html::from_u32(0x7A8B8BFF)),
+	("lightgoldenrod",       Html::from_u32(0xEEDD82FF)),
+	("lightgoldenrod1",      Html::from_u32(0xFFEC8BFF)),
+	("lightgoldenrod2",      Html::from_u32(0xEEDC82FF)),
+	("lightgoldenrod3",      Html::from_u32(0xCDBE70FF)),
+	("lightgoldenrod4",      Html::from_u32(0x8B814CFF)),
+	("lightgoldenrodyellow", Html::from_u32(0xFAFAD2FF)),
+	("lightgray",            Html::from_u32(0xD3D3D3FF)),
+	("lightgreen",           Html::from_u32(0x90EE90FF)),
+	("lightgrey",            Html::from_u32(0xD3D3D3FF)),
+	("lightpink",            Html::from_u32(0xFFB6C1FF)),
+	("lightpink1",           Html::from_u32(0xFFAEB9FF)),
+	("lightpink2",           Html::from_u32(0xEEA2ADFF)),
+	("lightpink3",           Html::from_u32(0xCD8C95FF)),
+	("lightpink4",           Html::from_u32(0x8B5F65FF)),
+	("lightsalmon",          Html::from_u32(0xFFA07AFF)),
+	("lightsalmon1",         Html::from_u32(0xFFA07AFF)),
+	("lightsalmon2",         Html::from_u32(0xEE9572FF)),
+	("lightsalmon3",         Html::from_u32(0xCD8162FF)),
+	("lightsalmon4",         Html::from_u32(0x8B5742FF)),
+	("lightseagreen",        Html::from_u32(0x20B2AAFF)),
+	("lightskyblue",         Html::from_u32(0x87CEFAFF)),
+	("lightskyblue1",        Html::from_u32(0xB0E2FFFF)),
+	("lightskyblue2",        Html::from_u32(0xA4D3EEFF)),
+	("lightskyblue3",        Html::from_u32(0x8DB6CDFF)),
+	("lightskyblue4",        Html::from_u32(0x607B8BFF)),
+	("lightslateblue",       Html::from_u32(0x8470FFFF)),
+	("lightslategray",       Html::from_u32(0x778899FF)),
+	("lightslategrey",       Html::from_u32(0x778899FF)),
+	("lightsteelblue",       Html::from_u32(0xB0C4DEFF)),
+	("lightsteelblue1",      Html::from_u32(0xCAE1FFFF)),
+	("lightsteelblue2",      Html::from_u32(0xBCD2EEFF)),
+	("lightsteelblue3",      Html::from_u32(0xA2B5CDFF)),
+	("lightsteelblue4",      Html::from_u32(0x6E7B8BFF)),
+	("lightyellow",          Html::from_u32(0xFFFFE0FF)),
+	("lightyellow1",         Html::from_u32(0xFFFFE0FF)),
+	("lightyellow2",         Html::from_u32(0xEEEED1FF)),
+	("lightyellow3",         Html::from_u32(0xCDCDB4FF)),
+	("lightyellow4",         Html::from_u32(0x8B8B7AFF)),
+	("limegreen",            Html::from_u32(0x32CD32FF)),
+	("linen",                Html::from_u32(0xFAF0E6FF)),
+	("magenta",              Html::from_u32(0xFF00FFFF)),
+	("magenta1",             Html::from_u32(0xFF00FFFF)),
+	("magenta2",             Html::from_u32(0xEE00EEFF)),
+	("magenta3",             Html::from_u32(0xCD00CDFF)),
+	("magenta4",             Html::from_u32(0x8B008BFF)),
+	("maroon",               Html::from_u32(0xB03060FF)),
+	("maroon1",              Html::from_u32(0xFF34B3FF)),
+	("maroon2",              Html::from_u32(0xEE30A7FF)),
+	("maroon3",              Html::from_u32(0xCD2990FF)),
+	("maroon4",              Html::from_u32(0x8B1C62FF)),
+	("mediumaquamarine",     Html::from_u32(0x66CDAAFF)),
+	("mediumblue",           Html::from_u32(0x0000CDFF)),
+	("mediumorchid",         Html::from_u32(0xBA55D3FF)),
+	("mediumorchid1",        Html::from_u32(0xE066FFFF)),
+	("mediumorchid2",        Html::from_u32(0xD15FEEFF)),
+	("mediumorchid3",        Html::from_u32(0xB452CDFF)),
+	("mediumorchid4",        Html::from_u32(0x7A378BFF)),
+	("mediumpurple",         Html::from_u32(0x9370DBFF)),
+	("mediumpurple1",        Html::from_u32(0xAB82FFFF)),
+	("mediumpurple2",        Html::from_u32(0x9F79EEFF)),
+	("mediumpurple3",        Html::from_u32(0x8968CDFF)),
+	("mediumpurple4",        Html::from_u32(0x5D478BFF)),
+	("mediumseagreen",       Html::from_u32(0x3CB371FF)),
+	("mediumslateblue",      Html::from_u32(0x7B68EEFF)),
+	("mediumspringgreen",    Html::from_u32(0x00FA9AFF)),
+	("mediumturquoise",      Html::from_u32(0x48D1CCFF)),
+	("mediumvioletred",      Html::from_u32(0xC71585FF)),
+	("midnightblue",         Html::from_u32(0x191970FF)),
+	("mintcream",            Html::from_u32(0xF5FFFAFF)),
+	("mistyrose",            Html::from_u32(0xFFE4E1FF)),
+	("mistyrose1",           Html::from_u32(0xFFE4E1FF)),
+	("mistyrose2",           Html::from_u32(0xEED5D2FF)),
+	("mistyrose3",           Html::from_u32(0xCDB7B5FF)),
+	("mistyrose4",           Html::from_u32(0x8B7D7BFF)),
+	("moccasin",             Html::from_u32(0xFFE4B5FF)),
+	("navajowhite",          Html::from_u32(0xFFDEADFF)),
+	("navajowhite1",         Html::from_u32(0xFFDEADFF)),
+	("navajowhite2",         Html::from_u32(0xEECFA1FF)),
+	("navajowhite3",         Html::from_u32(0xCDB38BFF)),
+	("navajowhite4",         Html::from_u32(0x8B795EFF)),
+	("navy",                 Html::from_u32(0x000080FF)),
+	("navyblue",             Html::from_u32(0x000080FF)),
+	("oldlace",              Html::from_u32(0xFDF5E6FF)),
+	("olivedrab",            Html::from_u32(0x6B8E23FF)),
+	("olivedrab1",           Html::from_u32(0xC0FF3EFF)),
+	("olivedrab2",           Html::from_u32(0xB3EE3AFF)),
+	("olivedrab3",           Html::from_u32(0x9ACD32FF)),
+	("olivedrab4",           Html::from_u32(0x698B22FF)),
+	("orange",               Html::from_u32(0xFFA500FF)),
+	("orange1",              Html::from_u32(0xFFA500FF)),
+	("orange2",              Html::from_u32(0xEE9A00FF)),
+	("orange3",              Html::from_u32(0xCD8500FF)),
+	("orange4",              Html::from_u32(0x8B5A00FF)),
+	("orangered",            Html::from_u32(0xFF4500FF)),
+	("orangered1",           Html::from_u32(0xFF4500FF)),
+	("orangered2",           Html::from_u32(0xEE4000FF)),
+	("orangered3",           Html::from_u32(0xCD3700FF)),
+	("orangered4",           Html::from_u32(0x8B2500FF)),
+	("orchid",               Html::from_u32(0xDA70D6FF)),
+	("orchid1",              Html::from_u32(0xFF83FAFF)),
+	("orchid2",              Html::from_u32(0xEE7AE9FF)),
+	("orchid3",              Html::from_u32(0xCD69C9FF)),
+	("orchid4",              Html::from_u32(0x8B4789FF)),
+	("palegoldenrod",        Html::from_u32(0xEEE8AAFF)),
+	("palegreen",            Html::from_u32(0x98FB98FF)),
+	("palegreen1",           Html::from_u32(0x9AFF9AFF)),
+	("palegreen2",           Html::from_u32(0x90EE90FF)),
+	("palegreen3",           Html::from_u32(0x7CCD7CFF)),
+	("palegreen4",           Html::from_u32(0x548B54FF)),
+	("paleturquoise",        Html::from_u32(0xAFEEEEFF)),
+	("paleturquoise1",       Html::from_u32(0xBBFFFFFF)),
+	("paleturquoise2",       Html::from_u32(0xAEEEEEFF)),
+	("paleturquoise3",       Html::from_u32(0x96CDCDFF)),
+	("paleturquoise4",       Html::from_u32(0x668B8BFF)),
+	("palevioletred",        Html::from_u32(0xDB7093FF)),
+	("palevioletred1",       Html::from_u32(0xFF82ABFF)),
+	("palevioletred2",       Html::from_u32(0xEE799FFF)),
+	("palevioletred3",       Html::from_u32(0xCD6889FF)),
+	("palevioletred4",       Html::from_u32(0x8B475DFF)),
+	("papayawhip",           Html::from_u32(0xFFEFD5FF)),
+	("peachpuff",            Html::from_u32(0xFFDAB9FF)),
+	("peachpuff1",           Html::from_u32(0xFFDAB9FF)),
+	("peachpuff2",           Html::from_u32(0xEECBADFF)),
+	("peachpuff3",           Html::from_u32(0xCDAF95FF)),
+	("peachpuff4",           Html::from_u32(0x8B7765FF)),
+	("peru",                 Html::from_u32(0xCD853FFF)),
+	("pink",                 Html::from_u32(0xFFC0CBFF)),
+	("pink1",                Html::from_u32(0xFFB5C5FF)),
+	("pink2",                Html::from_u32(0xEEA9B8FF)),
+	("pink3",                Html::from_u32(0xCD919EFF)),
+	("pink4",                Html::from_u32(0x8B636CFF)),
+	("plum",                 Html::from_u32(0xDDA0DDFF)),
+	("plum1",                Html::from_u32(0xFFBBFFFF)),
+	("plum2",                Html::from_u32(0xEEAEEEFF)),
+	("plum3",                Html::from_u32(0xCD96CDFF)),
+	("plum4",                Html::from_u32(0x8B668BFF)),
+	("powderblue",           Html::from_u32(0xB0E0E6FF)),
+	("purple",               Html::from_u32(0xA020F0FF)),
+	("purple1",              Html::from_u32(0x9B30FFFF)),
+	("purple2",              Html::from_u32(0x912CEEFF)),
+	("purple3",              Html::from_u32(0x7D26CDFF)),
+	("purple4",              Html::from_u32(0x551A8BFF)),
+	("red",                  Html::from_u32(0xFF0000FF)),
+	("red1",                 Html::from_u32(0xFF0000FF)),
+	("red2",                 Html::from_u32(0xEE0000FF)),
+	("red3",                 Html::from_u32(0xCD0000FF)),
+	("red4",                 Html::from_u32(0x8B0000FF)),
+	("rosybrown",            Html::from_u32(0xBC8F8FFF)),
+	("rosybrown1",           Html::from_u32(0xFFC1C1FF)),
+	("rosybrown2",           Html::from_u32(0xEEB4B4FF)),
+	("rosybrown3",           Html::from_u32(0xCD9B9BFF)),
+	("rosybrown4",           Html::from_u32(0x8B6969FF)),
+	("royalblue",            Html::from_u32(0x4169E1FF)),
+	("royalblue1",           Html::from_u32(0x4876FFFF)),
+	("royalblue2",           Html::from_u32(0x436EEEFF)),
+	("royalblue3",           Html::from_u32(0x3A5FCDFF)),
+	("royalblue4",           Html::from_u32(0x27408BFF)),
+	("saddlebrown",          Html::from_u32(0x8B4513FF)),
+	("salmon",               Html::from_u32(0xFA8072FF)),
+	("salmon1",              Html::from_u32(0xFF8C69FF)),
+	("salmon2",              Html::from_u32(0xEE8262FF)),
+	("salmon3",              Html::from_u32(0xCD7054FF)),
+	("salmon4",              Html::from_u32(0x8B4C39FF)),
+	("sandybrown",           Html::from_u32(0xF4A460FF)),
+	("seagreen",             Html::from_u32(0x2E8B57FF)),
+	("seagreen1",            Html::from_u32(0x54FF9FFF)),
+	("seagreen2",            Html::from_u32(0x4EEE94FF)),
+	("seagreen3",            Html::from_u32(0x43CD80FF)),
+	("seagreen4",            Html::from_u32(0x2E8B57FF)),
+	("seashell",             Html::from_u32(0xFFF5EEFF)),
+	("seashell1",            Html::from_u32(0xFFF5EEFF)),
+	("seashell2",            Html::from_u32(0xEEE5DEFF)),
+	("seashell3",            Html::from_u32(0xCDC5BFFF)),
+	("seashell4",            Html::from_u32(0x8B8682FF)),
+	("sienna",               Html::from_u32(0xA0522DFF)),
+	("sienna1",              Html::from_u32(0xFF8247FF)),
+	("sienna2",              Html::from_u32(0xEE7942FF)),
+	("sienna3",              Html::from_u32(0xCD6839FF)),
+	("sienna4",              Html::from_u32(0x8B4726FF)),
+	("skyblue",              Html::from_u32(0x87CEEBFF)),
+	("skyblue1",             Html::from_u32(0x87CEFFFF)),
+	("skyblue2",             Html::from_u32(0x7EC0EEFF)),
+	("skyblue3",             Html::from_u32(0x6CA6CDFF)),
+	("skyblue4",             Html::from_u32(0x4A708BFF)),
+	("slateblue",            Html::from_u32(0x6A5ACDFF)),
+	("slateblue1",           Html::from_u32(0x836FFFFF)),
+	("slateblue2",           Html::from_u32(0x7A67EEFF)),
+	("slateblue3",           Html::from_u32(0x6959CDFF)),
+	("slateblue4",           Html::from_u32(0x473C8BFF)),
+	("slategray",            Html::from_u32(0x708090FF)),
+	("slategray1",           Html::from_u32(0xC6E2FFFF)),
+	("slategray2",           Html::from_u32(0xB9D3EEFF)),
+	("slategray3",           Html::from_u32(0x9FB6CDFF)),
+	("slategray4",           Html::from_u32(0x6C7B8BFF)),
+	("slategrey",            Html::from_u32(0x708090FF)),
+	("snow",                 Html::from_u32(0xFFFAFAFF)),
+	("snow1",                Html::from_u32(0xFFFAFAFF)),
+	("snow2",                Html::from_u32(0xEEE9E9FF)),
+	("snow3",                Html::from_u32(0xCDC9C9FF)),
+	("snow4",                Html::from_u32(0x8B8989FF)),
+	("springgreen",          Html::from_u32(0x00FF7FFF)),
+	("springgreen1",         Html::from_u32(0x00FF7FFF)),
+	("springgreen2",         Html::from_u32(0x00EE76FF)),
+	("springgreen3",         Html::from_u32(0x00CD66FF)),
+	("springgreen4",         Html::from_u32(0x008B45FF)),
+	("steelblue",            Html::from_u32(0x4682B4FF)),
+	("steelblue1",           Html::from_u32(0x63B8FFFF)),
+	("steelblue2",           Html::from_u32(0x5CACEEFF)),
+	("steelblue3",           Html::from_u32(0x4F94CDFF)),
+	("steelblue4",           Html::from_u32(0x36648BFF)),
+	("tan",                  Html::from_u32(0xD2B48CFF)),
+	("tan1",                 Html::from_u32(0xFFA54FFF)),
+	("tan2",                 Html::from_u32(0xEE9A49FF)),
+	("tan3",                 Html::from_u32(0xCD853FFF)),
+	("tan4",                 Html::from_u32(0x8B5A2BFF)),
+	("thistle",              Html::from_u32(0xD8BFD8FF)),
+	("thistle1",             Html::from_u32(0xFFE1FFFF)),
+	("thistle2",             Html::from_u32(0xEED2EEFF)),
+	("thistle3",             Html::from_u32(0xCDB5CDFF)),
+	("thistle4",             Html::from_u32(0x8B7B8BFF)),
+	("tomato",               Html::from_u32(0xFF6347FF)),
+	("tomato1",              Html::from_u32(0xFF6347FF)),
+	("tomato2",              Html::from_u32(0xEE5C42FF)),
+	("tomato3",              Html::from_u32(0xCD4F39FF)),
+	("tomato4",              Html::from_u32(0x8B3626FF)),
+	("turquoise",            Html::from_u32(0x40E0D0FF)),
+	("turquoise1",           Html::from_u32(0x00F5FFFF)),
+	("turquoise2",           Html::from_u32(0x00E5EEFF)),
+	("turquoise3",           Html::from_u32(0x00C5CDFF)),
+	("turquoise4",           Html::from_u32(0x00868BFF)),
+	("violet",               Html::from_u32(0xEE82EEFF)),
+	("violetred",            Html::from_u32(0xD02090FF)),
+	("violetred1",           Html::from_u32(0xFF3E96FF)),
+	("violetred2",           Html::from_u32(0xEE3A8CFF)),
+	("violetred3",           Html::from_u32(0xCD3278FF)),
+	("violetred4",           Html::from_u32(0x8B2252FF)),
+	("wheat",                Html::from_u32(0xF5DEB3FF)),
+	("wheat1",               Html::from_u32(0xFFE7BAFF)),
+	("wheat2",               Html::from_u32(0xEED8AEFF)),
+	("wheat3",               Html::from_u32(0xCDBA96FF)),
+	("wheat4",               Html::from_u32(0x8B7E66FF)),
+	("white",                Html::from_u32(0xFFFFFFFF)),
+	("whitesmoke",           Html::from_u32(0xF5F5F5FF)),
+	("yellow",               Html::from_u32(0xFFFF00FF)),
+	("yellow1",              Html::from_u32(0xFFFF00FF)),
+	("yellow2",              Html::from_u32(0xEEEE00FF)),
+	("yellow3",              Html::from_u32(0xCDCD00FF)),
+	("yellow4",              Html::from_u32(0x8B8B00FF)),
+	("yellowgreen",          Html::from_u32(0x9ACD32FF)),
+];
@@ -0,0 +1,969 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+//! The colours of the xkcd colour survey.
+//!
+//! This is derived from the `rgb.txt` file published alongside the survey results, i.e. the colours available in Matplotlib as e.g. `xkcd:cloudy blue`.
+//! Names are given verbatim, i.e. in lowercase and with any spaces of the survey.
+//!
+//! A few names of the survey only differ by spaces (e.g. `blue green` and `bluegreen`), yet they denote different colours.
+
+use crate::www::Html;
+
+/// The entries of the xkcd colour survey, sorted by name with spaces ignored.
+pub(super) static ENTRIES: [(&str, Html); 0x3B5] = [
+	("acid green",                 Html::from_u32(0x8FFE09FF)),
+	("adobe",                      Html::from_u32(0xBD6C48FF)),
+	("algae",                      Html::from_u32(0x54AC68FF)),
+	("algae green",                Html::from_u32(0x21C36FFF)),
+	("almost black",               Html::from_u32(0x070D0DFF)),
+	("amber",                      Html::from_u32(0xFEB308FF)),
+	("amethyst",                   Html::from_u32(0x9B5FC0FF)),
+	("apple",                      Html::from_u32(0x6ECB3CFF)),
+	("apple green",                Html::from_u32(0x76CD26FF)),
+	("apricot",                    Html::from_u32(0xFFB16DFF)),
+	("aqua",                       Html::from_u32(0x13EAC9FF)),
+	("aqua blue",                  Html::from_u32(0x02D8E9FF)),
+	("aqua green",                 Html::from_u32(0x12E193FF)),
+	("aqua marine",                Html::from_u32(0x2EE8BBFF)),
+	("aquamarine",                 Html::from_u32(0x04D8B2FF)),
+	("army green",                 Html::from_u32(0x4B5D16FF)),
+	("asparagus",                  Html::from_u32(0x77AB56FF)),
+	("aubergine",                  Html::from_u32(0x3D0734FF)),
+	("auburn",                     Html::from_u32(0x9A3001FF)),
+	("avocado",                    Html::from_u32(0x90B134FF)),
+	("avocado green",              Html::from_u32(0x87A922FF)),
+	("azul",                       Html::from_u32(0x1D5DECFF)),
+	("azure",                      Html::from_u32(0x069AF3FF)),
+	("baby blue",                  Html::from_u32(0xA2CFFEFF)),
+	("baby green",                 Html::from_u32(0x8CFF9EFF)),
+	("baby pink",                  Html::from_u32(0xFFB7CEFF)),
+	("baby poo",                   Html::from_u32(0xAB9004FF)),
+	("baby poop",                  Html::from_u32(0x937C00FF)),
+	("baby poop green",            Html::from_u32(0x8F9805FF)),
+	("baby puke green",            Html::from_u32(0xB6C406FF)),
+	("baby purple",                Html::from_u32(0xCA9BF7FF)),
+	("baby shit brown",            Html::from_u32(0xAD900DFF)),
+	("baby shit green",            Html::from_u32(0x889717FF)),
+	("banana",                     Html::from_u32(0xFFFF7EFF)),
+	("banana yellow",              Html::from_u32(0xFAFE4BFF)),
+	("barbie pink",                Html::from_u32(0xFE46A5FF)),
+	("barf green",                 Html::from_u32(0x94AC02FF)),
+	("barney",                     Html::from_u32(0xAC1DB8FF)),
+	("barney purple",              Html::from_u32(0xA00498FF)),
+	("battleship grey",            Html::from_u32(0x6B7C85FF)),
+	("beige",                      Html::from_u32(0xE6DAA6FF)),
+	("berry",                      Html::from_u32(0x990F4BFF)),
+	("bile",                       Html::from_u32(0xB5C306FF)),
+	("black",                      Html::from_u32(0x000000FF)),
+	("bland",                      Html::from_u32(0xAFA88BFF)),
+	("blood",                      Html::from_u32(0x770001FF)),
+	("blood orange",               Html::from_u32(0xFE4B03FF)),
+	("blood red",                  Html::from_u32(0x980002FF)),
+	("blue",                       Html::from_u32(0x0343DFFF)),
+	("blue/green",                 Html::from_u32(0x0F9B8EFF)),
+	("blue/grey",                  Html::from_u32(0x758DA3FF)),
+	("blue/purple",                Html::from_u32(0x5A06EFFF)),
+	("blueberry",                  Html::from_u32(0x464196FF)),
+	("blue blue",                  Html::from_u32(0x2242C7FF)),
+	("blue green",                 Html::from_u32(0x137E6DFF)),
+	("bluegreen",                  Html::from_u32(0x017A79FF)),
+	("blue grey",                  Html::from_u32(0x607C8EFF)),
+	("bluegrey",                   Html::from_u32(0x85A3B2FF)),
+	("blue purple",                Html::from_u32(0x5729CEFF)),
+	("blue violet",                Html::from_u32(0x5D06E9FF)),
+	("blue with a hint of purple", Html::from_u32(0x533CC6FF)),
+	("bluey green",                Html::from_u32(0x2BB179FF)),
+	("bluey grey",                 Html::from_u32(0x89A0B0FF)),
+	("bluey purple",               Html::from_u32(0x6241C7FF)),
+	("bluish",                     Html::from_u32(0x2976BBFF)),
+	("bluish green",               Html::from_u32(0x10A674FF)),
+	("bluish grey",                Html::from_u32(0x748B97FF)),
+	("bluish purple",              Html::from_u32(0x703BE7FF)),
+	("blurple",                    Html::from_u32(0x5539CCFF)),
+	("blush",                      Html::from_u32(0xF29E8EFF)),
+	("blush pink",                 Html::from_u32(0xFE828CFF)),
+	("booger",                     Html::from_u32(0x9BB53CFF)),
+	("booger green",               Html::from_u32(0x96B403FF)),
+	("bordeaux",                   Html::from_u32(0x7B002CFF)),
+	("boring green",               Html::from_u32(0x63B365FF)),
+	("bottle green",               Html::from_u32(0x044A05FF)),
+	("brick",                      Html::from_u32(0xA03623FF)),
+	("brick orange",               Html::from_u32(0xC14A09FF)),
+	("brick red",                  Html::from_u32(0x8F1402FF)),
+	("bright aqua",                Html::from_u32(0x0BF9EAFF)),
+	("bright blue",                Html::from_u32(0x0165FCFF)),
+	("bright cyan",                Html::from_u32(0x41FDFEFF)),
+	("bright green",               Html::from_u32(0x01FF07FF)),
+	("bright lavender",            Html::from_u32(0xC760FFFF)),
+	("bright light blue",          Html::from_u32(0x26F7FDFF)),
+	("bright light green",         Html::from_u32(0x2DFE54FF)),
+	("bright lilac",               Html::from_u32(0xC95EFBFF)),
+	("bright lime",                Html::from_u32(0x87FD05FF)),
+	("bright lime green",          Html::from_u32(0x65FE08FF)),
+	("bright magenta",             Html::from_u32(0xFF08E8FF)),
+	("bright olive",               Html::from_u32(0x9CBB04FF)),
+	("bright orange",              Html::from_u32(0xFF5B00FF)),
+	("bright pink",                Html::from_u32(0xFE01B1FF)),
+	("bright purple",              Html::from_u32(0xBE03FDFF)),
+	("bright red",                 Html::from_u32(0xFF000DFF)),
+	("bright sea green",           Html::from_u32(0x05FFA6FF)),
+	("bright sky blue",            Html::from_u32(0x02CCFEFF)),
+	("bright teal",                Html::from_u32(0x01F9C6FF)),
+	("bright turquoise",           Html::from_u32(0x0FFEF9FF)),
+	("bright violet",              Html::from_u32(0xAD0AFDFF)),
+	("bright yellow",              Html::from_u32(0xFFFD01FF)),
+	("bright yellow green",        Html::from_u32(0x9DFF00FF)),
+	("british racing green",       Html::from_u32(0x05480DFF)),
+	("bronze",                     Html::from_u32(0xA87900FF)),
+	("brown",                      Html::from_u32(0x653700FF)),
+	("brown green",                Html::from_u32(0x706C11FF)),
+	("brown grey",                 Html::from_u32(0x8D8468FF)),
+	("brownish",                   Html::from_u32(0x9C6D57FF)),
+	("brownish green",             Html::from_u32(0x6A6E09FF)),
+	("brownish grey",              Html::from_u32(0x86775FFF)),
+	("brownish orange",            Html::from_u32(0xCB7723FF)),
+	("brownish pink",              Html::from_u32(0xC27E79FF)),
+	("brownish purple",            Html::from_u32(0x76424EFF)),
+	("brownish red",               Html::from_u32(0x9E3623FF)),
+	("brownish yellow",            Html::from_u32(0xC9B003FF)),
+	("brown orange",               Html::from_u32(0xB96902FF)),
+	("brown red",                  Html::from_u32(0x922B05FF)),
+	("brown yellow",               Html::from_u32(0xB29705FF)),
+	("browny green",               Html::from_u32(0x6F6C0AFF)),
+	("browny orange",              Html::from_u32(0xCA6B02FF)),
+	("bruise",                     Html::from_u32(0x7E4071FF)),
+	("bubblegum",                  Html::from_u32(0xFF6CB5FF)),
+	("bubble gum pink",            Html::from_u32(0xFF69AFFF)),
+	("bubblegum pink",             Html::from_u32(0xFE83CCFF)),
+	("buff",                       Html::from_u32(0xFEF69EFF)),
+	("burgundy",                   Html::from_u32(0x610023FF)),
+	("burnt orange",               Html::from_u32(0xC04E01FF)),
+	("burnt red",                  Html::from_u32(0x9F2305FF)),
+	("burnt siena",                Html::from_u32(0xB75203FF)),
+	("burnt sienna",               Html::from_u32(0xB04E0FFF)),
+	("burnt umber",                Html::from_u32(0xA0450EFF)),
+	("burnt yellow",               Html::from_u32(0xD5AB09FF)),
+	("burple",                     Html::from_u32(0x6832E3FF)),
+	("butter",                     Html::from_u32(0xFFFF81FF)),
+	("butterscotch",               Html::from_u32(0xFDB147FF)),
+	("butter yellow",              Html::from_u32(0xFFFD74FF)),
+	("cadet blue",                 Html::from_u32(0x4E7496FF)),
+	("camel",                      Html::from_u32(0xC69F59FF)),
+	("camo",                       Html::from_u32(0x7F8F4EFF)),
+	("camo green",                 Html::from_u32(0x526525FF)),
+	("camouflage green",           Html::from_u32(0x4B6113FF)),
+	("canary",                     Html::from_u32(0xFDFF63FF)),
+	("canary yellow",              Html::from_u32(0xFFFE40FF)),
+	("candy pink",                 Html::from_u32(0xFF63E9FF)),
+	("caramel",                    Html::from_u32(0xAF6F09FF)),
+	("carmine",                    Html::from_u32(0x9D0216FF)),
+	("carnation",                  Html::from_u32(0xFD798FFF)),
+	("carnation pink",             Html::from_u32(0xFF7FA7FF)),
+	("carolina blue",              Html::from_u32(0x8AB8FEFF)),
+	("celadon",                    Html::from_u32(0xBEFDB7FF)),
+	("celery",                     Html::from_u32(0xC1FD95FF)),
+	("cement",                     Html::from_u32(0xA5A391FF)),
+	("cerise",                     Html::from_u32(0xDE0C62FF)),
+	("cerulean",                   Html::from_u32(0x0485D1FF)),
+	("cerulean blue",              Html::from_u32(0x056EEEFF)),
+	("charcoal",                   Html::from_u32(0x343837FF)),
+	("charcoal grey",              Html::from_u32(0x3C4142FF)),
+	("chartreuse",                 Html::from_u32(0xC1F80AFF)),
+	("cherry",                     Html::from_u32(0xCF0234FF)),
+	("cherry red",                 Html::from_u32(0xF7022AFF)),
+	("chestnut",                   Html::from_u32(0x742802FF)),
+	("chocolate",                  Html::from_u32(0x3D1C02FF)),
+	("chocolate brown",            Html::from_u32(0x411900FF)),
+	("cinnamon",                   Html::from_u32(0xAC4F06FF)),
+	("claret",                     Html::from_u32(0x680018FF)),
+	("clay",                       Html::from_u32(0xB66A50FF)),
+	("clay brown",                 Html::from_u32(0xB2713DFF)),
+	("clear blue",                 Html::from_u32(0x247AFDFF)),
+	("cloudy blue",                Html::from_u32(0xACC2D9FF)),
+	("cobalt",                     Html::from_u32(0x1E488FFF)),
+	("cobalt blue",                Html::from_u32(0x030AA7FF)),
+	("cocoa",                      Html::from_u32(0x875F42FF)),
+	("coffee",                     Html::from_u32(0xA6814CFF)),
+	("cool blue",                  Html::from_u32(0x4984B8FF)),
+	("cool green",                 Html::from_u32(0x33B864FF)),
+	("cool grey",                  Html::from_u32(0x95A3A6FF)),
+	("copper",                     Html::from_u32(0xB66325FF)),
+	("coral",                      Html::from_u32(0xFC5A50FF)),
+	("coral pink",                 Html::from_u32(0xFF6163FF)),
+	("cornflower",                 Html::from_u32(0x6A79F7FF)),
+	("cornflower blue",            Html::from_u32(0x5170D7FF)),
+	("cranberry",                  Html::from_u32(0x9E003AFF)),
+	("cream",                      Html::from_u32(0xFFFFC2FF)),
+	("creme",                      Html::from_u32(0xFFFFB6FF)),
+	("crimson",                    Html::from_u32(0x8C000FFF)),
+	("custard",                    Html::from_u32(0xFFFD78FF)),
+	("cyan",                       Html::from_u32(0x00FFFFFF)),
+	("dandelion",                  Html::from_u32(0xFEDF08FF)),
+	("dark",                       Html::from_u32(0x1B2431FF)),
+	("dark aqua",                  Html::from_u32(0x05696BFF)),
+	("dark aquamarine",            Html::from_u32(0x017371FF)),
+	("dark beige",                 Html::from_u32(0xAC9362FF)),
+	("dark blue",                  Html::from_u32(0x00035BFF)),
+	("darkblue",                   Html::from_u32(0x030764FF)),
+	("dark blue green",            Html::from_u32(0x005249FF)),
+	("dark blue grey",             Html::from_u32(0x1F3B4DFF)),
+	("dark brown",                 Html::from_u32(0x341C02FF)),
+	("dark coral",                 Html::from_u32(0xCF524EFF)),
+	("dark cream",                 Html::from_u32(0xFFF39AFF)),
+	("dark cyan",                  Html::from_u32(0x0A888AFF)),
+	("dark forest green",          Html::from_u32(0x002D04FF)),
+	("dark fuchsia",               Html::from_u32(0x9D0759FF)),
+	("dark gold",                  Html::from_u32(0xB59410FF)),
+	("dark grass green",           Html::from_u32(0x388004FF)),
+	("dark green",                 Html::from_u32(0x033500FF)),
+	("darkgreen",                  Html::from_u32(0x054907FF)),
+	("dark green blue",            Html::from_u32(0x1F6357FF)),
+	("dark grey",                  Html::from_u32(0x363737FF)),
+	("dark grey blue",             Html::from_u32(0x29465BFF)),
+	("dark hot pink",              Html::from_u32(0xD90166FF)),
+	("dark indigo",                Html::from_u32(0x1F0954FF)),
+	("darkish blue",               Html::from_u32(0x014182FF)),
+	("darkish green",              Html::from_u32(0x287C37FF)),
+	("darkish pink",               Html::from_u32(0xDA467DFF)),
+	("darkish purple",             Html::from_u32(0x751973FF)),
+	("darkish red",                Html::from_u32(0xA90308FF)),
+	("dark khaki",                 Html::from_u32(0x9B8F55FF)),
+	("dark lavender",              Html::from_u32(0x856798FF)),
+	("dark lilac",                 Html::from_u32(0x9C6DA5FF)),
+	("dark lime",                  Html::from_u32(0x84B701FF)),
+	("dark lime green",            Html::from_u32(0x7EBD01FF)),
+	("dark magenta",               Html::from_u32(0x960056FF)),
+	("dark maroon",                Html::from_u32(0x3C0008FF)),
+	("dark mauve",                 Html::from_u32(0x874C62FF)),
+	("dark mint",                  Html::from_u32(0x48C072FF)),
+	("dark mint green",            Html::from_u32(0x20C073FF)),
+	("dark mustard",               Html::from_u32(0xA88905FF)),
+	("dark navy",                  Html::from_u32(0x000435FF)),
+	("dark navy blue",             Html::from_u32(0x00022EFF)),
+	("dark olive",                 Html::from_u32(0x373E02FF)),
+	("dark olive green",           Html::from_u32(0x3C4D03FF)),
+	("dark orange",                Html::from_u32(0xC65102FF)),
+	("dark pastel green",          Html::from_u32(0x56AE57FF)),
+	("dark peach",                 Html::from_u32(0xDE7E5DFF)),
+	("dark periwinkle",            Html::from_u32(0x665FD1FF)),
+	("dark pink",                  Html::from_u32(0xCB416BFF)),
+	("dark plum",                  Html::from_u32(0x3F012CFF)),
+	("dark purple",                Html::from_u32(0x35063EFF)),
+	("dark red",                   Html::from_u32(0x840000FF)),
+	("dark rose",                  Html::from_u32(0xB5485DFF)),
+	("dark royal blue",            Html::from_u32(0x02066FFF)),
+	("dark sage",                  Html::from_u32(0x598556FF)),
+	("dark salmon",                Html::from_u32(0xC85A53FF)),
+	("dark sand",                  Html::from_u32(0xA88F59FF)),
+	("dark seafoam",               Html::from_u32(0x1FB57AFF)),
+	("dark seafoam green",         Html::from_u32(0x3EAF76FF)),
+	("dark sea green",             Html::from_u32(0x11875DFF)),
+	("dark sky blue",              Html::from_u32(0x448EE4FF)),
+	("dark slate blue",            Html::from_u32(0x214761FF)),
+	("dark tan",                   Html::from_u32(0xAF884AFF)),
+	("dark taupe",                 Html::from_u32(0x7F684EFF)),
+	("dark teal",                  Html::from_u32(0x014D4EFF)),
+	("dark turquoise",             Html::from_u32(0x045C5AFF)),
+	("dark violet",                Html::from_u32(0x34013FFF)),
+	("dark yellow",                Html::from_u32(0xD5B60AFF)),
+	("dark yellow green",          Html::from_u32(0x728F02FF)),
+	("deep aqua",                  Html::from_u32(0x08787FFF)),
+	("deep blue",                  Html::from_u32(0x040273FF)),
+	("deep brown",                 Html::from_u32(0x410200FF)),
+	("deep green",                 Html::from_u32(0x02590FFF)),
+	("deep lavender",              Html::from_u32(0x8D5EB7FF)),
+	("deep lilac",                 Html::from_u32(0x966EBDFF)),
+	("deep magenta",               Html::from_u32(0xA0025CFF)),
+	("deep orange",                Html::from_u32(0xDC4D01FF)),
+	("deep pink",                  Html::from_u32(0xCB0162FF)),
+	("deep purple",                Html::from_u32(0x36013FFF)),
+	("deep red",                   Html::from_u32(0x9A0200FF)),
+	("deep rose",                  Html::from_u32(0xC74767FF)),
+	("deep sea blue",              Html::from_u32(0x015482FF)),
+	("deep sky blue",              Html::from_u32(0x0D75F8FF)),
+	("deep teal",                  Html::from_u32(0x00555AFF)),
+	("deep turquoise",             Html::from_u32(0x017374FF)),
+	("deep violet",                Html::from_u32(0x490648FF)),
+	("denim",                      Html::from_u32(0x3B638CFF)),
+	("denim blue",                 Html::from_u32(0x3B5B92FF)),
+	("desert",                     Html::from_u32(0xCCAD60FF)),
+	("diarrhea",                   Html::from_u32(0x9F8303FF)),
+	("dirt",                       Html::from_u32(0x8A6E45FF)),
+	("dirt brown",                 Html::from_u32(0x836539FF)),
+	("dirty blue",                 Html::from_u32(0x3F829DFF)),
+	("dirty green",                Html::from_u32(0x667E2CFF)),
+	("dirty orange",               Html::from_u32(0xC87606FF)),
+	("dirty pink",                 Html::from_u32(0xCA7B80FF)),
+	("dirty purple",               Html::from_u32(0x734A65FF)),
+	("dirty yellow",               Html::from_u32(0xCDC50AFF)),
+	("dodger blue",                Html::from_u32(0x3E82FCFF)),
+	("drab",                       Html::from_u32(0x828344FF)),
+	("drab green",                 Html::from_u32(0x749551FF)),
+	("dried blood",                Html::from_u32(0x4B0101FF)),
+	("duck egg blue",              Html::from_u32(0xC3FBF4FF)),
+	("dull blue",                  Html::from_u32(0x49759CFF)),
+	("dull brown",                 Html::from_u32(0x876E4BFF)),
+	("dull green",                 Html::from_u32(0x74A662FF)),
+	("dull orange",                Html::from_u32(0xD8863BFF)),
+	("dull pink",                  Html::from_u32(0xD5869DFF)),
+	("dull purple",                Html::from_u32(0x84597EFF)),
+	("dull red",                   Html::from_u32(0xBB3F3FFF)),
+	("dull teal",                  Html::from_u32(0x5F9E8FFF)),
+	("dull yellow",                Html::from_u32(0xEEDC5BFF)),
+	("dusk",                       Html::from_u32(0x4E5481FF)),
+	("dusk blue",                  Html::from_u32(0x26538DFF)),
+	("dusky blue",                 Html::from_u32(0x475F94FF)),
+	("dusky pink",                 Html::from_u32(0xCC7A8BFF)),
+	("dusky purple",               Html::from_u32(0x895B7BFF)),
+	("dusky rose",                 Html::from_u32(0xBA6873FF)),
+	("dust",                       Html::from_u32(0xB2996EFF)),
+	("dusty blue",                 Html::from_u32(0x5A86ADFF)),
+	("dusty green",                Html::from_u32(0x76A973FF)),
+	("dusty lavender",             Html::from_u32(0xAC86A8FF)),
+	("dusty orange",               Html::from_u32(0xF0833AFF)),
+	("dusty pink",                 Html::from_u32(0xD58A94FF)),
+	("dusty purple",               Html::from_u32(0x825F87FF)),
+	("dusty red",                  Html::from_u32(0xB9484EFF)),
+	("dusty rose",                 Html::from_u32(0xC0737AFF)),
+	("dusty teal",                 Html::from_u32(0x4C9085FF)),
+	("earth",                      Html::from_u32(0xA2653EFF)),
+	("easter green",               Html::from_u32(0x8CFD7EFF)),
+	("easter purple",              Html::from_u32(0xC071FEFF)),
+	("ecru",                       Html::from_u32(0xFEFFCAFF)),
+	("eggplant",                   Html::from_u32(0x380835FF)),
+	("eggplant purple",            Html::from_u32(0x430541FF)),
+	("egg shell",                  Html::from_u32(0xFFFCC4FF)),
+	("eggshell",                   Html::from_u32(0xFFFFD4FF)),
+	("eggshell blue",              Html::from_u32(0xC4FFF7FF)),
+	("electric blue",              Html::from_u32(0x0652FFFF)),
+	("electric green",             Html::from_u32(0x21FC0DFF)),
+	("electric lime",              Html::from_u32(0xA8FF04FF)),
+	("electric pink",              Html::from_u32(0xFF0490FF)),
+	("electric purple",            Html::from_u32(0xAA23FFFF)),
+	("emerald",                    Html::from_u32(0x01A049FF)),
+	("emerald green",              Html::from_u32(0x028F1EFF)),
+	("evergreen",                  Html::from_u32(0x05472AFF)),
+	("faded blue",                 Html::from_u32(0x658CBBFF)),
+	("faded green",                Html::from_u32(0x7BB274FF)),
+	("faded orange",               Html::from_u32(0xF0944DFF)),
+	("faded pink",                 Html::from_u32(0xDE9DACFF)),
+	("faded purple",               Html::from_u32(0x916E99FF)),
+	("faded red",                  Html::from_u32(0xD3494EFF)),
+	("faded yellow",               Html::from_u32(0xFEFF7FFF)),
+	("fawn",                       Html::from_u32(0xCFAF7BFF)),
+	("fern",                       Html::from_u32(0x63A950FF)),
+	("fern green",                 Html::from_u32(0x548D44FF)),
+	("fire engine red",            Html::from_u32(0xFE0002FF)),
+	("flat blue",                  Html::from_u32(0x3C73A8FF)),
+	("flat green",                 Html::from_u32(0x699D4CFF)),
+	("fluorescent green",          Html::from_u32(0x08FF08FF)),
+	("fluro green",                Html::from_u32(0x0AFF02FF)),
+	("foam green",                 Html::from_u32(0x90FDA9FF)),
+	("forest",                     Html::from_u32(0x0B5509FF)),
+	("forest green",               Html::from_u32(0x06470CFF)),
+	("forrest green",              Html::from_u32(0x154406FF)),
+	("french blue",                Html::from_u32(0x436BADFF)),
+	("fresh green",                Html::from_u32(0x69D84FFF)),
+	("frog green",                 Html::from_u32(0x58BC08FF)),
+	("fuchsia",                    Html::from_u32(0xED0DD9FF)),
+	("gold",                       Html::from_u32(0xDBB40CFF)),
+	("golden",                     Html::from_u32(0xF5BF03FF)),
+	("golden brown",               Html::from_u32(0xB27A01FF)),
+	("golden rod",                 Html::from_u32(0xF9BC08FF)),
+	("goldenrod",                  Html::from_u32(0xFAC205FF)),
+	("golden yellow",              Html::from_u32(0xFEC615FF)),
+	("grape",                      Html::from_u32(0x6C3461FF)),
+	("grapefruit",                 Html::from_u32(0xFD5956FF)),
+	("grape purple",               Html::from_u32(0x5D1451FF)),
+	("grass",                      Html::from_u32(0x5CAC2DFF)),
+	("grass green",                Html::from_u32(0x3F9B0BFF)),
+	("grassy green",               Html::from_u32(0x419C03FF)),
+	("green",                      Html::from_u32(0x15B01AFF)),
+	("green/blue",                 Html::from_u32(0x01C08DFF)),
+	("green/yellow",               Html::from_u32(0xB5CE08FF)),
+	("green apple",                Html::from_u32(0x5EDC1FFF)),
+	("green blue",                 Html::from_u32(0x06B48BFF)),
+	("greenblue",                  Html::from_u32(0x23C48BFF)),
+	("green brown",                Html::from_u32(0x544E03FF)),
+	("green grey",                 Html::from_u32(0x77926FFF)),
+	("greenish",                   Html::from_u32(0x40A368FF)),
+	("greenish beige",             Html::from_u32(0xC9D179FF)),
+	("greenish blue",              Html::from_u32(0x0B8B87FF)),
+	("greenish brown",             Html::from_u32(0x696112FF)),
+	("greenish cyan",              Html::from_u32(0x2AFEB7FF)),
+	("greenish grey",              Html::from_u32(0x96AE8DFF)),
+	("greenish tan",               Html::from_u32(0xBCCB7AFF)),
+	("greenish teal",              Html::from_u32(0x32BF84FF)),
+	("greenish turquoise",         Html::from_u32(0x00FBB0FF)),
+	("greenish yellow",            Html::from_u32(0xCDFD02FF)),
+	("green teal",                 Html::from_u32(0x0CB577FF)),
+	("greeny blue",                Html::from_u32(0x42B395FF)),
+	("greeny brown",               Html::from_u32(0x696006FF)),
+	("green yellow",               Html::from_u32(0xC9FF27FF)),
+	("greeny grey",                Html::from_u32(0x7EA07AFF)),
+	("greeny yellow",              Html::from_u32(0xC6F808FF)),
+	("grey",                       Html::from_u32(0x929591FF)),
+	("grey/blue",                  Html::from_u32(0x647D8EFF)),
+	("grey/green",                 Html::from_u32(0x86A17DFF)),
+	("grey blue",                  Html::from_u32(0x6B8BA4FF)),
+	("greyblue",                   Html::from_u32(0x77A1B5FF)),
+	("grey brown",                 Html::from_u32(0x7F7053FF)),
+	("grey green",                 Html::from_u32(0x789B73FF)),
+	("greyish",                    Html::from_u32(0xA8A495FF)),
+	("greyish blue",               Html::from_u32(0x5E819DFF)),
+	("greyish brown",              Html::from_u32(0x7A6A4FFF)),
+	("greyish green",              Html::from_u32(0x82A67DFF)),
+	("greyish pink",               Html::from_u32(0xC88D94FF)),
+	("greyish purple",             Html::from_u32(0x887191FF)),
+	("greyish teal",               Html::from_u32(0x719F91FF)),
+	("grey pink",                  Html::from_u32(0xC3909BFF)),
+	("grey purple",                Html::from_u32(0x826D8CFF)),
+	("grey teal",                  Html::from_u32(0x5E9B8AFF)),
+	("gross green",                Html::from_u32(0xA0BF16FF)),
+	("gunmetal",                   Html::from_u32(0x536267FF)),
+	("hazel",                      Html::from_u32(0x8E7618FF)),
+	("heather",                    Html::from_u32(0xA484ACFF)),
+	("heliotrope",                 Html::from_u32(0xD94FF5FF)),
+	("highlighter green",          Html::from_u32(0x1BFC06FF)),
+	("hospital green",             Html::from_u32(0x9BE5AAFF)),
+	("hot green",                  Html::from_u32(0x25FF29FF)),
+	("hot magenta",                Html::from_u32(0xF504C9FF)),
+	("hot pink",                   Html::from_u32(0xFF028DFF)),
+	("hot purple",                 Html::from_u32(0xCB00F5FF)),
+	("hunter green",               Html::from_u32(0x0B4008FF)),
+	("ice",                        Html::from_u32(0xD6FFFAFF)),
+	("ice blue",                   Html::from_u32(0xD7FFFEFF)),
+	("icky green",                 Html::from_u32(0x8FAE22FF)),
+	("indian red",                 Html::from_u32(0x850E04FF)),
+	("indigo",                     Html::from_u32(0x380282FF)),
+	("indigo blue",                Html::from_u32(0x3A18B1FF)),
+	("iris",                       Html::from_u32(0x6258C4FF)),
+	("irish green",                Html::from_u32(0x019529FF)),
+	("ivory",                      Html::from_u32(0xFFFFCBFF)),
+	("jade",                       Html::from_u32(0x1FA774FF)),
+	("jade green",                 Html::from_u32(0x2BAF6AFF)),
+	("jungle green",               Html::from_u32(0x048243FF)),
+	("kelley green",               Html::from_u32(0x009337FF)),
+	("kelly green",                Html::from_u32(0x02AB2EFF)),
+	("kermit green",               Html::from_u32(0x5CB200FF)),
+	("key lime",                   Html::from_u32(0xAEFF6EFF)),
+	("khaki",                      Html::from_u32(0xAAA662FF)),
+	("khaki green",                Html::from_u32(0x728639FF)),
+	("kiwi",                       Html::from_u32(0x9CEF43FF)),
+	("kiwi green",                 Html::from_u32(0x8EE53FFF)),
+	("lavender",                   Html::from_u32(0xC79FEFFF)),
+	("lavender blue",              Html::from_u32(0x8B88F8FF)),
+	("lavender pink",              Html::from_u32(0xDD85D7FF)),
+	("lawn green",                 Html::from_u32(0x4DA409FF)),
+	("leaf",                       Html::from_u32(0x71AA34FF)),
+	("leaf green",                 Html::from_u32(0x5CA904FF)),
+	("leafy green",                Html::from_u32(0x51B73BFF)),
+	("leather",                    Html::from_u32(0xAC7434FF)),
+	("lemon",                      Html::from_u32(0xFDFF52FF)),
+	("lemon green",                Html::from_u32(0xADF802FF)),
+	("lemon lime",                 Html::from_u32(0xBFFE28FF)),
+	("lemon yellow",               Html::from_u32(0xFDFF38FF)),
+	("lichen",                     Html::from_u32(0x8FB67BFF)),
+	("light aqua",                 Html::from_u32(0x8CFFDBFF)),
+	("light aquamarine",           Html::from_u32(0x7BFDC7FF)),
+	("light beige",                Html::from_u32(0xFFFEB6FF)),
+	("light blue",                 Html::from_u32(0x95D0FCFF)),
+	("lightblue",                  Html::from_u32(0x7BC8F6FF)),
+	("light blue green",           Html::from_u32(0x7EFBB3FF)),
+	("light blue grey",            Html::from_u32(0xB7C9E2FF)),
+	("light bluish green",         Html::from_u32(0x76FDA8FF)),
+	("light bright green",         Html::from_u32(0x53FE5CFF)),
+	("light brown",                Html::from_u32(0xAD8150FF)),
+	("light burgundy",             Html::from_u32(0xA8415BFF)),
+	("light cyan",                 Html::from_u32(0xACFFFCFF)),
+	("light eggplant",             Html::from_u32(0x894585FF)),
+	("lighter green",              Html::from_u32(0x75FD63FF)),
+	("lighter purple",             Html::from_u32(0xA55AF4FF)),
+	("light forest green",         Html::from_u32(0x4F9153FF)),
+	("light gold",                 Html::from_u32(0xFDDC5CFF)),
+	("light grass green",          Html::from_u32(0x9AF764FF)),
+	("light green",                Html::from_u32(0x96F97BFF)),
+	("lightgreen",                 Html::from_u32(0x76FF7BFF)),
+	("light green blue",           Html::from_u32(0x56FCA2FF)),
+	("light greenish blue",        Html::from_u32(0x63F7B4FF)),
+	("light grey",                 Html::from_u32(0xD8DCD6FF)),
+	("light grey blue",            Html::from_u32(0x9DBCD4FF)),
+	("light grey green",           Html::from_u32(0xB7E1A1FF)),
+	("light indigo",               Html::from_u32(0x6D5ACFFF)),
+	("lightish blue",              Html::from_u32(0x3D7AFDFF)),
+	("lightish green",             Html::from_u32(0x61E160FF)),
+	("lightish purple",            Html::from_u32(0xA552E6FF)),
+	("lightish red",               Html::from_u32(0xFE2F4AFF)),
+	("light khaki",                Html::from_u32(0xE6F2A2FF)),
+	("light lavendar",             Html::from_u32(0xEFC0FEFF)),
+	("light lavender",             Html::from_u32(0xDFC5FEFF)),
+	("light light blue",           Html::from_u32(0xCAFFFBFF)),
+	("light light green",          Html::from_u32(0xC8FFB0FF)),
+	("light lilac",                Html::from_u32(0xEDC8FFFF)),
+	("light lime",                 Html::from_u32(0xAEFD6CFF)),
+	("light lime green",           Html::from_u32(0xB9FF66FF)),
+	("light magenta",              Html::from_u32(0xFA5FF7FF)),
+	("light maroon",               Html::from_u32(0xA24857FF)),
+	("light mauve",                Html::from_u32(0xC292A1FF)),
+	("light mint",                 Html::from_u32(0xB6FFBBFF)),
+	("light mint green",           Html::from_u32(0xA6FBB2FF)),
+	("light moss green",           Html::from_u32(0xA6C875FF)),
+	("light mustard",              Html::from_u32(0xF7D560FF)),
+	("light navy",                 Html::from_u32(0x155084FF)),
+	("light navy blue",            Html::from_u32(0x2E5A88FF)),
+	("light neon green",           Html::from_u32(0x4EFD54FF)),
+	("light olive",                Html::from_u32(0xACBF69FF)),
+	("light olive green",          Html::from_u32(0xA4BE5CFF)),
+	("light orange",               Html::from_u32(0xFDAA48FF)),
+	("light pastel green",         Html::from_u32(0xB2FBA5FF)),
+	("light peach",                Html::from_u32(0xFFD8B1FF)),
+	("light pea green",            Html::from_u32(0xC4FE82FF)),
+	("light periwinkle",           Html::from_u32(0xC1C6FCFF)),
+	("light pink",                 Html::from_u32(0xFFD1DFFF)),
+	("light plum",                 Html::from_u32(0x9D5783FF)),
+	("light purple",               Html::from_u32(0xBF77F6FF)),
+	("light red",                  Html::from_u32(0xFF474CFF)),
+	("light rose",                 Html::from_u32(0xFFC5CBFF)),
+	("light royal blue",           Html::from_u32(0x3A2EFEFF)),
+	("light sage",                 Html::from_u32(0xBCECACFF)),
+	("light salmon",               Html::from_u32(0xFEA993FF)),
+	("light seafoam",              Html::from_u32(0xA0FEBFFF)),
+	("light seafoam green",        Html::from_u32(0xA7FFB5FF)),
+	("light sea green",            Html::from_u32(0x98F6B0FF)),
+	("light sky blue",             Html::from_u32(0xC6FCFFFF)),
+	("light tan",                  Html::from_u32(0xFBEEACFF)),
+	("light teal",                 Html::from_u32(0x90E4C1FF)),
+	("light turquoise",            Html::from_u32(0x7EF4CCFF)),
+	("light urple",                Html::from_u32(0xB36FF6FF)),
+	("light violet",               Html::from_u32(0xD6B4FCFF)),
+	("light yellow",               Html::from_u32(0xFFFE7AFF)),
+	("light yellow green",         Html::from_u32(0xCCFD7FFF)),
+	("light yellowish green",      Html::from_u32(0xC2FF89FF)),
+	("lilac",                      Html::from_u32(0xCEA2FDFF)),
+	("liliac",                     Html::from_u32(0xC48EFDFF)),
+	("lime",                       Html::from_u32(0xAAFF32FF)),
+	("lime green",                 Html::from_u32(0x89FE05FF)),
+	("lime yellow",                Html::from_u32(0xD0FE1DFF)),
+	("lipstick",                   Html::from_u32(0xD5174EFF)),
+	("lipstick red",               Html::from_u32(0xC0022FFF)),
+	("macaroni and cheese",        Html::from_u32(0xEFB435FF)),
+	("magenta",                    Html::from_u32(0xC20078FF)),
+	("mahogany",                   Html::from_u32(0x4A0100FF)),
+	("maize",                      Html::from_u32(0xF4D054FF)),
+	("mango",                      Html::from_u32(0xFFA62BFF)),
+	("manilla",                    Html::from_u32(0xFFFA86FF)),
+	("marigold",                   Html::from_u32(0xFCC006FF)),
+	("marine",                     Html::from_u32(0x042E60FF)),
+	("marine blue",                Html::from_u32(0x01386AFF)),
+	("maroon",                     Html::from_u32(0x650021FF)),
+	("mauve",                      Html::from_u32(0xAE7181FF)),
+	("medium blue",                Html::from_u32(0x2C6FBBFF)),
+	("medium brown",               Html::from_u32(0x7F5112FF)),
+	("medium green",               Html::from_u32(0x39AD48FF)),
+	("medium grey",                Html::from_u32(0x7D7F7CFF)),
+	("medium pink",                Html::from_u32(0xF36196FF)),
+	("medium purple",              Html::from_u32(0x9E43A2FF)),
+	("melon",                      Html::from_u32(0xFF7855FF)),
+	("merlot",                     Html::from_u32(0x730039FF)),
+	("metallic blue",              Html::from_u32(0x4F738EFF)),
+	("mid blue",                   Html::from_u32(0x276AB3FF)),
+	("mid green",                  Html::from_u32(0x50A747FF)),
+	("midnight",                   Html::from_u32(0x03012DFF)),
+	("midnight blue",              Html::from_u32(0x020035FF)),
+	("midnight purple",            Html::from_u32(0x280137FF)),
+	("military green",             Html::from_u32(0x667C3EFF)),
+	("milk chocolate",             Html::from_u32(0x7F4E1EFF)),
+	("mint",                       Html::from_u32(0x9FFEB0FF)),
+	("mint green",                 Html::from_u32(0x8FFF9FFF)),
+	("minty green",                Html::from_u32(0x0BF77DFF)),
+	("mocha",                      Html::from_u32(0x9D7651FF)),
+	("moss",                       Html::from_u32(0x769958FF)),
+	("moss green",                 Html::from_u32(0x658B38FF)),
+	("mossy green",                Html::from_u32(0x638B27FF)),
+	("mud",                        Html::from_u32(0x735C12FF)),
+	("mud brown",                  Html::from_u32(0x60460FFF)),
+	("muddy brown",                Html::from_u32(0x886806FF)),
+	("muddy green",                Html::from_u32(0x657432FF)),
+	("muddy yellow",               Html::from_u32(0xBFAC05FF)),
+	("mud green",                  Html::from_u32(0x606602FF)),
+	("mulberry",                   Html::from_u32(0x920A4EFF)),
+	("murky green",                Html::from_u32(0x6C7A0EFF)),
+	("mushroom",                   Html::from_u32(0xBA9E88FF)),
+	("mustard",                    Html::from_u32(0xCEB301FF)),
+	("mustard brown",              Html::from_u32(0xAC7E04FF)),
+	("mustard green",              Html::from_u32(0xA8B504FF)),
+	("mustard yellow",             Html::from_u32(0xD2BD0AFF)),
+	("muted blue",                 Html::from_u32(0x3B719FFF)),
+	("muted green",                Html::from_u32(0x5FA052FF)),
+	("muted pink",                 Html::from_u32(0xD1768FFF)),
+	("muted purple",               Html::from_u32(0x805B87FF)),
+	("nasty green",                Html::from_u32(0x70B23FFF)),
+	("navy",                       Html::from_u32(0x01153EFF)),
+	("navy blue",                  Html::from_u32(0x001146FF)),
+	("navy green",                 Html::from_u32(0x35530AFF)),
+	("neon blue",                  Html::from_u32(0x04D9FFFF)),
+	("neon green",                 Html::from_u32(0x0CFF0CFF)),
+	("neon pink",                  Html::from_u32(0xFE019AFF)),
+	("neon purple",                Html::from_u32(0xBC13FEFF)),
+	("neon red",                   Html::from_u32(0xFF073AFF)),
+	("neon yellow",                Html::from_u32(0xCFFF04FF)),
+	("nice blue",                  Html::from_u32(0x107AB0FF)),
+	("night blue",                 Html::from_u32(0x040348FF)),
+	("ocean",                      Html::from_u32(0x017B92FF)),
+	("ocean blue",                 Html::from_u32(0x03719CFF)),
+	("ocean green",                Html::from_u32(0x3D9973FF)),
+	("ocher",                      Html::from_u32(0xBF9B0CFF)),
+	("ochre",                      Html::from_u32(0xBF9005FF)),
+	("ocre",                       Html::from_u32(0xC69C04FF)),
+	("off blue",                   Html::from_u32(0x5684AEFF)),
+	("off green",                  Html::from_u32(0x6BA353FF)),
+	("off white",                  Html::from_u32(0xFFFFE4FF)),
+	("off yellow",                 Html::from_u32(0xF1F33FFF)),
+	("old pink",                   Html::from_u32(0xC77986FF)),
+	("old rose",                   Html::from_u32(0xC87F89FF)),
+	("olive",                      Html::from_u32(0x6E750EFF)),
+	("olive brown",                Html::from_u32(0x645403FF)),
+	("olive drab",                 Html::from_u32(0x6F7632FF)),
+	("olive green",                Html::from_u32(0x677A04FF)),
+	("olive yellow",               Html::from_u32(0xC2B709FF)),
+	("orange",                     Html::from_u32(0xF97306FF)),
+	("orange brown",               Html::from_u32(0xBE6400FF)),
+	("orangeish",                  Html::from_u32(0xFD8D49FF)),
+	("orange pink",                Html::from_u32(0xFF6F52FF)),
+	("orange red",                 Html::from_u32(0xFD411EFF)),
+	("orangered",                  Html::from_u32(0xFE420FFF)),
+	("orangey brown",              Html::from_u32(0xB16002FF)),
+	("orange yellow",              Html::from_u32(0xFFAD01FF)),
+	("orangey red",                Html::from_u32(0xFA4224FF)),
+	("orangey yellow",             Html::from_u32(0xFDB915FF)),
+	("orangish",                   Html::from_u32(0xFC824AFF)),
+	("orangish brown",             Html::from_u32(0xB25F03FF)),
+	("orangish red",               Html::from_u32(0xF43605FF)),
+	("orchid",                     Html::from_u32(0xC875C4FF)),
+	("pale",                       Html::from_u32(0xFFF9D0FF)),
+	("pale aqua",                  Html::from_u32(0xB8FFEBFF)),
+	("pale blue",                  Html::from_u32(0xD0FEFEFF)),
+	("pale brown",                 Html::from_u32(0xB1916EFF)),
+	("pale cyan",                  Html::from_u32(0xB7FFFAFF)),
+	("pale gold",                  Html::from_u32(0xFDDE6CFF)),
+	("pale green",                 Html::from_u32(0xC7FDB5FF)),
+	("pale grey",                  Html::from_u32(0xFDFDFEFF)),
+	("pale lavender",              Html::from_u32(0xEECFFEFF)),
+	("pale light green",           Html::from_u32(0xB1FC99FF)),
+	("pale lilac",                 Html::from_u32(0xE4CBFFFF)),
+	("pale lime",                  Html::from_u32(0xBEFD73FF)),
+	("pale lime green",            Html::from_u32(0xB1FF65FF)),
+	("pale magenta",               Html::from_u32(0xD767ADFF)),
+	("pale mauve",                 Html::from_u32(0xFED0FCFF)),
+	("pale olive",                 Html::from_u32(0xB9CC81FF)),
+	("pale olive green",           Html::from_u32(0xB1D27BFF)),
+	("pale orange",                Html::from_u32(0xFFA756FF)),
+	("pale peach",                 Html::from_u32(0xFFE5ADFF)),
+	("pale pink",                  Html::from_u32(0xFFCFDCFF)),
+	("pale purple",                Html::from_u32(0xB790D4FF)),
+	("pale red",                   Html::from_u32(0xD9544DFF)),
+	("pale rose",                  Html::from_u32(0xFDC1C5FF)),
+	("pale salmon",                Html::from_u32(0xFFB19AFF)),
+	("pale sky blue",              Html::from_u32(0xBDF6FEFF)),
+	("pale teal",                  Html::from_u32(0x82CBB2FF)),
+	("pale turquoise",             Html::from_u32(0xA5FBD5FF)),
+	("pale violet",                Html::from_u32(0xCEAEFAFF)),
+	("pale yellow",                Html::from_u32(0xFFFF84FF)),
+	("parchment",                  Html::from_u32(0xFEFCAFFF)),
+	("pastel blue",                Html::from_u32(0xA2BFFEFF)),
+	("pastel green",               Html::from_u32(0xB0FF9DFF)),
+	("pastel orange",              Html::from_u32(0xFF964FFF)),
+	("pastel pink",                Html::from_u32(0xFFBACDFF)),
+	("pastel purple",              Html::from_u32(0xCAA0FFFF)),
+	("pastel red",                 Html::from_u32(0xDB5856FF)),
+	("pastel yellow",              Html::from_u32(0xFFFE71FF)),
+	("pea",                        Html::from_u32(0xA4BF20FF)),
+	("peach",                      Html::from_u32(0xFFB07CFF)),
+	("peachy pink",                Html::from_u32(0xFF9A8AFF)),
+	("peacock blue",               Html::from_u32(0x016795FF)),
+	("pea green",                  Html::from_u32(0x8EAB12FF)),
+	("pear",                       Html::from_u32(0xCBF85FFF)),
+	("pea soup",                   Html::from_u32(0x929901FF)),
+	("pea soup green",             Html::from_u32(0x94A617FF)),
+	("periwinkle",                 Html::from_u32(0x8E82FEFF)),
+	("periwinkle blue",            Html::from_u32(0x8F99FBFF)),
+	("perrywinkle",                Html::from_u32(0x8F8CE7FF)),
+	("petrol",                     Html::from_u32(0x005F6AFF)),
+	("pig pink",                   Html::from_u32(0xE78EA5FF)),
+	("pine",                       Html::from_u32(0x2B5D34FF)),
+	("pine green",                 Html::from_u32(0x0A481EFF)),
+	("pink",                       Html::from_u32(0xFF81C0FF)),
+	("pink/purple",                Html::from_u32(0xEF1DE7FF)),
+	("pinkish",                    Html::from_u32(0xD46A7EFF)),
+	("pinkish brown",              Html::from_u32(0xB17261FF)),
+	("pinkish grey",               Html::from_u32(0xC8ACA9FF)),
+	("pinkish orange",             Html::from_u32(0xFF724CFF)),
+	("pinkish purple",             Html::from_u32(0xD648D7FF)),
+	("pinkish red",                Html::from_u32(0xF10C45FF)),
+	("pinkish tan",                Html::from_u32(0xD99B82FF)),
+	("pink purple",                Html::from_u32(0xDB4BDAFF)),
+	("pink red",                   Html::from_u32(0xF5054FFF)),
+	("pinky",                      Html::from_u32(0xFC86AAFF)),
+	("pinky purple",               Html::from_u32(0xC94CBEFF)),
+	("pinky red",                  Html::from_u32(0xFC2647FF)),
+	("piss yellow",                Html::from_u32(0xDDD618FF)),
+	("pistachio",                  Html::from_u32(0xC0FA8BFF)),
+	("plum",                       Html::from_u32(0x580F41FF)),
+	("plum purple",                Html::from_u32(0x4E0550FF)),
+	("poison green",               Html::from_u32(0x40FD14FF)),
+	("poo",                        Html::from_u32(0x8F7303FF)),
+	("poo brown",                  Html::from_u32(0x885F01FF)),
+	("poop",                       Html::from_u32(0x7F5E00FF)),
+	("poop brown",                 Html::from_u32(0x7A5901FF)),
+	("poop green",                 Html::from_u32(0x6F7C00FF)),
+	("powder blue",                Html::from_u32(0xB1D1FCFF)),
+	("powder pink",                Html::from_u32(0xFFB2D0FF)),
+	("primary blue",               Html::from_u32(0x0804F9FF)),
+	("prussian blue",              Html::from_u32(0x004577FF)),
+	("puce",                       Html::from_u32(0xA57E52FF)),
+	("puke",                       Html::from_u32(0xA5A502FF)),
+	("puke brown",                 Html::from_u32(0x947706FF)),
+	("puke green",                 Html::from_u32(0x9AAE07FF)),
+	("puke yellow",                Html::from_u32(0xC2BE0EFF)),
+	("pumpkin",                    Html::from_u32(0xE17701FF)),
+	("pumpkin orange",             Html::from_u32(0xFB7D07FF)),
+	("pure blue",                  Html::from_u32(0x0203E2FF)),
+	("purple",                     Html::from_u32(0x7E1E9CFF)),
+	("purple/blue",                Html::from_u32(0x5D21D0FF)),
+	("purple/pink",                Html::from_u32(0xD725DEFF)),
+	("purple blue",                Html::from_u32(0x632DE9FF)),
+	("purple brown",               Html::from_u32(0x673A3FFF)),
+	("purple grey",                Html::from_u32(0x866F85FF)),
+	("purpleish",                  Html::from_u32(0x98568DFF)),
+	("purpleish blue",             Html::from_u32(0x6140EFFF)),
+	("purpleish pink",             Html::from_u32(0xDF4EC8FF)),
+	("purple pink",                Html::from_u32(0xE03FD8FF)),
+	("purple red",                 Html::from_u32(0x990147FF)),
+	("purpley",                    Html::from_u32(0x8756E4FF)),
+	("purpley blue",               Html::from_u32(0x5F34E7FF)),
+	("purpley grey",               Html::from_u32(0x947E94FF)),
+	("purpley pink",               Html::from_u32(0xC83CB9FF)),
+	("purplish",                   Html::from_u32(0x94568CFF)),
+	("purplish blue",              Html::from_u32(0x601EF9FF)),
+	("purplish brown",             Html::from_u32(0x6B4247FF)),
+	("purplish grey",              Html::from_u32(0x7A687FFF)),
+	("purplish pink",              Html::from_u32(0xCE5DAEFF)),
+	("purplish red",               Html::from_u32(0xB0054BFF)),
+	("purply",                     Html::from_u32(0x983FB2FF)),
+	("purply blue",                Html::from_u32(0x661AEEFF)),
+	("purply pink",                Html::from_u32(0xF075E6FF)),
+	("putty",                      Html::from_u32(0xBEAE8AFF)),
+	("racing green",               Html::from_u32(0x014600FF)),
+	("radioactive green",          Html::from_u32(0x2CFA1FFF)),
+	("raspberry",                  Html::from_u32(0xB00149FF)),
+	("raw sienna",                 Html::from_u32(0x9A6200FF)),
+	("raw umber",                  Html::from_u32(0xA75E09FF)),
+	("really light blue",          Html::from_u32(0xD4FFFFFF)),
+	("red",                        Html::from_u32(0xE50000FF)),
+	("red brown",                  Html::from_u32(0x8B2E16FF)),
+	("reddish",                    Html::from_u32(0xC44240FF)),
+	("reddish brown",              Html::from_u32(0x7F2B0AFF)),
+	("reddish grey",               Html::from_u32(0x997570FF)),
+	("reddish orange",             Html::from_u32(0xF8481CFF)),
+	("reddish pink",               Html::from_u32(0xFE2C54FF)),
+	("reddish purple",             Html::from_u32(0x910951FF)),
+	("reddy brown",                Html::from_u32(0x6E1005FF)),
+	("red orange",                 Html::from_u32(0xFD3C06FF)),
+	("red pink",                   Html::from_u32(0xFA2A55FF)),
+	("red purple",                 Html::from_u32(0x820747FF)),
+	("red violet",                 Html::from_u32(0x9E0168FF)),
+	("red wine",                   Html::from_u32(0x8C0034FF)),
+	("rich blue",                  Html::from_u32(0x021BF9FF)),
+	("rich purple",                Html::from_u32(0x720058FF)),
+	("robin's egg",                Html::from_u32(0x6DEDFDFF)),
+	("robin's egg blue",           Html::from_u32(0x98EFF9FF)),
+	("robin egg blue",             Html::from_u32(0x8AF1FEFF)),
+	("rosa",                       Html::from_u32(0xFE86A4FF)),
+	("rose",                       Html::from_u32(0xCF6275FF)),
+	("rose pink",                  Html::from_u32(0xF7879AFF)),
+	("rose red",                   Html::from_u32(0xBE013CFF)),
+	("rosy pink",                  Html::from_u32(0xF6688EFF)),
+	("rouge",                      Html::from_u32(0xAB1239FF)),
+	("royal",                      Html::from_u32(0x0C1793FF)),
+	("royal blue",                 Html::from_u32(0x0504AAFF)),
+	("royal purple",               Html::from_u32(0x4B006EFF)),
+	("ruby",                       Html::from_u32(0xCA0147FF)),
+	("russet",                     Html::from_u32(0xA13905FF)),
+	("rust",                       Html::from_u32(0xA83C09FF)),
+	("rust brown",                 Html::from_u32(0x8B3103FF)),
+	("rust orange",                Html::from_u32(0xC45508FF)),
+	("rust red",                   Html::from_u32(0xAA2704FF)),
+	("rusty orange",               Html::from_u32(0xCD5909FF)),
+	("rusty red",                  Html::from_u32(0xAF2F0DFF)),
+	("saffron",                    Html::from_u32(0xFEB209FF)),
+	("sage",                       Html::from_u32(0x87AE73FF)),
+	("sage green",                 Html::from_u32(0x88B378FF)),
+	("salmon",                     Html::from_u32(0xFF796CFF)),
+	("salmon pink",                Html::from_u32(0xFE7B7CFF)),
+	("sand",                       Html::from_u32(0xE2CA76FF)),
+	("sand brown",                 Html::from_u32(0xCBA560FF)),
+	("sandstone",                  Html::from_u32(0xC9AE74FF)),
+	("sandy",                      Html::from_u32(0xF1DA7AFF)),
+	("sandy brown",                Html::from_u32(0xC4A661FF)),
+	("sand yellow",                Html::from_u32(0xFCE166FF)),
+	("sandy yellow",               Html::from_u32(0xFDEE73FF)),
+	("sap green",                  Html::from_u32(0x5C8B15FF)),
+	("sapphire",                   Html::from_u32(0x2138ABFF)),
+	("scarlet",                    Html::from_u32(0xBE0119FF)),
+	("sea",                        Html::from_u32(0x3C9992FF)),
+	("sea blue",                   Html::from_u32(0x047495FF)),
+	("seafoam",                    Html::from_u32(0x80F9ADFF)),
+	("seafoam blue",               Html::from_u32(0x78D1B6FF)),
+	("seafoam green",              Html::from_u32(0x7AF9ABFF)),
+	("sea green",                  Html::from_u32(0x53FCA1FF)),
+	("seaweed",                    Html::from_u32(0x18D17BFF)),
+	("seaweed green",              Html::from_u32(0x35AD6BFF)),
+	("sepia",                      Html::from_u32(0x985E2BFF)),
+	("shamrock",                   Html::from_u32(0x01B44CFF)),
+	("shamrock green",             Html::from_u32(0x02C14DFF)),
+	("shit",                       Html::from_u32(0x7F5F00FF)),
+	("shit brown",                 Html::from_u32(0x7B5804FF)),
+	("shit green",                 Html::from_u32(0x758000FF)),
+	("shocking pink",              Html::from_u32(0xFE02A2FF)),
+	("sick green",                 Html::from_u32(0x9DB92CFF)),
+	("sickly green",               Html::from_u32(0x94B21CFF)),
+	("sickly yellow",              Html::from_u32(0xD0E429FF)),
+	("sienna",                     Html::from_u32(0xA9561EFF)),
+	("silver",                     Html::from_u32(0xC5C9C7FF)),
+	("sky",                        Html::from_u32(0x82CAFCFF)),
+	("sky blue",                   Html::from_u32(0x75BBFDFF)),
+	("slate",                      Html::from_u32(0x516572FF)),
+	("slate blue",                 Html::from_u32(0x5B7C99FF)),
+	("slate green",                Html::from_u32(0x658D6DFF)),
+	("slate grey",                 Html::from_u32(0x59656DFF)),
+	("slime green",                Html::from_u32(0x99CC04FF)),
+	("snot",                       Html::from_u32(0xACBB0DFF)),
+	("snot green",                 Html::from_u32(0x9DC100FF)),
+	("soft blue",                  Html::from_u32(0x6488EAFF)),
+	("soft green",                 Html::from_u32(0x6FC276FF)),
+	("soft pink",                  Html::from_u32(0xFDB0C0FF)),
+	("soft purple",                Html::from_u32(0xA66FB5FF)),
+	("spearmint",                  Html::from_u32(0x1EF876FF)),
+	("spring green",               Html::from_u32(0xA9F971FF)),
+	("spruce",                     Html::from_u32(0x0A5F38FF)),
+	("squash",                     Html::from_u32(0xF2AB15FF)),
+	("steel",                      Html::from_u32(0x738595FF)),
+	("steel blue",                 Html::from_u32(0x5A7D9AFF)),
+	("steel grey",                 Html::from_u32(0x6F828AFF)),
+	("stone",                      Html::from_u32(0xADA587FF)),
+	("stormy blue",                Html::from_u32(0x507B9CFF)),
+	("straw",                      Html::from_u32(0xFCF679FF)),
+	("strawberry",                 Html::from_u32(0xFB2943FF)),
+	("strong blue",                Html::from_u32(0x0C06F7FF)),
+	("strong pink",                Html::from_u32(0xFF0789FF)),
+	("sunflower",                  Html::from_u32(0xFFC512FF)),
+	("sunflower yellow",           Html::from_u32(0xFFDA03FF)),
+	("sunny yellow",               Html::from_u32(0xFFF917FF)),
+	("sunshine yellow",            Html::from_u32(0xFFFD37FF)),
+	("sun yellow",                 Html::from_u32(0xFFDF22FF)),
+	("swamp",                      Html::from_u32(0x698339FF)),
+	("swamp green",                Html::from_u32(0x748500FF)),
+	("tan",                        Html::from_u32(0xD1B26FFF)),
+	("tan brown",                  Html::from_u32(0xAB7E4CFF)),
+	("tangerine",                  Html::from_u32(0xFF9408FF)),
+	("tan green",                  Html::from_u32(0xA9BE70FF)),
+	("taupe",                      Html::from_u32(0xB9A281FF)),
+	("tea",                        Html::from_u32(0x65AB7CFF)),
+	("tea green",                  Html::from_u32(0xBDF8A3FF)),
+	("teal",                       Html::from_u32(0x029386FF)),
+	("teal blue",                  Html::from_u32(0x01889FFF)),
+	("teal green",                 Html::from_u32(0x25A36FFF)),
+	("tealish",                    Html::from_u32(0x24BCA8FF)),
+	("tealish green",              Html::from_u32(0x0CDC73FF)),
+	("terracota",                  Html::from_u32(0xCB6843FF)),
+	("terra cotta",                Html::from_u32(0xC9643BFF)),
+	("terracotta",                 Html::from_u32(0xCA6641FF)),
+	("tiffany blue",               Html::from_u32(0x7BF2DAFF)),
+	("tomato",                     Html::from_u32(0xEF4026FF)),
+	("tomato red",                 Html::from_u32(0xEC2D01FF)),
+	("topaz",                      Html::from_u32(0x13BBAFFF)),
+	("toupe",                      Html::from_u32(0xC7AC7DFF)),
+	("toxic green",                Html::from_u32(0x61DE2AFF)),
+	("tree green",                 Html::from_u32(0x2A7E19FF)),
+	("true blue",                  Html::from_u32(0x010FCCFF)),
+	("true green",                 Html::from_u32(0x089404FF)),
+	("turquoise",                  Html::from_u32(0x06C2ACFF)),
+	("turquoise blue",             Html::from_u32(0x06B1C4FF)),
+	("turquoise green",            Html::from_u32(0x04F489FF)),
+	("turtle green",               Html::from_u32(0x75B84FFF)),
+	("twilight",                   Html::from_u32(0x4E518BFF)),
+	("twilight blue",              Html::from_u32(0x0A437AFF)),
+	("ugly blue",                  Html::from_u32(0x31668AFF)),
+	("ugly brown",                 Html::from_u32(0x7D7103FF)),
+	("ugly green",                 Html::from_u32(0x7A9703FF)),
+	("ugly pink",                  Html::from_u32(0xCD7584FF)),
+	("ugly purple",                Html::from_u32(0xA442A0FF)),
+	("ugly yellow",                Html::from_u32(0xD0C101FF)),
+	("ultramarine",                Html::from_u32(0x2000B1FF)),
+	("ultramarine blue",           Html::from_u32(0x1805DBFF)),
+	("umber",                      Html::from_u32(0xB26400FF)),
+	("velvet",                     Html::from_u32(0x750851FF)),
+	("vermillion",                 Html::from_u32(0xF4320CFF)),
+	("very dark blue",             Html::from_u32(0x000133FF)),
+	("very dark brown",            Html::from_u32(0x1D0200FF)),
+	("very dark green",            Html::from_u32(0x062E03FF)),
+	("very dark purple",           Html::from_u32(0x2A0134FF)),
+	("very light blue",            Html::from_u32(0xD5FFFFFF)),
+	("very light brown",           Html::from_u32(0xD3B683FF)),
+	("very light green",           Html::from_u32(0xD1FFBDFF)),
+	("very light pink",            Html::from_u32(0xFFF4F2FF)),
+	("very light purple",          Html::from_u32(0xF6CEFCFF)),
+	("very pale blue",             Html::from_u32(0xD6FFFEFF)),
+	("very pale green",            Html::from_u32(0xCFFDBCFF)),
+	("vibrant blue",               Html::from_u32(0x0339F8FF)),
+	("vibrant green",              Html::from_u32(0x0ADD08FF)),
+	("vibrant purple",             Html::from_u32(0xAD03DEFF)),
+	("violet",                     Html::from_u32(0x9A0EEAFF)),
+	("violet blue",                Html::from_u32(0x510AC9FF)),
+	("violet pink",                Html::from_u32(0xFB5FFCFF)),
+	("violet red",                 Html::from_u32(0xA50055FF)),
+	("viridian",                   Html::from_u32(0x1E9167FF)),
+	("vivid blue",                 Html::from_u32(0x152EFFFF)),
+	("vivid green",                Html::from_u32(0x2FEF10FF)),
+	("vivid purple",               Html::from_u32(0x9900FAFF)),
+	("vomit",                      Html::from_u32(0xA2A415FF)),
+	("vomit green",                Html::from_u32(0x89A203FF)),
+	("vomit yellow",               Html::from_u32(0xC7C10CFF)),
+	("warm blue",                  Html::from_u32(0x4B57DBFF)),
+	("warm brown",                 Html::from_u32(0x964E02FF)),
+	("warm grey",                  Html::from_u32(0x978A84FF)),
+	("warm pink",                  Html::from_u32(0xFB5581FF)),
+	("warm purple",                Html::from_u32(0x952E8FFF)),
+	("washed out green",           Html::from_u32(0xBCF5A6FF)),
+	("water blue",                 Html::from_u32(0x0E87CCFF)),
+	("watermelon",                 Html::from_u32(0xFD4659FF)),
+	("weird green",                Html::from_u32(0x3AE57FFF)),
+	("wheat",                      Html::from_u32(0xFBDD7EFF)),
+	("white",                      Html::from_u32(0xFFFFFFFF)),
+	("windows blue",               Html::from_u32(0x3778BFFF)),
+	("wine",                       Html::from_u32(0x80013FFF)),
+	("wine red",                   Html::from_u32(0x7B0323FF)),
+	("wintergreen",                Html::from_u32(0x20F986FF)),
+	("wisteria",                   Html::from_u32(0xA87DC2FF)),
+	("yellow",                     Html::from_u32(0xFFFF14FF)),
+	("yellow/green",               Html::from_u32(0xC8FD3DFF)),
+	("yellow brown",               Html::from_u32(0xB79400FF)),
+	("yellow green",               Html::from_u32(0xC0FB2DFF)),
+	("yellowgreen",                Html::from_u32(0xBBF90FFF)),
+	("yellowish",                  Html::from_u32(0xFAEE66FF)),
+	("yellowish brown",            Html::from_u32(0x9B7A01FF)),
+	("yellowish green",            Html::from_u32(0xB0DD16FF)),
+	("yellowish orange",           Html::from_u32(0xFFAB0FFF)),
+	("yellowish tan",              Html::from_u32(0xFCFC81FF)),
+	("yellow ochre",               Html::from_u32(0xCB9D06FF)),
+	("yellow orange",              Html::from_u32(0xFCB001FF)),
+	("yellow tan",                 Html::from_u32(0xFFE36EFF)),
+	("yellowy brown",              Html::from_u32(0xAE8B0CFF)),
+	("yellowy green",              Html::from_u32(0xBFF128FF)),
+];
@@ -0,0 +1,41 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::www::WcagTarget;
+
+/// A WCAG 2.x conformance level.
+///
+/// See [`Html::meets_wcag`](crate::www::Html::meets_wcag) for checking colour pairs against these levels.
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub enum WcagLevel {
+	/// Level AA.
+	#[default]
+	Aa,
+
+	/// Level AAA.
+	Aaa,
+}
+
+impl WcagLevel {
+	/// Retrieves the minimum contrast ratio required for the given target.
+	///
+	/// The returned value is the left-hand side of the `x:1` ratio.
+	/// Note that WCAG 2.x does not define an enhanced requirement for non-text contrast, and so the requirement is `3.0` for both levels.
+	#[inline]
+	#[must_use]
+	pub const fn minimum_ratio(self, target: WcagTarget) -> f64 {
+		match (self, target) {
+			(Self::Aa,  WcagTarget::NormalText) => 4.5,
+			(Self::Aa,  WcagTarget::LargeText)  => 3.0,
+			(Self::Aaa, WcagTarget::NormalText) => 7.0,
+			(Self::Aaa, WcagTarget::LargeText)  => 4.5,
+
+			(_, WcagTarget::NonText) => 3.0,
+		}
+	}
+}
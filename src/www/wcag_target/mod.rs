@@ -0,0 +1,26 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+/// A WCAG 2.x contrast target.
+///
+/// The required contrast ratio for each target depends on the [conformance level](crate::www::WcagLevel).
+#[allow(clippy::enum_variant_names)]
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub enum WcagTarget {
+	/// Normal text (success criteria 1.4.3 and 1.4.6).
+	#[default]
+	NormalText,
+
+	/// Large-scale text (success criteria 1.4.3 and 1.4.6).
+	///
+	/// This is text that is at least 18 point, or 14 point and bold.
+	LargeText,
+
+	/// User interface components and graphical objects (success criterion 1.4.11).
+	NonText,
+}
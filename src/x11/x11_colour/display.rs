@@ -0,0 +1,67 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::x11::X11Colour;
+
+use core::fmt::{self, Display, Formatter};
+
+impl Display for X11Colour {
+	/// Formats the colour as an X11 colour specification.
+	///
+	/// Each variant is written in its own syntax, i.e. `rgb:`, `rgbi:`, `CIEXYZ:`, `CIELab:`, or `CIELuv:`.
+	///
+	/// For [`Rgb`](Self::Rgb) colours, the precision specifies the number of hexadecimal digits per channel (from one to four, with four being the default), and the alternate flag selects the legacy `#` syntax.
+	/// For all other colours, the precision specifies the number of decimal places.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use polywave::rgb::Rgb;
+	/// use polywave::x11::X11Colour;
+	///
+	/// let colour = X11Colour::Rgb(Rgb::new(0xFFFF, 0x8080, 0x0000));
+	///
+	/// assert_eq!(format!("{colour}"),     "rgb:ffff/8080/0000");
+	/// assert_eq!(format!("{colour:.2}"),  "rgb:ff/80/00");
+	/// assert_eq!(format!("{colour:#.2}"), "#FF8000");
+	/// ```
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		let (prefix, channels) = match *self {
+			Self::Rgb(colour) => {
+				let digits = f.precision().unwrap_or(0x4).clamp(0x1, 0x4);
+
+				let (red, green, blue) = colour.get();
+
+				if f.alternate() {
+					let shift = 0x10 - 0x4 * digits;
+
+					return write!(f, "#{:0digits$X}{:0digits$X}{:0digits$X}", red >> shift, green >> shift, blue >> shift);
+				}
+
+				let maximum = (0x1 << (0x4 * digits)) - 0x1;
+
+				let scale = |channel: u16| (u32::from(channel) * maximum + 0x7FFF) / 0xFFFF;
+
+				return write!(f, "rgb:{:0digits$x}/{:0digits$x}/{:0digits$x}", scale(red), scale(green), scale(blue));
+			}
+
+			Self::RgbIntensity(colour) => ("rgbi",   colour.get()),
+			Self::CieXyz(colour)       => ("CIEXYZ", colour.get()),
+			Self::CieLab(colour)       => ("CIELab", colour.get()),
+			Self::CieLuv(colour)       => ("CIELuv", colour.get()),
+		};
+
+		let (first, second, third) = channels;
+
+		if let Some(precision) = f.precision() {
+			write!(f, "{prefix}:{first:.precision$}/{second:.precision$}/{third:.precision$}")
+		} else {
+			write!(f, "{prefix}:{first}/{second}/{third}")
+		}
+	}
+}
@@ -0,0 +1,260 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+use crate::error::X11ColourFromStrError;
+use crate::lab::{CieLab, CieLuv};
+#[cfg(feature = "maths")]
+use crate::maths::Maths;
+use crate::rgb::Rgb;
+#[cfg(feature = "x11-names")]
+use crate::www::NamedColours;
+use crate::x11::X11Colour;
+use crate::xyz::CieXyz;
+
+use core::str::FromStr;
+
+/// The bounds of a channel that is not bounded.
+const UNBOUNDED: (f64, f64) = (f64::NEG_INFINITY, f64::INFINITY);
+
+/// The bounds of a channel in the range `0` to `1`.
+const UNIT: (f64, f64) = (0.0, 1.0);
+
+/// The bounds of a lightness channel.
+const LIGHTNESS: (f64, f64) = (0.0, 100.0);
+
+impl FromStr for X11Colour {
+	type Err = X11ColourFromStrError;
+
+	/// Parses an X11 colour specification.
+	///
+	/// The formats supported by this implementation are those of `XParseColor`:
+	///
+	/// * `rgb:<red>/<green>/<blue>` with one to four hexadecimal digits per channel, e.g. `rgb:f/80/0` or `rgb:ffff/8080/0000`
+	/// * `rgbi:<red>/<green>/<blue>` with intensities in the range `0` to `1`
+	/// * `CIEXYZ:<X>/<Y>/<Z>`, `CIEuvY:<u'>/<v'>/<Y>`, and `CIExyY:<x>/<y>/<Y>` with Y in the range `0` to `1`
+	/// * `CIELab:<L*>/<a*>/<b*>` and `CIELuv:<L*>/<u*>/<v*>` with L* in the range `0` to `100`
+	/// * `TekHVC:<H>/<V>/<C>` with the hue in degrees (requires the `maths` feature)
+	/// * The legacy `#RGB`, `#RRGGBB`, `#RRRGGGBBB`, and `#RRRRGGGGBBBB` hexadecimal codes
+	/// * The names of the X11 colour database, i.e. `NamedColours::X11` (requires the `x11-names` feature)
+	///
+	/// Prefixes are matched case-insensitively, and surrounding whitespace is ignored.
+	///
+	/// Note that `rgb:` channels are scaled to sixteen bits (so `rgb:f/f/f` is white), whereas legacy hexadecimal channels denote the most significant bits (so `#FFF` is `#F000F000F000`).
+	/// This also differs from [HTML colours](crate::www::Html), in which `#FFF` is white.
+	///
+	/// # Errors
+	///
+	/// If the string is not a valid colour specification, an error is returned.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let s = s.trim();
+
+		if let Some(digits) = s.strip_prefix('#') {
+			return parse_hexadecimal(digits).map(Self::Rgb);
+		}
+
+		let Some((prefix, channels)) = s.split_once(':') else {
+			return parse_name(s);
+		};
+
+		let is = |name: &str| prefix.eq_ignore_ascii_case(name);
+
+		let colour = if is("rgb") {
+			let [red, green, blue] = parse_channels(channels, |_, channel| parse_scaled(channel))?;
+			Self::Rgb(Rgb::new(red, green, blue))
+		} else if is("rgbi") {
+			let [red, green, blue] = parse_floats(channels, [UNIT, UNIT, UNIT])?;
+			Self::RgbIntensity(Rgb::new(red, green, blue))
+		} else if is("CIEXYZ") {
+			let [x, y, z] = parse_floats(channels, [UNBOUNDED, UNIT, UNBOUNDED])?;
+			Self::CieXyz(CieXyz::new(x, y, z))
+		} else if is("CIEuvY") {
+			let [u_prime, v_prime, y] = parse_floats(channels, [UNIT, UNIT, UNIT])?;
+			Self::CieXyz(from_uv_y(u_prime, v_prime, y))
+		} else if is("CIExyY") {
+			let [x, y, luminance] = parse_floats(channels, [UNIT, UNIT, UNIT])?;
+			Self::CieXyz(from_xy_y(x, y, luminance))
+		} else if is("CIELab") {
+			let [luminance, a_star, b_star] = parse_floats(channels, [LIGHTNESS, UNBOUNDED, UNBOUNDED])?;
+			Self::CieLab(CieLab::new(luminance, a_star, b_star))
+		} else if is("CIELuv") {
+			let [luminance, u_star, v_star] = parse_floats(channels, [LIGHTNESS, UNBOUNDED, UNBOUNDED])?;
+			Self::CieLuv(CieLuv::new(luminance, u_star, v_star))
+		} else {
+			return parse_tek_hvc(prefix, channels);
+		};
+
+		Ok(colour)
+	}
+}
+
+/// Parses three channels separated by slashes.
+///
+/// Each channel is parsed by `parse`, which is also given the index of the channel.
+fn parse_channels<T, F>(s: &str, mut parse: F) -> Result<[T; 0x3], X11ColourFromStrError>
+where
+	T: Copy + Default,
+	F: FnMut(usize, &str) -> Option<T>,
+{
+	let count = s.split('/').count();
+
+	if count != 0x3 {
+		return Err(X11ColourFromStrError::InvalidChannelCount { count });
+	}
+
+	let mut channels = [T::default(); 0x3];
+
+	for (index, channel) in s.split('/').enumerate() {
+		channels[index] = parse(index, channel).ok_or(X11ColourFromStrError::InvalidChannel { index })?;
+	}
+
+	Ok(channels)
+}
+
+/// Parses three floating-point channels within the given (inclusive) bounds.
+fn parse_floats(s: &str, bounds: [(f64, f64); 0x3]) -> Result<[f64; 0x3], X11ColourFromStrError> {
+	parse_channels(s, |index, channel| {
+		let (minimum, maximum) = bounds[index];
+
+		f64::from_str(channel)
+			.ok()
+			.filter(|value| value.is_finite() && (minimum..=maximum).contains(value))
+	})
+}
+
+/// Parses an `rgb:` channel of one to four hexadecimal digits, scaling it to sixteen bits.
+#[must_use]
+fn parse_scaled(s: &str) -> Option<u16> {
+	if !(0x1..=0x4).contains(&s.len()) || !s.bytes().all(|c| c.is_ascii_hexdigit()) {
+		return None;
+	}
+
+	let value = u32::from_str_radix(s, 0x10).ok()?;
+
+	let maximum = (0x1 << (0x4 * s.len())) - 0x1;
+
+	u16::try_from((value * 0xFFFF + maximum / 0x2) / maximum).ok()
+}
+
+/// Parses the digits of a legacy hexadecimal colour.
+fn parse_hexadecimal(digits: &str) -> Result<Rgb<u16>, X11ColourFromStrError> {
+	if ![0x3, 0x6, 0x9, 0xC].contains(&digits.len()) || !digits.bytes().all(|c| c.is_ascii_hexdigit()) {
+		return Err(X11ColourFromStrError::InvalidHexadecimal);
+	}
+
+	let count = digits.len() / 0x3;
+	let shift = 0x10 - 0x4 * count;
+
+	// The digits have already been validated.
+	let channel = |index: usize| {
+		let digits = &digits[index * count..(index + 0x1) * count];
+		u16::from_str_radix(digits, 0x10).unwrap_or_default() << shift
+	};
+
+	Ok(Rgb::new(channel(0x0), channel(0x1), channel(0x2)))
+}
+
+/// Looks up a name in the X11 colour database.
+#[cfg(feature = "x11-names")]
+fn parse_name(s: &str) -> Result<X11Colour, X11ColourFromStrError> {
+	let colour = NamedColours::X11.get(s).ok_or(X11ColourFromStrError::UnknownFormat)?;
+
+	let (red, green, blue, _) = colour.get();
+
+	let scale = |channel: u8| u16::from(channel) * 0x101;
+
+	Ok(X11Colour::Rgb(Rgb::new(scale(red), scale(green), scale(blue))))
+}
+
+/// Fails as names are not supported without the X11 colour database.
+#[cfg(not(feature = "x11-names"))]
+fn parse_name(_: &str) -> Result<X11Colour, X11ColourFromStrError> {
+	Err(X11ColourFromStrError::UnknownFormat)
+}
+
+/// Parses a `TekHVC:` colour, or fails if the prefix is unknown.
+#[cfg(feature = "maths")]
+fn parse_tek_hvc(prefix: &str, channels: &str) -> Result<X11Colour, X11ColourFromStrError> {
+	if !prefix.eq_ignore_ascii_case("TekHVC") {
+		return Err(X11ColourFromStrError::UnknownFormat);
+	}
+
+	let [hue, value, chroma] = parse_floats(channels, [(0.0, 360.0), LIGHTNESS, (0.0, f64::INFINITY)])?;
+
+	Ok(X11Colour::CieXyz(from_tek_hvc(hue, value, chroma)))
+}
+
+/// Fails as TekHVC colours cannot be converted without the `maths` feature.
+#[cfg(not(feature = "maths"))]
+fn parse_tek_hvc(_: &str, _: &str) -> Result<X11Colour, X11ColourFromStrError> {
+	Err(X11ColourFromStrError::UnknownFormat)
+}
+
+/// Converts CIE 1976 chromaticity coordinates and a luminance to CIEXYZ.
+#[must_use]
+fn from_uv_y(u_prime: f64, v_prime: f64, y: f64) -> CieXyz<f64> {
+	if v_prime == 0.0 {
+		return CieXyz::new(0.0, 0.0, 0.0);
+	}
+
+	let x = y * 9.0 * u_prime / (4.0 * v_prime);
+	let z = y * (12.0 - 3.0 * u_prime - 20.0 * v_prime) / (4.0 * v_prime);
+
+	CieXyz::new(x, y, z)
+}
+
+/// Converts CIE 1931 chromaticity coordinates and a luminance to CIEXYZ.
+#[must_use]
+fn from_xy_y(x: f64, y: f64, luminance: f64) -> CieXyz<f64> {
+	if y == 0.0 {
+		return CieXyz::new(0.0, 0.0, 0.0);
+	}
+
+	CieXyz::new(x * luminance / y, luminance, (1.0 - x - y) * luminance / y)
+}
+
+/// Converts a TekHVC colour to CIEXYZ relative to the D65 white point.
+///
+/// This follows the conversion of Xcms, in which the hue is measured from the "best red" of Tektronix.
+#[cfg(feature = "maths")]
+#[must_use]
+fn from_tek_hvc(hue: f64, value: f64, chroma: f64) -> CieXyz<f64> {
+	/// The chromaticity of the "best red."
+	const BEST_RED: (f64, f64) = (0.7127, 0.4931);
+
+	/// The scale factor of the chroma.
+	const CHROMA_SCALE_FACTOR: f64 = 7.50725;
+
+	if value == 0.0 {
+		return CieXyz::new(0.0, 0.0, 0.0);
+	}
+
+	let (white_x, white_y, white_z) = CieXyz::<f64>::D65.get();
+
+	let divisor = white_x + 15.0 * white_y + 3.0 * white_z;
+
+	let white_u_prime = 4.0 * white_x / divisor;
+	let white_v_prime = 9.0 * white_y / divisor;
+
+	let offset = Maths::atan((BEST_RED.1 - white_v_prime) / (BEST_RED.0 - white_u_prime)).to_degrees();
+
+	let (sin, cos) = Maths::sin_cos((hue + offset).to_radians());
+
+	let radius = chroma / (value * CHROMA_SCALE_FACTOR);
+
+	let u_prime = white_u_prime + cos * radius;
+	let v_prime = white_v_prime + sin * radius;
+
+	let y = if value < 7.999_591_99 {
+		value / 903.29
+	} else {
+		let value = (value + 16.0) / 116.0;
+		value * value * value
+	};
+
+	from_uv_y(u_prime, v_prime, y)
+}
@@ -0,0 +1,86 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+mod display;
+mod from_str;
+mod test;
+
+use crate::lab::{CieLab, CieLuv};
+use crate::rgb::Rgb;
+use crate::xyz::CieXyz;
+
+/// An X11 colour specification.
+///
+/// This type represents the colour specifications accepted by `XParseColor`, i.e. the device-dependent `rgb:` and legacy `#` syntaxes as well as the device-independent `rgbi:`, `CIEXYZ:`, `CIEuvY:`, `CIExyY:`, `CIELab:`, `CIELuv:`, and `TekHVC:` syntaxes.
+/// These are also the syntaxes in which terminal emulators answer colour queries (e.g. `OSC 11 ; ? ST`).
+///
+/// The chromaticity-based syntaxes (`CIEuvY:`, `CIExyY:`, and `TekHVC:`) are converted to [`CieXyz`] when parsed, and TekHVC is taken relative to the [D65](CieXyz::D65) white point.
+///
+/// # Examples
+///
+/// ```
+/// use polywave::rgb::Rgb;
+/// use polywave::x11::X11Colour;
+///
+/// let colour: X11Colour = "rgb:ffff/8080/0".parse().unwrap();
+///
+/// assert_eq!(colour, X11Colour::Rgb(Rgb::new(0xFFFF, 0x8080, 0x0000)));
+/// assert_eq!(colour.to_string(), "rgb:ffff/8080/0000");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum X11Colour {
+	/// An `rgb:` or legacy `#` colour, i.e. device RGB with sixteen bits per channel.
+	Rgb(Rgb<u16>),
+
+	/// An `rgbi:` colour, i.e. device RGB intensities in the range `0` to `1`.
+	RgbIntensity(Rgb<f64>),
+
+	/// A `CIEXYZ:`, `CIEuvY:`, `CIExyY:`, or `TekHVC:` colour.
+	CieXyz(CieXyz<f64>),
+
+	/// A `CIELab:` colour.
+	CieLab(CieLab<f64>),
+
+	/// A `CIELuv:` colour.
+	CieLuv(CieLuv<f64>),
+}
+
+impl From<Rgb<u16>> for X11Colour {
+	#[inline(always)]
+	fn from(value: Rgb<u16>) -> Self {
+		Self::Rgb(value)
+	}
+}
+
+impl From<Rgb<f64>> for X11Colour {
+	#[inline(always)]
+	fn from(value: Rgb<f64>) -> Self {
+		Self::RgbIntensity(value)
+	}
+}
+
+impl From<CieXyz<f64>> for X11Colour {
+	#[inline(always)]
+	fn from(value: CieXyz<f64>) -> Self {
+		Self::CieXyz(value)
+	}
+}
+
+impl From<CieLab<f64>> for X11Colour {
+	#[inline(always)]
+	fn from(value: CieLab<f64>) -> Self {
+		Self::CieLab(value)
+	}
+}
+
+impl From<CieLuv<f64>> for X11Colour {
+	#[inline(always)]
+	fn from(value: CieLuv<f64>) -> Self {
+		Self::CieLuv(value)
+	}
+}
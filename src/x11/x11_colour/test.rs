@@ -0,0 +1,98 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License, v. 2.0. If a copy of
+// the MPL was not distributed with this file, you
+// can obtain one at:
+// <https://mozilla.org/MPL/2.0/>.
+
+#![cfg(test)]
+
+use crate::error::X11ColourFromStrError;
+use crate::lab::{CieLab, CieLuv};
+use crate::rgb::Rgb;
+use crate::x11::X11Colour;
+use crate::xyz::CieXyz;
+
+use alloc::format;
+use core::str::FromStr;
+
+#[test]
+fn test_x11_colour_from_str() {
+	assert_eq!(X11Colour::from_str("rgb:ffff/8080/0000"), Ok(X11Colour::Rgb(Rgb::new(0xFFFF, 0x8080, 0x0000))));
+	assert_eq!(X11Colour::from_str("RGB:f/80/000"),       Ok(X11Colour::Rgb(Rgb::new(0xFFFF, 0x8080, 0x0000))));
+	assert_eq!(X11Colour::from_str("rgb:1/12/123"),       Ok(X11Colour::Rgb(Rgb::new(0x1111, 0x1212, 0x1231))));
+
+	assert_eq!(X11Colour::from_str("#F80"),          Ok(X11Colour::Rgb(Rgb::new(0xF000, 0x8000, 0x0000))));
+	assert_eq!(X11Colour::from_str("#ff8000"),       Ok(X11Colour::Rgb(Rgb::new(0xFF00, 0x8000, 0x0000))));
+	assert_eq!(X11Colour::from_str("#123456789ABC"), Ok(X11Colour::Rgb(Rgb::new(0x1234, 0x5678, 0x9ABC))));
+
+	assert_eq!(X11Colour::from_str("rgbi:1/0.5/0"), Ok(X11Colour::RgbIntensity(Rgb::new(1.0, 0.5, 0.0))));
+
+	assert_eq!(X11Colour::from_str("CIEXYZ:0.5/1/0.25"), Ok(X11Colour::CieXyz(CieXyz::new(0.5, 1.0, 0.25))));
+	assert_eq!(X11Colour::from_str("CIEuvY:0.25/0.5/1"), Ok(X11Colour::CieXyz(CieXyz::new(1.125, 1.0, 0.625))));
+	assert_eq!(X11Colour::from_str("CIExyY:0.25/0.5/1"), Ok(X11Colour::CieXyz(CieXyz::new(0.5, 1.0, 0.5))));
+
+	assert_eq!(X11Colour::from_str("cielab:50/-20/30.5"), Ok(X11Colour::CieLab(CieLab::new(50.0, -20.0, 30.5))));
+	assert_eq!(X11Colour::from_str("CIELuv:75/10/-10"),   Ok(X11Colour::CieLuv(CieLuv::new(75.0, 10.0, -10.0))));
+
+	assert_eq!(X11Colour::from_str("rgb:ffff/8080"),   Err(X11ColourFromStrError::InvalidChannelCount { count: 0x2 }));
+	assert_eq!(X11Colour::from_str("rgb:ffff//0"),     Err(X11ColourFromStrError::InvalidChannel { index: 0x1 }));
+	assert_eq!(X11Colour::from_str("rgb:fffff/0/0"),   Err(X11ColourFromStrError::InvalidChannel { index: 0x0 }));
+	assert_eq!(X11Colour::from_str("rgb:0/0/+f"),      Err(X11ColourFromStrError::InvalidChannel { index: 0x2 }));
+	assert_eq!(X11Colour::from_str("rgbi:1/1.5/0"),    Err(X11ColourFromStrError::InvalidChannel { index: 0x1 }));
+	assert_eq!(X11Colour::from_str("CIELab:inf/0/0"),  Err(X11ColourFromStrError::InvalidChannel { index: 0x0 }));
+	assert_eq!(X11Colour::from_str("#FFFF"),           Err(X11ColourFromStrError::InvalidHexadecimal));
+	assert_eq!(X11Colour::from_str("#GGG"),            Err(X11ColourFromStrError::InvalidHexadecimal));
+	assert_eq!(X11Colour::from_str("hsl:0/0/0"),       Err(X11ColourFromStrError::UnknownFormat));
+}
+
+#[cfg(feature = "maths")]
+#[test]
+fn test_x11_colour_from_str_tek_hvc() {
+	let assert_near = |s: &str, expected: CieXyz<f64>| {
+		let Ok(X11Colour::CieXyz(colour)) = X11Colour::from_str(s) else {
+			panic!("`{s}` is not a valid TekHVC colour");
+		};
+
+		let (x, y, z) = colour.get();
+		let (expected_x, expected_y, expected_z) = expected.get();
+
+		assert!((x - expected_x).abs() < 1e-6, "{colour:?} != {expected:?}");
+		assert!((y - expected_y).abs() < 1e-6, "{colour:?} != {expected:?}");
+		assert!((z - expected_z).abs() < 1e-6, "{colour:?} != {expected:?}");
+	};
+
+	assert_near("TekHVC:0/100/0",   CieXyz::<f64>::D65);
+	assert_near("TekHVC:180/0/0",   CieXyz::new(0.0, 0.0, 0.0));
+	assert_near("tekhvc:90/50/0",   CieXyz::<f64>::D65.map(|channel| channel * 0.184_186_518_512_444_2));
+
+	assert_eq!(X11Colour::from_str("TekHVC:361/50/0"), Err(X11ColourFromStrError::InvalidChannel { index: 0x0 }));
+}
+
+#[cfg(feature = "x11-names")]
+#[test]
+fn test_x11_colour_from_str_name() {
+	assert_eq!(X11Colour::from_str("Navy Blue"), Ok(X11Colour::Rgb(Rgb::new(0x0000, 0x0000, 0x8080))));
+	assert_eq!(X11Colour::from_str("gray42"),    Ok(X11Colour::Rgb(Rgb::new(0x6B6B, 0x6B6B, 0x6B6B))));
+}
+
+#[test]
+fn test_x11_colour_display() {
+	let colour = X11Colour::Rgb(Rgb::new(0xFFFF, 0x8080, 0x0123));
+
+	assert_eq!(format!("{colour}"),     "rgb:ffff/8080/0123");
+	assert_eq!(format!("{colour:.1}"),  "rgb:f/8/0");
+	assert_eq!(format!("{colour:.3}"),  "rgb:fff/808/012");
+	assert_eq!(format!("{colour:#}"),   "#FFFF80800123");
+	assert_eq!(format!("{colour:#.2}"), "#FF8001");
+
+	assert_eq!(format!("{}",    X11Colour::RgbIntensity(Rgb::new(1.0, 0.5, 0.0))),  "rgbi:1/0.5/0");
+	assert_eq!(format!("{:.2}", X11Colour::CieXyz(CieXyz::new(0.5, 1.0, 0.25))),   "CIEXYZ:0.50/1.00/0.25");
+	assert_eq!(format!("{}",    X11Colour::CieLab(CieLab::new(50.0, -20.0, 30.5))), "CIELab:50/-20/30.5");
+	assert_eq!(format!("{}",    X11Colour::CieLuv(CieLuv::new(75.0, 10.0, -10.0))), "CIELuv:75/10/-10");
+
+	for s in ["rgb:1234/5678/9abc", "rgbi:0.25/0.5/0.75", "CIEXYZ:0.5/1/0.25", "CIELab:50/-20/30.5"] {
+		assert_eq!(format!("{}", X11Colour::from_str(s).unwrap()), s);
+	}
+}
@@ -6,14 +6,12 @@
 // can obtain one at:
 // <https://mozilla.org/MPL/2.0/>.
 
-use crate::{Colour, Component, DefinedGamut};
+use crate::{BalancedColour, Colour, Component, DefinedGamut};
+use crate::component::zero;
 
 #[cfg(feature = "bytemuck")]
 use bytemuck::{Pod, Zeroable};
 
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
-
 #[cfg(feature = "zerocopy")]
 use zerocopy::{FromZeros, Immutable, IntoBytes};
 
@@ -23,7 +21,6 @@ use zerocopy::{FromZeros, Immutable, IntoBytes};
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
-#[cfg_attr(feature = "serde",    derive(Deserialize, Serialize))]
 #[cfg_attr(feature = "zerocopy", derive(FromZeros, Immutable, IntoBytes))]
 pub struct CieXyz<T>([T; 0x3]);
 
@@ -62,6 +59,147 @@ impl<T: Component> CieXyz<T> {
 	}
 }
 
+macro_rules! impl_conversions {
+	($($tys:ty),+$(,)?) => {
+		$(
+			impl ::polywave::xyz::CieXyz<$tys> {
+				/// The white point of the CIE standard illuminant D65.
+				///
+				/// This is the reference white of sRGB and most other RGB colour spaces.
+				#[allow(clippy::excessive_precision)]
+				pub const D65: Self = Self::new(0.950_455_927_051_671_6, 1.0, 1.089_057_750_759_878_4);
+
+				/// The white point of the CIE standard illuminant D50.
+				///
+				/// This is the reference white commonly used for CIELAB in print-related contexts.
+				#[allow(clippy::excessive_precision)]
+				pub const D50: Self = Self::new(0.964_295_676_429_567_7, 1.0, 0.825_104_602_510_460_2);
+
+				/// The Bradford matrix transforming CIEXYZ to cone responses.
+				const BRADFORD: [[$tys; 0x3]; 0x3] = [
+					[ 0.895_1,  0.266_4, -0.161_4],
+					[-0.750_2,  1.713_5,  0.036_7],
+					[ 0.038_9, -0.068_5,  1.029_6],
+				];
+
+				/// The inverse of the Bradford matrix.
+				#[allow(clippy::excessive_precision)]
+				const BRADFORD_INVERSE: [[$tys; 0x3]; 0x3] = [
+					[ 0.986_992_905_466_712_1, -0.147_054_256_420_990_1, 0.159_962_651_663_731_2],
+					[ 0.432_305_269_723_394_5,  0.518_360_271_536_777_6, 0.049_291_228_212_855_6],
+					[-0.008_528_664_575_177_3,  0.040_042_821_654_084_9, 0.968_486_695_787_550_0],
+				];
+
+				/// Multiplies a matrix with the colour.
+				#[inline(always)]
+				#[must_use]
+				pub(crate) const fn transform(self, matrix: &[[$tys; 0x3]; 0x3]) -> Self {
+					let (x, y, z) = self.get();
+
+					let [m0, m1, m2] = *matrix;
+
+					Self::new(
+						m0[0x0] * x + m0[0x1] * y + m0[0x2] * z,
+						m1[0x0] * x + m1[0x1] * y + m1[0x2] * z,
+						m2[0x0] * x + m2[0x1] * y + m2[0x2] * z,
+					)
+				}
+
+				/// Adapts the colour from one white point to another.
+				///
+				/// This is done using the Bradford chromatic adaptation transform, which is also used by CSS for converting between the [D65](Self::D65) and [D50](Self::D50) white points.
+				#[inline]
+				#[must_use]
+				pub const fn adapt(self, source: Self, destination: Self) -> Self {
+					let (rho,        gamma,        beta)        = self.transform(&Self::BRADFORD).get();
+					let (source_rho, source_gamma, source_beta) = source.transform(&Self::BRADFORD).get();
+					let (dest_rho,   dest_gamma,   dest_beta)   = destination.transform(&Self::BRADFORD).get();
+
+					let cone = Self::new(
+						rho   * dest_rho   / source_rho,
+						gamma * dest_gamma / source_gamma,
+						beta  * dest_beta  / source_beta,
+					);
+
+					cone.transform(&Self::BRADFORD_INVERSE)
+				}
+
+				/// The matrix transforming linear sRGB to CIEXYZ.
+				#[allow(clippy::excessive_precision)]
+				pub(crate) const FROM_S_RGB: [[$tys; 0x3]; 0x3] = [
+					[0.412_390_799_265_950_0, 0.357_584_339_383_870_0, 0.180_480_788_401_830_0],
+					[0.212_639_005_871_510_0, 0.715_168_678_767_750_0, 0.072_192_315_360_733_0],
+					[0.019_330_818_715_591_0, 0.119_194_779_794_620_0, 0.950_532_152_249_660_0],
+				];
+
+				/// The matrix transforming CIEXYZ to linear sRGB.
+				#[allow(clippy::excessive_precision)]
+				pub(crate) const TO_S_RGB: [[$tys; 0x3]; 0x3] = [
+					[ 3.240_969_941_904_521_0, -1.537_383_177_570_093_0, -0.498_610_760_293_000_0],
+					[-0.969_243_636_280_870_0,  1.875_967_501_507_720_0,  0.041_555_057_407_175_0],
+					[ 0.055_630_079_696_993_0, -0.203_976_958_888_970_0,  1.056_971_514_242_878_0],
+				];
+
+				/// Converts an sRGB colour to CIEXYZ.
+				///
+				/// The returned colour is relative to the [D65](Self::D65) white point.
+				/// This is usable in constant expressions (see [`SRgb::untransfer`](crate::rgb::SRgb::untransfer)).
+				#[inline]
+				#[must_use]
+				pub const fn from_s_rgb(colour: ::polywave::rgb::SRgb<$tys>) -> Self {
+					let (red, green, blue) = colour.untransfer().get();
+
+					Self::new(red, green, blue).transform(&Self::FROM_S_RGB)
+				}
+
+				/// Converts the CIEXYZ colour to sRGB.
+				///
+				/// The colour is expected to be relative to the [D65](Self::D65) white point.
+				/// This is usable in constant expressions (see [`SRgb::transfer`](crate::rgb::SRgb::transfer)).
+				///
+				/// Note that the returned colour is not clamped to the sRGB gamut.
+				#[inline]
+				#[must_use]
+				pub const fn to_s_rgb(self) -> ::polywave::rgb::SRgb<$tys> {
+					let (red, green, blue) = self.transform(&Self::TO_S_RGB).get();
+
+					let colour = ::polywave::rgb::Rgb::new(red, green, blue);
+					::polywave::rgb::SRgb::<$tys>::transfer(colour)
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(feature = "f16")]
+impl_conversions!(f16);
+
+impl_conversions!(f32, f64);
+
+#[cfg(feature = "f128")]
+impl_conversions!(f128);
+
+unsafe impl<T: Component> BalancedColour for CieXyz<T> {
+	type Component = T;
+}
+
 impl<T: Component> Colour for CieXyz<T> { }
 
-impl<T: Component> DefinedGamut for CieXyz<T> { }
+impl<T: Component> DefinedGamut for CieXyz<T> {
+	type Scalar = T;
+
+	#[inline(always)]
+	fn is_in_gamut(&self, _epsilon: Self::Scalar) -> bool {
+		true
+	}
+
+	#[inline(always)]
+	fn clip(self) -> Self {
+		self
+	}
+
+	#[inline(always)]
+	fn gamut_distance(&self) -> Self::Scalar {
+		zero()
+	}
+}